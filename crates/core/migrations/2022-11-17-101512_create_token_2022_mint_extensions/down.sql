@@ -0,0 +1,3 @@
+drop trigger token_2022_mint_extensions_check_slot_wv on token_2022_mint_extensions;
+
+drop table token_2022_mint_extensions;
@@ -0,0 +1,25 @@
+create table token_2022_mint_extensions (
+  mint_address varchar(48) primary key,
+  transfer_fee_config_authority varchar(48),
+  withdraw_withheld_authority varchar(48),
+  withheld_amount numeric,
+  older_transfer_fee_epoch bigint,
+  older_maximum_fee numeric,
+  older_transfer_fee_basis_points smallint,
+  newer_transfer_fee_epoch bigint,
+  newer_maximum_fee numeric,
+  newer_transfer_fee_basis_points smallint,
+  non_transferable bool not null,
+  metadata_pointer_authority varchar(48),
+  metadata_pointer_address varchar(48),
+  permanent_delegate varchar(48),
+  slot bigint not null,
+  write_version bigint not null
+);
+
+create index token_2022_mint_extensions_metadata_pointer_address_idx
+on token_2022_mint_extensions (metadata_pointer_address);
+
+create trigger token_2022_mint_extensions_check_slot_wv
+before update on token_2022_mint_extensions for row
+execute function check_slot_wv();
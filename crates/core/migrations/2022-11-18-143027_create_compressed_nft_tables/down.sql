@@ -0,0 +1,6 @@
+drop trigger update_compressed_nft_leaves_trigger on compressed_nft_leaves;
+drop function update_compressed_nft_leaves();
+
+drop table compressed_nft_leaves;
+drop table compressed_nft_creators;
+drop table compressed_nft_metadatas;
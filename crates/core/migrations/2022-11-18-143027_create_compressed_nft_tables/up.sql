@@ -0,0 +1,65 @@
+-- Metadata is only known by its hash and tree until its leaf schema event
+-- reveals the asset ID, and identical metadata can be minted into any number
+-- of trees
+create table compressed_nft_metadatas (
+  data_hash varchar(48) not null,
+  creator_hash varchar(48) not null,
+  merkle_tree varchar(48) not null,
+  tree_delegate varchar(48) not null,
+  name text not null,
+  symbol text not null,
+  uri text not null,
+  seller_fee_basis_points integer not null,
+  primary_sale_happened bool not null,
+  is_mutable bool not null,
+  edition_nonce integer,
+  token_standard token_standard,
+  collection_address varchar(48),
+  collection_verified bool not null,
+  slot bigint not null,
+  primary key (merkle_tree, data_hash)
+);
+
+create table compressed_nft_creators (
+  merkle_tree varchar(48) not null,
+  data_hash varchar(48) not null,
+  creator_address varchar(48) not null,
+  share integer not null,
+  verified bool not null,
+  position integer not null,
+  primary key (merkle_tree, data_hash, creator_address)
+);
+
+create table compressed_nft_leaves (
+  merkle_tree varchar(48) not null,
+  leaf_index bigint not null,
+  asset_id varchar(48) not null unique,
+  owner varchar(48) not null,
+  delegate varchar(48) not null,
+  nonce bigint not null,
+  data_hash varchar(48) not null,
+  creator_hash varchar(48) not null,
+  burned bool not null default false,
+  redeemed bool not null default false,
+  slot bigint not null,
+  primary key (merkle_tree, leaf_index)
+);
+
+create index compressed_nft_leaves_owner_idx on compressed_nft_leaves (owner);
+create index compressed_nft_leaves_data_hash_idx on compressed_nft_leaves (data_hash);
+
+create function update_compressed_nft_leaves() returns trigger
+  language plpgsql
+  as $EOF$
+begin
+  if old.slot > new.slot or old.burned then
+    return old;
+  end if;
+
+  return new;
+end
+$EOF$;
+
+create trigger update_compressed_nft_leaves_trigger
+before update on compressed_nft_leaves for row
+execute function update_compressed_nft_leaves();
@@ -0,0 +1,9 @@
+drop table candy_guard_groups;
+
+drop trigger candy_guards_check_slot_wv on candy_guards;
+drop table candy_guards;
+
+drop table candy_machine_config_line_settings;
+
+drop trigger candy_machines_v3_check_slot_wv on candy_machines_v3;
+drop table candy_machines_v3;
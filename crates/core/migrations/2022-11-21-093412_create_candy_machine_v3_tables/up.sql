@@ -0,0 +1,84 @@
+create table candy_machines_v3 (
+  address varchar(48) primary key,
+  authority varchar(48) not null,
+  mint_authority varchar(48) not null,
+  collection_mint varchar(48) not null,
+  items_redeemed bigint not null,
+  items_available bigint not null,
+  symbol text not null,
+  seller_fee_basis_points smallint not null,
+  max_supply bigint not null,
+  is_mutable bool not null,
+  features bigint not null,
+  slot bigint not null,
+  write_version bigint not null
+);
+
+create index candy_machines_v3_authority_idx on candy_machines_v3 (authority);
+create index candy_machines_v3_mint_authority_idx on candy_machines_v3 (mint_authority);
+
+create trigger candy_machines_v3_check_slot_wv
+before update on candy_machines_v3 for row
+execute function check_slot_wv();
+
+create table candy_machine_config_line_settings (
+  candy_machine_address varchar(48) primary key,
+  prefix_name text not null,
+  name_length integer not null,
+  prefix_uri text not null,
+  uri_length integer not null,
+  is_sequential bool not null
+);
+
+create table candy_guards (
+  address varchar(48) primary key,
+  base varchar(48) not null,
+  bump smallint not null,
+  authority varchar(48) not null,
+  slot bigint not null,
+  write_version bigint not null
+);
+
+create index candy_guards_authority_idx on candy_guards (authority);
+
+create trigger candy_guards_check_slot_wv
+before update on candy_guards for row
+execute function check_slot_wv();
+
+create table candy_guard_groups (
+  candy_guard_address varchar(48) not null,
+  label text not null,
+  bot_tax_lamports bigint,
+  bot_tax_last_instruction bool,
+  sol_payment_lamports bigint,
+  sol_payment_destination varchar(48),
+  token_payment_amount bigint,
+  token_payment_mint varchar(48),
+  token_payment_destination_ata varchar(48),
+  start_date bigint,
+  third_party_signer_key varchar(48),
+  token_gate_amount bigint,
+  token_gate_mint varchar(48),
+  gatekeeper_network varchar(48),
+  gatekeeper_expire_on_use bool,
+  end_date bigint,
+  allow_list_merkle_root bytea,
+  mint_limit_id smallint,
+  mint_limit_limit integer,
+  nft_payment_required_collection varchar(48),
+  nft_payment_destination varchar(48),
+  redeemed_amount_maximum bigint,
+  address_gate_address varchar(48),
+  nft_gate_required_collection varchar(48),
+  nft_burn_required_collection varchar(48),
+  token_burn_amount bigint,
+  token_burn_mint varchar(48),
+  freeze_sol_payment_lamports bigint,
+  freeze_sol_payment_destination varchar(48),
+  freeze_token_payment_amount bigint,
+  freeze_token_payment_mint varchar(48),
+  freeze_token_payment_destination_ata varchar(48),
+  program_gate_additional varchar(48)[],
+  slot bigint not null,
+  primary key (candy_guard_address, label)
+);
@@ -0,0 +1,32 @@
+drop trigger rule_sets_check_slot_wv on rule_sets;
+drop table rule_sets;
+
+drop trigger metadata_programmable_configs_check_slot_wv on metadata_programmable_configs;
+drop table metadata_programmable_configs;
+
+drop trigger token_records_check_slot_wv on token_records;
+drop table token_records;
+
+drop type token_delegate_role;
+drop type token_record_state;
+
+-- Postgres can't drop a single enum value, so rebuild token_standard without
+-- the programmable variant
+update metadatas set token_standard = null
+where token_standard = 'ProgrammableNonFungible';
+
+update compressed_nft_metadatas set token_standard = null
+where token_standard = 'ProgrammableNonFungible';
+
+alter type token_standard rename to token_standard_old;
+
+create type token_standard
+as enum ('NonFungible', 'FungibleAsset', 'Fungible', 'NonFungibleEdition');
+
+alter table metadatas alter column token_standard
+type token_standard using token_standard::text::token_standard;
+
+alter table compressed_nft_metadatas alter column token_standard
+type token_standard using token_standard::text::token_standard;
+
+drop type token_standard_old;
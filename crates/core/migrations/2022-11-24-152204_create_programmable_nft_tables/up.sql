@@ -0,0 +1,54 @@
+alter type token_standard add value 'ProgrammableNonFungible';
+
+create type token_record_state
+as enum ('Unlocked', 'Locked', 'Listed');
+
+create type token_delegate_role
+as enum ('Sale', 'Transfer', 'Utility', 'Staking', 'Standard', 'LockedTransfer', 'Migration');
+
+create table token_records (
+  address varchar(48) primary key,
+  bump smallint not null,
+  state token_record_state not null,
+  rule_set_revision bigint,
+  delegate varchar(48),
+  delegate_role token_delegate_role,
+  locked_transfer varchar(48),
+  slot bigint not null,
+  write_version bigint not null
+);
+
+create index token_records_delegate_idx on token_records (delegate);
+
+create trigger token_records_check_slot_wv
+before update on token_records for row
+execute function check_slot_wv();
+
+create table metadata_programmable_configs (
+  metadata_address varchar(48) primary key,
+  rule_set varchar(48),
+  slot bigint not null,
+  write_version bigint not null
+);
+
+create index metadata_programmable_configs_rule_set_idx
+on metadata_programmable_configs (rule_set);
+
+create trigger metadata_programmable_configs_check_slot_wv
+before update on metadata_programmable_configs for row
+execute function check_slot_wv();
+
+create table rule_sets (
+  address varchar(48) primary key,
+  owner varchar(48) not null,
+  name text not null,
+  revision bigint not null,
+  slot bigint not null,
+  write_version bigint not null
+);
+
+create index rule_sets_owner_idx on rule_sets (owner);
+
+create trigger rule_sets_check_slot_wv
+before update on rule_sets for row
+execute function check_slot_wv();
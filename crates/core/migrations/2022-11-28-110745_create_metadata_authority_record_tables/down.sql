@@ -0,0 +1,8 @@
+drop trigger edition_markers_check_slot_wv on edition_markers;
+drop table edition_markers;
+
+drop trigger use_authority_records_check_slot_wv on use_authority_records;
+drop table use_authority_records;
+
+drop trigger collection_authority_records_check_slot_wv on collection_authority_records;
+drop table collection_authority_records;
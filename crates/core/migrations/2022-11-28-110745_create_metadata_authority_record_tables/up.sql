@@ -0,0 +1,61 @@
+-- Record accounts don't store the mint or authority they were derived from,
+-- so those columns are filled in from the approving instruction and the
+-- account columns from account updates, in whichever order they arrive
+
+create table collection_authority_records (
+  address varchar(48) primary key,
+  bump smallint,
+  collection_authority varchar(48),
+  metadata_address varchar(48),
+  mint_address varchar(48),
+  slot bigint,
+  write_version bigint
+);
+
+create index collection_authority_records_collection_authority_idx
+on collection_authority_records (collection_authority);
+
+create index collection_authority_records_metadata_address_idx
+on collection_authority_records (metadata_address);
+
+create trigger collection_authority_records_check_slot_wv
+before update on collection_authority_records for row
+execute function check_slot_wv();
+
+create table use_authority_records (
+  address varchar(48) primary key,
+  allowed_uses bigint,
+  bump smallint,
+  use_authority varchar(48),
+  owner varchar(48),
+  metadata_address varchar(48),
+  mint_address varchar(48),
+  slot bigint,
+  write_version bigint
+);
+
+create index use_authority_records_use_authority_idx
+on use_authority_records (use_authority);
+
+create index use_authority_records_metadata_address_idx
+on use_authority_records (metadata_address);
+
+create trigger use_authority_records_check_slot_wv
+before update on use_authority_records for row
+execute function check_slot_wv();
+
+create table edition_markers (
+  address varchar(48) primary key,
+  ledger bytea,
+  master_edition_address varchar(48),
+  marker_index bigint,
+  slot bigint,
+  write_version bigint
+);
+
+create index edition_markers_master_edition_address_idx
+on edition_markers (master_edition_address);
+
+create trigger edition_markers_check_slot_wv
+before update on edition_markers for row
+execute function check_slot_wv();
@@ -0,0 +1,6 @@
+drop table burn_instructions;
+drop table sign_metadata_instructions;
+drop table unverify_collection_instructions;
+drop table set_and_verify_sized_collection_item_instructions;
+drop table verify_collection_instructions;
+drop table update_metadata_account_instructions;
@@ -0,0 +1,160 @@
+-- Instruction notifications carry no transaction signature, so redelivered
+-- instructions are deduplicated on their slot and every field they carry.
+-- Nullable fields are coalesced since nulls never conflict.
+
+create table update_metadata_account_instructions (
+    id                                       uuid            primary key default gen_random_uuid(),
+    metadata                                 varchar(48)     not null,
+    update_authority                         varchar(48)     not null,
+    name                                     text            null,
+    symbol                                   text            null,
+    uri                                      text            null,
+    seller_fee_basis_points                  integer         null,
+    new_update_authority                     varchar(48)     null,
+    primary_sale_happened                    boolean         null,
+    is_mutable                               boolean         null,
+    created_at                               timestamp       not null,
+    slot                                     bigint          not null
+);
+
+create index if not exists update_metadata_account_ins_metadata_idx
+  on update_metadata_account_instructions using hash (metadata);
+
+create unique index if not exists update_metadata_account_ins_unique_idx
+  on update_metadata_account_instructions (
+    metadata,
+    slot,
+    update_authority,
+    coalesce(name, ''),
+    coalesce(symbol, ''),
+    coalesce(uri, ''),
+    coalesce(seller_fee_basis_points, -1),
+    coalesce(new_update_authority, ''),
+    coalesce(primary_sale_happened::integer, -1),
+    coalesce(is_mutable::integer, -1)
+  );
+
+create table verify_collection_instructions (
+    id                                       uuid            primary key default gen_random_uuid(),
+    metadata                                 varchar(48)     not null,
+    collection_authority                     varchar(48)     not null,
+    payer                                    varchar(48)     not null,
+    collection_mint                          varchar(48)     not null,
+    collection_metadata                      varchar(48)     not null,
+    collection_master_edition                varchar(48)     not null,
+    collection_authority_record              varchar(48)     null,
+    created_at                               timestamp       not null,
+    slot                                     bigint          not null
+);
+
+create index if not exists verify_collection_ins_metadata_idx
+  on verify_collection_instructions using hash (metadata);
+
+create unique index if not exists verify_collection_ins_unique_idx
+  on verify_collection_instructions (
+    metadata,
+    slot,
+    collection_authority,
+    payer,
+    collection_mint,
+    collection_metadata,
+    collection_master_edition,
+    coalesce(collection_authority_record, '')
+  );
+
+create table set_and_verify_sized_collection_item_instructions (
+    id                                       uuid            primary key default gen_random_uuid(),
+    metadata                                 varchar(48)     not null,
+    collection_authority                     varchar(48)     not null,
+    payer                                    varchar(48)     not null,
+    update_authority                         varchar(48)     not null,
+    collection_mint                          varchar(48)     not null,
+    collection_metadata                      varchar(48)     not null,
+    collection_master_edition                varchar(48)     not null,
+    collection_authority_record              varchar(48)     null,
+    created_at                               timestamp       not null,
+    slot                                     bigint          not null
+);
+
+create index if not exists set_and_verify_sized_collection_item_ins_metadata_idx
+  on set_and_verify_sized_collection_item_instructions using hash (metadata);
+
+create unique index if not exists set_and_verify_sized_collection_item_ins_unique_idx
+  on set_and_verify_sized_collection_item_instructions (
+    metadata,
+    slot,
+    collection_authority,
+    payer,
+    update_authority,
+    collection_mint,
+    collection_metadata,
+    collection_master_edition,
+    coalesce(collection_authority_record, '')
+  );
+
+create table unverify_collection_instructions (
+    id                                       uuid            primary key default gen_random_uuid(),
+    metadata                                 varchar(48)     not null,
+    collection_authority                     varchar(48)     not null,
+    collection_mint                          varchar(48)     not null,
+    collection_metadata                      varchar(48)     not null,
+    collection_master_edition                varchar(48)     not null,
+    collection_authority_record              varchar(48)     null,
+    created_at                               timestamp       not null,
+    slot                                     bigint          not null
+);
+
+create index if not exists unverify_collection_ins_metadata_idx
+  on unverify_collection_instructions using hash (metadata);
+
+create unique index if not exists unverify_collection_ins_unique_idx
+  on unverify_collection_instructions (
+    metadata,
+    slot,
+    collection_authority,
+    collection_mint,
+    collection_metadata,
+    collection_master_edition,
+    coalesce(collection_authority_record, '')
+  );
+
+create table sign_metadata_instructions (
+    id                                       uuid            primary key default gen_random_uuid(),
+    metadata                                 varchar(48)     not null,
+    creator                                  varchar(48)     not null,
+    created_at                               timestamp       not null,
+    slot                                     bigint          not null,
+
+    unique (metadata, slot, creator)
+);
+
+create index if not exists sign_metadata_ins_metadata_idx
+  on sign_metadata_instructions using hash (metadata);
+
+create table burn_instructions (
+    id                                       uuid            primary key default gen_random_uuid(),
+    metadata                                 varchar(48)     not null,
+    owner                                    varchar(48)     not null,
+    mint                                     varchar(48)     not null,
+    token_account                            varchar(48)     not null,
+    edition                                  varchar(48)     null,
+    collection_metadata                      varchar(48)     null,
+    amount                                   bigint          not null,
+    created_at                               timestamp       not null,
+    slot                                     bigint          not null
+);
+
+create index if not exists burn_ins_metadata_idx
+  on burn_instructions using hash (metadata);
+
+create unique index if not exists burn_ins_unique_idx
+  on burn_instructions (
+    metadata,
+    slot,
+    owner,
+    mint,
+    token_account,
+    coalesce(edition, ''),
+    coalesce(collection_metadata, ''),
+    amount
+  );
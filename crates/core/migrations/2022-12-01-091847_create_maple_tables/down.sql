@@ -0,0 +1,6 @@
+drop table maple_withdrawal_requests;
+drop table maple_open_term_loans;
+drop table maple_loans;
+drop table maple_lenders;
+drop table maple_pools;
+drop table maple_globals;
@@ -0,0 +1,93 @@
+create table maple_globals (
+  address varchar(48) primary key,
+  admin varchar(48) not null,
+  slot bigint not null,
+  write_version bigint not null
+);
+
+create trigger maple_globals_check_slot_wv
+before update on maple_globals for row
+execute function check_slot_wv();
+
+create table maple_pools (
+  address varchar(48) primary key,
+  globals varchar(48) not null,
+  pool_delegate varchar(48) not null,
+  base_mint varchar(48) not null,
+  shares_mint varchar(48) not null,
+  locker varchar(48) not null,
+  total_value bigint not null,
+  slot bigint not null,
+  write_version bigint not null
+);
+
+create index maple_pools_pool_delegate_idx on maple_pools (pool_delegate);
+
+create trigger maple_pools_check_slot_wv
+before update on maple_pools for row
+execute function check_slot_wv();
+
+create table maple_lenders (
+  address varchar(48) primary key,
+  owner varchar(48) not null,
+  pool varchar(48) not null,
+  slot bigint not null,
+  write_version bigint not null
+);
+
+create index maple_lenders_owner_idx on maple_lenders (owner);
+create index maple_lenders_pool_idx on maple_lenders (pool);
+
+create trigger maple_lenders_check_slot_wv
+before update on maple_lenders for row
+execute function check_slot_wv();
+
+create table maple_loans (
+  address varchar(48) primary key,
+  pool varchar(48) not null,
+  borrower varchar(48) not null,
+  principal bigint not null,
+  state text not null,
+  slot bigint not null,
+  write_version bigint not null
+);
+
+create index maple_loans_pool_idx on maple_loans (pool);
+create index maple_loans_borrower_idx on maple_loans (borrower);
+
+create trigger maple_loans_check_slot_wv
+before update on maple_loans for row
+execute function check_slot_wv();
+
+create table maple_open_term_loans (
+  address varchar(48) primary key,
+  pool varchar(48) not null,
+  borrower varchar(48) not null,
+  principal bigint not null,
+  state text not null,
+  slot bigint not null,
+  write_version bigint not null
+);
+
+create index maple_open_term_loans_pool_idx on maple_open_term_loans (pool);
+create index maple_open_term_loans_borrower_idx on maple_open_term_loans (borrower);
+
+create trigger maple_open_term_loans_check_slot_wv
+before update on maple_open_term_loans for row
+execute function check_slot_wv();
+
+create table maple_withdrawal_requests (
+  address varchar(48) primary key,
+  pool varchar(48) not null,
+  lender varchar(48) not null,
+  shares bigint not null,
+  slot bigint not null,
+  write_version bigint not null
+);
+
+create index maple_withdrawal_requests_pool_idx on maple_withdrawal_requests (pool);
+create index maple_withdrawal_requests_lender_idx on maple_withdrawal_requests (lender);
+
+create trigger maple_withdrawal_requests_check_slot_wv
+before update on maple_withdrawal_requests for row
+execute function check_slot_wv();
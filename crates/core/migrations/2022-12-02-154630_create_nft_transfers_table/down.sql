@@ -0,0 +1 @@
+drop table nft_transfers;
@@ -0,0 +1,25 @@
+create table nft_transfers (
+    id                                       uuid            primary key default gen_random_uuid(),
+    mint_address                             varchar(48)     not null,
+    source_token_account                     varchar(48)     not null,
+    destination_token_account                varchar(48)     not null,
+    source_owner                             varchar(48)     null,
+    destination_owner                        varchar(48)     null,
+    authority                                varchar(48)     not null,
+    created_at                               timestamp       not null,
+    slot                                     bigint          not null,
+
+    unique (mint_address, slot, source_token_account, destination_token_account)
+);
+
+create index if not exists nft_transfers_mint_address_idx
+  on nft_transfers using hash (mint_address);
+
+create index if not exists nft_transfers_source_owner_idx
+  on nft_transfers using hash (source_owner);
+
+create index if not exists nft_transfers_destination_owner_idx
+  on nft_transfers using hash (destination_owner);
+
+create index if not exists nft_transfers_destination_token_account_idx
+  on nft_transfers (destination_token_account) where destination_owner is null;
@@ -0,0 +1,3 @@
+drop table slots;
+
+drop type slot_status;
@@ -0,0 +1,12 @@
+create type slot_status
+as enum ('Processed', 'Confirmed', 'Rooted');
+
+create table slots (
+  slot bigint primary key,
+  parent_slot bigint,
+  status slot_status not null,
+  block_time timestamp,
+  updated_at timestamp not null default now()
+);
+
+create index slots_parent_slot_idx on slots (parent_slot);
@@ -0,0 +1,8 @@
+create table pending_messages (
+  id bigserial primary key,
+  slot bigint not null,
+  message jsonb not null,
+  created_at timestamp not null default now()
+);
+
+create index pending_messages_slot_idx on pending_messages (slot);
@@ -0,0 +1,2 @@
+drop trigger auctioneer_listing_configs_check_slot_wv on auctioneer_listing_configs;
+drop table auctioneer_listing_configs;
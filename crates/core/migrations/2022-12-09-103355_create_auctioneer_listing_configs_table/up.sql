@@ -0,0 +1,32 @@
+-- Listing configs don't store the listing they belong to, so the seller,
+-- NFT and trade state are filled in from the sell instruction and the auction
+-- state from account updates, in whichever order they arrive
+
+create table auctioneer_listing_configs (
+  address varchar(48) primary key,
+  auction_house varchar(48),
+  seller varchar(48),
+  metadata varchar(48),
+  trade_state varchar(48),
+  start_time timestamp not null,
+  end_time timestamp not null,
+  reserve_price bigint not null,
+  min_bid_increment bigint not null,
+  time_ext_period bigint not null,
+  time_ext_delta bigint not null,
+  allow_high_bid_cancel bool not null,
+  highest_bid bigint,
+  highest_bid_trade_state varchar(48),
+  slot bigint not null,
+  write_version bigint
+);
+
+create index auctioneer_listing_configs_trade_state_idx
+on auctioneer_listing_configs (trade_state);
+
+create index auctioneer_listing_configs_metadata_idx
+on auctioneer_listing_configs (metadata);
+
+create trigger auctioneer_listing_configs_check_slot_wv
+before update on auctioneer_listing_configs for row
+execute function check_slot_wv();
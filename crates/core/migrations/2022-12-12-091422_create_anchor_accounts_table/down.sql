@@ -0,0 +1,2 @@
+drop trigger anchor_accounts_check_slot_wv on anchor_accounts;
+drop table anchor_accounts;
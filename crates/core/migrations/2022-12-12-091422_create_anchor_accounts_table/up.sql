@@ -0,0 +1,17 @@
+-- Accounts of programs indexed from an Anchor IDL rather than a dedicated
+-- processor, decoded into JSON
+create table anchor_accounts (
+  address varchar(48) primary key,
+  program varchar(48) not null,
+  account_type text not null,
+  data jsonb not null,
+  slot bigint not null,
+  write_version bigint not null
+);
+
+create index anchor_accounts_program_account_type_idx
+on anchor_accounts (program, account_type);
+
+create trigger anchor_accounts_check_slot_wv
+before update on anchor_accounts for row
+execute function check_slot_wv();
@@ -0,0 +1,2 @@
+alter table current_metadata_owners
+drop column write_version;
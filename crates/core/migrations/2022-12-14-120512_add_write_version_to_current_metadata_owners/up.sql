@@ -0,0 +1,2 @@
+alter table current_metadata_owners
+add column write_version bigint not null default 0;
@@ -112,6 +112,9 @@ pub enum TokenStandardEnum {
     Fungible,
     /// This is a limited edition
     NonFungibleEdition,
+    /// A master edition whose transfers are subject to a token-auth-rules
+    /// rule set
+    ProgrammableNonFungible,
 }
 
 impl ToSql<TokenStandard, Pg> for TokenStandardEnum {
@@ -610,3 +613,107 @@ impl FromSql<PayoutOperation, Pg> for PayoutOperationEnum {
         from_bytes(bytes)
     }
 }
+
+#[derive(SqlType, Debug, Clone, Copy)]
+/// Represents database `token_record_state` enum
+#[postgres(type_name = "token_record_state")]
+pub struct TokenRecordState;
+
+#[derive(
+    Debug, PartialEq, FromSqlRow, AsExpression, Clone, Copy, strum::EnumString, strum::Display,
+)]
+#[sql_type = "TokenRecordState"]
+/// Lock state of a programmable NFT's token account
+pub enum TokenRecordStateEnum {
+    /// The token can be transferred and burned
+    Unlocked,
+    /// The token has been locked by a delegate
+    Locked,
+    /// The token has been delegated for sale
+    Listed,
+}
+
+impl ToSql<TokenRecordState, Pg> for TokenRecordStateEnum {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        to_bytes(self, out, |_| false)
+    }
+}
+
+impl FromSql<TokenRecordState, Pg> for TokenRecordStateEnum {
+    fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
+        from_bytes(bytes)
+    }
+}
+
+#[derive(SqlType, Debug, Clone, Copy)]
+/// Represents database `token_delegate_role` enum
+#[postgres(type_name = "token_delegate_role")]
+pub struct TokenDelegateRole;
+
+#[derive(
+    Debug, PartialEq, FromSqlRow, AsExpression, Clone, Copy, strum::EnumString, strum::Display,
+)]
+#[sql_type = "TokenDelegateRole"]
+#[allow(missing_docs)]
+pub enum TokenDelegateRoleEnum {
+    Sale,
+    Transfer,
+    Utility,
+    Staking,
+    Standard,
+    LockedTransfer,
+    Migration,
+}
+
+impl ToSql<TokenDelegateRole, Pg> for TokenDelegateRoleEnum {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        to_bytes(self, out, |_| false)
+    }
+}
+
+impl FromSql<TokenDelegateRole, Pg> for TokenDelegateRoleEnum {
+    fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
+        from_bytes(bytes)
+    }
+}
+
+#[derive(SqlType, Debug, Clone, Copy)]
+/// Represents database `slot_status` enum
+#[postgres(type_name = "slot_status")]
+pub struct SlotStatus;
+
+#[derive(
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    FromSqlRow,
+    AsExpression,
+    Clone,
+    Copy,
+    strum::EnumString,
+    strum::Display,
+)]
+#[sql_type = "SlotStatus"]
+/// Commitment level reached by a slot, in increasing order of finality
+pub enum SlotStatusEnum {
+    /// The slot has been processed by the validator
+    Processed,
+    /// The slot has been confirmed by a supermajority of the cluster
+    Confirmed,
+    /// The slot has been rooted and can no longer be rolled back
+    Rooted,
+}
+
+impl ToSql<SlotStatus, Pg> for SlotStatusEnum {
+    fn to_sql<W: Write>(&self, out: &mut Output<W, Pg>) -> serialize::Result {
+        to_bytes(self, out, |_| false)
+    }
+}
+
+impl FromSql<SlotStatus, Pg> for SlotStatusEnum {
+    fn from_sql(bytes: Option<&[u8]>) -> deserialize::Result<Self> {
+        from_bytes(bytes)
+    }
+}
@@ -4,6 +4,7 @@ pub mod custom_types;
 pub mod models;
 pub mod mutations;
 pub mod queries;
+pub mod versioned;
 
 #[allow(missing_docs, unused_imports)]
 mod schema;
@@ -45,6 +46,8 @@ pub type ConnectionManager = r2d2::ConnectionManager<Connection>;
 pub type Pool = r2d2::Pool<ConnectionManager>;
 /// Pooled Postgres connection
 pub type PooledConnection = r2d2::PooledConnection<ConnectionManager>;
+/// Error acquiring a connection from the pool
+pub type PoolError = r2d2::PoolError;
 
 /// Hint indicating how the database should be connected
 #[derive(Debug, Clone, Copy)]
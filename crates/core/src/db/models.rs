@@ -18,9 +18,9 @@ use crate::db::custom_types::{
     InstructionExecutionFlagsEnum, ListingEventLifecycle, ListingEventLifecycleEnum,
     MintMaxVoteEnum, OfferEventLifecycle, OfferEventLifecycleEnum, OptionVoteResultEnum,
     PayoutOperationEnum, ProposalState, ProposalStateEnum, ProposalVoteType, ProposalVoteTypeEnum,
-    TokenStandardEnum, TransactionExecutionStatusEnum, VoteRecordV2Vote, VoteRecordV2VoteEnum,
-    VoteThresholdEnum, VoteThresholdType, VoteTippingEnum, VoteWeightV1, VoteWeightV1Enum,
-    WhitelistMintMode,
+    SlotStatusEnum, TokenDelegateRoleEnum, TokenRecordStateEnum, TokenStandardEnum,
+    TransactionExecutionStatusEnum, VoteRecordV2Vote, VoteRecordV2VoteEnum, VoteThresholdEnum,
+    VoteThresholdType, VoteTippingEnum, VoteWeightV1, VoteWeightV1Enum, WhitelistMintMode,
 };
 
 /* HPL LISTING REWARDS */
@@ -448,7 +448,8 @@ pub struct Nft {
     pub token_account_address: String,
 }
 
-/// Union of `listings` and `purchases` for an `NFTActivity`
+/// Union of `listings`, `purchases`, `offers` and metadata program
+/// instructions for an `NFTActivity`
 #[derive(Debug, Clone, Queryable, QueryableByName)]
 pub struct NftActivity {
     /// The id of the activity
@@ -459,17 +460,17 @@ pub struct NftActivity {
     #[sql_type = "VarChar"]
     pub metadata: String,
 
-    /// The auction house activity generated from
-    #[sql_type = "VarChar"]
-    pub auction_house: String,
+    /// The auction house activity generated from, if any
+    #[sql_type = "Nullable<VarChar>"]
+    pub auction_house: Option<String>,
 
     /// The marketplace program pubkey
     #[sql_type = "VarChar"]
     pub marketplace_program: String,
 
-    /// The price of listing or purchase
-    #[sql_type = "Int8"]
-    pub price: i64,
+    /// The price of listing or purchase, if any
+    #[sql_type = "Nullable<Int8>"]
+    pub price: Option<i64>,
 
     /// Listing/Purchase created time
     #[sql_type = "Timestamp"]
@@ -649,7 +650,8 @@ pub struct CollectionVolume {
     pub volume: BigDecimal,
 }
 
-/// Union of `listings`, `purchases`, `sell` and 'offers' for a `WalletActivity`
+/// Union of `listings`, `purchases`, `sell`, 'offers' and `nft_transfers` for
+/// a `WalletActivity`
 #[derive(Debug, Clone, Queryable, QueryableByName)]
 pub struct WalletActivity {
     /// The id of the activity
@@ -660,17 +662,17 @@ pub struct WalletActivity {
     #[sql_type = "VarChar"]
     pub metadata: String,
 
-    /// The auction house activity generated from
-    #[sql_type = "VarChar"]
-    pub auction_house: String,
+    /// The auction house activity generated from, if any
+    #[sql_type = "Nullable<VarChar>"]
+    pub auction_house: Option<String>,
 
     /// The marketplace program pubkey
     #[sql_type = "VarChar"]
     pub marketplace_program: String,
 
-    /// The price of listing or purchase
-    #[sql_type = "Int8"]
-    pub price: i64,
+    /// The price of listing or purchase, if any
+    #[sql_type = "Nullable<Int8>"]
+    pub price: Option<i64>,
 
     /// Listing/Purchase created time
     #[sql_type = "Timestamp"]
@@ -684,7 +686,7 @@ pub struct WalletActivity {
     #[sql_type = "Array<Nullable<Text>>"]
     pub wallet_twitter_handles: Vec<Option<String>>,
 
-    /// Activity type - listing, purchase, sell or offer
+    /// Activity type - listing, purchase, sell, offer or transfer
     #[sql_type = "Text"]
     pub activity_type: String,
 }
@@ -1319,6 +1321,150 @@ pub struct CMEndSetting<'a> {
     pub number: i64,
 }
 
+/// A row in the `candy_machines_v3` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+#[table_name = "candy_machines_v3"]
+pub struct CandyMachineV3<'a> {
+    /// CandyMachine account address
+    pub address: Cow<'a, str>,
+    /// CandyMachine 'Authority' address
+    pub authority: Cow<'a, str>,
+    /// The authority allowed to mint, usually a candy guard
+    pub mint_authority: Cow<'a, str>,
+    /// The collection mint of all items minted from this candy machine
+    pub collection_mint: Cow<'a, str>,
+    /// Items redeemed
+    pub items_redeemed: i64,
+    /// Number of items available
+    pub items_available: i64,
+    /// Symbol
+    pub symbol: Cow<'a, str>,
+    /// Royalty basis points that goes to creators in secondary sales (0-10000)
+    pub seller_fee_basis_points: i16,
+    /// Max supply of each individual mint
+    pub max_supply: i64,
+    /// Whether or not minted metadata are mutable
+    pub is_mutable: bool,
+    /// Feature flags of the account
+    pub features: i64,
+    /// The slot number of this account's last known update
+    pub slot: i64,
+    /// The write version of this account's last known update
+    pub write_version: i64,
+}
+
+/// A row in the `candy_machine_config_line_settings` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+#[table_name = "candy_machine_config_line_settings"]
+pub struct CMConfigLineSetting<'a> {
+    /// CandyMachine account address
+    pub candy_machine_address: Cow<'a, str>,
+    /// Common prefix of all config line names
+    pub prefix_name: Cow<'a, str>,
+    /// Length of the name stored in each config line
+    pub name_length: i32,
+    /// Common prefix of all config line URIs
+    pub prefix_uri: Cow<'a, str>,
+    /// Length of the URI stored in each config line
+    pub uri_length: i32,
+    /// Whether items are minted in order rather than at random
+    pub is_sequential: bool,
+}
+
+/// A row in the `candy_guards` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct CandyGuard<'a> {
+    /// CandyGuard account address
+    pub address: Cow<'a, str>,
+    /// The base key used to derive this candy guard
+    pub base: Cow<'a, str>,
+    /// Bump seed of this candy guard
+    pub bump: i16,
+    /// CandyGuard 'Authority' address
+    pub authority: Cow<'a, str>,
+    /// The slot number of this account's last known update
+    pub slot: i64,
+    /// The write version of this account's last known update
+    pub write_version: i64,
+}
+
+/// A row in the `candy_guard_groups` table, holding a single guard set
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct CandyGuardGroup<'a> {
+    /// CandyGuard account address
+    pub candy_guard_address: Cow<'a, str>,
+    /// Label of the group, empty for the default guard set
+    pub label: Cow<'a, str>,
+    /// Lamports charged when a mint fails a guard
+    pub bot_tax_lamports: Option<i64>,
+    /// Whether the mint must be the last instruction of the transaction
+    pub bot_tax_last_instruction: Option<bool>,
+    /// Price of a mint in lamports
+    pub sol_payment_lamports: Option<i64>,
+    /// Wallet receiving SOL payments
+    pub sol_payment_destination: Option<Cow<'a, str>>,
+    /// Price of a mint in SPL tokens
+    pub token_payment_amount: Option<i64>,
+    /// Mint of the token used for payment
+    pub token_payment_mint: Option<Cow<'a, str>>,
+    /// Token account receiving SPL token payments
+    pub token_payment_destination_ata: Option<Cow<'a, str>>,
+    /// Unix timestamp at which minting starts
+    pub start_date: Option<i64>,
+    /// Key required to sign every mint
+    pub third_party_signer_key: Option<Cow<'a, str>>,
+    /// Amount of tokens a minter must hold
+    pub token_gate_amount: Option<i64>,
+    /// Mint of the token a minter must hold
+    pub token_gate_mint: Option<Cow<'a, str>>,
+    /// Gatekeeper network a minter must hold a valid pass for
+    pub gatekeeper_network: Option<Cow<'a, str>>,
+    /// Whether the gateway pass expires after minting
+    pub gatekeeper_expire_on_use: Option<bool>,
+    /// Unix timestamp at which minting ends
+    pub end_date: Option<i64>,
+    /// Merkle root of the allowed minter addresses
+    pub allow_list_merkle_root: Option<Vec<u8>>,
+    /// Identifier of the mint limit counter
+    pub mint_limit_id: Option<i16>,
+    /// Maximum number of mints per wallet
+    pub mint_limit_limit: Option<i32>,
+    /// Collection of the NFT to pay with
+    pub nft_payment_required_collection: Option<Cow<'a, str>>,
+    /// Wallet receiving NFT payments
+    pub nft_payment_destination: Option<Cow<'a, str>>,
+    /// Number of redeemed items at which minting ends
+    pub redeemed_amount_maximum: Option<i64>,
+    /// The only address allowed to mint
+    pub address_gate_address: Option<Cow<'a, str>>,
+    /// Collection of the NFT a minter must hold
+    pub nft_gate_required_collection: Option<Cow<'a, str>>,
+    /// Collection of the NFT a minter must burn
+    pub nft_burn_required_collection: Option<Cow<'a, str>>,
+    /// Amount of tokens a minter must burn
+    pub token_burn_amount: Option<i64>,
+    /// Mint of the token a minter must burn
+    pub token_burn_mint: Option<Cow<'a, str>>,
+    /// Price of a mint in lamports, held in escrow until thawed
+    pub freeze_sol_payment_lamports: Option<i64>,
+    /// Wallet receiving frozen SOL payments
+    pub freeze_sol_payment_destination: Option<Cow<'a, str>>,
+    /// Price of a mint in SPL tokens, held in escrow until thawed
+    pub freeze_token_payment_amount: Option<i64>,
+    /// Mint of the token used for frozen payment
+    pub freeze_token_payment_mint: Option<Cow<'a, str>>,
+    /// Token account receiving frozen SPL token payments
+    pub freeze_token_payment_destination_ata: Option<Cow<'a, str>>,
+    /// Additional programs allowed in a mint transaction
+    pub program_gate_additional: Option<Vec<String>>,
+    /// The slot number of this account's last known update
+    pub slot: i64,
+}
+
 /// A row in a `mint_stats` query, representing stats for a single token type
 /// identified by its mint
 #[derive(Debug, Clone, QueryableByName)]
@@ -2250,6 +2396,610 @@ pub struct CurrentMetadataOwner<'a> {
     /// Solana slot number
     /// The period of time for which each leader ingests transactions and produces a block.
     pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: i64,
+}
+
+/// A row in the `token_2022_mint_extensions` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+#[table_name = "token_2022_mint_extensions"]
+pub struct Token2022MintExtensions<'a> {
+    /// The address of the mint account
+    pub mint_address: Cow<'a, str>,
+    /// The authority allowed to change the transfer fee, if any
+    pub transfer_fee_config_authority: Option<Cow<'a, str>>,
+    /// The authority allowed to withdraw withheld transfer fees, if any
+    pub withdraw_withheld_authority: Option<Cow<'a, str>>,
+    /// Transfer fees withheld on the mint itself
+    pub withheld_amount: Option<BigDecimal>,
+    /// The first epoch of the older transfer fee schedule
+    pub older_transfer_fee_epoch: Option<i64>,
+    /// The maximum fee of the older transfer fee schedule
+    pub older_maximum_fee: Option<BigDecimal>,
+    /// The fee of the older transfer fee schedule, in basis points
+    pub older_transfer_fee_basis_points: Option<i16>,
+    /// The first epoch of the newer transfer fee schedule
+    pub newer_transfer_fee_epoch: Option<i64>,
+    /// The maximum fee of the newer transfer fee schedule
+    pub newer_maximum_fee: Option<BigDecimal>,
+    /// The fee of the newer transfer fee schedule, in basis points
+    pub newer_transfer_fee_basis_points: Option<i16>,
+    /// True if tokens of this mint cannot be transferred
+    pub non_transferable: bool,
+    /// The authority allowed to change the metadata pointer, if any
+    pub metadata_pointer_authority: Option<Cow<'a, str>>,
+    /// The account holding metadata for this mint, if any
+    pub metadata_pointer_address: Option<Cow<'a, str>>,
+    /// The permanent delegate of all token accounts for this mint, if any
+    pub permanent_delegate: Option<Cow<'a, str>>,
+    /// The slot number of this account's last known update
+    pub slot: i64,
+    /// The write version of this account's last known update
+    pub write_version: i64,
+}
+
+/// A row in the `compressed_nft_metadatas` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct CompressedNftMetadata<'a> {
+    /// The hash of the metadata arguments stored in the leaf
+    pub data_hash: Cow<'a, str>,
+    /// The hash of the creators stored in the leaf
+    pub creator_hash: Cow<'a, str>,
+    /// The merkle tree this metadata was minted into
+    pub merkle_tree: Cow<'a, str>,
+    /// The tree delegate that minted this item
+    pub tree_delegate: Cow<'a, str>,
+    /// The name of this item
+    pub name: Cow<'a, str>,
+    /// The symbol for this item
+    pub symbol: Cow<'a, str>,
+    /// The URI for the off-chain item data
+    pub uri: Cow<'a, str>,
+    /// The royalty percentage of the creator, in basis points
+    pub seller_fee_basis_points: i32,
+    /// True if this item is in the secondary market
+    pub primary_sale_happened: bool,
+    /// True if this item can be changed by the update authority
+    pub is_mutable: bool,
+    /// The edition nonce of this item, if any
+    pub edition_nonce: Option<i32>,
+    /// Type of NFT token
+    pub token_standard: Option<TokenStandardEnum>,
+    /// The collection this item belongs to, if any
+    pub collection_address: Option<Cow<'a, str>>,
+    /// Whether the collection has been verified
+    pub collection_verified: bool,
+    /// Solana slot number
+    pub slot: i64,
+}
+
+/// A row in the `compressed_nft_creators` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct CompressedNftCreator<'a> {
+    /// The merkle tree of this record's compressed metadata
+    pub merkle_tree: Cow<'a, str>,
+    /// The data hash of this record's compressed metadata
+    pub data_hash: Cow<'a, str>,
+    /// The address of this record's creator wallet
+    pub creator_address: Cow<'a, str>,
+    /// The share of the creator, in percentage points
+    pub share: i32,
+    /// Whether this creator has verified this metadata
+    pub verified: bool,
+    /// Position of creator in metadata creator array
+    pub position: i32,
+}
+
+/// A row in the `compressed_nft_leaves` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct CompressedNftLeaf<'a> {
+    /// The merkle tree containing this leaf
+    pub merkle_tree: Cow<'a, str>,
+    /// The index of this leaf in the tree
+    pub leaf_index: i64,
+    /// The asset ID derived from the tree and nonce
+    pub asset_id: Cow<'a, str>,
+    /// The owner of this leaf
+    pub owner: Cow<'a, str>,
+    /// The delegate of this leaf, equal to the owner if none is set
+    pub delegate: Cow<'a, str>,
+    /// The nonce used to derive the asset ID
+    pub nonce: i64,
+    /// The hash of the metadata arguments stored in the leaf
+    pub data_hash: Cow<'a, str>,
+    /// The hash of the creators stored in the leaf
+    pub creator_hash: Cow<'a, str>,
+    /// True if this leaf has been burned
+    pub burned: bool,
+    /// True if this leaf has been redeemed for decompression
+    pub redeemed: bool,
+    /// Solana slot number
+    pub slot: i64,
+}
+
+/// A row in the `token_records` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct TokenRecord<'a> {
+    /// The address of this account
+    pub address: Cow<'a, str>,
+    /// The bump seed of this PDA
+    pub bump: i16,
+    /// The lock state of the programmable NFT's token account
+    pub state: TokenRecordStateEnum,
+    /// The revision of the rule set cached when the delegate was approved
+    pub rule_set_revision: Option<i64>,
+    /// The current delegate of the token account
+    pub delegate: Option<Cow<'a, str>>,
+    /// The role of the current delegate
+    pub delegate_role: Option<TokenDelegateRoleEnum>,
+    /// The destination a locked transfer delegate is restricted to
+    pub locked_transfer: Option<Cow<'a, str>>,
+    /// Solana slot number
+    pub slot: i64,
+    /// Solana write version number
+    pub write_version: i64,
+}
+
+/// A row in the `metadata_programmable_configs` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct MetadataProgrammableConfig<'a> {
+    /// The address of the programmable NFT's metadata
+    pub metadata_address: Cow<'a, str>,
+    /// The rule set governing transfers, if any
+    pub rule_set: Option<Cow<'a, str>>,
+    /// Solana slot number
+    pub slot: i64,
+    /// Solana write version number
+    pub write_version: i64,
+}
+
+/// A row in the `rule_sets` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct RuleSet<'a> {
+    /// The address of this account
+    pub address: Cow<'a, str>,
+    /// The owner of the rule set
+    pub owner: Cow<'a, str>,
+    /// The name of the rule set
+    pub name: Cow<'a, str>,
+    /// The index of the latest revision of the rule set
+    pub revision: i64,
+    /// Solana slot number
+    pub slot: i64,
+    /// Solana write version number
+    pub write_version: i64,
+}
+
+/// A row in the `collection_authority_records` table
+///
+/// Account fields are unset until the account itself has been indexed, and
+/// link fields are unset until the approving instruction has been indexed.
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct CollectionAuthorityRecord<'a> {
+    /// The address of this account
+    pub address: Cow<'a, str>,
+    /// The bump seed of this PDA
+    pub bump: Option<i16>,
+    /// The delegated collection authority
+    pub collection_authority: Option<Cow<'a, str>>,
+    /// The metadata of the collection NFT
+    pub metadata_address: Option<Cow<'a, str>>,
+    /// The mint of the collection NFT
+    pub mint_address: Option<Cow<'a, str>>,
+    /// Solana slot number
+    pub slot: Option<i64>,
+    /// Solana write version number
+    pub write_version: Option<i64>,
+}
+
+/// A row in the `use_authority_records` table
+///
+/// Account fields are unset until the account itself has been indexed, and
+/// link fields are unset until the approving instruction has been indexed.
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct UseAuthorityRecord<'a> {
+    /// The address of this account
+    pub address: Cow<'a, str>,
+    /// The number of uses the authority may still spend
+    pub allowed_uses: Option<i64>,
+    /// The bump seed of this PDA
+    pub bump: Option<i16>,
+    /// The delegated use authority
+    pub use_authority: Option<Cow<'a, str>>,
+    /// The owner of the NFT who approved the use authority
+    pub owner: Option<Cow<'a, str>>,
+    /// The metadata of the NFT
+    pub metadata_address: Option<Cow<'a, str>>,
+    /// The mint of the NFT
+    pub mint_address: Option<Cow<'a, str>>,
+    /// Solana slot number
+    pub slot: Option<i64>,
+    /// Solana write version number
+    pub write_version: Option<i64>,
+}
+
+/// A row in the `edition_markers` table
+///
+/// Account fields are unset until the account itself has been indexed, and
+/// link fields are unset until a printing instruction has been indexed.
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct EditionMarker<'a> {
+    /// The address of this account
+    pub address: Cow<'a, str>,
+    /// Bitmask of the printed editions covered by this marker
+    pub ledger: Option<Vec<u8>>,
+    /// The master edition the editions were printed from
+    pub master_edition_address: Option<Cow<'a, str>>,
+    /// The index of this marker, covering editions from `248 * marker_index`
+    pub marker_index: Option<i64>,
+    /// Solana slot number
+    pub slot: Option<i64>,
+    /// Solana write version number
+    pub write_version: Option<i64>,
+}
+
+/// A row in the `update_metadata_account_instructions` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct UpdateMetadataAccountInstruction<'a> {
+    /// Metadata account pubkey
+    pub metadata: Cow<'a, str>,
+    /// Update authority signing the instruction
+    pub update_authority: Cow<'a, str>,
+    /// The new name, if the data was updated
+    pub name: Option<Cow<'a, str>>,
+    /// The new symbol, if the data was updated
+    pub symbol: Option<Cow<'a, str>>,
+    /// The new URI, if the data was updated
+    pub uri: Option<Cow<'a, str>>,
+    /// The new royalty basis points, if the data was updated
+    pub seller_fee_basis_points: Option<i32>,
+    /// The new update authority, if it was changed
+    pub new_update_authority: Option<Cow<'a, str>>,
+    /// The new primary sale flag, if it was changed
+    pub primary_sale_happened: Option<bool>,
+    /// The new mutability flag, if it was changed
+    pub is_mutable: Option<bool>,
+    /// Timestamp when the instruction was received
+    pub created_at: NaiveDateTime,
+    /// Solana slot number
+    pub slot: i64,
+}
+
+/// A row in the `verify_collection_instructions` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct VerifyCollectionInstruction<'a> {
+    /// Metadata account pubkey of the collection item
+    pub metadata: Cow<'a, str>,
+    /// Collection update authority or delegated collection authority
+    pub collection_authority: Cow<'a, str>,
+    /// Payer pubkey
+    pub payer: Cow<'a, str>,
+    /// Mint pubkey of the collection NFT
+    pub collection_mint: Cow<'a, str>,
+    /// Metadata account pubkey of the collection NFT
+    pub collection_metadata: Cow<'a, str>,
+    /// Master edition pubkey of the collection NFT
+    pub collection_master_edition: Cow<'a, str>,
+    /// Collection authority record pubkey, if a delegate verified the item
+    pub collection_authority_record: Option<Cow<'a, str>>,
+    /// Timestamp when the instruction was received
+    pub created_at: NaiveDateTime,
+    /// Solana slot number
+    pub slot: i64,
+}
+
+/// A row in the `set_and_verify_sized_collection_item_instructions` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct SetAndVerifySizedCollectionItemInstruction<'a> {
+    /// Metadata account pubkey of the collection item
+    pub metadata: Cow<'a, str>,
+    /// Collection update authority or delegated collection authority
+    pub collection_authority: Cow<'a, str>,
+    /// Payer pubkey
+    pub payer: Cow<'a, str>,
+    /// Update authority of the collection item
+    pub update_authority: Cow<'a, str>,
+    /// Mint pubkey of the collection NFT
+    pub collection_mint: Cow<'a, str>,
+    /// Metadata account pubkey of the collection NFT
+    pub collection_metadata: Cow<'a, str>,
+    /// Master edition pubkey of the collection NFT
+    pub collection_master_edition: Cow<'a, str>,
+    /// Collection authority record pubkey, if a delegate verified the item
+    pub collection_authority_record: Option<Cow<'a, str>>,
+    /// Timestamp when the instruction was received
+    pub created_at: NaiveDateTime,
+    /// Solana slot number
+    pub slot: i64,
+}
+
+/// A row in the `unverify_collection_instructions` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct UnverifyCollectionInstruction<'a> {
+    /// Metadata account pubkey of the collection item
+    pub metadata: Cow<'a, str>,
+    /// Collection update authority or delegated collection authority
+    pub collection_authority: Cow<'a, str>,
+    /// Mint pubkey of the collection NFT
+    pub collection_mint: Cow<'a, str>,
+    /// Metadata account pubkey of the collection NFT
+    pub collection_metadata: Cow<'a, str>,
+    /// Master edition pubkey of the collection NFT
+    pub collection_master_edition: Cow<'a, str>,
+    /// Collection authority record pubkey, if a delegate unverified the item
+    pub collection_authority_record: Option<Cow<'a, str>>,
+    /// Timestamp when the instruction was received
+    pub created_at: NaiveDateTime,
+    /// Solana slot number
+    pub slot: i64,
+}
+
+/// A row in the `sign_metadata_instructions` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct SignMetadataInstruction<'a> {
+    /// Metadata account pubkey
+    pub metadata: Cow<'a, str>,
+    /// Creator verifying themselves on the metadata
+    pub creator: Cow<'a, str>,
+    /// Timestamp when the instruction was received
+    pub created_at: NaiveDateTime,
+    /// Solana slot number
+    pub slot: i64,
+}
+
+/// A row in the `burn_instructions` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct BurnInstruction<'a> {
+    /// Metadata account pubkey
+    pub metadata: Cow<'a, str>,
+    /// Owner or delegate burning the NFT
+    pub owner: Cow<'a, str>,
+    /// Mint pubkey
+    pub mint: Cow<'a, str>,
+    /// Token account pubkey
+    pub token_account: Cow<'a, str>,
+    /// Edition or master edition pubkey of the NFT, if one was passed
+    pub edition: Option<Cow<'a, str>>,
+    /// Metadata account pubkey of the NFT's collection, if one was passed
+    pub collection_metadata: Option<Cow<'a, str>>,
+    /// Number of tokens burned
+    pub amount: i64,
+    /// Timestamp when the instruction was received
+    pub created_at: NaiveDateTime,
+    /// Solana slot number
+    pub slot: i64,
+}
+
+/// A row in the `nft_transfers` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct NftTransfer<'a> {
+    /// Mint pubkey of the transferred NFT
+    pub mint_address: Cow<'a, str>,
+    /// Token account the NFT was transferred from
+    pub source_token_account: Cow<'a, str>,
+    /// Token account the NFT was transferred to
+    pub destination_token_account: Cow<'a, str>,
+    /// Wallet owning the source token account, unless it was closed before
+    /// it could be read
+    pub source_owner: Option<Cow<'a, str>>,
+    /// Wallet owning the destination token account, once it has been indexed
+    pub destination_owner: Option<Cow<'a, str>>,
+    /// Owner or delegate signing the transfer
+    pub authority: Cow<'a, str>,
+    /// Time of the block containing the transfer
+    pub created_at: NaiveDateTime,
+    /// Solana slot number
+    pub slot: i64,
+}
+
+/// A row in the `slots` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct Slot {
+    /// Solana slot number
+    pub slot: i64,
+    /// The parent of this slot, if known
+    pub parent_slot: Option<i64>,
+    /// The highest commitment level this slot has reached
+    pub status: SlotStatusEnum,
+    /// The time at which the block in this slot was produced
+    pub block_time: Option<NaiveDateTime>,
+    /// Timestamp of the last status update for this slot
+    pub updated_at: NaiveDateTime,
+}
+
+/// A row in the `pending_messages` table
+#[derive(Debug, Clone, Queryable, Insertable)]
+pub struct PendingMessage<'a> {
+    /// The slot the message was produced in
+    pub slot: i64,
+    /// The serialized Geyser message, held until its slot is committed
+    pub message: Cow<'a, serde_json::Value>,
+}
+
+/// A row in the `auctioneer_listing_configs` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct AuctioneerListingConfig<'a> {
+    /// The address of this account
+    pub address: Cow<'a, str>,
+    /// The auction house the listing was made on, once known
+    pub auction_house: Option<Cow<'a, str>>,
+    /// The wallet selling the NFT, once known
+    pub seller: Option<Cow<'a, str>>,
+    /// The metadata of the NFT being auctioned, once known
+    pub metadata: Option<Cow<'a, str>>,
+    /// The seller trade state of the listing, once known
+    pub trade_state: Option<Cow<'a, str>>,
+    /// The time at which bidding opens
+    pub start_time: NaiveDateTime,
+    /// The time at which bidding closes
+    pub end_time: NaiveDateTime,
+    /// The minimum price the NFT can be sold for
+    pub reserve_price: i64,
+    /// The minimum amount a new bid must exceed the highest bid by
+    pub min_bid_increment: i64,
+    /// Seconds before the end of the auction in which a bid extends it
+    pub time_ext_period: i64,
+    /// Seconds the auction is extended by when a late bid is placed
+    pub time_ext_delta: i64,
+    /// Whether the highest bidder may cancel their bid
+    pub allow_high_bid_cancel: bool,
+    /// The current highest bid, if any
+    pub highest_bid: Option<i64>,
+    /// The buyer trade state of the current highest bid, if any
+    pub highest_bid_trade_state: Option<Cow<'a, str>>,
+    /// The slot number of the most recent update for this account
+    pub slot: i64,
+    /// The write version of the most recent update for this account
+    pub write_version: Option<i64>,
+}
+
+/// A row in the `anchor_accounts` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+pub struct AnchorAccount<'a> {
+    /// The address of this account
+    pub address: Cow<'a, str>,
+    /// The program which owns this account
+    pub program: Cow<'a, str>,
+    /// The name of this account's type in the program's IDL
+    pub account_type: Cow<'a, str>,
+    /// The account data, decoded using the program's IDL
+    pub data: Cow<'a, serde_json::Value>,
+    /// Solana slot number
+    pub slot: i64,
+    /// Solana write version number
+    pub write_version: i64,
+}
+
+/// A row in the `maple_globals` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+#[table_name = "maple_globals"]
+pub struct MapleGlobals<'a> {
+    /// The address of this account
+    pub address: Cow<'a, str>,
+    /// The Maple program admin
+    pub admin: Cow<'a, str>,
+    /// Solana slot number
+    pub slot: i64,
+    /// Solana write version number
+    pub write_version: i64,
+}
+
+/// A row in the `maple_pools` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct MaplePool<'a> {
+    /// The address of this account
+    pub address: Cow<'a, str>,
+    /// The globals account this pool belongs to
+    pub globals: Cow<'a, str>,
+    /// The delegate managing this pool
+    pub pool_delegate: Cow<'a, str>,
+    /// The mint of the token lent out by this pool
+    pub base_mint: Cow<'a, str>,
+    /// The mint of the shares issued to lenders
+    pub shares_mint: Cow<'a, str>,
+    /// The token account holding the pool's idle liquidity
+    pub locker: Cow<'a, str>,
+    /// The total value deposited into the pool, in base mint tokens
+    pub total_value: i64,
+    /// Solana slot number
+    pub slot: i64,
+    /// Solana write version number
+    pub write_version: i64,
+}
+
+/// A row in the `maple_lenders` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct MapleLender<'a> {
+    /// The address of this account
+    pub address: Cow<'a, str>,
+    /// The wallet owning this lender account
+    pub owner: Cow<'a, str>,
+    /// The pool lent to
+    pub pool: Cow<'a, str>,
+    /// Solana slot number
+    pub slot: i64,
+    /// Solana write version number
+    pub write_version: i64,
+}
+
+/// A row in the `maple_loans` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct MapleLoan<'a> {
+    /// The address of this account
+    pub address: Cow<'a, str>,
+    /// The pool funding this loan
+    pub pool: Cow<'a, str>,
+    /// The borrowing wallet
+    pub borrower: Cow<'a, str>,
+    /// The loan principal, in base mint tokens
+    pub principal: i64,
+    /// The name of the loan's current state
+    pub state: Cow<'a, str>,
+    /// Solana slot number
+    pub slot: i64,
+    /// Solana write version number
+    pub write_version: i64,
+}
+
+/// A row in the `maple_open_term_loans` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct MapleOpenTermLoan<'a> {
+    /// The address of this account
+    pub address: Cow<'a, str>,
+    /// The pool funding this loan
+    pub pool: Cow<'a, str>,
+    /// The borrowing wallet
+    pub borrower: Cow<'a, str>,
+    /// The loan principal, in base mint tokens
+    pub principal: i64,
+    /// The name of the loan's current state
+    pub state: Cow<'a, str>,
+    /// Solana slot number
+    pub slot: i64,
+    /// Solana write version number
+    pub write_version: i64,
+}
+
+/// A row in the `maple_withdrawal_requests` table
+#[derive(Debug, Clone, Queryable, Insertable, AsChangeset)]
+#[diesel(treat_none_as_null = true)]
+pub struct MapleWithdrawalRequest<'a> {
+    /// The address of this account
+    pub address: Cow<'a, str>,
+    /// The pool being withdrawn from
+    pub pool: Cow<'a, str>,
+    /// The lender account requesting the withdrawal
+    pub lender: Cow<'a, str>,
+    /// The number of pool shares locked for withdrawal
+    pub shares: i64,
+    /// Solana slot number
+    pub slot: i64,
+    /// Solana write version number
+    pub write_version: i64,
 }
 
 /// A row in a `metadatas::count_by_store_creator` query, representing stats for
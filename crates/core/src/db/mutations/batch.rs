@@ -0,0 +1,309 @@
+use diesel::sql_types::{Array, Int2, Int8, Nullable, Text, Timestamp};
+
+use crate::{
+    db::{
+        models::{
+            AssociatedTokenAccount, BidReceipt, CurrentMetadataOwner, ListingReceipt,
+            PurchaseReceipt,
+        },
+        PooledConnection,
+    },
+    error::Result,
+    prelude::*,
+};
+
+const ASSOCIATED_TOKEN_ACCOUNTS_QUERY: &str = r"
+insert into associated_token_accounts (address, mint, owner, amount, slot, write_version)
+select * from unnest($1, $2, $3, $4, $5, $6)
+on conflict (address) do update set
+    mint = excluded.mint,
+    owner = excluded.owner,
+    amount = excluded.amount,
+    slot = excluded.slot,
+    write_version = excluded.write_version
+where (excluded.slot, excluded.write_version)
+    > (associated_token_accounts.slot, associated_token_accounts.write_version) is not false;
+ -- $1: address::varchar[]
+ -- $2: mint::varchar[]
+ -- $3: owner::varchar[]
+ -- $4: amount::bigint[]
+ -- $5: slot::bigint[]
+ -- $6: write_version::bigint[]";
+
+const CURRENT_METADATA_OWNERS_QUERY: &str = r"
+insert into current_metadata_owners (
+    mint_address, owner_address, token_account_address, slot, write_version
+)
+select * from unnest($1, $2, $3, $4, $5)
+on conflict (mint_address) do update set
+    owner_address = excluded.owner_address,
+    token_account_address = excluded.token_account_address,
+    slot = excluded.slot,
+    write_version = excluded.write_version
+where (excluded.slot, excluded.write_version)
+    > (current_metadata_owners.slot, current_metadata_owners.write_version) is not false;
+ -- $1: mint_address::varchar[]
+ -- $2: owner_address::varchar[]
+ -- $3: token_account_address::varchar[]
+ -- $4: slot::bigint[]
+ -- $5: write_version::bigint[]";
+
+const LISTING_RECEIPTS_QUERY: &str = r"
+insert into listing_receipts (
+    address, trade_state, bookkeeper, auction_house, seller, metadata,
+    purchase_receipt, price, token_size, bump, trade_state_bump, created_at,
+    canceled_at, slot, write_version
+)
+select * from unnest($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+on conflict (address) do update set
+    trade_state = excluded.trade_state,
+    bookkeeper = excluded.bookkeeper,
+    auction_house = excluded.auction_house,
+    seller = excluded.seller,
+    metadata = excluded.metadata,
+    purchase_receipt = excluded.purchase_receipt,
+    price = excluded.price,
+    token_size = excluded.token_size,
+    bump = excluded.bump,
+    trade_state_bump = excluded.trade_state_bump,
+    created_at = excluded.created_at,
+    canceled_at = excluded.canceled_at,
+    slot = excluded.slot,
+    write_version = excluded.write_version
+where (excluded.slot, excluded.write_version)
+    > (listing_receipts.slot, listing_receipts.write_version) is not false;
+ -- $1: address::varchar[]
+ -- $2: trade_state::varchar[]
+ -- $3: bookkeeper::varchar[]
+ -- $4: auction_house::varchar[]
+ -- $5: seller::varchar[]
+ -- $6: metadata::varchar[]
+ -- $7: purchase_receipt::varchar[] (nullable elements)
+ -- $8: price::bigint[]
+ -- $9: token_size::bigint[]
+ -- $10: bump::smallint[]
+ -- $11: trade_state_bump::smallint[]
+ -- $12: created_at::timestamp[]
+ -- $13: canceled_at::timestamp[] (nullable elements)
+ -- $14: slot::bigint[]
+ -- $15: write_version::bigint[]";
+
+const BID_RECEIPTS_QUERY: &str = r"
+insert into bid_receipts (
+    address, trade_state, bookkeeper, auction_house, buyer, metadata,
+    token_account, purchase_receipt, price, token_size, bump, trade_state_bump,
+    created_at, canceled_at, slot, write_version
+)
+select * from unnest($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
+on conflict (address) do update set
+    trade_state = excluded.trade_state,
+    bookkeeper = excluded.bookkeeper,
+    auction_house = excluded.auction_house,
+    buyer = excluded.buyer,
+    metadata = excluded.metadata,
+    token_account = excluded.token_account,
+    purchase_receipt = excluded.purchase_receipt,
+    price = excluded.price,
+    token_size = excluded.token_size,
+    bump = excluded.bump,
+    trade_state_bump = excluded.trade_state_bump,
+    created_at = excluded.created_at,
+    canceled_at = excluded.canceled_at,
+    slot = excluded.slot,
+    write_version = excluded.write_version
+where (excluded.slot, excluded.write_version)
+    > (bid_receipts.slot, bid_receipts.write_version) is not false;
+ -- $1: address::varchar[]
+ -- $2: trade_state::varchar[]
+ -- $3: bookkeeper::varchar[]
+ -- $4: auction_house::varchar[]
+ -- $5: buyer::varchar[]
+ -- $6: metadata::varchar[]
+ -- $7: token_account::varchar[] (nullable elements)
+ -- $8: purchase_receipt::varchar[] (nullable elements)
+ -- $9: price::bigint[]
+ -- $10: token_size::bigint[]
+ -- $11: bump::smallint[]
+ -- $12: trade_state_bump::smallint[]
+ -- $13: created_at::timestamp[]
+ -- $14: canceled_at::timestamp[] (nullable elements)
+ -- $15: slot::bigint[]
+ -- $16: write_version::bigint[]";
+
+const PURCHASE_RECEIPTS_QUERY: &str = r"
+insert into purchase_receipts (
+    address, bookkeeper, buyer, seller, auction_house, metadata, token_size,
+    price, bump, created_at, slot, write_version
+)
+select * from unnest($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+on conflict (address) do update set
+    bookkeeper = excluded.bookkeeper,
+    buyer = excluded.buyer,
+    seller = excluded.seller,
+    auction_house = excluded.auction_house,
+    metadata = excluded.metadata,
+    token_size = excluded.token_size,
+    price = excluded.price,
+    bump = excluded.bump,
+    created_at = excluded.created_at,
+    slot = excluded.slot,
+    write_version = excluded.write_version
+where (excluded.slot, excluded.write_version)
+    > (purchase_receipts.slot, purchase_receipts.write_version) is not false;
+ -- $1: address::varchar[]
+ -- $2: bookkeeper::varchar[]
+ -- $3: buyer::varchar[]
+ -- $4: seller::varchar[]
+ -- $5: auction_house::varchar[]
+ -- $6: metadata::varchar[]
+ -- $7: token_size::bigint[]
+ -- $8: price::bigint[]
+ -- $9: bump::smallint[]
+ -- $10: created_at::timestamp[]
+ -- $11: slot::bigint[]
+ -- $12: write_version::bigint[]";
+
+fn column<'a, T, U>(rows: &'a [T], f: impl FnMut(&'a T) -> U) -> Vec<U> {
+    rows.iter().map(f).collect()
+}
+
+fn opt_str<'a>(s: &'a Option<Cow<str>>) -> Option<&'a str> {
+    s.as_deref()
+}
+
+/// Insert or update a batch of rows in the associated token accounts table
+/// with a single statement.  Rows no newer than the indexed row for the same
+/// address (by slot, then write version) are ignored.
+///
+/// Each row in a batch must have a distinct address.
+///
+/// # Errors
+/// This function fails if the upsert fails
+pub fn associated_token_accounts(
+    db: &PooledConnection,
+    rows: &[AssociatedTokenAccount],
+) -> Result<()> {
+    diesel::sql_query(ASSOCIATED_TOKEN_ACCOUNTS_QUERY)
+        .bind::<Array<Text>, _>(column(rows, |r| &*r.address))
+        .bind::<Array<Text>, _>(column(rows, |r| &*r.mint))
+        .bind::<Array<Text>, _>(column(rows, |r| &*r.owner))
+        .bind::<Array<Int8>, _>(column(rows, |r| r.amount))
+        .bind::<Array<Int8>, _>(column(rows, |r| r.slot))
+        .bind::<Array<Int8>, _>(column(rows, |r| r.write_version))
+        .execute(db)
+        .context("Failed to upsert associated token accounts")?;
+
+    Ok(())
+}
+
+/// Insert or update a batch of rows in the current metadata owners table with
+/// a single statement.  Rows no newer than the indexed row for the same mint
+/// (by slot, then write version) are ignored.
+///
+/// Each row in a batch must have a distinct mint address.
+///
+/// # Errors
+/// This function fails if the upsert fails
+pub fn current_metadata_owners(db: &PooledConnection, rows: &[CurrentMetadataOwner]) -> Result<()> {
+    diesel::sql_query(CURRENT_METADATA_OWNERS_QUERY)
+        .bind::<Array<Text>, _>(column(rows, |r| &*r.mint_address))
+        .bind::<Array<Text>, _>(column(rows, |r| &*r.owner_address))
+        .bind::<Array<Text>, _>(column(rows, |r| &*r.token_account_address))
+        .bind::<Array<Int8>, _>(column(rows, |r| r.slot))
+        .bind::<Array<Int8>, _>(column(rows, |r| r.write_version))
+        .execute(db)
+        .context("Failed to upsert current metadata owners")?;
+
+    Ok(())
+}
+
+/// Insert or update a batch of rows in the listing receipts table with a
+/// single statement.  Rows no newer than the indexed row for the same
+/// address (by slot, then write version) are ignored.
+///
+/// Each row in a batch must have a distinct address.
+///
+/// # Errors
+/// This function fails if the upsert fails
+pub fn listing_receipts(db: &PooledConnection, rows: &[ListingReceipt]) -> Result<()> {
+    diesel::sql_query(LISTING_RECEIPTS_QUERY)
+        .bind::<Array<Text>, _>(column(rows, |r| &*r.address))
+        .bind::<Array<Text>, _>(column(rows, |r| &*r.trade_state))
+        .bind::<Array<Text>, _>(column(rows, |r| &*r.bookkeeper))
+        .bind::<Array<Text>, _>(column(rows, |r| &*r.auction_house))
+        .bind::<Array<Text>, _>(column(rows, |r| &*r.seller))
+        .bind::<Array<Text>, _>(column(rows, |r| &*r.metadata))
+        .bind::<Array<Nullable<Text>>, _>(column(rows, |r| opt_str(&r.purchase_receipt)))
+        .bind::<Array<Int8>, _>(column(rows, |r| r.price))
+        .bind::<Array<Int8>, _>(column(rows, |r| r.token_size))
+        .bind::<Array<Int2>, _>(column(rows, |r| r.bump))
+        .bind::<Array<Int2>, _>(column(rows, |r| r.trade_state_bump))
+        .bind::<Array<Timestamp>, _>(column(rows, |r| r.created_at))
+        .bind::<Array<Nullable<Timestamp>>, _>(column(rows, |r| r.canceled_at))
+        .bind::<Array<Int8>, _>(column(rows, |r| r.slot))
+        .bind::<Array<Int8>, _>(column(rows, |r| r.write_version))
+        .execute(db)
+        .context("Failed to upsert listing receipts")?;
+
+    Ok(())
+}
+
+/// Insert or update a batch of rows in the bid receipts table with a single
+/// statement.  Rows no newer than the indexed row for the same address (by
+/// slot, then write version) are ignored.
+///
+/// Each row in a batch must have a distinct address.
+///
+/// # Errors
+/// This function fails if the upsert fails
+pub fn bid_receipts(db: &PooledConnection, rows: &[BidReceipt]) -> Result<()> {
+    diesel::sql_query(BID_RECEIPTS_QUERY)
+        .bind::<Array<Text>, _>(column(rows, |r| &*r.address))
+        .bind::<Array<Text>, _>(column(rows, |r| &*r.trade_state))
+        .bind::<Array<Text>, _>(column(rows, |r| &*r.bookkeeper))
+        .bind::<Array<Text>, _>(column(rows, |r| &*r.auction_house))
+        .bind::<Array<Text>, _>(column(rows, |r| &*r.buyer))
+        .bind::<Array<Text>, _>(column(rows, |r| &*r.metadata))
+        .bind::<Array<Nullable<Text>>, _>(column(rows, |r| opt_str(&r.token_account)))
+        .bind::<Array<Nullable<Text>>, _>(column(rows, |r| opt_str(&r.purchase_receipt)))
+        .bind::<Array<Int8>, _>(column(rows, |r| r.price))
+        .bind::<Array<Int8>, _>(column(rows, |r| r.token_size))
+        .bind::<Array<Int2>, _>(column(rows, |r| r.bump))
+        .bind::<Array<Int2>, _>(column(rows, |r| r.trade_state_bump))
+        .bind::<Array<Timestamp>, _>(column(rows, |r| r.created_at))
+        .bind::<Array<Nullable<Timestamp>>, _>(column(rows, |r| r.canceled_at))
+        .bind::<Array<Int8>, _>(column(rows, |r| r.slot))
+        .bind::<Array<Int8>, _>(column(rows, |r| r.write_version))
+        .execute(db)
+        .context("Failed to upsert bid receipts")?;
+
+    Ok(())
+}
+
+/// Insert or update a batch of rows in the purchase receipts table with a
+/// single statement.  Rows no newer than the indexed row for the same
+/// address (by slot, then write version) are ignored.
+///
+/// Each row in a batch must have a distinct address.
+///
+/// # Errors
+/// This function fails if the upsert fails
+pub fn purchase_receipts(db: &PooledConnection, rows: &[PurchaseReceipt]) -> Result<()> {
+    diesel::sql_query(PURCHASE_RECEIPTS_QUERY)
+        .bind::<Array<Text>, _>(column(rows, |r| &*r.address))
+        .bind::<Array<Text>, _>(column(rows, |r| &*r.bookkeeper))
+        .bind::<Array<Text>, _>(column(rows, |r| &*r.buyer))
+        .bind::<Array<Text>, _>(column(rows, |r| &*r.seller))
+        .bind::<Array<Text>, _>(column(rows, |r| &*r.auction_house))
+        .bind::<Array<Text>, _>(column(rows, |r| &*r.metadata))
+        .bind::<Array<Int8>, _>(column(rows, |r| r.token_size))
+        .bind::<Array<Int8>, _>(column(rows, |r| r.price))
+        .bind::<Array<Int2>, _>(column(rows, |r| r.bump))
+        .bind::<Array<Timestamp>, _>(column(rows, |r| r.created_at))
+        .bind::<Array<Int8>, _>(column(rows, |r| r.slot))
+        .bind::<Array<Int8>, _>(column(rows, |r| r.write_version))
+        .execute(db)
+        .context("Failed to upsert purchase receipts")?;
+
+    Ok(())
+}
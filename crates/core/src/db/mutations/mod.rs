@@ -1,6 +1,10 @@
-//! listing and offer upsert functions
+//! listing, offer, slot and batched upsert functions
 
+/// Multi-row upserts for tables written by frequently updated accounts
+pub mod batch;
 /// Generic listing upsert function which returns listing uuid if upsert is successful
 pub mod listing;
 /// Generic offer upsert function which returns offer uuid if upsert is successful
 pub mod offer;
+/// Slot status upsert function which preserves the highest commitment reached
+pub mod slot;
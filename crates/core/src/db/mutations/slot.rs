@@ -0,0 +1,39 @@
+use diesel::sql_types::{Int8, Nullable, Timestamp};
+
+use crate::{
+    db::{custom_types::SlotStatus, models::Slot, PooledConnection},
+    error::Result,
+    prelude::*,
+};
+
+const UPSERT_QUERY: &str = r"
+insert into slots (slot, parent_slot, status, block_time, updated_at)
+values ($1, $2, $3, $4, $5)
+on conflict (slot) do update set
+    parent_slot = coalesce(excluded.parent_slot, slots.parent_slot),
+    status = greatest(slots.status, excluded.status),
+    block_time = coalesce(slots.block_time, excluded.block_time),
+    updated_at = excluded.updated_at;
+ -- $1: slot::bigint
+ -- $2: parent_slot::bigint
+ -- $3: status::slot_status
+ -- $4: block_time::timestamp
+ -- $5: updated_at::timestamp";
+
+/// Insert or update a row in the slots table.  Status updates never lower the
+/// commitment level of a slot, and the first known block time is kept.
+///
+/// # Errors
+/// This function fails if the slot row upsert fails
+pub fn upsert(db: &PooledConnection, slot: &Slot) -> Result<()> {
+    diesel::sql_query(UPSERT_QUERY)
+        .bind::<Int8, _>(slot.slot)
+        .bind::<Nullable<Int8>, _>(slot.parent_slot)
+        .bind::<SlotStatus, _>(slot.status)
+        .bind::<Nullable<Timestamp>, _>(slot.block_time)
+        .bind::<Timestamp, _>(slot.updated_at)
+        .execute(db)
+        .context("Failed to upsert slot")?;
+
+    Ok(())
+}
@@ -826,10 +826,64 @@ SELECT listings.id as id, metadata, auction_house, price, auction_house, created
         LEFT JOIN twitter_handle_name_services bth on (bth.wallet_address = offers.buyer)
         WHERE metadata = ANY($1) and auction_house != '3o9d13qUvEuuauhFrVom1vuCzgNsJifeaBYDPquaT73Y'
         AND offers.purchase_id IS NULL
+    UNION
+    SELECT ins.id as id, metadata, null as auction_house, null as price, null as auction_house, created_at,
+    'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s' as marketplace_program,
+    array[update_authority] as wallets,
+    array[th.twitter_handle] as wallet_twitter_handles,
+    'update_metadata' as activity_type
+        FROM update_metadata_account_instructions ins
+        LEFT JOIN twitter_handle_name_services th on (th.wallet_address = ins.update_authority)
+        WHERE metadata = ANY($1)
+    UNION
+    SELECT ins.id as id, metadata, null as auction_house, null as price, null as auction_house, created_at,
+    'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s' as marketplace_program,
+    array[collection_authority] as wallets,
+    array[th.twitter_handle] as wallet_twitter_handles,
+    'verify_collection' as activity_type
+        FROM verify_collection_instructions ins
+        LEFT JOIN twitter_handle_name_services th on (th.wallet_address = ins.collection_authority)
+        WHERE metadata = ANY($1)
+    UNION
+    SELECT ins.id as id, metadata, null as auction_house, null as price, null as auction_house, created_at,
+    'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s' as marketplace_program,
+    array[collection_authority] as wallets,
+    array[th.twitter_handle] as wallet_twitter_handles,
+    'verify_collection' as activity_type
+        FROM set_and_verify_sized_collection_item_instructions ins
+        LEFT JOIN twitter_handle_name_services th on (th.wallet_address = ins.collection_authority)
+        WHERE metadata = ANY($1)
+    UNION
+    SELECT ins.id as id, metadata, null as auction_house, null as price, null as auction_house, created_at,
+    'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s' as marketplace_program,
+    array[collection_authority] as wallets,
+    array[th.twitter_handle] as wallet_twitter_handles,
+    'unverify_collection' as activity_type
+        FROM unverify_collection_instructions ins
+        LEFT JOIN twitter_handle_name_services th on (th.wallet_address = ins.collection_authority)
+        WHERE metadata = ANY($1)
+    UNION
+    SELECT ins.id as id, metadata, null as auction_house, null as price, null as auction_house, created_at,
+    'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s' as marketplace_program,
+    array[creator] as wallets,
+    array[th.twitter_handle] as wallet_twitter_handles,
+    'sign_metadata' as activity_type
+        FROM sign_metadata_instructions ins
+        LEFT JOIN twitter_handle_name_services th on (th.wallet_address = ins.creator)
+        WHERE metadata = ANY($1)
+    UNION
+    SELECT ins.id as id, metadata, null as auction_house, null as price, null as auction_house, created_at,
+    'metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s' as marketplace_program,
+    array[owner] as wallets,
+    array[th.twitter_handle] as wallet_twitter_handles,
+    'burn' as activity_type
+        FROM burn_instructions ins
+        LEFT JOIN twitter_handle_name_services th on (th.wallet_address = ins.owner)
+        WHERE metadata = ANY($1)
     ORDER BY created_at DESC;
  -- $1: addresses::text[]";
 
-/// Load listing and sales activity for nfts
+/// Load listing, sales and metadata update activity for nfts
 ///
 /// # Errors
 /// This function fails if the underlying SQL query returns an error
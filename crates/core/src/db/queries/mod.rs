@@ -16,6 +16,7 @@ pub mod reward_centers;
 pub mod spl_governance;
 pub mod stats;
 pub mod store_denylist;
+pub mod table_dump;
 pub mod twitter_handle_name_service;
 pub mod wallet;
 
@@ -0,0 +1,36 @@
+//! Dump the contents of whole tables as JSON, for comparing indexer output
+//! against known-good snapshots.
+
+use diesel::{prelude::*, sql_types::Jsonb};
+
+use crate::{db::Connection, prelude::*};
+
+#[derive(QueryableByName)]
+struct Row {
+    #[sql_type = "Jsonb"]
+    row: serde_json::Value,
+}
+
+/// Load every row of a table as a JSON object keyed by column name.  Rows are
+/// sorted by their JSON text so the output is stable across runs.
+///
+/// # Errors
+/// This function fails if the table name is not a plain identifier or the
+/// query fails.
+pub fn load(conn: &Connection, table: &str) -> Result<Vec<serde_json::Value>> {
+    if table.is_empty()
+        || !table
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    {
+        bail!("Invalid table name {:?}", table);
+    }
+
+    let rows: Vec<Row> = diesel::sql_query(format!(
+        "select to_jsonb(t) as row from {table} t order by to_jsonb(t)::text"
+    ))
+    .load(conn)
+    .with_context(|| format!("Failed to dump table {table}"))?;
+
+    Ok(rows.into_iter().map(|r| r.row).collect())
+}
@@ -56,6 +56,22 @@ array[bth.twitter_handle] as wallet_twitter_handles,
     AND offers.purchase_id IS NULL
     AND offers.auction_house != '3o9d13qUvEuuauhFrVom1vuCzgNsJifeaBYDPquaT73Y'
     AND ('OFFERS' = ANY($2) OR $2 IS NULL)
+UNION
+SELECT nft_transfers.id as id, metadatas.address as metadata, null as price, null as auction_house,
+nft_transfers.created_at as created_at, 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA' as marketplace_program,
+CASE WHEN source_owner IS NULL THEN array[destination_owner]
+    WHEN destination_owner IS NULL THEN array[source_owner]
+    ELSE array[source_owner, destination_owner] END as wallets,
+CASE WHEN source_owner IS NULL THEN array[dth.twitter_handle]
+    WHEN destination_owner IS NULL THEN array[sth.twitter_handle]
+    ELSE array[sth.twitter_handle, dth.twitter_handle] END as wallet_twitter_handles,
+'transfer' as activity_type
+    FROM nft_transfers
+    INNER JOIN metadatas on (metadatas.mint_address = nft_transfers.mint_address)
+    LEFT JOIN twitter_handle_name_services sth on (sth.wallet_address = nft_transfers.source_owner)
+    LEFT JOIN twitter_handle_name_services dth on (dth.wallet_address = nft_transfers.destination_owner)
+    WHERE (source_owner = $1 OR destination_owner = $1)
+    AND ('TRANSFERS' = ANY($2) OR $2 IS NULL)
 ORDER BY created_at DESC
 LIMIT $3
 OFFSET $4;
@@ -65,7 +81,7 @@ OFFSET $4;
 -- $3: limit::integer
 -- $4: offset::integer";
 
-/// Load listing, purchase, sales and offer activity for wallets.
+/// Load listing, purchase, sales, offer and transfer activity for wallets.
 ///
 /// # Errors
 /// This function fails if the underlying SQL query returns an error
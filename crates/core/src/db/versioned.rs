@@ -0,0 +1,220 @@
+//! Last-writer-wins upserts for tables tracking the slot and write version of
+//! the account data each row was read from
+//!
+//! Account updates can arrive out of order, so an upsert of a row should only
+//! replace an existing row if it was read from a newer version of the
+//! account.  Appending [`if_newer`](IfNewerDsl::if_newer) to an upsert adds a
+//! `WHERE` clause to its `DO UPDATE` action doing exactly that:
+//!
+//! ```ignore
+//! insert_into(associated_token_accounts::table)
+//!     .values(&row)
+//!     .on_conflict(associated_token_accounts::address)
+//!     .do_update()
+//!     .set(&row)
+//!     .if_newer()
+//!     .execute(db)?;
+//! ```
+
+use std::marker::PhantomData;
+
+use diesel::{
+    query_builder::{AstPass, InsertStatement, QueryFragment, QueryId},
+    Column, QueryResult, RunQueryDsl, Table,
+};
+
+use super::{tables, Pg};
+
+/// A table with `slot` and `write_version` columns recording the version of
+/// the account data a row was last written from
+pub trait Versioned: Table {
+    /// The unqualified name of the table
+    const NAME: &'static str;
+
+    /// The slot column
+    type Slot: Column<Table = Self>;
+
+    /// The write version column
+    type WriteVersion: Column<Table = Self>;
+}
+
+macro_rules! versioned {
+    ($($table:ident),* $(,)?) => {
+        $(
+            impl Versioned for tables::$table::table {
+                const NAME: &'static str = stringify!($table);
+
+                type Slot = tables::$table::slot;
+                type WriteVersion = tables::$table::write_version;
+            }
+        )*
+    };
+}
+
+versioned!(
+    anchor_accounts,
+    associated_token_accounts,
+    attributes,
+    auctioneer_listing_configs,
+    bid_receipts,
+    candy_guards,
+    candy_machines_v3,
+    cardinal_entries,
+    cardinal_namespaces,
+    collection_authority_records,
+    current_metadata_owners,
+    edition_markers,
+    files,
+    geno_habitat_datas,
+    geno_rental_agreements,
+    governance_configs,
+    governances,
+    graph_connections,
+    listing_receipts,
+    maple_globals,
+    maple_lenders,
+    maple_loans,
+    maple_open_term_loans,
+    maple_pools,
+    maple_withdrawal_requests,
+    metadata_collections,
+    metadata_jsons,
+    metadata_programmable_configs,
+    proposal_options,
+    proposal_transaction_instruction_accounts,
+    proposal_transaction_instructions,
+    proposal_transactions,
+    proposal_vote_type_multi_choices,
+    proposals_v1,
+    proposals_v2,
+    purchase_receipts,
+    realm_config_accounts,
+    realm_configs,
+    realms,
+    reward_centers,
+    reward_payouts,
+    rewards_listings,
+    rewards_offers,
+    rule_sets,
+    signatory_records,
+    token_2022_mint_extensions,
+    token_owner_records,
+    token_records,
+    twitter_handle_name_services,
+    use_authority_records,
+    vote_record_v2_vote_approve_vote_choices,
+    vote_records_v1,
+    vote_records_v2,
+);
+
+/// Extension trait adding [`if_newer`](Self::if_newer) to upserts into a
+/// [`Versioned`] table
+pub trait IfNewerDsl: Sized {
+    /// The table being upserted into
+    type Table: Versioned;
+
+    /// Only update a conflicting row if the incoming row has a greater slot
+    /// and write version (compared in that order) than the existing one.
+    ///
+    /// This must be called on an upsert ending in `.do_update().set(...)`.
+    /// Statements with a `RETURNING` clause are not supported, since a row
+    /// which is left alone would not be returned.  The number of rows
+    /// affected is zero if the existing row was kept.
+    fn if_newer(self) -> IfNewer<Self, Self::Table>;
+}
+
+impl<T: Versioned, U, Op> IfNewerDsl for InsertStatement<T, U, Op> {
+    type Table = T;
+
+    fn if_newer(self) -> IfNewer<Self, T> {
+        IfNewer {
+            query: self,
+            table: PhantomData,
+        }
+    }
+}
+
+/// An upsert whose update is guarded by the slot and write version of the
+/// incoming and existing rows
+#[derive(Debug, Clone, Copy)]
+#[must_use = "Queries are only executed when calling `load`, `get_result` or similar."]
+pub struct IfNewer<Q, T> {
+    query: Q,
+    table: PhantomData<T>,
+}
+
+impl<Q: QueryFragment<Pg>, T: Versioned> QueryFragment<Pg> for IfNewer<Q, T> {
+    fn walk_ast(&self, mut out: AstPass<Pg>) -> QueryResult<()> {
+        let slot = <T::Slot as Column>::NAME;
+        let write_version = <T::WriteVersion as Column>::NAME;
+
+        self.query.walk_ast(out.reborrow())?;
+
+        // Rows with a null slot or write version are always overwritten, as
+        // with the check_slot_wv trigger
+        out.push_sql(" WHERE (excluded.");
+        out.push_identifier(slot)?;
+        out.push_sql(", excluded.");
+        out.push_identifier(write_version)?;
+        out.push_sql(") > (");
+        out.push_identifier(T::NAME)?;
+        out.push_sql(".");
+        out.push_identifier(slot)?;
+        out.push_sql(", ");
+        out.push_identifier(T::NAME)?;
+        out.push_sql(".");
+        out.push_identifier(write_version)?;
+        out.push_sql(") IS NOT FALSE");
+
+        Ok(())
+    }
+}
+
+impl<Q, T> QueryId for IfNewer<Q, T> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<Q, T, C> RunQueryDsl<C> for IfNewer<Q, T> {}
+
+#[cfg(test)]
+mod tests {
+    use diesel::insert_into;
+
+    use super::*;
+    use crate::db::{
+        debug_query, models::AssociatedTokenAccount, tables::associated_token_accounts,
+    };
+
+    #[test]
+    fn test_if_newer_sql() {
+        let row = AssociatedTokenAccount {
+            address: "account".into(),
+            mint: "mint".into(),
+            owner: "owner".into(),
+            amount: 1,
+            slot: 2,
+            write_version: 3,
+        };
+
+        let query = insert_into(associated_token_accounts::table)
+            .values(&row)
+            .on_conflict(associated_token_accounts::address)
+            .do_update()
+            .set(&row)
+            .if_newer();
+        let sql = debug_query::<Pg, _>(&query).to_string();
+
+        assert!(
+            sql.contains(r#"ON CONFLICT ("address") DO UPDATE SET"#),
+            "{sql}"
+        );
+        assert!(
+            sql.contains(
+                r#" WHERE (excluded."slot", excluded."write_version") > ("associated_token_accounts"."slot", "associated_token_accounts"."write_version") IS NOT FALSE"#
+            ),
+            "{sql}"
+        );
+    }
+}
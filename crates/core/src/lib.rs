@@ -24,6 +24,9 @@ pub extern crate bigdecimal;
 pub extern crate chrono;
 pub extern crate clap;
 pub extern crate num_cpus;
+pub extern crate rand;
+#[cfg(feature = "solana")]
+pub extern crate solana_program;
 pub extern crate url;
 pub extern crate uuid;
 
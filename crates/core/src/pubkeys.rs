@@ -20,12 +20,20 @@ pub static ME_HAUS: Pubkey = pubkey!("M2mx93ekt1fmXSVkTrUL9xVFHkmME8HTUi5Cyc5aF7
 pub static METAPLEX: Pubkey = pubkey!("p1exdMJcjVao65QdewkaZRUnU6VPSXhus9n2GzWfh98");
 /// SPL token program key
 pub static TOKEN: Pubkey = pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+/// SPL Token-2022 program key
+pub static TOKEN_2022: Pubkey = pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
 /// MPL auction house program key
 pub static AUCTION_HOUSE: Pubkey = pubkey!("hausS13jsjafwWwGqZTUQRmWyvyxn9EQpqMwV1PBBmk");
 /// HPL reward center program key
 pub static REWARD_CENTER: Pubkey = pubkey!("RwDDvPp7ta9qqUwxbBfShsNreBaSsKvFcHzMxfBC3Ki");
+/// MPL auctioneer program key
+pub static AUCTIONEER: Pubkey = pubkey!("neer8g6yJq2mQM6KbnViEDAD4gr3gRZyMMf4F2p3MEh");
 /// Metaplex candy machine program key
 pub static CANDY_MACHINE: Pubkey = pubkey!("cndy3Z4yapfJBmL3ShUp5exZKqR3z33thTzeNMm2gRZ");
+/// Metaplex Core Candy Machine (v3) program key
+pub static CANDY_MACHINE_V3: Pubkey = pubkey!("CndyV3LdqHUfDLmE5naZjVN8rBZz4tqhdefbAnjHG3JR");
+/// Metaplex Candy Guard program key
+pub static CANDY_GUARD: Pubkey = pubkey!("Guard1JwRhJkVH6XZhzoYxeBVQe872VivGQ8ePCaUd9Bp");
 /// HPL graph program key
 pub static GRAPH_PROGRAM: Pubkey = pubkey!("grphAFGNvCjLKHeEmPNa91eGJChcUhrdaYYharcZCTQ");
 /// SPL name service program key
@@ -58,6 +66,12 @@ pub static CARDINAL_TWITTER_NAMESPACE: Pubkey =
 pub static OPENSEA_AUCTION_HOUSE: Pubkey = pubkey!("3o9d13qUvEuuauhFrVom1vuCzgNsJifeaBYDPquaT73Y");
 /// Mint addresses of token accounts to index
 pub const TOKEN_MINTS: [Pubkey; 1] = [pubkey!("FpRTwmoN3dprxdkmas5J3AdGb1rjpWRpH2dhEW8vL9Qn")];
+/// Metaplex Bubblegum compressed NFT program key
+pub static BUBBLEGUM: Pubkey = pubkey!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+/// SPL no-op program key, used by account compression to log events
+pub static SPL_NOOP: Pubkey = pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+/// Metaplex token-auth-rules program key, owner of programmable NFT rule sets
+pub static TOKEN_AUTH_RULES: Pubkey = pubkey!("auth9SigNpDKz4sJJ1DfCTuZrZNSAgh9sFD3rboVmgg");
 /// Maple lending protocol program key
 pub static MAPLE: Pubkey = pubkey!("5D9yi4BKrxF8h65NkVE1raCCWFKUs5ngub2ECxhvfaZe");
 /// Spl Governance programs pubkey
@@ -147,6 +161,33 @@ pub fn find_bidder_metadata(
     )
 }
 
+/// Find the address of a `Metadata` account, given the token mint
+pub fn find_metadata(mint: impl Borrow<Pubkey>) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            "metadata".as_bytes(),
+            &METADATA.to_bytes(),
+            &mint.borrow().to_bytes(),
+        ],
+        &METADATA,
+    )
+}
+
+/// Find the address of a programmable NFT's `TokenRecord` account, given the
+/// token mint and the token account holding it
+pub fn find_token_record(mint: impl Borrow<Pubkey>, token: impl Borrow<Pubkey>) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            "metadata".as_bytes(),
+            &METADATA.to_bytes(),
+            &mint.borrow().to_bytes(),
+            "token_record".as_bytes(),
+            &token.borrow().to_bytes(),
+        ],
+        &METADATA,
+    )
+}
+
 /// Find the address of an `Edition` account, given the token mint
 pub fn find_edition(mint: impl Borrow<Pubkey>) -> (Pubkey, u8) {
     Pubkey::find_program_address(
@@ -172,3 +213,15 @@ pub fn find_store_config(store: impl Borrow<Pubkey>) -> (Pubkey, u8) {
         &METAPLEX,
     )
 }
+
+/// Find the asset ID of a compressed NFT, given its merkle tree and leaf nonce
+pub fn find_compressed_asset_id(tree: impl Borrow<Pubkey>, nonce: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            "asset".as_bytes(),
+            &tree.borrow().to_bytes(),
+            &nonce.to_le_bytes(),
+        ],
+        &BUBBLEGUM,
+    )
+}
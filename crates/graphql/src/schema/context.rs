@@ -11,16 +11,21 @@ use objects::{
     auction_house::AuctionHouse,
     bid_receipt::BidReceipt,
     candy_machine::{
-        CandyMachine, CandyMachineCollectionPda, CandyMachineConfigLine, CandyMachineCreator,
-        CandyMachineEndSetting, CandyMachineGateKeeperConfig, CandyMachineHiddenSetting,
-        CandyMachineWhitelistMintSetting,
+        CandyGuard, CandyGuardSet, CandyMachine, CandyMachineCollectionPda, CandyMachineConfigLine,
+        CandyMachineConfigLineSetting, CandyMachineCreator, CandyMachineEndSetting,
+        CandyMachineGateKeeperConfig, CandyMachineHiddenSetting, CandyMachineWhitelistMintSetting,
     },
     collection::{Collection, CollectionTrend},
     genopets::{GenoHabitat, GenoRentalAgreement},
     graph_connection::GraphConnection,
     listing::{Bid, Listing},
     listing_receipt::ListingReceipt,
-    nft::{CollectionNFT, Nft, NftActivity, NftAttribute, NftCreator, NftFile, NftOwner},
+    maple::{MapleLoan, MaplePool},
+    nft::{
+        CollectionNFT, Nft, NftActivity, NftAttribute, NftCollectionAuthority, NftCompression,
+        NftCreator, NftFile, NftOwner, NftTokenRecord, NftTransfer, NftUseAuthority, RuleSet,
+        TokenStandard,
+    },
     profile::TwitterProfile,
     purchase_receipt::PurchaseReceipt,
     reward_center::RewardCenter,
@@ -35,7 +40,7 @@ use objects::{
 };
 use scalars::{
     markers::{StoreConfig, TokenMint},
-    PublicKey,
+    PublicKey, U64,
 };
 
 use super::{objects::nft::LastSale, prelude::*};
@@ -51,10 +56,14 @@ pub struct AppContext {
     pub reward_center_loader: Loader<PublicKey<AuctionHouse>, Option<RewardCenter>>,
     pub bid_receipt_loader: Loader<PublicKey<BidReceipt>, Option<BidReceipt>>,
     pub bid_receipts_loader: Loader<PublicKey<Nft>, Vec<BidReceipt>>,
+    pub candy_guard_loader: Loader<PublicKey<CandyGuard>, Option<CandyGuard>>,
+    pub candy_guard_groups_loader: Loader<PublicKey<CandyGuard>, Vec<CandyGuardSet>>,
     pub candy_machine_collection_pda_loader:
         Loader<PublicKey<CandyMachine>, Option<CandyMachineCollectionPda>>,
     pub candy_machine_config_line_loader:
         Loader<PublicKey<CandyMachine>, Vec<CandyMachineConfigLine>>,
+    pub candy_machine_config_line_settings_loader:
+        Loader<PublicKey<CandyMachine>, Option<CandyMachineConfigLineSetting>>,
     pub candy_machine_creator_loader: Loader<PublicKey<CandyMachine>, Vec<CandyMachineCreator>>,
     pub candy_machine_end_settings_loader:
         Loader<PublicKey<CandyMachine>, Option<CandyMachineEndSetting>>,
@@ -80,6 +89,7 @@ pub struct AppContext {
     pub listing_nfts_loader: Loader<PublicKey<Listing>, Vec<(usize, Nft)>>,
     pub listing_receipt_loader: Loader<PublicKey<ListingReceipt>, Option<ListingReceipt>>,
     pub listing_receipts_loader: Loader<PublicKey<Nft>, Vec<ListingReceipt>>,
+    pub maple_pool_loans_loader: Loader<PublicKey<MaplePool>, Vec<MapleLoan>>,
     pub market_stats_loader: Loader<PublicKey<StoreConfig>, Option<MarketStats>>,
     pub mint_stats_loader: Loader<PublicKey<AuctionHouse>, Option<MintStats>>,
     pub mr_collection_holders_count_loader:
@@ -94,10 +104,18 @@ pub struct AppContext {
     pub metaplex_certified_collection_loader: Loader<PublicKey<Nft>, Option<CollectionNFT>>,
     pub generic_collection_loader:
         Loader<objects::collection::CollectionId, Option<objects::collection::Collection>>,
+    pub nft_collection_authorities_loader: Loader<PublicKey<Nft>, Vec<NftCollectionAuthority>>,
+    pub nft_compression_loader: Loader<PublicKey<Nft>, Option<NftCompression>>,
     pub nft_creators_loader: Loader<PublicKey<Nft>, Vec<NftCreator>>,
     pub nft_files_loader: Loader<PublicKey<Nft>, Vec<NftFile>>,
     pub nft_loader: Loader<PublicKey<Nft>, Option<Nft>>,
     pub nft_owner_loader: Loader<PublicKey<Nft>, Option<NftOwner>>,
+    pub nft_printed_editions_loader: Loader<PublicKey<Nft>, Vec<U64>>,
+    pub nft_provenance_loader: Loader<PublicKey<Nft>, Vec<NftTransfer>>,
+    pub nft_rule_set_loader: Loader<PublicKey<Nft>, Option<RuleSet>>,
+    pub nft_token_record_loader: Loader<PublicKey<NftTokenRecord>, Option<NftTokenRecord>>,
+    pub nft_token_standard_loader: Loader<PublicKey<Nft>, Option<TokenStandard>>,
+    pub nft_use_authorities_loader: Loader<PublicKey<Nft>, Vec<NftUseAuthority>>,
     pub offer_loader: Loader<Uuid, Option<AhOffer>>,
     pub offers_loader: Loader<PublicKey<Nft>, Vec<AhOffer>>,
     pub purchase_loader: Loader<Uuid, Option<AhPurchase>>,
@@ -143,8 +161,11 @@ impl AppContext {
             reward_center_loader: Loader::new(batcher.clone()),
             bid_receipt_loader: Loader::new(batcher.clone()),
             bid_receipts_loader: Loader::new(batcher.clone()),
+            candy_guard_loader: Loader::new(batcher.clone()),
+            candy_guard_groups_loader: Loader::new(batcher.clone()),
             candy_machine_collection_pda_loader: Loader::new(batcher.clone()),
             candy_machine_config_line_loader: Loader::new(batcher.clone()),
+            candy_machine_config_line_settings_loader: Loader::new(batcher.clone()),
             candy_machine_creator_loader: Loader::new(batcher.clone()),
             candy_machine_end_settings_loader: Loader::new(batcher.clone()),
             candy_machine_gatekeeper_configs_loader: Loader::new(batcher.clone()),
@@ -164,6 +185,7 @@ impl AppContext {
             listing_nfts_loader: Loader::new(batcher.clone()),
             listing_receipt_loader: Loader::new(batcher.clone()),
             listing_receipts_loader: Loader::new(batcher.clone()),
+            maple_pool_loans_loader: Loader::new(batcher.clone()),
             market_stats_loader: Loader::new(batcher.clone()),
             mint_stats_loader: Loader::new(batcher.clone()),
             mr_collection_holders_count_loader: Loader::new(batcher.clone()),
@@ -176,10 +198,18 @@ impl AppContext {
             nft_last_sale_loader: Loader::new(batcher.clone()),
             generic_collection_loader: Loader::new(batcher.clone()),
             metaplex_certified_collection_loader: Loader::new(batcher.clone()),
+            nft_collection_authorities_loader: Loader::new(batcher.clone()),
+            nft_compression_loader: Loader::new(batcher.clone()),
             nft_creators_loader: Loader::new(batcher.clone()),
             nft_files_loader: Loader::new(batcher.clone()),
             nft_loader: Loader::new(batcher.clone()),
             nft_owner_loader: Loader::new(batcher.clone()),
+            nft_printed_editions_loader: Loader::new(batcher.clone()),
+            nft_provenance_loader: Loader::new(batcher.clone()),
+            nft_rule_set_loader: Loader::new(batcher.clone()),
+            nft_token_record_loader: Loader::new(batcher.clone()),
+            nft_token_standard_loader: Loader::new(batcher.clone()),
+            nft_use_authorities_loader: Loader::new(batcher.clone()),
             offer_loader: Loader::new(batcher.clone()),
             offers_loader: Loader::new(batcher.clone()),
             purchase_loader: Loader::new(batcher.clone()),
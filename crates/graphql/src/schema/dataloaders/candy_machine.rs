@@ -1,11 +1,12 @@
 use objects::candy_machine::{
-    CandyMachine, CandyMachineCollectionPda, CandyMachineConfigLine, CandyMachineCreator,
-    CandyMachineEndSetting, CandyMachineGateKeeperConfig, CandyMachineHiddenSetting,
-    CandyMachineWhitelistMintSetting,
+    CandyGuard, CandyGuardSet, CandyMachine, CandyMachineCollectionPda, CandyMachineConfigLine,
+    CandyMachineConfigLineSetting, CandyMachineCreator, CandyMachineEndSetting,
+    CandyMachineGateKeeperConfig, CandyMachineHiddenSetting, CandyMachineWhitelistMintSetting,
 };
 use scalars::PublicKey;
 use tables::{
-    candy_machine_collection_pdas, candy_machine_config_lines, candy_machine_creators,
+    candy_guard_groups, candy_guards, candy_machine_collection_pdas,
+    candy_machine_config_line_settings, candy_machine_config_lines, candy_machine_creators,
     candy_machine_end_settings, candy_machine_gate_keeper_configs, candy_machine_hidden_settings,
     candy_machine_whitelist_mint_settings,
 };
@@ -150,3 +151,64 @@ impl TryBatchFn<PublicKey<CandyMachine>, Option<CandyMachineGateKeeperConfig>> f
             .batch(addresses))
     }
 }
+
+#[async_trait]
+impl TryBatchFn<PublicKey<CandyMachine>, Option<CandyMachineConfigLineSetting>> for Batcher {
+    async fn load(
+        &mut self,
+        addresses: &[PublicKey<CandyMachine>],
+    ) -> TryBatchMap<PublicKey<CandyMachine>, Option<CandyMachineConfigLineSetting>> {
+        let conn = self.db()?;
+
+        let rows: Vec<models::CMConfigLineSetting> = candy_machine_config_line_settings::table
+            .filter(candy_machine_config_line_settings::candy_machine_address.eq(any(addresses)))
+            .load(&conn)
+            .context("Failed to load candy machine config line settings")?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| (r.candy_machine_address.clone(), r.into()))
+            .batch(addresses))
+    }
+}
+
+#[async_trait]
+impl TryBatchFn<PublicKey<CandyGuard>, Option<CandyGuard>> for Batcher {
+    async fn load(
+        &mut self,
+        addresses: &[PublicKey<CandyGuard>],
+    ) -> TryBatchMap<PublicKey<CandyGuard>, Option<CandyGuard>> {
+        let conn = self.db()?;
+
+        let rows: Vec<models::CandyGuard> = candy_guards::table
+            .filter(candy_guards::address.eq(any(addresses)))
+            .load(&conn)
+            .context("Failed to load candy guards")?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| (r.address.clone(), r.into()))
+            .batch(addresses))
+    }
+}
+
+#[async_trait]
+impl TryBatchFn<PublicKey<CandyGuard>, Vec<CandyGuardSet>> for Batcher {
+    async fn load(
+        &mut self,
+        addresses: &[PublicKey<CandyGuard>],
+    ) -> TryBatchMap<PublicKey<CandyGuard>, Vec<CandyGuardSet>> {
+        let conn = self.db()?;
+
+        let rows: Vec<models::CandyGuardGroup> = candy_guard_groups::table
+            .filter(candy_guard_groups::candy_guard_address.eq(any(addresses)))
+            .order_by(candy_guard_groups::label)
+            .load(&conn)
+            .context("Failed to load candy guard groups")?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| (r.candy_guard_address.clone(), r.try_into()))
+            .batch(addresses))
+    }
+}
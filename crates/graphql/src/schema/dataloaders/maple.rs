@@ -0,0 +1,35 @@
+use objects::maple::{MapleLoan, MaplePool};
+use scalars::PublicKey;
+use tables::{maple_loans, maple_open_term_loans};
+
+use super::prelude::*;
+
+#[async_trait]
+impl TryBatchFn<PublicKey<MaplePool>, Vec<MapleLoan>> for Batcher {
+    async fn load(
+        &mut self,
+        addresses: &[PublicKey<MaplePool>],
+    ) -> TryBatchMap<PublicKey<MaplePool>, Vec<MapleLoan>> {
+        let conn = self.db()?;
+
+        let loans: Vec<models::MapleLoan> = maple_loans::table
+            .filter(maple_loans::pool.eq(any(addresses)))
+            .load(&conn)
+            .context("Failed to load Maple loans")?;
+
+        let open_term_loans: Vec<models::MapleOpenTermLoan> = maple_open_term_loans::table
+            .filter(maple_open_term_loans::pool.eq(any(addresses)))
+            .load(&conn)
+            .context("Failed to load Maple open-term loans")?;
+
+        Ok(loans
+            .into_iter()
+            .map(|l| (l.pool.clone(), l.try_into()))
+            .chain(
+                open_term_loans
+                    .into_iter()
+                    .map(|l| (l.pool.clone(), l.try_into())),
+            )
+            .batch(addresses))
+    }
+}
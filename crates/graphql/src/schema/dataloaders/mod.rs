@@ -9,6 +9,7 @@ pub mod genopets;
 pub mod graph_connection;
 pub mod listing;
 pub mod listing_receipt;
+pub mod maple;
 pub mod nft;
 pub mod purchase_receipt;
 pub mod reward_center;
@@ -1,19 +1,27 @@
-use indexer_core::db::{queries, tables::last_sold_metadatas};
+use indexer_core::db::{custom_types::TokenStandardEnum, queries, tables::last_sold_metadatas};
 use objects::{
     collection::Collection,
     listing_receipt::ListingReceipt,
-    nft::{Nft, NftActivity, NftAttribute, NftCreator, NftFile, NftOwner},
+    nft::{
+        Nft, NftActivity, NftAttribute, NftCollectionAuthority, NftCompression, NftCreator,
+        NftFile, NftOwner, NftTokenRecord, NftTransfer, NftUseAuthority, RuleSet, TokenStandard,
+    },
     purchase_receipt::PurchaseReceipt,
 };
-use scalars::{markers::TokenMint, PublicKey};
+use scalars::{markers::TokenMint, PublicKey, U64};
 use tables::{
-    attributes, collection_mints, collections, current_metadata_owners, files, listing_receipts,
-    metadata_creators, metadata_jsons, metadatas, purchase_receipts, twitter_handle_name_services,
+    attributes, collection_authority_records, collection_mints, collections, compressed_nft_leaves,
+    current_metadata_owners, edition_markers, files, listing_receipts, metadata_creators,
+    metadata_jsons, metadata_programmable_configs, metadatas, nft_transfers, purchase_receipts,
+    rule_sets, token_records, twitter_handle_name_services, use_authority_records,
 };
 
 use super::prelude::*;
 use crate::schema::{objects::nft::LastSale, scalars::I64};
 
+/// Number of editions tracked by a single edition marker account
+const EDITION_MARKER_BIT_SIZE: u64 = 248;
+
 #[async_trait]
 impl TryBatchFn<PublicKey<Nft>, Vec<NftAttribute>> for Batcher {
     async fn load(
@@ -138,6 +146,224 @@ impl TryBatchFn<PublicKey<Nft>, Vec<NftCreator>> for Batcher {
     }
 }
 
+#[async_trait]
+impl TryBatchFn<PublicKey<Nft>, Option<NftCompression>> for Batcher {
+    async fn load(
+        &mut self,
+        addresses: &[PublicKey<Nft>],
+    ) -> TryBatchMap<PublicKey<Nft>, Option<NftCompression>> {
+        let conn = self.db()?;
+
+        let rows: Vec<models::CompressedNftLeaf> = compressed_nft_leaves::table
+            .filter(compressed_nft_leaves::asset_id.eq(any(addresses)))
+            .load(&conn)
+            .context("Failed to load NFT compression info")?;
+
+        Ok(rows
+            .into_iter()
+            .map(|l| (l.asset_id.clone().into_owned(), l.try_into()))
+            .batch(addresses))
+    }
+}
+
+#[async_trait]
+impl TryBatchFn<PublicKey<Nft>, Option<TokenStandard>> for Batcher {
+    async fn load(
+        &mut self,
+        addresses: &[PublicKey<Nft>],
+    ) -> TryBatchMap<PublicKey<Nft>, Option<TokenStandard>> {
+        let conn = self.db()?;
+
+        let rows: Vec<(String, Option<TokenStandardEnum>)> = metadatas::table
+            .filter(metadatas::address.eq(any(addresses)))
+            .select((metadatas::address, metadatas::token_standard))
+            .load(&conn)
+            .context("Failed to load NFT token standards")?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(address, standard)| standard.map(|s| (address, TokenStandard::from(s))))
+            .batch(addresses))
+    }
+}
+
+#[async_trait]
+impl TryBatchFn<PublicKey<NftTokenRecord>, Option<NftTokenRecord>> for Batcher {
+    async fn load(
+        &mut self,
+        addresses: &[PublicKey<NftTokenRecord>],
+    ) -> TryBatchMap<PublicKey<NftTokenRecord>, Option<NftTokenRecord>> {
+        let conn = self.db()?;
+
+        let rows: Vec<models::TokenRecord> = token_records::table
+            .filter(token_records::address.eq(any(addresses)))
+            .load(&conn)
+            .context("Failed to load token records")?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| (r.address.clone(), r.try_into()))
+            .batch(addresses))
+    }
+}
+
+#[async_trait]
+impl TryBatchFn<PublicKey<Nft>, Option<RuleSet>> for Batcher {
+    async fn load(
+        &mut self,
+        addresses: &[PublicKey<Nft>],
+    ) -> TryBatchMap<PublicKey<Nft>, Option<RuleSet>> {
+        let conn = self.db()?;
+
+        let rows: Vec<(String, models::RuleSet)> = metadata_programmable_configs::table
+            .inner_join(
+                rule_sets::table
+                    .on(metadata_programmable_configs::rule_set.eq(rule_sets::address.nullable())),
+            )
+            .filter(metadata_programmable_configs::metadata_address.eq(any(addresses)))
+            .select((
+                metadata_programmable_configs::metadata_address,
+                rule_sets::all_columns,
+            ))
+            .load(&conn)
+            .context("Failed to load NFT rule sets")?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(address, r)| (address, r.try_into()))
+            .batch(addresses))
+    }
+}
+
+#[async_trait]
+impl TryBatchFn<PublicKey<Nft>, Vec<NftCollectionAuthority>> for Batcher {
+    async fn load(
+        &mut self,
+        addresses: &[PublicKey<Nft>],
+    ) -> TryBatchMap<PublicKey<Nft>, Vec<NftCollectionAuthority>> {
+        let conn = self.db()?;
+
+        let rows: Vec<models::CollectionAuthorityRecord> = collection_authority_records::table
+            .filter(collection_authority_records::metadata_address.eq(any(addresses)))
+            .filter(collection_authority_records::collection_authority.is_not_null())
+            .load(&conn)
+            .context("Failed to load NFT collection authorities")?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|r| r.metadata_address.clone().map(|a| (a, r.try_into())))
+            .batch(addresses))
+    }
+}
+
+#[async_trait]
+impl TryBatchFn<PublicKey<Nft>, Vec<NftUseAuthority>> for Batcher {
+    async fn load(
+        &mut self,
+        addresses: &[PublicKey<Nft>],
+    ) -> TryBatchMap<PublicKey<Nft>, Vec<NftUseAuthority>> {
+        let conn = self.db()?;
+
+        let rows: Vec<models::UseAuthorityRecord> = use_authority_records::table
+            .filter(use_authority_records::metadata_address.eq(any(addresses)))
+            .filter(use_authority_records::use_authority.is_not_null())
+            .load(&conn)
+            .context("Failed to load NFT use authorities")?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|r| r.metadata_address.clone().map(|a| (a, r.try_into())))
+            .batch(addresses))
+    }
+}
+
+#[async_trait]
+impl TryBatchFn<PublicKey<Nft>, Vec<NftTransfer>> for Batcher {
+    async fn load(
+        &mut self,
+        addresses: &[PublicKey<Nft>],
+    ) -> TryBatchMap<PublicKey<Nft>, Vec<NftTransfer>> {
+        let conn = self.db()?;
+
+        let rows: Vec<(String, models::NftTransfer)> = nft_transfers::table
+            .inner_join(
+                metadatas::table.on(metadatas::mint_address.eq(nft_transfers::mint_address)),
+            )
+            .filter(metadatas::address.eq(any(addresses)))
+            .select((
+                metadatas::address,
+                (
+                    nft_transfers::mint_address,
+                    nft_transfers::source_token_account,
+                    nft_transfers::destination_token_account,
+                    nft_transfers::source_owner,
+                    nft_transfers::destination_owner,
+                    nft_transfers::authority,
+                    nft_transfers::created_at,
+                    nft_transfers::slot,
+                ),
+            ))
+            .order_by(nft_transfers::slot.desc())
+            .load(&conn)
+            .context("Failed to load NFT transfers")?;
+
+        Ok(rows
+            .into_iter()
+            .map(|(address, transfer)| (address, transfer.try_into()))
+            .batch(addresses))
+    }
+}
+
+#[async_trait]
+impl TryBatchFn<PublicKey<Nft>, Vec<U64>> for Batcher {
+    async fn load(
+        &mut self,
+        addresses: &[PublicKey<Nft>],
+    ) -> TryBatchMap<PublicKey<Nft>, Vec<U64>> {
+        let conn = self.db()?;
+
+        let rows: Vec<(String, Option<i64>, Option<Vec<u8>>)> = metadatas::table
+            .inner_join(
+                edition_markers::table.on(metadatas::edition_pda
+                    .nullable()
+                    .eq(edition_markers::master_edition_address)),
+            )
+            .filter(metadatas::address.eq(any(addresses)))
+            .select((
+                metadatas::address,
+                edition_markers::marker_index,
+                edition_markers::ledger,
+            ))
+            .order_by(edition_markers::marker_index)
+            .load(&conn)
+            .context("Failed to load NFT edition markers")?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|(address, index, ledger)| {
+                Some((address, u64::try_from(index?).ok()?, ledger?))
+            })
+            .flat_map(|(address, index, ledger)| {
+                // Each bit of the ledger marks an edition, most significant
+                // bit first
+                (0..EDITION_MARKER_BIT_SIZE)
+                    .filter(move |&bit| {
+                        usize::try_from(bit / 8)
+                            .ok()
+                            .and_then(|byte| ledger.get(byte))
+                            .map_or(false, |b| b & (1 << (7 - bit % 8)) != 0)
+                    })
+                    .map(move |bit| {
+                        (
+                            address.clone(),
+                            U64::from(index * EDITION_MARKER_BIT_SIZE + bit),
+                        )
+                    })
+            })
+            .batch(addresses))
+    }
+}
+
 #[async_trait]
 impl TryBatchFn<PublicKey<Nft>, Option<NftOwner>> for Batcher {
     async fn load(
@@ -161,6 +387,7 @@ impl TryBatchFn<PublicKey<Nft>, Option<NftOwner>> for Batcher {
                         current_metadata_owners::owner_address,
                         current_metadata_owners::token_account_address,
                         current_metadata_owners::slot,
+                        current_metadata_owners::write_version,
                     ),
                 ))
                 .load(&conn)
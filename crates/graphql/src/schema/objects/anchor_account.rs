@@ -0,0 +1,62 @@
+use indexer_core::db::models;
+use scalars::{markers::ProgramId, Json, PublicKey, U64};
+
+use super::prelude::*;
+
+/// An account of a program indexed from its Anchor IDL
+#[derive(Debug, Clone)]
+pub struct AnchorAccount {
+    pub address: PublicKey<Self>,
+    pub program: PublicKey<ProgramId>,
+    pub account_type: String,
+    pub data: Json,
+    pub slot: U64,
+}
+
+impl<'a> TryFrom<models::AnchorAccount<'a>> for AnchorAccount {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(
+        models::AnchorAccount {
+            address,
+            program,
+            account_type,
+            data,
+            slot,
+            write_version: _,
+        }: models::AnchorAccount,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            address: address.into(),
+            program: program.into(),
+            account_type: account_type.into_owned(),
+            data: data.into_owned().into(),
+            slot: slot.try_into()?,
+        })
+    }
+}
+
+#[graphql_object(Context = AppContext)]
+impl AnchorAccount {
+    pub fn address(&self) -> &PublicKey<Self> {
+        &self.address
+    }
+
+    pub fn program(&self) -> &PublicKey<ProgramId> {
+        &self.program
+    }
+
+    #[graphql(description = "The name of the account's type in the program's IDL")]
+    pub fn account_type(&self) -> &str {
+        &self.account_type
+    }
+
+    #[graphql(description = "The account data, decoded using the program's IDL")]
+    pub fn data(&self) -> &Json {
+        &self.data
+    }
+
+    pub fn slot(&self) -> U64 {
+        self.slot
+    }
+}
@@ -18,6 +18,8 @@ pub struct CandyMachine {
 
     pub uuid: String,
     pub price: U64,
+    pub sol_payment_lamports: Option<U64>,
+    pub token_payment_amount: Option<U64>,
     pub symbol: String,
     pub seller_fee_basis_points: i32,
     pub max_supply: U64,
@@ -25,6 +27,10 @@ pub struct CandyMachine {
     pub retain_authority: bool,
     pub go_live_date: Option<U64>,
     pub items_available: U64,
+
+    pub version: i32,
+    pub mint_authority: Option<PublicKey<CandyGuard>>,
+    pub collection_mint: Option<PublicKey<TokenMint>>,
 }
 
 #[graphql_object(Context = AppContext)]
@@ -53,10 +59,24 @@ impl CandyMachine {
         &self.uuid
     }
 
+    #[graphql(
+        description = "The mint price of a v2 candy machine, in `tokenMint` tokens if it is set \
+                       or lamports otherwise, or the SOL payment of a v3 candy machine in lamports"
+    )]
     pub fn price(&self) -> &U64 {
         &self.price
     }
 
+    #[graphql(description = "The price paid in SOL to mint, in lamports")]
+    pub fn sol_payment_lamports(&self) -> &Option<U64> {
+        &self.sol_payment_lamports
+    }
+
+    #[graphql(description = "The price paid in `tokenMint` tokens to mint")]
+    pub fn token_payment_amount(&self) -> &Option<U64> {
+        &self.token_payment_amount
+    }
+
     pub fn symbol(&self) -> &String {
         &self.symbol
     }
@@ -85,6 +105,44 @@ impl CandyMachine {
         &self.items_available
     }
 
+    #[graphql(description = "The candy machine program version, either 2 or 3")]
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+
+    #[graphql(description = "The authority allowed to mint from a v3 candy machine")]
+    pub fn mint_authority(&self) -> &Option<PublicKey<CandyGuard>> {
+        &self.mint_authority
+    }
+
+    #[graphql(description = "The collection mint of a v3 candy machine")]
+    pub fn collection_mint(&self) -> &Option<PublicKey<TokenMint>> {
+        &self.collection_mint
+    }
+
+    pub async fn config_line_settings(
+        &self,
+        ctx: &AppContext,
+    ) -> FieldResult<Option<CandyMachineConfigLineSetting>> {
+        ctx.candy_machine_config_line_settings_loader
+            .load(self.address.clone())
+            .await
+            .map_err(Into::into)
+    }
+
+    #[graphql(description = "The candy guard wrapping a v3 candy machine, if any")]
+    pub async fn candy_guard(&self, ctx: &AppContext) -> FieldResult<Option<CandyGuard>> {
+        let mint_authority = match self.mint_authority {
+            Some(ref m) => m.clone(),
+            None => return Ok(None),
+        };
+
+        ctx.candy_guard_loader
+            .load(mint_authority)
+            .await
+            .map_err(Into::into)
+    }
+
     #[graphql(description = "NOTE - this is currently bugged and will only return one creator")]
     pub async fn creators(&self, ctx: &AppContext) -> FieldResult<Vec<CandyMachineCreator>> {
         ctx.candy_machine_creator_loader
@@ -178,6 +236,15 @@ impl<'a, 'b> TryFrom<(models::CandyMachine<'a>, models::CandyMachineData<'b>)> f
             },
         ): (models::CandyMachine, models::CandyMachineData),
     ) -> Result<Self, Self::Error> {
+        let price = U64::try_from(price)?;
+
+        // v2 candy machines charge their price in the token mint if one is set
+        let (sol_payment_lamports, token_payment_amount) = if token_mint.is_some() {
+            (None, Some(price))
+        } else {
+            (Some(price), None)
+        };
+
         Ok(Self {
             address: address.into(),
             authority: authority.into(),
@@ -185,7 +252,9 @@ impl<'a, 'b> TryFrom<(models::CandyMachine<'a>, models::CandyMachineData<'b>)> f
             token_mint: token_mint.map(Into::into),
             items_redeemed: items_redeemed.try_into()?,
             uuid: uuid.into_owned(),
-            price: price.try_into()?,
+            price,
+            sol_payment_lamports,
+            token_payment_amount,
             symbol: symbol.into_owned(),
             seller_fee_basis_points: seller_fee_basis_points.try_into()?,
             max_supply: max_supply.try_into()?,
@@ -193,6 +262,297 @@ impl<'a, 'b> TryFrom<(models::CandyMachine<'a>, models::CandyMachineData<'b>)> f
             retain_authority,
             go_live_date: go_live_date.map(U64::try_from).transpose()?,
             items_available: items_available.try_into()?,
+            version: 2,
+            mint_authority: None,
+            collection_mint: None,
+        })
+    }
+}
+
+/// Present a v3 candy machine with the v2 fields derived from the default
+/// guard set of its candy guard, if any
+impl<'a, 'b>
+    TryFrom<(
+        models::CandyMachineV3<'a>,
+        Option<models::CandyGuardGroup<'b>>,
+    )> for CandyMachine
+{
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(
+        (
+            models::CandyMachineV3 {
+                address,
+                authority,
+                mint_authority,
+                collection_mint,
+                items_redeemed,
+                items_available,
+                symbol,
+                seller_fee_basis_points,
+                max_supply,
+                is_mutable,
+                ..
+            },
+            guards,
+        ): (models::CandyMachineV3, Option<models::CandyGuardGroup>),
+    ) -> Result<Self, Self::Error> {
+        let guards = guards.as_ref();
+
+        Ok(Self {
+            // v3 candy machines have no UUID, so mimic the v2 convention of
+            // using a prefix of the address
+            uuid: address.chars().take(6).collect(),
+            address: address.into(),
+            wallet: guards
+                .and_then(|g| g.sol_payment_destination.clone())
+                .unwrap_or_else(|| authority.clone())
+                .into(),
+            authority: authority.into(),
+            token_mint: guards
+                .and_then(|g| g.token_payment_mint.clone())
+                .map(Into::into),
+            items_redeemed: items_redeemed.try_into()?,
+            price: guards
+                .and_then(|g| g.sol_payment_lamports)
+                .unwrap_or_default()
+                .try_into()?,
+            sol_payment_lamports: guards
+                .and_then(|g| g.sol_payment_lamports)
+                .map(U64::try_from)
+                .transpose()?,
+            token_payment_amount: guards
+                .and_then(|g| g.token_payment_amount)
+                .map(U64::try_from)
+                .transpose()?,
+            symbol: symbol.into_owned(),
+            seller_fee_basis_points: seller_fee_basis_points.into(),
+            max_supply: max_supply.try_into()?,
+            is_mutable,
+            retain_authority: true,
+            go_live_date: guards
+                .and_then(|g| g.start_date)
+                .map(U64::try_from)
+                .transpose()?,
+            items_available: items_available.try_into()?,
+            version: 3,
+            mint_authority: Some(mint_authority.into()),
+            collection_mint: Some(collection_mint.into()),
+        })
+    }
+}
+
+#[derive(Debug, Clone, GraphQLObject)]
+pub struct CandyMachineConfigLineSetting {
+    pub candy_machine_address: PublicKey<CandyMachine>,
+    pub prefix_name: String,
+    pub name_length: i32,
+    pub prefix_uri: String,
+    pub uri_length: i32,
+    pub is_sequential: bool,
+}
+
+impl<'a> From<models::CMConfigLineSetting<'a>> for CandyMachineConfigLineSetting {
+    fn from(
+        models::CMConfigLineSetting {
+            candy_machine_address,
+            prefix_name,
+            name_length,
+            prefix_uri,
+            uri_length,
+            is_sequential,
+        }: models::CMConfigLineSetting,
+    ) -> Self {
+        Self {
+            candy_machine_address: candy_machine_address.into(),
+            prefix_name: prefix_name.into_owned(),
+            name_length,
+            prefix_uri: prefix_uri.into_owned(),
+            uri_length,
+            is_sequential,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CandyGuard {
+    pub address: PublicKey<CandyGuard>,
+    pub base: String,
+    pub authority: PublicKey<Wallet>,
+}
+
+#[graphql_object(Context = AppContext)]
+impl CandyGuard {
+    pub fn address(&self) -> &PublicKey<CandyGuard> {
+        &self.address
+    }
+
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+
+    pub fn authority(&self) -> &PublicKey<Wallet> {
+        &self.authority
+    }
+
+    #[graphql(description = "The guards applied to every mint, regardless of group")]
+    pub async fn default_guard_set(&self, ctx: &AppContext) -> FieldResult<Option<CandyGuardSet>> {
+        let groups = ctx
+            .candy_guard_groups_loader
+            .load(self.address.clone())
+            .await?;
+
+        Ok(groups.into_iter().find(|g| g.label.is_empty()))
+    }
+
+    #[graphql(description = "Labeled guard groups, one of which must be chosen when minting")]
+    pub async fn groups(&self, ctx: &AppContext) -> FieldResult<Vec<CandyGuardSet>> {
+        let groups = ctx
+            .candy_guard_groups_loader
+            .load(self.address.clone())
+            .await?;
+
+        Ok(groups.into_iter().filter(|g| !g.label.is_empty()).collect())
+    }
+}
+
+impl<'a> From<models::CandyGuard<'a>> for CandyGuard {
+    fn from(
+        models::CandyGuard {
+            address,
+            base,
+            authority,
+            ..
+        }: models::CandyGuard,
+    ) -> Self {
+        Self {
+            address: address.into(),
+            base: base.into_owned(),
+            authority: authority.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, GraphQLObject)]
+#[graphql(description = "A set of candy guards, unset guards are null")]
+pub struct CandyGuardSet {
+    #[graphql(description = "The group label, empty for the default guard set")]
+    pub label: String,
+    pub bot_tax_lamports: Option<U64>,
+    pub bot_tax_last_instruction: Option<bool>,
+    pub sol_payment_lamports: Option<U64>,
+    pub sol_payment_destination: Option<PublicKey<Wallet>>,
+    pub token_payment_amount: Option<U64>,
+    pub token_payment_mint: Option<PublicKey<TokenMint>>,
+    pub token_payment_destination_ata: Option<String>,
+    pub start_date: Option<DateTime<Utc>>,
+    pub third_party_signer_key: Option<PublicKey<Wallet>>,
+    pub token_gate_amount: Option<U64>,
+    pub token_gate_mint: Option<PublicKey<TokenMint>>,
+    pub gatekeeper_network: Option<String>,
+    pub gatekeeper_expire_on_use: Option<bool>,
+    pub end_date: Option<DateTime<Utc>>,
+    #[graphql(description = "lowercase base64 encoded string of the merkle root")]
+    pub allow_list_merkle_root: Option<String>,
+    pub mint_limit_id: Option<i32>,
+    pub mint_limit_limit: Option<i32>,
+    pub nft_payment_required_collection: Option<String>,
+    pub nft_payment_destination: Option<PublicKey<Wallet>>,
+    pub redeemed_amount_maximum: Option<U64>,
+    pub address_gate_address: Option<PublicKey<Wallet>>,
+    pub nft_gate_required_collection: Option<String>,
+    pub nft_burn_required_collection: Option<String>,
+    pub token_burn_amount: Option<U64>,
+    pub token_burn_mint: Option<PublicKey<TokenMint>>,
+    pub freeze_sol_payment_lamports: Option<U64>,
+    pub freeze_sol_payment_destination: Option<PublicKey<Wallet>>,
+    pub freeze_token_payment_amount: Option<U64>,
+    pub freeze_token_payment_mint: Option<PublicKey<TokenMint>>,
+    pub freeze_token_payment_destination_ata: Option<String>,
+    pub program_gate_additional: Option<Vec<String>>,
+}
+
+impl<'a> TryFrom<models::CandyGuardGroup<'a>> for CandyGuardSet {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(
+        models::CandyGuardGroup {
+            label,
+            bot_tax_lamports,
+            bot_tax_last_instruction,
+            sol_payment_lamports,
+            sol_payment_destination,
+            token_payment_amount,
+            token_payment_mint,
+            token_payment_destination_ata,
+            start_date,
+            third_party_signer_key,
+            token_gate_amount,
+            token_gate_mint,
+            gatekeeper_network,
+            gatekeeper_expire_on_use,
+            end_date,
+            allow_list_merkle_root,
+            mint_limit_id,
+            mint_limit_limit,
+            nft_payment_required_collection,
+            nft_payment_destination,
+            redeemed_amount_maximum,
+            address_gate_address,
+            nft_gate_required_collection,
+            nft_burn_required_collection,
+            token_burn_amount,
+            token_burn_mint,
+            freeze_sol_payment_lamports,
+            freeze_sol_payment_destination,
+            freeze_token_payment_amount,
+            freeze_token_payment_mint,
+            freeze_token_payment_destination_ata,
+            program_gate_additional,
+            ..
+        }: models::CandyGuardGroup,
+    ) -> Result<Self, Self::Error> {
+        let date = |d: i64| Utc.timestamp_opt(d, 0).single();
+
+        Ok(Self {
+            label: label.into_owned(),
+            bot_tax_lamports: bot_tax_lamports.map(U64::try_from).transpose()?,
+            bot_tax_last_instruction,
+            sol_payment_lamports: sol_payment_lamports.map(U64::try_from).transpose()?,
+            sol_payment_destination: sol_payment_destination.map(Into::into),
+            token_payment_amount: token_payment_amount.map(U64::try_from).transpose()?,
+            token_payment_mint: token_payment_mint.map(Into::into),
+            token_payment_destination_ata: token_payment_destination_ata.map(Cow::into_owned),
+            start_date: start_date.and_then(date),
+            third_party_signer_key: third_party_signer_key.map(Into::into),
+            token_gate_amount: token_gate_amount.map(U64::try_from).transpose()?,
+            token_gate_mint: token_gate_mint.map(Into::into),
+            gatekeeper_network: gatekeeper_network.map(Cow::into_owned),
+            gatekeeper_expire_on_use,
+            end_date: end_date.and_then(date),
+            allow_list_merkle_root: allow_list_merkle_root
+                .map(|r| base64::encode_config(r, base64::STANDARD_NO_PAD)),
+            mint_limit_id: mint_limit_id.map(Into::into),
+            mint_limit_limit,
+            nft_payment_required_collection: nft_payment_required_collection.map(Cow::into_owned),
+            nft_payment_destination: nft_payment_destination.map(Into::into),
+            redeemed_amount_maximum: redeemed_amount_maximum.map(U64::try_from).transpose()?,
+            address_gate_address: address_gate_address.map(Into::into),
+            nft_gate_required_collection: nft_gate_required_collection.map(Cow::into_owned),
+            nft_burn_required_collection: nft_burn_required_collection.map(Cow::into_owned),
+            token_burn_amount: token_burn_amount.map(U64::try_from).transpose()?,
+            token_burn_mint: token_burn_mint.map(Into::into),
+            freeze_sol_payment_lamports: freeze_sol_payment_lamports
+                .map(U64::try_from)
+                .transpose()?,
+            freeze_sol_payment_destination: freeze_sol_payment_destination.map(Into::into),
+            freeze_token_payment_amount: freeze_token_payment_amount
+                .map(U64::try_from)
+                .transpose()?,
+            freeze_token_payment_mint: freeze_token_payment_mint.map(Into::into),
+            freeze_token_payment_destination_ata: freeze_token_payment_destination_ata
+                .map(Cow::into_owned),
+            program_gate_additional,
         })
     }
 }
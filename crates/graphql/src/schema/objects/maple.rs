@@ -0,0 +1,156 @@
+use indexer_core::db::models;
+use objects::wallet::Wallet;
+use scalars::{markers::TokenMint, PublicKey, U64};
+
+use super::prelude::*;
+
+/// A Maple lending pool
+#[derive(Debug, Clone)]
+pub struct MaplePool {
+    pub address: PublicKey<Self>,
+    pub globals: String,
+    pub pool_delegate: PublicKey<Wallet>,
+    pub base_mint: PublicKey<TokenMint>,
+    pub shares_mint: PublicKey<TokenMint>,
+    pub locker: String,
+    pub total_value: U64,
+    pub slot: U64,
+}
+
+impl<'a> TryFrom<models::MaplePool<'a>> for MaplePool {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(
+        models::MaplePool {
+            address,
+            globals,
+            pool_delegate,
+            base_mint,
+            shares_mint,
+            locker,
+            total_value,
+            slot,
+            write_version: _,
+        }: models::MaplePool,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            address: address.into(),
+            globals: globals.into_owned(),
+            pool_delegate: pool_delegate.into(),
+            base_mint: base_mint.into(),
+            shares_mint: shares_mint.into(),
+            locker: locker.into_owned(),
+            total_value: total_value.try_into()?,
+            slot: slot.try_into()?,
+        })
+    }
+}
+
+#[graphql_object(Context = AppContext)]
+impl MaplePool {
+    pub fn address(&self) -> &PublicKey<Self> {
+        &self.address
+    }
+
+    pub fn globals(&self) -> &str {
+        &self.globals
+    }
+
+    pub fn pool_delegate(&self) -> &PublicKey<Wallet> {
+        &self.pool_delegate
+    }
+
+    #[graphql(description = "The mint of the token lent out by this pool")]
+    pub fn base_mint(&self) -> &PublicKey<TokenMint> {
+        &self.base_mint
+    }
+
+    #[graphql(description = "The mint of the shares issued to lenders")]
+    pub fn shares_mint(&self) -> &PublicKey<TokenMint> {
+        &self.shares_mint
+    }
+
+    #[graphql(description = "The token account holding the pool's idle liquidity")]
+    pub fn locker(&self) -> &str {
+        &self.locker
+    }
+
+    #[graphql(description = "Total value locked in the pool, in base mint tokens")]
+    pub fn tvl(&self) -> U64 {
+        self.total_value
+    }
+
+    pub fn slot(&self) -> U64 {
+        self.slot
+    }
+
+    #[graphql(description = "Amortized and open-term loans funded by this pool")]
+    pub async fn loans(&self, ctx: &AppContext) -> FieldResult<Vec<MapleLoan>> {
+        ctx.maple_pool_loans_loader
+            .load(self.address.clone())
+            .await
+            .map_err(Into::into)
+    }
+}
+
+/// A loan funded by a Maple pool
+#[derive(Debug, Clone, GraphQLObject)]
+pub struct MapleLoan {
+    pub address: String,
+    pub pool: PublicKey<MaplePool>,
+    pub borrower: PublicKey<Wallet>,
+    #[graphql(description = "The loan principal, in base mint tokens")]
+    pub principal: U64,
+    #[graphql(description = "The name of the loan's current state")]
+    pub state: String,
+    #[graphql(description = "True for open-term loans, false for amortized loans")]
+    pub open_term: bool,
+}
+
+impl<'a> TryFrom<models::MapleLoan<'a>> for MapleLoan {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(
+        models::MapleLoan {
+            address,
+            pool,
+            borrower,
+            principal,
+            state,
+            ..
+        }: models::MapleLoan,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            address: address.into_owned(),
+            pool: pool.into(),
+            borrower: borrower.into(),
+            principal: principal.try_into()?,
+            state: state.into_owned(),
+            open_term: false,
+        })
+    }
+}
+
+impl<'a> TryFrom<models::MapleOpenTermLoan<'a>> for MapleLoan {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(
+        models::MapleOpenTermLoan {
+            address,
+            pool,
+            borrower,
+            principal,
+            state,
+            ..
+        }: models::MapleOpenTermLoan,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            address: address.into_owned(),
+            pool: pool.into(),
+            borrower: borrower.into(),
+            principal: principal.try_into()?,
+            state: state.into_owned(),
+            open_term: true,
+        })
+    }
+}
@@ -1,6 +1,7 @@
 pub mod ah_listing;
 pub mod ah_offer;
 pub mod ah_purchase;
+pub mod anchor_account;
 pub mod attributes;
 pub mod auction_house;
 pub mod bid_receipt;
@@ -15,6 +16,7 @@ pub mod genopets;
 pub mod graph_connection;
 pub mod listing;
 pub mod listing_receipt;
+pub mod maple;
 pub mod marketplace;
 pub mod nft;
 pub mod profile;
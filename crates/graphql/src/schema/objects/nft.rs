@@ -1,7 +1,10 @@
+use std::str::FromStr;
+
 use indexer_core::{
     assets::{proxy_url, AssetIdentifier, ImageSize},
     bigdecimal::ToPrimitive,
     db::{
+        custom_types::{TokenDelegateRoleEnum, TokenRecordStateEnum, TokenStandardEnum},
         queries::{self, metadatas::CollectionNftOptions},
         sql_query,
         sql_types::Text,
@@ -11,6 +14,7 @@ use indexer_core::{
         },
     },
     pubkeys,
+    solana_program::pubkey::Pubkey,
     url::Url,
     util::unix_timestamp,
     uuid::Uuid,
@@ -217,13 +221,367 @@ impl NftOwner {
     }
 }
 
+/// Merkle tree information for an NFT compressed with Bubblegum
+#[derive(Debug, Clone)]
+pub struct NftCompression {
+    pub merkle_tree: String,
+    pub leaf_index: U64,
+    pub asset_id: String,
+    pub delegate: String,
+    pub data_hash: String,
+    pub creator_hash: String,
+    pub burned: bool,
+    pub redeemed: bool,
+}
+
+impl TryFrom<models::CompressedNftLeaf<'_>> for NftCompression {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(
+        models::CompressedNftLeaf {
+            merkle_tree,
+            leaf_index,
+            asset_id,
+            delegate,
+            data_hash,
+            creator_hash,
+            burned,
+            redeemed,
+            ..
+        }: models::CompressedNftLeaf,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            merkle_tree: merkle_tree.into_owned(),
+            leaf_index: leaf_index.try_into()?,
+            asset_id: asset_id.into_owned(),
+            delegate: delegate.into_owned(),
+            data_hash: data_hash.into_owned(),
+            creator_hash: creator_hash.into_owned(),
+            burned,
+            redeemed,
+        })
+    }
+}
+
+#[graphql_object(Context = AppContext)]
+impl NftCompression {
+    /// The address of the merkle tree holding this NFT
+    pub fn merkle_tree(&self) -> &str {
+        &self.merkle_tree
+    }
+
+    /// The index of this NFT's leaf in the merkle tree
+    pub fn leaf_index(&self) -> U64 {
+        self.leaf_index
+    }
+
+    /// The asset ID of this NFT, derived from the tree and leaf nonce
+    pub fn asset_id(&self) -> &str {
+        &self.asset_id
+    }
+
+    /// The delegate of this NFT's leaf, equal to the owner if none is set
+    pub fn delegate(&self) -> &str {
+        &self.delegate
+    }
+
+    /// The base58-encoded hash of the leaf's metadata
+    pub fn data_hash(&self) -> &str {
+        &self.data_hash
+    }
+
+    /// The base58-encoded hash of the leaf's creators
+    pub fn creator_hash(&self) -> &str {
+        &self.creator_hash
+    }
+
+    /// True if this NFT's leaf has been removed from the tree, either by
+    /// burning or redeeming it
+    pub fn burned(&self) -> bool {
+        self.burned
+    }
+
+    /// True if this NFT has been redeemed to be decompressed
+    pub fn redeemed(&self) -> bool {
+        self.redeemed
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, juniper::GraphQLEnum)]
+pub enum TokenStandard {
+    NonFungible,
+    FungibleAsset,
+    Fungible,
+    NonFungibleEdition,
+    ProgrammableNonFungible,
+}
+
+impl From<TokenStandardEnum> for TokenStandard {
+    fn from(v: TokenStandardEnum) -> Self {
+        match v {
+            TokenStandardEnum::NonFungible => Self::NonFungible,
+            TokenStandardEnum::FungibleAsset => Self::FungibleAsset,
+            TokenStandardEnum::Fungible => Self::Fungible,
+            TokenStandardEnum::NonFungibleEdition => Self::NonFungibleEdition,
+            TokenStandardEnum::ProgrammableNonFungible => Self::ProgrammableNonFungible,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, juniper::GraphQLEnum)]
+pub enum TokenRecordState {
+    Unlocked,
+    Locked,
+    Listed,
+}
+
+impl From<TokenRecordStateEnum> for TokenRecordState {
+    fn from(v: TokenRecordStateEnum) -> Self {
+        match v {
+            TokenRecordStateEnum::Unlocked => Self::Unlocked,
+            TokenRecordStateEnum::Locked => Self::Locked,
+            TokenRecordStateEnum::Listed => Self::Listed,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, juniper::GraphQLEnum)]
+pub enum TokenDelegateRole {
+    Sale,
+    Transfer,
+    Utility,
+    Staking,
+    Standard,
+    LockedTransfer,
+    Migration,
+}
+
+impl From<TokenDelegateRoleEnum> for TokenDelegateRole {
+    fn from(v: TokenDelegateRoleEnum) -> Self {
+        match v {
+            TokenDelegateRoleEnum::Sale => Self::Sale,
+            TokenDelegateRoleEnum::Transfer => Self::Transfer,
+            TokenDelegateRoleEnum::Utility => Self::Utility,
+            TokenDelegateRoleEnum::Staking => Self::Staking,
+            TokenDelegateRoleEnum::Standard => Self::Standard,
+            TokenDelegateRoleEnum::LockedTransfer => Self::LockedTransfer,
+            TokenDelegateRoleEnum::Migration => Self::Migration,
+        }
+    }
+}
+
+/// The lock and delegate state of a programmable NFT's token account
+#[derive(Debug, Clone)]
+pub struct NftTokenRecord {
+    pub address: String,
+    pub state: TokenRecordState,
+    pub rule_set_revision: Option<U64>,
+    pub delegate: Option<PublicKey<Wallet>>,
+    pub delegate_role: Option<TokenDelegateRole>,
+    pub locked_transfer: Option<PublicKey<Wallet>>,
+}
+
+impl TryFrom<models::TokenRecord<'_>> for NftTokenRecord {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(
+        models::TokenRecord {
+            address,
+            state,
+            rule_set_revision,
+            delegate,
+            delegate_role,
+            locked_transfer,
+            ..
+        }: models::TokenRecord,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            address: address.into_owned(),
+            state: state.into(),
+            rule_set_revision: rule_set_revision.map(TryInto::try_into).transpose()?,
+            delegate: delegate.map(Into::into),
+            delegate_role: delegate_role.map(Into::into),
+            locked_transfer: locked_transfer.map(Into::into),
+        })
+    }
+}
+
+#[graphql_object(Context = AppContext)]
+impl NftTokenRecord {
+    /// The address of the token record account
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// The lock state of the token account
+    pub fn state(&self) -> TokenRecordState {
+        self.state
+    }
+
+    /// True if the token can't be transferred until its delegate unlocks it
+    pub fn locked(&self) -> bool {
+        self.state == TokenRecordState::Locked
+    }
+
+    /// The revision of the rule set cached when the delegate was approved
+    pub fn rule_set_revision(&self) -> Option<U64> {
+        self.rule_set_revision
+    }
+
+    /// The current delegate of the token account
+    pub fn delegate(&self) -> &Option<PublicKey<Wallet>> {
+        &self.delegate
+    }
+
+    /// The role granted to the current delegate
+    pub fn delegate_role(&self) -> Option<TokenDelegateRole> {
+        self.delegate_role
+    }
+
+    /// The only destination a locked transfer delegate may transfer to
+    pub fn locked_transfer(&self) -> &Option<PublicKey<Wallet>> {
+        &self.locked_transfer
+    }
+}
+
+/// A token-auth-rules rule set governing programmable NFT transfers
+#[derive(Debug, Clone, GraphQLObject)]
+pub struct RuleSet {
+    pub address: String,
+    pub owner: PublicKey<Wallet>,
+    pub name: String,
+    #[graphql(description = "The index of the latest revision of the rule set")]
+    pub revision: U64,
+}
+
+impl TryFrom<models::RuleSet<'_>> for RuleSet {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(
+        models::RuleSet {
+            address,
+            owner,
+            name,
+            revision,
+            ..
+        }: models::RuleSet,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            address: address.into_owned(),
+            owner: owner.into(),
+            name: name.into_owned(),
+            revision: revision.try_into()?,
+        })
+    }
+}
+
+/// An authority delegated to verify NFTs into a collection
+#[derive(Debug, Clone, GraphQLObject)]
+pub struct NftCollectionAuthority {
+    #[graphql(description = "The address of the collection authority record")]
+    pub address: String,
+    pub authority: PublicKey<Wallet>,
+}
+
+impl TryFrom<models::CollectionAuthorityRecord<'_>> for NftCollectionAuthority {
+    type Error = Error;
+
+    fn try_from(
+        models::CollectionAuthorityRecord {
+            address,
+            collection_authority,
+            ..
+        }: models::CollectionAuthorityRecord,
+    ) -> Result<Self> {
+        Ok(Self {
+            address: address.into_owned(),
+            authority: collection_authority
+                .context("Collection authority record was missing its authority")?
+                .into(),
+        })
+    }
+}
+
+/// An authority delegated to spend an NFT's uses
+#[derive(Debug, Clone, GraphQLObject)]
+pub struct NftUseAuthority {
+    #[graphql(description = "The address of the use authority record")]
+    pub address: String,
+    pub authority: PublicKey<Wallet>,
+    #[graphql(description = "The NFT owner who approved the authority")]
+    pub owner: Option<PublicKey<Wallet>>,
+    #[graphql(description = "The number of uses the authority may still spend")]
+    pub allowed_uses: Option<U64>,
+}
+
+impl TryFrom<models::UseAuthorityRecord<'_>> for NftUseAuthority {
+    type Error = Error;
+
+    fn try_from(
+        models::UseAuthorityRecord {
+            address,
+            allowed_uses,
+            use_authority,
+            owner,
+            ..
+        }: models::UseAuthorityRecord,
+    ) -> Result<Self> {
+        Ok(Self {
+            address: address.into_owned(),
+            authority: use_authority
+                .context("Use authority record was missing its authority")?
+                .into(),
+            owner: owner.map(Into::into),
+            allowed_uses: allowed_uses.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+/// A single-token transfer of an NFT between two token accounts
+#[derive(Debug, Clone, GraphQLObject)]
+pub struct NftTransfer {
+    #[graphql(description = "The sending wallet, if its token account could be read")]
+    pub source_owner: Option<PublicKey<Wallet>>,
+    #[graphql(description = "The receiving wallet, if its token account has been indexed")]
+    pub destination_owner: Option<PublicKey<Wallet>>,
+    pub source_token_account: String,
+    pub destination_token_account: String,
+    pub slot: U64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TryFrom<models::NftTransfer<'_>> for NftTransfer {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(
+        models::NftTransfer {
+            source_token_account,
+            destination_token_account,
+            source_owner,
+            destination_owner,
+            created_at,
+            slot,
+            ..
+        }: models::NftTransfer,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            source_owner: source_owner.map(Into::into),
+            destination_owner: destination_owner.map(Into::into),
+            source_token_account: source_token_account.into_owned(),
+            destination_token_account: destination_token_account.into_owned(),
+            slot: slot.try_into()?,
+            created_at: DateTime::from_utc(created_at, Utc),
+        })
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct NftActivity {
     pub id: Uuid,
     pub metadata: PublicKey<Nft>,
-    pub auction_house: PublicKey<AuctionHouse>,
+    pub auction_house: Option<PublicKey<AuctionHouse>>,
     pub marketplace_program_address: String,
-    pub price: U64,
+    pub price: Option<U64>,
     pub created_at: DateTime<Utc>,
     pub wallets: Vec<Wallet>,
     pub activity_type: String,
@@ -248,9 +606,9 @@ impl TryFrom<models::NftActivity> for NftActivity {
         Ok(Self {
             id,
             metadata: metadata.into(),
-            auction_house: auction_house.into(),
+            auction_house: auction_house.map(Into::into),
             marketplace_program_address: marketplace_program,
-            price: price.try_into()?,
+            price: price.map(TryInto::try_into).transpose()?,
             created_at: DateTime::from_utc(created_at, Utc),
             wallets: wallets
                 .into_iter()
@@ -272,7 +630,9 @@ impl NftActivity {
         &self.metadata
     }
 
-    fn price(&self) -> U64 {
+    /// The price of the listing, offer or purchase, or null for metadata
+    /// program activity
+    fn price(&self) -> Option<U64> {
         self.price
     }
 
@@ -300,9 +660,14 @@ impl NftActivity {
     }
 
     pub async fn auction_house(&self, context: &AppContext) -> FieldResult<Option<AuctionHouse>> {
+        let auction_house = match self.auction_house {
+            Some(ref auction_house) => auction_house.clone(),
+            None => return Ok(None),
+        };
+
         context
             .auction_house_loader
-            .load(self.auction_house.clone())
+            .load(auction_house)
             .await
             .map_err(Into::into)
     }
@@ -537,6 +902,84 @@ If no value is provided, it will return XSmall")))]
             .map_err(Into::into)
     }
 
+    pub async fn token_standard(&self, ctx: &AppContext) -> FieldResult<Option<TokenStandard>> {
+        ctx.nft_token_standard_loader
+            .load(self.address.clone().into())
+            .await
+            .map_err(Into::into)
+    }
+
+    /// The lock and delegate state of this NFT's token account, if it is a
+    /// programmable NFT
+    pub async fn token_record(&self, ctx: &AppContext) -> FieldResult<Option<NftTokenRecord>> {
+        let (mint, token) = match (
+            Pubkey::from_str(&self.mint_address),
+            Pubkey::from_str(&self.token_account_address),
+        ) {
+            (Ok(m), Ok(t)) => (m, t),
+            _ => return Ok(None),
+        };
+
+        let (address, _bump) = pubkeys::find_token_record(mint, token);
+
+        ctx.nft_token_record_loader
+            .load(address.to_string().into())
+            .await
+            .map_err(Into::into)
+    }
+
+    /// The rule set governing transfers of this NFT, if it is a programmable
+    /// NFT
+    pub async fn rule_set(&self, ctx: &AppContext) -> FieldResult<Option<RuleSet>> {
+        ctx.nft_rule_set_loader
+            .load(self.address.clone().into())
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Authorities delegated to verify NFTs into this collection NFT
+    pub async fn collection_authorities(
+        &self,
+        ctx: &AppContext,
+    ) -> FieldResult<Vec<NftCollectionAuthority>> {
+        ctx.nft_collection_authorities_loader
+            .load(self.address.clone().into())
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Authorities delegated to spend this NFT's uses
+    pub async fn use_authorities(&self, ctx: &AppContext) -> FieldResult<Vec<NftUseAuthority>> {
+        ctx.nft_use_authorities_loader
+            .load(self.address.clone().into())
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Transfers of this NFT between wallets, newest first
+    pub async fn provenance(&self, ctx: &AppContext) -> FieldResult<Vec<NftTransfer>> {
+        ctx.nft_provenance_loader
+            .load(self.address.clone().into())
+            .await
+            .map_err(Into::into)
+    }
+
+    /// The edition numbers printed from this NFT, if it is a master edition
+    pub async fn printed_editions(&self, ctx: &AppContext) -> FieldResult<Vec<U64>> {
+        ctx.nft_printed_editions_loader
+            .load(self.address.clone().into())
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Merkle tree information for this NFT, if it is compressed
+    pub async fn compression(&self, ctx: &AppContext) -> FieldResult<Option<NftCompression>> {
+        ctx.nft_compression_loader
+            .load(self.address.clone().into())
+            .await
+            .map_err(Into::into)
+    }
+
     pub async fn listings(&self, ctx: &AppContext) -> FieldResult<Vec<AhListing>> {
         ctx.ah_listings_loader
             .load(self.address.clone().into())
@@ -177,9 +177,9 @@ impl CollectedCollection {
 pub struct WalletActivity {
     pub id: Uuid,
     pub metadata: PublicKey<Nft>,
-    pub auction_house: PublicKey<AuctionHouse>,
+    pub auction_house: Option<PublicKey<AuctionHouse>>,
     pub marketplace_program_address: String,
-    pub price: U64,
+    pub price: Option<U64>,
     pub created_at: DateTime<Utc>,
     pub wallets: Vec<Wallet>,
     pub activity_type: String,
@@ -204,9 +204,9 @@ impl TryFrom<models::WalletActivity> for WalletActivity {
         Ok(Self {
             id,
             metadata: metadata.into(),
-            auction_house: auction_house.into(),
+            auction_house: auction_house.map(Into::into),
             marketplace_program_address: marketplace_program,
-            price: price.try_into()?,
+            price: price.map(TryInto::try_into).transpose()?,
             created_at: DateTime::from_utc(created_at, Utc),
             wallets: wallets
                 .into_iter()
@@ -228,7 +228,8 @@ impl WalletActivity {
         &self.metadata
     }
 
-    fn price(&self) -> U64 {
+    /// The price of the listing, offer or purchase, or null for transfers
+    fn price(&self) -> Option<U64> {
         self.price
     }
 
@@ -256,9 +257,14 @@ impl WalletActivity {
     }
 
     pub async fn auction_house(&self, context: &AppContext) -> FieldResult<Option<AuctionHouse>> {
+        let auction_house = match self.auction_house {
+            Some(ref auction_house) => auction_house.clone(),
+            None => return Ok(None),
+        };
+
         context
             .auction_house_loader
-            .load(self.auction_house.clone())
+            .load(auction_house)
             .await
             .map_err(Into::into)
     }
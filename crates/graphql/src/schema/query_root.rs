@@ -9,6 +9,7 @@ use indexer_core::{
 };
 use objects::{
     ah_listing::AhListing,
+    anchor_account::AnchorAccount,
     auction_house::AuctionHouse,
     bid_receipt::BidReceipt,
     bonding_change::EnrichedBondingChange,
@@ -21,6 +22,7 @@ use objects::{
     genopets::{GenoHabitat, GenoHabitatList, GenoHabitatsParams},
     graph_connection::GraphConnection,
     listing::{Listing, ListingColumns, ListingRow},
+    maple::{MapleLoan, MaplePool},
     marketplace::Marketplace,
     nft::{CollectionNFT, MetadataJson, Nft, NftActivity, NftCount, NftCreator, NftsStats},
     profile::{ProfilesStats, TwitterProfile},
@@ -30,14 +32,18 @@ use objects::{
     storefront::{Storefront, StorefrontColumns},
     wallet::{AssociatedTokenAccount, Wallet},
 };
-use scalars::{markers::TokenMint, PublicKey};
+use scalars::{
+    markers::{ProgramId, TokenMint},
+    PublicKey,
+};
 use serde_json::Value;
 use tables::{
-    associated_token_accounts, auction_caches, auction_datas, auction_datas_ext, auction_houses,
-    bid_receipts, candy_machine_datas, candy_machines, current_metadata_owners, geno_habitat_datas,
-    governances, graph_connections, metadata_jsons, metadatas, realms, signatory_records,
-    store_config_jsons, storefronts, token_owner_records, twitter_handle_name_services,
-    wallet_totals,
+    anchor_accounts, associated_token_accounts, auction_caches, auction_datas, auction_datas_ext,
+    auction_houses, bid_receipts, candy_guard_groups, candy_machine_datas, candy_machines,
+    candy_machines_v3, current_metadata_owners, geno_habitat_datas, governances, graph_connections,
+    maple_loans, maple_open_term_loans, maple_pools, metadata_jsons, metadatas, realms,
+    signatory_records, store_config_jsons, storefronts, token_owner_records,
+    twitter_handle_name_services, wallet_totals,
 };
 
 use super::prelude::*;
@@ -61,19 +67,40 @@ impl QueryRoot {
     fn candy_machine(context: &AppContext, address: String) -> FieldResult<Option<CandyMachine>> {
         let conn = context.shared.db.get()?;
 
-        candy_machines::table
+        let v2 = candy_machines::table
             .inner_join(
                 candy_machine_datas::table
                     .on(candy_machines::address.eq(candy_machine_datas::candy_machine_address)),
             )
-            .filter(candy_machines::address.eq(address))
+            .filter(candy_machines::address.eq(&address))
             .select((
                 candy_machines::all_columns,
                 candy_machine_datas::all_columns,
             ))
             .first::<(models::CandyMachine, models::CandyMachineData)>(&conn)
             .optional()
-            .context("Failed to load candy machine by address.")?
+            .context("Failed to load candy machine by address.")?;
+
+        if let Some(v2) = v2 {
+            return v2.try_into().map(Some).map_err(Into::into);
+        }
+
+        // v3 candy machines are usually wrapped by a candy guard acting as
+        // their mint authority, whose default guard set holds the mint price
+        candy_machines_v3::table
+            .left_join(
+                candy_guard_groups::table.on(candy_machines_v3::mint_authority
+                    .eq(candy_guard_groups::candy_guard_address)
+                    .and(candy_guard_groups::label.eq(""))),
+            )
+            .filter(candy_machines_v3::address.eq(address))
+            .select((
+                candy_machines_v3::all_columns,
+                candy_guard_groups::all_columns.nullable(),
+            ))
+            .first::<(models::CandyMachineV3, Option<models::CandyGuardGroup>)>(&conn)
+            .optional()
+            .context("Failed to load v3 candy machine by address.")?
             .map(TryInto::try_into)
             .transpose()
             .map_err(Into::into)
@@ -705,7 +732,7 @@ impl QueryRoot {
         Self::candy_machine(ctx, addr)
     }
 
-    #[graphql(description = "Get a candy machine by the candy machine config address")]
+    #[graphql(description = "Get a v2 or v3 candy machine by its address")]
     fn candy_machine(
         &self,
         context: &AppContext,
@@ -714,6 +741,134 @@ impl QueryRoot {
         Self::candy_machine(context, address)
     }
 
+    #[graphql(description = "Get an account indexed from its program's Anchor IDL")]
+    fn anchor_account(
+        &self,
+        context: &AppContext,
+        address: PublicKey<AnchorAccount>,
+    ) -> FieldResult<Option<AnchorAccount>> {
+        let conn = context.shared.db.get()?;
+
+        anchor_accounts::table
+            .filter(anchor_accounts::address.eq(address))
+            .first::<models::AnchorAccount>(&conn)
+            .optional()
+            .context("Failed to load Anchor account")?
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(Into::into)
+    }
+
+    #[graphql(description = "List accounts indexed from an Anchor IDL, latest first")]
+    fn anchor_accounts(
+        &self,
+        context: &AppContext,
+        #[graphql(description = "Filter on the owning program")] program: PublicKey<ProgramId>,
+        #[graphql(description = "Filter on the IDL account type")] account_type: Option<String>,
+        #[graphql(description = "Query limit")] limit: i32,
+        #[graphql(description = "Query offset")] offset: i32,
+    ) -> FieldResult<Vec<AnchorAccount>> {
+        let conn = context.shared.db.get()?;
+
+        let mut query = anchor_accounts::table
+            .filter(anchor_accounts::program.eq(program))
+            .into_boxed();
+
+        if let Some(account_type) = account_type {
+            query = query.filter(anchor_accounts::account_type.eq(account_type));
+        }
+
+        query
+            .order_by(anchor_accounts::slot.desc())
+            .offset(offset.into())
+            .limit(limit.into())
+            .load::<models::AnchorAccount>(&conn)
+            .context("Failed to load Anchor accounts")?
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()
+            .map_err(Into::into)
+    }
+
+    #[graphql(description = "Get a Maple lending pool by its address")]
+    fn maple_pool(
+        &self,
+        context: &AppContext,
+        address: PublicKey<MaplePool>,
+    ) -> FieldResult<Option<MaplePool>> {
+        let conn = context.shared.db.get()?;
+
+        maple_pools::table
+            .filter(maple_pools::address.eq(address))
+            .first::<models::MaplePool>(&conn)
+            .optional()
+            .context("Failed to load Maple pool")?
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(Into::into)
+    }
+
+    #[graphql(description = "List Maple lending pools, ordered by TVL")]
+    fn maple_pools(
+        &self,
+        context: &AppContext,
+        #[graphql(description = "Filter on pool delegates")] pool_delegates: Option<
+            Vec<PublicKey<Wallet>>,
+        >,
+        #[graphql(description = "Query limit")] limit: i32,
+        #[graphql(description = "Query offset")] offset: i32,
+    ) -> FieldResult<Vec<MaplePool>> {
+        if limit > 250 {
+            return Err(FieldError::new(
+                "The query limit cannot be higher than 250",
+                graphql_value!(limit),
+            ));
+        }
+
+        let conn = context.shared.db.get()?;
+
+        let mut query = maple_pools::table.into_boxed();
+
+        if let Some(pool_delegates) = pool_delegates {
+            query = query.filter(maple_pools::pool_delegate.eq(any(pool_delegates)));
+        }
+
+        query
+            .order_by(maple_pools::total_value.desc())
+            .offset(offset.into())
+            .limit(limit.into())
+            .load::<models::MaplePool>(&conn)
+            .context("Failed to load Maple pools")?
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()
+            .map_err(Into::into)
+    }
+
+    #[graphql(description = "Get an amortized or open-term Maple loan by its address")]
+    fn maple_loan(&self, context: &AppContext, address: String) -> FieldResult<Option<MapleLoan>> {
+        let conn = context.shared.db.get()?;
+
+        let loan = maple_loans::table
+            .filter(maple_loans::address.eq(&address))
+            .first::<models::MapleLoan>(&conn)
+            .optional()
+            .context("Failed to load Maple loan")?;
+
+        if let Some(loan) = loan {
+            return loan.try_into().map(Some).map_err(Into::into);
+        }
+
+        maple_open_term_loans::table
+            .filter(maple_open_term_loans::address.eq(address))
+            .first::<models::MapleOpenTermLoan>(&conn)
+            .optional()
+            .context("Failed to load Maple open-term loan")?
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(Into::into)
+    }
+
     fn storefronts(&self, context: &AppContext) -> FieldResult<Vec<Storefront>> {
         let conn = context.shared.db.get()?;
         let rows: Vec<models::Storefront> = storefronts::table
@@ -0,0 +1,56 @@
+use juniper::Object;
+
+use super::prelude::*;
+
+/// Arbitrary JSON data
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Json(serde_json::Value);
+
+/// Integers which don't fit in an `Int` are returned as strings, matching the
+/// `I64` and `U64` scalars
+fn to_value<S: ScalarValue>(json: &serde_json::Value) -> Value<S> {
+    use serde_json::Value as J;
+
+    match json {
+        J::Null => Value::null(),
+        J::Bool(b) => Value::scalar(*b),
+        J::Number(n) => n
+            .as_i64()
+            .and_then(|i| i32::try_from(i).ok())
+            .map(Value::scalar)
+            .or_else(|| n.as_f64().filter(|_| n.is_f64()).map(Value::scalar))
+            .unwrap_or_else(|| Value::scalar(n.to_string())),
+        J::String(s) => Value::scalar(s.clone()),
+        J::Array(a) => Value::list(a.iter().map(to_value).collect()),
+        J::Object(o) => Value::object(o.iter().fold(
+            Object::with_capacity(o.len()),
+            |mut obj, (k, v)| {
+                obj.add_field(k.clone(), to_value(v));
+                obj
+            },
+        )),
+    }
+}
+
+#[graphql_scalar(description = "JSON")]
+impl<S: ScalarValue> GraphQLScalar for Json {
+    fn resolve(&self) -> Value {
+        to_value(&self.0)
+    }
+
+    fn from_input_value(v: &InputValue) -> Option<Self> {
+        v.as_string_value()
+            .and_then(|s| serde_json::from_str(s).ok())
+            .map(Self)
+    }
+
+    fn from_str<'a>(value: ScalarToken<'a>) -> ParseScalarResult<'a, S> {
+        <String as ParseScalarValue<S>>::from_str(value)
+    }
+}
+
+impl From<serde_json::Value> for Json {
+    fn from(value: serde_json::Value) -> Self {
+        Self(value)
+    }
+}
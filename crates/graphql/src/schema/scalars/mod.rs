@@ -1,4 +1,5 @@
 mod bigint;
+mod json;
 mod numeric;
 mod public_key;
 
@@ -17,5 +18,6 @@ pub mod markers {
 }
 
 pub use bigint::{I64, U64};
+pub use json::Json;
 pub use numeric::Numeric;
 pub use public_key::PublicKey;
@@ -0,0 +1,17 @@
+//! Build script generating the Geyser gRPC client from the Yellowstone
+//! protobuf definitions in `proto/`
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    #[cfg(feature = "geyser")]
+    {
+        // Use a vendored protoc so builds don't depend on a system install
+        std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+
+        tonic_build::configure().compile(&["proto/geyser.proto"], &[
+            std::path::PathBuf::from("proto"),
+            protoc_bin_vendored::include_path()?,
+        ])?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use holaplex_indexer::geyser::{BackfillArgs, Client, ClientArgs, Registry, RegistryArgs};
+use indexer_core::{clap, prelude::*};
+use indexer_rabbitmq::{fungible_indexer, geyser, http_indexer, search_indexer, suffix::Suffix};
+
+/// Indexer worker for backfilling program accounts from a Solana RPC node
+#[derive(Debug, clap::Args)]
+#[group(skip)]
+#[command(name = "holaplex-indexer-backfill", version, long_about = None)]
+struct Args {
+    /// The address of an AMQP server to connect to
+    ///
+    /// If this is omitted, dispatches to the other indexers are skipped.
+    #[arg(long, env, requires("network"))]
+    amqp_url: Option<String>,
+
+    /// The network the RPC node belongs to
+    #[arg(long, env, requires("amqp_url"))]
+    network: Option<geyser::Network>,
+
+    #[command(flatten)]
+    queue_suffix: Suffix,
+
+    #[command(flatten)]
+    client: ClientArgs,
+
+    #[command(flatten)]
+    programs: RegistryArgs,
+
+    #[command(flatten)]
+    backfill: BackfillArgs,
+}
+
+fn main() {
+    holaplex_indexer::run(
+        |Args {
+             amqp_url,
+             network,
+             queue_suffix,
+             client,
+             programs,
+             backfill,
+         },
+         params,
+         db| async move {
+            let registry = Registry::new(programs)?;
+
+            // Keep the connection open until the backfill is finished
+            let conn = match amqp_url {
+                Some(url) => {
+                    Some(holaplex_indexer::amqp_connect(url, env!("CARGO_BIN_NAME")).await?)
+                },
+                None => None,
+            };
+
+            let client = if let (Some(conn), Some(network)) = (&conn, &network) {
+                let receiver = match queue_suffix {
+                    Suffix::Debug(ref s) => s.clone(),
+                    _ => network.to_string(),
+                };
+
+                Client::new_rc(
+                    db,
+                    conn,
+                    http_indexer::QueueType::new(&receiver, &queue_suffix)?,
+                    http_indexer::QueueType::new(&receiver, &queue_suffix)?,
+                    search_indexer::QueueType::new(&receiver, &queue_suffix)?,
+                    fungible_indexer::QueueType::new(&receiver, &queue_suffix)?,
+                    client,
+                )
+                .await
+            } else {
+                Client::new_offline(db, client)
+            }
+            .context("Failed to construct Client")?;
+
+            holaplex_indexer::geyser::backfill(&client, &registry, backfill, &params).await
+        },
+    );
+}
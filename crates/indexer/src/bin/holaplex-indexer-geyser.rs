@@ -1,6 +1,8 @@
-use std::{collections::HashSet, sync::Arc};
+use std::{path::PathBuf, sync::Arc};
 
-use holaplex_indexer::geyser::{Client, ClientArgs, IgnoreType};
+use holaplex_indexer::geyser::{
+    Client, ClientArgs, GrpcArgs, ProgramName, Recorder, Registry, RegistryArgs, SlotRange,
+};
 use indexer_core::{clap, prelude::*};
 use indexer_rabbitmq::{fungible_indexer, geyser, http_indexer, search_indexer, suffix::Suffix};
 
@@ -10,29 +12,56 @@ use indexer_rabbitmq::{fungible_indexer, geyser, http_indexer, search_indexer, s
 #[command(name = "holaplex-indexer-geyser", version, long_about = None)]
 struct Args {
     /// The address of an AMQP server to connect to
-    #[arg(long, env)]
-    amqp_url: String,
+    ///
+    /// This is optional when replaying messages or consuming from a gRPC
+    /// server, in which case dispatches to the other indexers are skipped.
+    #[arg(long, env, required_unless_present_any(["replay", "grpc_url"]))]
+    amqp_url: Option<String>,
 
     /// The network to listen to events for
-    #[arg(long, env)]
-    network: geyser::Network,
+    #[arg(long, env, required_unless_present_any(["replay", "grpc_url"]))]
+    network: Option<geyser::Network>,
 
     /// The startup type of events to listen for
     #[arg(long, env, default_value_t = geyser::StartupType::Normal)]
     startup: geyser::StartupType,
 
-    /// List of topics or programs to ignore on startup
+    /// Index messages from a JSON lines file instead of an AMQP queue, then
+    /// exit
     ///
-    /// For example, `metadata,candy-machine` will ignore the Metaplex metadata
-    /// and candy machine programs.
-    #[arg(long, env, use_value_delimiter(true))]
-    ignore_on_startup: Option<Vec<IgnoreType>>,
+    /// Pass `-` to read messages from standard input.
+    #[arg(long, env, conflicts_with_all(["record", "grpc_url"]))]
+    replay: Option<PathBuf>,
+
+    /// The first slot to replay messages from
+    #[arg(long, env, requires("replay"))]
+    replay_start_slot: Option<u64>,
+
+    /// The last slot to replay messages from
+    #[arg(long, env, requires("replay"))]
+    replay_end_slot: Option<u64>,
+
+    /// Append every message successfully indexed from the AMQP queue to a JSON
+    /// lines file, which can later be passed to `--replay`
+    #[arg(long, env, conflicts_with("grpc_url"))]
+    record: Option<PathBuf>,
+
+    /// Process updates of the same account or auction house trade state one
+    /// at a time, in the order they were received, instead of concurrently
+    #[arg(long, env, conflicts_with_all(["replay", "grpc_url"]))]
+    partitioned: bool,
+
+    #[command(flatten)]
+    grpc: GrpcArgs,
 
     #[command(flatten)]
     queue_suffix: Suffix,
 
     #[command(flatten)]
     client: ClientArgs,
+
+    #[command(flatten)]
+    programs: RegistryArgs,
 }
 
 fn main() {
@@ -41,59 +70,157 @@ fn main() {
              amqp_url,
              network,
              startup,
-             ignore_on_startup,
+             replay,
+             replay_start_slot,
+             replay_end_slot,
+             record,
+             partitioned,
+             grpc,
              queue_suffix,
              client,
+             programs,
          },
          params,
          db| async move {
-            let receiver = match queue_suffix {
-                Suffix::Debug(ref s) => s.clone(),
-                _ => network.to_string(),
+            let registry = Arc::new(Registry::new(programs)?);
+
+            let conn = match amqp_url {
+                Some(url) => {
+                    Some(holaplex_indexer::amqp_connect(url, env!("CARGO_BIN_NAME")).await?)
+                },
+                None => None,
             };
 
-            let conn = holaplex_indexer::amqp_connect(amqp_url, env!("CARGO_BIN_NAME")).await?;
-            let client = Client::new_rc(
-                db,
-                &conn,
-                http_indexer::QueueType::new(&receiver, &queue_suffix)?,
-                http_indexer::QueueType::new(&receiver, &queue_suffix)?,
-                search_indexer::QueueType::new(&receiver, &queue_suffix)?,
-                fungible_indexer::QueueType::new(&receiver, &queue_suffix)?,
-                client,
-            )
-            .await
+            let client = if let (Some(conn), Some(network)) = (&conn, &network) {
+                let receiver = match queue_suffix {
+                    Suffix::Debug(ref s) => s.clone(),
+                    _ => network.to_string(),
+                };
+
+                Client::new_rc(
+                    db,
+                    conn,
+                    http_indexer::QueueType::new(&receiver, &queue_suffix)?,
+                    http_indexer::QueueType::new(&receiver, &queue_suffix)?,
+                    search_indexer::QueueType::new(&receiver, &queue_suffix)?,
+                    fungible_indexer::QueueType::new(&receiver, &queue_suffix)?,
+                    client,
+                )
+                .await
+            } else {
+                Client::new_offline(db, client)
+            }
             .context("Failed to construct Client")?;
 
+            // Transfers are only indexed once RPC confirms the mint is an NFT
+            if !client.has_solana_endpoint()
+                && [ProgramName::Token, ProgramName::Token2022]
+                    .into_iter()
+                    .any(|p| registry.is_enabled(p))
+            {
+                warn!("No --solana-endpoint was given, so NFT transfers will not be indexed");
+            }
+
+            if let Some(path) = replay {
+                return holaplex_indexer::geyser::replay(&client, &registry, path, SlotRange {
+                    start: replay_start_slot,
+                    end: replay_end_slot,
+                })
+                .await;
+            }
+
+            if grpc.enabled() {
+                return holaplex_indexer::geyser::grpc_consume(
+                    &client,
+                    &registry,
+                    grpc,
+                    params.concurrency(),
+                )
+                .await;
+            }
+
+            let (conn, network) = conn
+                .zip(network)
+                .ok_or_else(|| anyhow!("An AMQP URL and network are required to consume"))?;
+
+            // AMQP messages carry no block times, so without an RPC node every
+            // timestamped event would be retried until it is dead-lettered
+            let timestamped = registry.timestamped_programs();
+
+            if !client.has_solana_endpoint() && !timestamped.is_empty() {
+                bail!(
+                    "A Solana RPC endpoint is required to timestamp events of {}; pass \
+                     --solana-endpoint or disable these programs",
+                    timestamped
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+            }
+
             let queue_type = geyser::QueueType::new(network, startup, &queue_suffix)?;
             let consumer = geyser::Consumer::new(&conn, queue_type.clone(), "geyser-consumer")
                 .await
                 .context("Failed to create queue consumer")?;
 
-            let ignore_on_startup = Arc::new(
-                ignore_on_startup
-                    .into_iter()
-                    .flatten()
-                    .collect::<HashSet<_>>(),
-            );
-
-            holaplex_indexer::amqp_consume(
-                &params,
-                conn,
-                consumer,
-                queue_type,
-                StdDuration::from_millis(100),
-                move |m| {
-                    let client = client.clone();
-                    let ignore_on_startup = ignore_on_startup.clone();
-
-                    async move {
-                        holaplex_indexer::geyser::process_message(m, &client, ignore_on_startup)
-                            .await
+            let recorder = record.map(Recorder::new).transpose()?.map(Arc::new);
+            let finish_recorder = recorder.clone();
+            let flush_client = Arc::clone(&client);
+
+            let process = move |m: geyser::Message| {
+                let client = client.clone();
+                let registry = registry.clone();
+                let recorder = recorder.clone();
+
+                async move {
+                    // Each delivery is recorded once before it is processed,
+                    // so messages which fail can be replayed as well
+                    if let Some(recorder) = recorder {
+                        if holaplex_indexer::attempt() == Some(1) {
+                            if let Err(e) = recorder.record(&m).await {
+                                warn!("Failed to record message: {:?}", e);
+                            }
+                        }
                     }
-                },
-            )
-            .await
+
+                    holaplex_indexer::geyser::process_message(m, &client, &registry).await
+                }
+            };
+
+            let grace_period = StdDuration::from_millis(100);
+
+            let res = if partitioned {
+                holaplex_indexer::amqp_consume_partitioned(
+                    &params,
+                    conn,
+                    consumer,
+                    queue_type,
+                    grace_period,
+                    holaplex_indexer::geyser::partition_key,
+                    process,
+                )
+                .await
+            } else {
+                holaplex_indexer::amqp_consume(
+                    &params,
+                    conn,
+                    consumer,
+                    queue_type,
+                    grace_period,
+                    process,
+                )
+                .await
+            };
+
+            let flushed = flush_client.flush_writes().await;
+
+            // The consumer has dropped its copies of the recorder by now
+            if let Some(recorder) = finish_recorder.and_then(|r| Arc::try_unwrap(r).ok()) {
+                recorder.finish().await?;
+            }
+
+            res.and(flushed)
         },
     );
 }
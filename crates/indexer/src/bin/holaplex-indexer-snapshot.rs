@@ -0,0 +1,88 @@
+use std::{path::PathBuf, sync::Arc};
+
+use holaplex_indexer::geyser::{Client, ClientArgs, Registry, RegistryArgs};
+use indexer_core::{clap, prelude::*};
+use indexer_rabbitmq::{fungible_indexer, geyser, http_indexer, search_indexer, suffix::Suffix};
+
+/// Indexer worker for loading accounts from a Solana snapshot
+#[derive(Debug, clap::Args)]
+#[group(skip)]
+#[command(name = "holaplex-indexer-snapshot", version, long_about = None)]
+struct Args {
+    /// Path to a snapshot archive (optionally zstd-compressed), an unpacked
+    /// snapshot, or a directory of account storage files
+    snapshot: PathBuf,
+
+    /// The address of an AMQP server to connect to
+    ///
+    /// If this is omitted, dispatches to the other indexers are skipped.
+    #[arg(long, env, requires("network"))]
+    amqp_url: Option<String>,
+
+    /// The network the snapshot was taken from
+    #[arg(long, env, requires("amqp_url"))]
+    network: Option<geyser::Network>,
+
+    #[command(flatten)]
+    queue_suffix: Suffix,
+
+    #[command(flatten)]
+    client: ClientArgs,
+
+    #[command(flatten)]
+    programs: RegistryArgs,
+}
+
+fn main() {
+    holaplex_indexer::run(
+        |Args {
+             snapshot,
+             amqp_url,
+             network,
+             queue_suffix,
+             client,
+             programs,
+         },
+         params,
+         db| async move {
+            let registry = Arc::new(Registry::new(programs)?);
+
+            // Keep the connection open until loading is finished
+            let conn = match amqp_url {
+                Some(url) => {
+                    Some(holaplex_indexer::amqp_connect(url, env!("CARGO_BIN_NAME")).await?)
+                },
+                None => None,
+            };
+
+            let client = if let (Some(conn), Some(network)) = (&conn, &network) {
+                let receiver = match queue_suffix {
+                    Suffix::Debug(ref s) => s.clone(),
+                    _ => network.to_string(),
+                };
+
+                Client::new_rc(
+                    db,
+                    conn,
+                    http_indexer::QueueType::new(&receiver, &queue_suffix)?,
+                    http_indexer::QueueType::new(&receiver, &queue_suffix)?,
+                    search_indexer::QueueType::new(&receiver, &queue_suffix)?,
+                    fungible_indexer::QueueType::new(&receiver, &queue_suffix)?,
+                    client,
+                )
+                .await
+            } else {
+                Client::new_offline(db, client)
+            }
+            .context("Failed to construct Client")?;
+
+            holaplex_indexer::geyser::load_snapshot(
+                &client,
+                registry,
+                snapshot,
+                params.concurrency(),
+            )
+            .await
+        },
+    );
+}
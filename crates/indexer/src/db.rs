@@ -39,11 +39,13 @@ impl Pool {
         &self,
         f: impl FnOnce(&PooledConnection) -> Result<T, E> + Send + 'static,
     ) -> Result<T> {
+        let start = std::time::Instant::now();
         let db = self
             .0
             .pool
             .get()
             .context("Failed to acquire database connection");
+        crate::metrics::pool_waited(start.elapsed());
 
         tokio::task::spawn_blocking(|| f(&db?).map_err(Into::into))
             .await
@@ -0,0 +1,42 @@
+use indexer_core::db::{
+    insert_into, models::AnchorAccount, tables::anchor_accounts, versioned::IfNewerDsl,
+};
+use serde_json::Value;
+
+use super::Client;
+use crate::prelude::*;
+
+pub(crate) async fn process(
+    client: &Client,
+    key: Pubkey,
+    program: Pubkey,
+    account_type: String,
+    data: Value,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
+    let row = AnchorAccount {
+        address: Owned(key.to_string()),
+        program: Owned(program.to_string()),
+        account_type: Owned(account_type),
+        data: Owned(data),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
+    };
+
+    client
+        .db()
+        .run(move |db| {
+            insert_into(anchor_accounts::table)
+                .values(&row)
+                .on_conflict(anchor_accounts::address)
+                .do_update()
+                .set(&row)
+                .if_newer()
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert Anchor account")?;
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use chrono::{offset::Local, Duration, NaiveDateTime};
+use chrono::{Duration, NaiveDateTime};
 use indexer_core::{
     db::{
         insert_into,
@@ -19,6 +19,7 @@ pub(crate) async fn process(
     client: &Client,
     key: Pubkey,
     auction: AuctionDataAccount,
+    slot: u64,
 ) -> Result<()> {
     let total_uncancelled_bids;
     let highest_bid;
@@ -44,8 +45,7 @@ pub(crate) async fn process(
         },
     }
 
-    let now = Local::now().naive_utc();
-    let (ends_at, _ended, last_bid_time) = get_end_info(&auction, now)?;
+    let (ends_at, _ended, last_bid_time) = get_end_info(&auction, client.slot_time(slot).await?)?;
 
     let values = AuctionData {
         address: Owned(bs58::encode(key).into_string()),
@@ -0,0 +1,121 @@
+use indexer_core::{
+    db::{
+        excluded, insert_into, models::AuctioneerListingConfig, tables::auctioneer_listing_configs,
+        versioned::IfNewerDsl,
+    },
+    util,
+};
+
+use super::{super::programs::auctioneer::ListingConfig, Client};
+use crate::prelude::*;
+
+pub(crate) async fn process_listing_config(
+    client: &Client,
+    key: Pubkey,
+    config: ListingConfig,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
+    let has_bid = config.highest_bid.amount > 0;
+
+    let row = AuctioneerListingConfig {
+        address: Owned(key.to_string()),
+        auction_house: None,
+        seller: None,
+        metadata: None,
+        trade_state: None,
+        start_time: util::unix_timestamp(config.start_time)?,
+        end_time: util::unix_timestamp(config.end_time)?,
+        reserve_price: config
+            .reserve_price
+            .try_into()
+            .context("Reserve price was too big to store")?,
+        min_bid_increment: config
+            .min_bid_increment
+            .try_into()
+            .context("Minimum bid increment was too big to store")?,
+        time_ext_period: config.time_ext_period.into(),
+        time_ext_delta: config.time_ext_delta.into(),
+        allow_high_bid_cancel: config.allow_high_bid_cancel,
+        highest_bid: has_bid
+            .then(|| config.highest_bid.amount.try_into())
+            .transpose()
+            .context("Highest bid was too big to store")?,
+        highest_bid_trade_state: has_bid
+            .then(|| Owned(config.highest_bid.buyer_trade_state.to_string())),
+        slot: slot.try_into()?,
+        write_version: Some(write_version.try_into()?),
+    };
+
+    client
+        .db()
+        .run(move |db| {
+            // The listing the config belongs to is only known from the sell
+            // instruction, so leave it untouched
+            insert_into(auctioneer_listing_configs::table)
+                .values(&row)
+                .on_conflict(auctioneer_listing_configs::address)
+                .do_update()
+                .set((
+                    auctioneer_listing_configs::start_time
+                        .eq(excluded(auctioneer_listing_configs::start_time)),
+                    auctioneer_listing_configs::end_time
+                        .eq(excluded(auctioneer_listing_configs::end_time)),
+                    auctioneer_listing_configs::reserve_price
+                        .eq(excluded(auctioneer_listing_configs::reserve_price)),
+                    auctioneer_listing_configs::min_bid_increment
+                        .eq(excluded(auctioneer_listing_configs::min_bid_increment)),
+                    auctioneer_listing_configs::time_ext_period
+                        .eq(excluded(auctioneer_listing_configs::time_ext_period)),
+                    auctioneer_listing_configs::time_ext_delta
+                        .eq(excluded(auctioneer_listing_configs::time_ext_delta)),
+                    auctioneer_listing_configs::allow_high_bid_cancel
+                        .eq(excluded(auctioneer_listing_configs::allow_high_bid_cancel)),
+                    auctioneer_listing_configs::highest_bid
+                        .eq(excluded(auctioneer_listing_configs::highest_bid)),
+                    auctioneer_listing_configs::highest_bid_trade_state.eq(excluded(
+                        auctioneer_listing_configs::highest_bid_trade_state,
+                    )),
+                    auctioneer_listing_configs::slot.eq(excluded(auctioneer_listing_configs::slot)),
+                    auctioneer_listing_configs::write_version
+                        .eq(excluded(auctioneer_listing_configs::write_version)),
+                ))
+                .if_newer()
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert auctioneer listing config")?;
+
+    Ok(())
+}
+
+pub(crate) async fn process_sell(
+    client: &Client,
+    row: AuctioneerListingConfig<'static>,
+) -> Result<()> {
+    client
+        .db()
+        .run(move |db| {
+            // Auction state is owned by the account update, so only link the
+            // config to its listing here
+            insert_into(auctioneer_listing_configs::table)
+                .values(&row)
+                .on_conflict(auctioneer_listing_configs::address)
+                .do_update()
+                .set((
+                    auctioneer_listing_configs::auction_house
+                        .eq(excluded(auctioneer_listing_configs::auction_house)),
+                    auctioneer_listing_configs::seller
+                        .eq(excluded(auctioneer_listing_configs::seller)),
+                    auctioneer_listing_configs::metadata
+                        .eq(excluded(auctioneer_listing_configs::metadata)),
+                    auctioneer_listing_configs::trade_state
+                        .eq(excluded(auctioneer_listing_configs::trade_state)),
+                ))
+                .execute(db)
+        })
+        .await
+        .context("Failed to link auctioneer listing config")?;
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use indexer_core::db::{
+    excluded, insert_into,
+    models::{CollectionAuthorityRecord, UseAuthorityRecord},
+    tables::{collection_authority_records, use_authority_records},
+    versioned::IfNewerDsl,
+};
+use mpl_token_metadata::state::{
+    CollectionAuthorityRecord as CollectionAuthorityRecordAccount,
+    UseAuthorityRecord as UseAuthorityRecordAccount,
+};
+
+use super::Client;
+use crate::prelude::*;
+
+pub(crate) async fn process_collection_authority(
+    client: &Client,
+    key: Pubkey,
+    record: CollectionAuthorityRecordAccount,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
+    let row = CollectionAuthorityRecord {
+        address: Owned(key.to_string()),
+        bump: Some(record.bump.into()),
+        collection_authority: None,
+        metadata_address: None,
+        mint_address: None,
+        slot: Some(slot.try_into()?),
+        write_version: Some(write_version.try_into()?),
+    };
+
+    client
+        .db()
+        .run(move |db| {
+            // The authority and NFT are only known from the approving
+            // instruction, so leave them untouched
+            insert_into(collection_authority_records::table)
+                .values(&row)
+                .on_conflict(collection_authority_records::address)
+                .do_update()
+                .set((
+                    collection_authority_records::bump
+                        .eq(excluded(collection_authority_records::bump)),
+                    collection_authority_records::slot
+                        .eq(excluded(collection_authority_records::slot)),
+                    collection_authority_records::write_version
+                        .eq(excluded(collection_authority_records::write_version)),
+                ))
+                .if_newer()
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert collection authority record")?;
+
+    Ok(())
+}
+
+pub(crate) async fn process_use_authority(
+    client: &Client,
+    key: Pubkey,
+    record: UseAuthorityRecordAccount,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
+    let row = UseAuthorityRecord {
+        address: Owned(key.to_string()),
+        allowed_uses: Some(
+            record
+                .allowed_uses
+                .try_into()
+                .context("Allowed uses were too high to store")?,
+        ),
+        bump: Some(record.bump.into()),
+        use_authority: None,
+        owner: None,
+        metadata_address: None,
+        mint_address: None,
+        slot: Some(slot.try_into()?),
+        write_version: Some(write_version.try_into()?),
+    };
+
+    client
+        .db()
+        .run(move |db| {
+            // The authority and NFT are only known from the approving
+            // instruction, so leave them untouched
+            insert_into(use_authority_records::table)
+                .values(&row)
+                .on_conflict(use_authority_records::address)
+                .do_update()
+                .set((
+                    use_authority_records::allowed_uses
+                        .eq(excluded(use_authority_records::allowed_uses)),
+                    use_authority_records::bump.eq(excluded(use_authority_records::bump)),
+                    use_authority_records::slot.eq(excluded(use_authority_records::slot)),
+                    use_authority_records::write_version
+                        .eq(excluded(use_authority_records::write_version)),
+                ))
+                .if_newer()
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert use authority record")?;
+
+    Ok(())
+}
@@ -15,7 +15,7 @@ pub(crate) async fn process(
 ) -> Result<()> {
     let row = BondingChange {
         address: Owned(key.to_string()),
-        insert_ts: Local::now().naive_utc(),
+        insert_ts: client.slot_time(slot.try_into()?).await?,
         slot,
         current_reserves_from_bonding: i64::try_from(bonding.reserve_balance_from_bonding)
             .context("Reserves from bonding was too big to store")?,
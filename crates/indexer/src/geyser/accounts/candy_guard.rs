@@ -0,0 +1,173 @@
+use indexer_core::{
+    db::{
+        delete, insert_into,
+        models::{CandyGuard as DbCandyGuard, CandyGuardGroup},
+        tables::{candy_guard_groups, candy_guards},
+        versioned::IfNewerDsl,
+    },
+    prelude::*,
+};
+
+use super::{
+    super::programs::candy_guard::{CandyGuard, GuardSet},
+    Client,
+};
+use crate::prelude::*;
+
+fn group_row(
+    key: &str,
+    label: String,
+    guards: GuardSet,
+    slot: i64,
+) -> Result<CandyGuardGroup<'static>> {
+    let GuardSet {
+        bot_tax,
+        sol_payment,
+        token_payment,
+        start_date,
+        third_party_signer,
+        token_gate,
+        gatekeeper,
+        end_date,
+        allow_list,
+        mint_limit,
+        nft_payment,
+        redeemed_amount,
+        address_gate,
+        nft_gate,
+        nft_burn,
+        token_burn,
+        freeze_sol_payment,
+        freeze_token_payment,
+        program_gate,
+    } = guards;
+
+    let key_str = |k: Pubkey| Owned(k.to_string());
+
+    Ok(CandyGuardGroup {
+        candy_guard_address: Owned(key.to_owned()),
+        label: Owned(label),
+        bot_tax_lamports: bot_tax
+            .as_ref()
+            .map(|g| g.lamports.try_into())
+            .transpose()?,
+        bot_tax_last_instruction: bot_tax.map(|g| g.last_instruction),
+        sol_payment_lamports: sol_payment
+            .as_ref()
+            .map(|g| g.lamports.try_into())
+            .transpose()?,
+        sol_payment_destination: sol_payment.map(|g| key_str(g.destination)),
+        token_payment_amount: token_payment
+            .as_ref()
+            .map(|g| g.amount.try_into())
+            .transpose()?,
+        token_payment_mint: token_payment.as_ref().map(|g| key_str(g.mint)),
+        token_payment_destination_ata: token_payment.map(|g| key_str(g.destination_ata)),
+        start_date: start_date.map(|g| g.date),
+        third_party_signer_key: third_party_signer.map(|g| key_str(g.signer_key)),
+        token_gate_amount: token_gate
+            .as_ref()
+            .map(|g| g.amount.try_into())
+            .transpose()?,
+        token_gate_mint: token_gate.map(|g| key_str(g.mint)),
+        gatekeeper_network: gatekeeper.as_ref().map(|g| key_str(g.gatekeeper_network)),
+        gatekeeper_expire_on_use: gatekeeper.map(|g| g.expire_on_use),
+        end_date: end_date.map(|g| g.date),
+        allow_list_merkle_root: allow_list.map(|g| g.merkle_root.to_vec()),
+        mint_limit_id: mint_limit.as_ref().map(|g| g.id.into()),
+        mint_limit_limit: mint_limit.map(|g| g.limit.into()),
+        nft_payment_required_collection: nft_payment
+            .as_ref()
+            .map(|g| key_str(g.required_collection)),
+        nft_payment_destination: nft_payment.map(|g| key_str(g.destination)),
+        redeemed_amount_maximum: redeemed_amount.map(|g| g.maximum.try_into()).transpose()?,
+        address_gate_address: address_gate.map(|g| key_str(g.address)),
+        nft_gate_required_collection: nft_gate.map(|g| key_str(g.required_collection)),
+        nft_burn_required_collection: nft_burn.map(|g| key_str(g.required_collection)),
+        token_burn_amount: token_burn
+            .as_ref()
+            .map(|g| g.amount.try_into())
+            .transpose()?,
+        token_burn_mint: token_burn.map(|g| key_str(g.mint)),
+        freeze_sol_payment_lamports: freeze_sol_payment
+            .as_ref()
+            .map(|g| g.lamports.try_into())
+            .transpose()?,
+        freeze_sol_payment_destination: freeze_sol_payment.map(|g| key_str(g.destination)),
+        freeze_token_payment_amount: freeze_token_payment
+            .as_ref()
+            .map(|g| g.amount.try_into())
+            .transpose()?,
+        freeze_token_payment_mint: freeze_token_payment.as_ref().map(|g| key_str(g.mint)),
+        freeze_token_payment_destination_ata: freeze_token_payment
+            .map(|g| key_str(g.destination_ata)),
+        program_gate_additional: program_gate
+            .map(|g| g.additional.iter().map(ToString::to_string).collect()),
+        slot,
+    })
+}
+
+pub(crate) async fn process(
+    client: &Client,
+    key: Pubkey,
+    guard: CandyGuard,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
+    let addr = key.to_string();
+    let slot = i64::try_from(slot)?;
+
+    let row = DbCandyGuard {
+        address: Owned(addr.clone()),
+        base: Owned(guard.base.to_string()),
+        bump: guard.bump.into(),
+        authority: Owned(guard.authority.to_string()),
+        slot,
+        write_version: write_version.try_into()?,
+    };
+
+    // The default guard set is stored as a group with an empty label
+    let groups = std::iter::once((String::new(), guard.default))
+        .chain(guard.groups)
+        .map(|(label, guards)| group_row(&addr, label, guards, slot))
+        .collect::<Result<Vec<_>>>()?;
+
+    client
+        .db()
+        .run(move |db| {
+            db.build_transaction().read_write().run(|| {
+                let written = insert_into(candy_guards::table)
+                    .values(&row)
+                    .on_conflict(candy_guards::address)
+                    .do_update()
+                    .set(&row)
+                    .if_newer()
+                    .execute(db)
+                    .context("Failed to insert candy guard")?;
+
+                // A newer version of the guard owns the stored groups
+                if written == 0 {
+                    return Ok(());
+                }
+
+                // Groups may be removed, so the stored set is replaced outright
+                delete(
+                    candy_guard_groups::table
+                        .filter(candy_guard_groups::candy_guard_address.eq(&addr)),
+                )
+                .execute(db)
+                .context("Failed to clear candy guard groups")?;
+
+                insert_into(candy_guard_groups::table)
+                    .values(&groups)
+                    .execute(db)
+                    .context("Failed to insert candy guard groups")?;
+
+                Result::<_>::Ok(())
+            })
+        })
+        .await
+        .context("Failed to insert candy guard")?;
+
+    Ok(())
+}
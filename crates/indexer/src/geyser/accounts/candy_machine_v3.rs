@@ -0,0 +1,213 @@
+use indexer_core::{
+    db::{
+        delete, excluded, insert_into,
+        models::{CMConfigLine, CMConfigLineSetting, CMCreator, CMHiddenSetting, CandyMachineV3},
+        tables::{
+            candy_machine_config_line_settings, candy_machine_config_lines, candy_machine_creators,
+            candy_machine_hidden_settings, candy_machines_v3,
+        },
+        versioned::IfNewerDsl,
+    },
+    prelude::*,
+};
+
+use super::{
+    super::programs::candy_machine_v3::{
+        CandyMachine, ConfigLine, ConfigLineSettings, Creator, HiddenSettings,
+    },
+    Client,
+};
+use crate::prelude::*;
+
+/// Number of config lines to insert per statement
+const CONFIG_LINE_CHUNK_SIZE: usize = 1000;
+
+pub(crate) async fn process(
+    client: &Client,
+    key: Pubkey,
+    candy_machine: CandyMachine,
+    config_lines: Option<Vec<ConfigLine>>,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
+    let row = CandyMachineV3 {
+        address: Owned(key.to_string()),
+        authority: Owned(candy_machine.authority.to_string()),
+        mint_authority: Owned(candy_machine.mint_authority.to_string()),
+        collection_mint: Owned(candy_machine.collection_mint.to_string()),
+        items_redeemed: candy_machine.items_redeemed.try_into()?,
+        items_available: candy_machine.data.items_available.try_into()?,
+        symbol: Owned(candy_machine.data.symbol.trim_end_matches('\0').to_owned()),
+        seller_fee_basis_points: candy_machine.data.seller_fee_basis_points.try_into()?,
+        max_supply: candy_machine.data.max_supply.try_into()?,
+        is_mutable: candy_machine.data.is_mutable,
+        features: candy_machine.features.try_into()?,
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
+    };
+
+    let addr = key.to_string();
+    let creators = candy_machine
+        .data
+        .creators
+        .into_iter()
+        .map(|c| creator_row(&addr, c))
+        .collect::<Vec<_>>();
+    let config_line_settings = candy_machine
+        .data
+        .config_line_settings
+        .map(|c| config_line_settings_row(&addr, c))
+        .transpose()?;
+    let hidden_settings = candy_machine
+        .data
+        .hidden_settings
+        .map(|h| hidden_settings_row(&addr, h));
+    let config_lines = config_lines
+        .map(|l| config_line_rows(&addr, l))
+        .transpose()?;
+
+    client
+        .db()
+        .run(move |db| {
+            db.build_transaction().read_write().run(|| {
+                let written = insert_into(candy_machines_v3::table)
+                    .values(&row)
+                    .on_conflict(candy_machines_v3::address)
+                    .do_update()
+                    .set(&row)
+                    .if_newer()
+                    .execute(db)
+                    .context("Failed to insert candy machine v3")?;
+
+                // A newer version of the candy machine owns the stored
+                // settings and lines
+                if written == 0 {
+                    return Ok(());
+                }
+
+                // Creators and settings may be removed, so they are replaced
+                // outright
+                delete(
+                    candy_machine_creators::table
+                        .filter(candy_machine_creators::candy_machine_address.eq(&addr)),
+                )
+                .execute(db)
+                .context("Failed to clear candy machine v3 creators")?;
+
+                insert_into(candy_machine_creators::table)
+                    .values(&creators)
+                    .execute(db)
+                    .context("Failed to insert candy machine v3 creators")?;
+
+                delete(
+                    candy_machine_config_line_settings::table.filter(
+                        candy_machine_config_line_settings::candy_machine_address.eq(&addr),
+                    ),
+                )
+                .execute(db)
+                .context("Failed to clear config line settings")?;
+
+                if let Some(ref cls) = config_line_settings {
+                    insert_into(candy_machine_config_line_settings::table)
+                        .values(cls)
+                        .execute(db)
+                        .context("Failed to insert config line settings")?;
+                }
+
+                delete(
+                    candy_machine_hidden_settings::table
+                        .filter(candy_machine_hidden_settings::candy_machine_address.eq(&addr)),
+                )
+                .execute(db)
+                .context("Failed to clear hidden settings")?;
+
+                if let Some(ref hs) = hidden_settings {
+                    insert_into(candy_machine_hidden_settings::table)
+                        .values(hs)
+                        .execute(db)
+                        .context("Failed to insert hidden settings")?;
+                }
+
+                for chunk in config_lines
+                    .iter()
+                    .flat_map(|l| l.chunks(CONFIG_LINE_CHUNK_SIZE))
+                {
+                    // Unlike v2 lines, v3 lines are refreshed so minted items
+                    // are marked as taken
+                    insert_into(candy_machine_config_lines::table)
+                        .values(chunk)
+                        .on_conflict((
+                            candy_machine_config_lines::candy_machine_address,
+                            candy_machine_config_lines::idx,
+                        ))
+                        .do_update()
+                        .set((
+                            candy_machine_config_lines::name
+                                .eq(excluded(candy_machine_config_lines::name)),
+                            candy_machine_config_lines::uri
+                                .eq(excluded(candy_machine_config_lines::uri)),
+                            candy_machine_config_lines::taken
+                                .eq(excluded(candy_machine_config_lines::taken)),
+                        ))
+                        .execute(db)
+                        .context("Failed to insert config line chunk")?;
+                }
+
+                Result::<_>::Ok(())
+            })
+        })
+        .await
+        .context("Failed to insert candy machine v3")?;
+
+    Ok(())
+}
+
+fn creator_row(addr: &str, creator: Creator) -> CMCreator<'static> {
+    CMCreator {
+        candy_machine_address: Owned(addr.to_owned()),
+        creator_address: Owned(creator.address.to_string()),
+        verified: creator.verified,
+        share: creator.percentage_share.into(),
+    }
+}
+
+fn config_line_settings_row(
+    addr: &str,
+    cls: ConfigLineSettings,
+) -> Result<CMConfigLineSetting<'static>> {
+    Ok(CMConfigLineSetting {
+        candy_machine_address: Owned(addr.to_owned()),
+        prefix_name: Owned(cls.prefix_name),
+        name_length: cls.name_length.try_into()?,
+        prefix_uri: Owned(cls.prefix_uri),
+        uri_length: cls.uri_length.try_into()?,
+        is_sequential: cls.is_sequential,
+    })
+}
+
+fn hidden_settings_row(addr: &str, hs: HiddenSettings) -> CMHiddenSetting<'static> {
+    CMHiddenSetting {
+        candy_machine_address: Owned(addr.to_owned()),
+        name: Owned(hs.name),
+        uri: Owned(hs.uri),
+        hash: hs.hash.to_vec(),
+    }
+}
+
+fn config_line_rows(
+    addr: &str,
+    config_lines: Vec<ConfigLine>,
+) -> Result<Vec<CMConfigLine<'static>>> {
+    config_lines
+        .into_iter()
+        .map(|l| {
+            Ok(CMConfigLine {
+                candy_machine_address: Owned(addr.to_owned()),
+                name: Owned(l.name),
+                uri: Owned(l.uri),
+                idx: l.idx.try_into()?,
+                taken: l.taken,
+            })
+        })
+        .collect()
+}
@@ -1,11 +1,12 @@
 use indexer_core::db::{
-    insert_into,
-    models::{Edition, MasterEdition},
-    tables::{editions, master_editions},
+    excluded, insert_into,
+    models::{Edition, EditionMarker, MasterEdition},
+    tables::{edition_markers, editions, master_editions},
+    versioned::IfNewerDsl,
 };
 use mpl_token_metadata::state::{
-    Edition as EditionAccount, MasterEdition as MasterEditionTrait,
-    MasterEditionV2 as MasterEditionV2Account,
+    Edition as EditionAccount, EditionMarker as EditionMarkerAccount,
+    MasterEdition as MasterEditionTrait, MasterEditionV2 as MasterEditionV2Account,
 };
 
 use super::Client;
@@ -86,3 +87,42 @@ pub(crate) async fn process_master(
 
     Ok(())
 }
+
+pub(crate) async fn process_marker(
+    client: &Client,
+    marker_key: Pubkey,
+    marker: EditionMarkerAccount,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
+    let row = EditionMarker {
+        address: Owned(marker_key.to_string()),
+        ledger: Some(marker.ledger.to_vec()),
+        master_edition_address: None,
+        marker_index: None,
+        slot: Some(slot.try_into()?),
+        write_version: Some(write_version.try_into()?),
+    };
+
+    client
+        .db()
+        .run(move |db| {
+            // The master edition is only known from printing instructions, so
+            // leave it untouched
+            insert_into(edition_markers::table)
+                .values(&row)
+                .on_conflict(edition_markers::address)
+                .do_update()
+                .set((
+                    edition_markers::ledger.eq(excluded(edition_markers::ledger)),
+                    edition_markers::slot.eq(excluded(edition_markers::slot)),
+                    edition_markers::write_version.eq(excluded(edition_markers::write_version)),
+                ))
+                .if_newer()
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert edition marker")?;
+
+    Ok(())
+}
@@ -2,9 +2,10 @@ use genostub::state::{HabitatData, RentalAgreement};
 use indexer_core::{
     bigdecimal::BigDecimal,
     db::{
-        delete, insert_into, models, select,
+        insert_into, models,
         tables::{geno_habitat_datas, geno_rental_agreements},
         update,
+        versioned::IfNewerDsl,
     },
     util,
 };
@@ -20,7 +21,7 @@ async fn process_rent(
     write_version: i64,
 ) -> Result<()> {
     let row = models::GenoRentalAgreement {
-        habitat_address: Owned(addr.clone()),
+        habitat_address: Owned(addr),
         alchemist: rent.alchemist.map(|a| Owned(a.to_string())),
         rental_period: rent
             .rental_period
@@ -48,26 +49,12 @@ async fn process_rent(
     client
         .db()
         .run(move |db| {
-            let geno_rental = select(exists(
-                geno_rental_agreements::table.filter(
-                    geno_rental_agreements::habitat_address
-                        .eq(addr.clone())
-                        .and(geno_rental_agreements::slot.lt(slot)),
-                ),
-            ))
-            .get_result::<bool>(db);
-
-            if geno_rental == Ok(true) {
-                delete(
-                    geno_rental_agreements::table
-                        .filter(geno_rental_agreements::habitat_address.eq(addr)),
-                )
-                .execute(db)?;
-            }
-
             insert_into(geno_rental_agreements::table)
                 .values(&row)
-                .on_conflict_do_nothing()
+                .on_conflict(geno_rental_agreements::habitat_address)
+                .do_update()
+                .set(&row)
+                .if_newer()
                 .execute(db)
         })
         .await
@@ -185,26 +172,12 @@ pub(crate) async fn process(
     client
         .db()
         .run(move |db| {
-            let geno_exists = select(exists(
-                geno_habitat_datas::table.filter(
-                    geno_habitat_datas::address
-                        .eq(key.to_string())
-                        .and(geno_habitat_datas::slot.lt(slot)),
-                ),
-            ))
-            .get_result::<bool>(db);
-
-            if geno_exists == Ok(true) {
-                delete(
-                    geno_habitat_datas::table
-                        .filter(geno_habitat_datas::address.eq(key.to_string())),
-                )
-                .execute(db)?;
-            }
-
             insert_into(geno_habitat_datas::table)
                 .values(&row)
-                .on_conflict_do_nothing()
+                .on_conflict(geno_habitat_datas::address)
+                .do_update()
+                .set(&row)
+                .if_newer()
                 .execute(db)
         })
         .await
@@ -5,6 +5,7 @@ use indexer_core::{
         models::{FeedEventWallet, FollowEvent, GraphConnection as DbGraphConnection},
         select,
         tables::{feed_event_wallets, feed_events, follow_events, graph_connections},
+        versioned::IfNewerDsl,
     },
     prelude::*,
     util,
@@ -47,6 +48,7 @@ pub(crate) async fn process(
                 .on_conflict(graph_connections::address)
                 .do_update()
                 .set(&row)
+                .if_newer()
                 .execute(db)?;
 
             if Ok(true) == graph_connection_exists {
@@ -3,18 +3,17 @@ use std::str::FromStr;
 use hpl_reward_center::state::Listing;
 use indexer_core::{
     db::{
-        custom_types::ListingEventLifecycleEnum,
         insert_into,
         models::{
-            AuctionHouse, CurrentMetadataOwner, FeedEventWallet, Listing as DbListing,
-            ListingEvent, RewardsListing as DbRewardsListing,
+            AuctionHouse, CurrentMetadataOwner, Listing as DbListing,
+            RewardsListing as DbRewardsListing,
         },
         mutations, select,
         tables::{
-            auction_houses, current_metadata_owners, feed_event_wallets, feed_events,
-            listing_events, listings, metadatas, purchases, reward_centers, rewards_listings,
+            auction_houses, current_metadata_owners, listings, metadatas, purchases,
+            reward_centers, rewards_listings,
         },
-        Error as DbError,
+        versioned::IfNewerDsl,
     },
     prelude::*,
     pubkeys, util,
@@ -23,7 +22,7 @@ use indexer_core::{
 use mpl_auction_house::pda::find_auctioneer_trade_state_address;
 use solana_program::pubkey::Pubkey;
 
-use super::super::Client;
+use super::super::{super::marketplace, Client};
 use crate::prelude::*;
 
 #[allow(clippy::too_many_lines)]
@@ -65,6 +64,7 @@ pub(crate) async fn process(
                     .on_conflict(rewards_listings::address)
                     .do_update()
                     .set(&values)
+                    .if_newer()
                     .execute(db)
             }
         })
@@ -90,6 +90,7 @@ pub(crate) async fn process(
                         current_metadata_owners::owner_address,
                         current_metadata_owners::token_account_address,
                         current_metadata_owners::slot,
+                        current_metadata_owners::write_version,
                     ))
                     .inner_join(
                         metadatas::table
@@ -157,35 +158,7 @@ pub(crate) async fn process(
                     return Ok(());
                 }
 
-                db.build_transaction().read_write().run(|| {
-                    let feed_event_id = insert_into(feed_events::table)
-                        .default_values()
-                        .returning(feed_events::id)
-                        .get_result::<Uuid>(db)
-                        .context("Failed to insert feed event")?;
-
-                    let listing_event = insert_into(listing_events::table)
-                        .values(&ListingEvent {
-                            feed_event_id,
-                            lifecycle: ListingEventLifecycleEnum::Created,
-                            listing_id,
-                        })
-                        .execute(db);
-
-                    if Err(DbError::RollbackTransaction) == listing_event {
-                        return Ok(());
-                    }
-
-                    insert_into(feed_event_wallets::table)
-                        .values(&FeedEventWallet {
-                            wallet_address: row.seller,
-                            feed_event_id,
-                        })
-                        .execute(db)
-                        .context("Failed to insert listing feed event wallet")?;
-
-                    Result::<_>::Ok(())
-                })
+                marketplace::insert_listing_created(db, listing_id, row.seller)
             }
         })
         .await
@@ -3,17 +3,16 @@ use std::str::FromStr;
 use hpl_reward_center::state::Offer;
 use indexer_core::{
     db::{
-        custom_types::OfferEventLifecycleEnum,
         insert_into,
         models::{
-            AuctionHouse, CurrentMetadataOwner, FeedEventWallet, Offer as Dboffer, OfferEvent,
-            RewardsOffer as DbRewardsOffer,
+            AuctionHouse, CurrentMetadataOwner, Offer as Dboffer, RewardsOffer as DbRewardsOffer,
         },
         mutations, select,
         tables::{
-            auction_houses, current_metadata_owners, feed_event_wallets, feed_events, metadatas,
-            offer_events, offers, purchases, reward_centers, rewards_offers,
+            auction_houses, current_metadata_owners, metadatas, offers, purchases, reward_centers,
+            rewards_offers,
         },
+        versioned::IfNewerDsl,
     },
     prelude::*,
     pubkeys, util,
@@ -22,7 +21,7 @@ use indexer_core::{
 use mpl_auction_house::pda::find_public_bid_trade_state_address;
 use solana_program::pubkey::Pubkey;
 
-use super::super::Client;
+use super::super::{super::marketplace, Client};
 use crate::prelude::*;
 
 #[allow(clippy::too_many_lines)]
@@ -64,6 +63,7 @@ pub(crate) async fn process(
                     .on_conflict(rewards_offers::address)
                     .do_update()
                     .set(&values)
+                    .if_newer()
                     .execute(db)
             }
         })
@@ -89,6 +89,7 @@ pub(crate) async fn process(
                         current_metadata_owners::owner_address,
                         current_metadata_owners::token_account_address,
                         current_metadata_owners::slot,
+                        current_metadata_owners::write_version,
                     ))
                     .inner_join(
                         metadatas::table
@@ -157,49 +158,7 @@ pub(crate) async fn process(
                     return Ok(());
                 }
 
-                db.build_transaction().read_write().run(|| {
-                    let metadata_owner: String =
-                        current_metadata_owners::table
-                            .inner_join(metadatas::table.on(
-                                metadatas::mint_address.eq(current_metadata_owners::mint_address),
-                            ))
-                            .filter(metadatas::address.eq(row.metadata.clone()))
-                            .select(current_metadata_owners::owner_address)
-                            .first(db)?;
-
-                    let feed_event_id = insert_into(feed_events::table)
-                        .default_values()
-                        .returning(feed_events::id)
-                        .get_result::<Uuid>(db)
-                        .context("Failed to insert feed event")?;
-
-                    insert_into(offer_events::table)
-                        .values(&OfferEvent {
-                            feed_event_id,
-                            lifecycle: OfferEventLifecycleEnum::Created,
-                            offer_id,
-                        })
-                        .execute(db)
-                        .context("failed to insert offer created event")?;
-
-                    insert_into(feed_event_wallets::table)
-                        .values(&FeedEventWallet {
-                            wallet_address: row.buyer,
-                            feed_event_id,
-                        })
-                        .execute(db)
-                        .context("Failed to insert offer feed event wallet for buyer")?;
-
-                    insert_into(feed_event_wallets::table)
-                        .values(&FeedEventWallet {
-                            wallet_address: Owned(metadata_owner),
-                            feed_event_id,
-                        })
-                        .execute(db)
-                        .context("Failed to insert offer feed event wallet for metadata owner")?;
-
-                    Result::<_>::Ok(())
-                })
+                marketplace::insert_offer_created(db, offer_id, row.buyer, row.metadata)
             }
         })
         .await
@@ -2,7 +2,7 @@ use hpl_reward_center::state::{PayoutOperation, RewardCenter};
 use indexer_core::{
     db::{
         custom_types::PayoutOperationEnum, insert_into, models::RewardCenter as DbRewardCenter,
-        tables::reward_centers,
+        tables::reward_centers, versioned::IfNewerDsl,
     },
     prelude::*,
 };
@@ -42,6 +42,7 @@ pub(crate) async fn process(
                 .on_conflict(reward_centers::address)
                 .do_update()
                 .set(&row)
+                .if_newer()
                 .execute(db)
         })
         .await
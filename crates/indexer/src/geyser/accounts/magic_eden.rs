@@ -0,0 +1,185 @@
+use indexer_core::{
+    db::{
+        select,
+        tables::{listings, offers},
+        update,
+    },
+    pubkeys,
+};
+
+use super::{
+    super::{
+        marketplace::{self, NewListing, NewOffer},
+        programs::magic_eden_haus::{BuyerTradeState, SellerTradeState},
+    },
+    Client,
+};
+use crate::prelude::*;
+
+pub(crate) async fn process_seller_trade_state(
+    client: &Client,
+    key: Pubkey,
+    state: SellerTradeState,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
+    let row = NewListing {
+        trade_state: key,
+        auction_house: state.auction_house,
+        seller: state.seller,
+        metadata: pubkeys::find_metadata(state.token_mint).0,
+        price: state.buyer_price,
+        token_size: state.token_size,
+        trade_state_bump: state.bump,
+        expiry: Some(state.expiry),
+    }
+    .into_row(
+        pubkeys::ME_HAUS,
+        client.slot_time(slot).await?,
+        slot.try_into()?,
+        Some(write_version.try_into()?),
+    )?;
+
+    let values = row.clone();
+    let missing = client
+        .db()
+        .run(move |db| {
+            // Correct the terms of an open listing in place, leaving its
+            // lifecycle to the instruction processors.  Rows written from a
+            // newer trade state or a later instruction are left alone.
+            let updated = update(
+                listings::table.filter(
+                    listings::trade_state
+                        .eq(&values.trade_state)
+                        .and(listings::marketplace_program.eq(&values.marketplace_program))
+                        .and(listings::purchase_id.is_null())
+                        .and(listings::canceled_at.is_null())
+                        .and(
+                            listings::slot
+                                .lt(values.slot)
+                                .or(listings::slot.eq(values.slot).and(
+                                    listings::write_version
+                                        .is_null()
+                                        .or(listings::write_version.lt(values.write_version)),
+                                )),
+                        ),
+                ),
+            )
+            .set((
+                listings::price.eq(values.price),
+                listings::token_size.eq(values.token_size),
+                listings::expiry.eq(values.expiry),
+                listings::slot.eq(values.slot),
+                listings::write_version.eq(values.write_version),
+            ))
+            .execute(db)?;
+
+            if updated > 0 {
+                return Ok(false);
+            }
+
+            select(exists(
+                listings::table.filter(
+                    listings::trade_state
+                        .eq(&values.trade_state)
+                        .and(listings::marketplace_program.eq(&values.marketplace_program)),
+                ),
+            ))
+            .get_result::<bool>(db)
+            .map(|e| !e)
+        })
+        .await
+        .context("Failed to reconcile ME listing")?;
+
+    // The sell instruction was missed, so recover the listing from its
+    // trade state
+    if missing {
+        marketplace::upsert_listing(client, row)
+            .await
+            .context("Failed to insert ME listing from trade state")?;
+    }
+
+    Ok(())
+}
+
+pub(crate) async fn process_buyer_trade_state(
+    client: &Client,
+    key: Pubkey,
+    state: BuyerTradeState,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
+    let row = NewOffer {
+        trade_state: key,
+        auction_house: state.auction_house,
+        buyer: state.buyer,
+        metadata: pubkeys::find_metadata(state.token_mint).0,
+        token_account: None,
+        price: state.buyer_price,
+        token_size: state.token_size,
+        trade_state_bump: state.bump,
+        expiry: Some(state.expiry),
+    }
+    .into_row(
+        pubkeys::ME_HAUS,
+        client.slot_time(slot).await?,
+        slot.try_into()?,
+        Some(write_version.try_into()?),
+    )?;
+
+    let values = row.clone();
+    let missing = client
+        .db()
+        .run(move |db| {
+            let updated = update(
+                offers::table.filter(
+                    offers::trade_state
+                        .eq(&values.trade_state)
+                        .and(offers::marketplace_program.eq(&values.marketplace_program))
+                        .and(offers::purchase_id.is_null())
+                        .and(offers::canceled_at.is_null())
+                        .and(
+                            offers::slot
+                                .lt(values.slot)
+                                .or(offers::slot.eq(values.slot).and(
+                                    offers::write_version
+                                        .is_null()
+                                        .or(offers::write_version.lt(values.write_version)),
+                                )),
+                        ),
+                ),
+            )
+            .set((
+                offers::price.eq(values.price),
+                offers::token_size.eq(values.token_size),
+                offers::expiry.eq(values.expiry),
+                offers::slot.eq(values.slot),
+                offers::write_version.eq(values.write_version),
+            ))
+            .execute(db)?;
+
+            if updated > 0 {
+                return Ok(false);
+            }
+
+            select(exists(
+                offers::table.filter(
+                    offers::trade_state
+                        .eq(&values.trade_state)
+                        .and(offers::marketplace_program.eq(&values.marketplace_program)),
+                ),
+            ))
+            .get_result::<bool>(db)
+            .map(|e| !e)
+        })
+        .await
+        .context("Failed to reconcile ME bid")?;
+
+    if missing {
+        marketplace::upsert_offer(client, row)
+            .await
+            .context("Failed to insert ME bid from trade state")?;
+    }
+
+    Ok(())
+}
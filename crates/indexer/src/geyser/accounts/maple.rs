@@ -1,5 +1,16 @@
 use indexer_core::{
-    // db::{insert_into, models::CurrentMetadataOwner, tables::current_metadata_owners, update},
+    db::{
+        insert_into,
+        models::{
+            MapleGlobals, MapleLender, MapleLoan, MapleOpenTermLoan, MaplePool,
+            MapleWithdrawalRequest,
+        },
+        tables::{
+            maple_globals, maple_lenders, maple_loans, maple_open_term_loans, maple_pools,
+            maple_withdrawal_requests,
+        },
+        versioned::IfNewerDsl,
+    },
     prelude::*,
 };
 use syrup_cpi::{Globals, Lender, Loan, OpenTermLoan, Pool, WithdrawalRequest};
@@ -7,47 +18,269 @@ use syrup_cpi::{Globals, Lender, Loan, OpenTermLoan, Pool, WithdrawalRequest};
 use super::Client;
 use crate::prelude::*;
 
-pub async fn process_globals(
-    _client: &Client,
+pub(crate) fn globals_row(
     key: Pubkey,
-    _globals: Globals,
-    _slot: u64,
+    globals: &Globals,
+    slot: u64,
+    write_version: u64,
+) -> Result<MapleGlobals<'static>> {
+    Ok(MapleGlobals {
+        address: Owned(key.to_string()),
+        admin: Owned(globals.admin.to_string()),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
+    })
+}
+
+pub(crate) async fn process_globals(
+    client: &Client,
+    key: Pubkey,
+    globals: &Globals,
+    slot: u64,
+    write_version: u64,
 ) -> Result<()> {
-    debug!("processing globals account {}", key);
+    let row = globals_row(key, globals, slot, write_version)?;
+
+    client
+        .db()
+        .run(move |db| {
+            insert_into(maple_globals::table)
+                .values(&row)
+                .on_conflict(maple_globals::address)
+                .do_update()
+                .set(&row)
+                .if_newer()
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert Maple globals")?;
+
     Ok(())
 }
 
-pub async fn process_lender(_client: &Client, key: Pubkey, _lender: Lender, _slot: u64) -> Result<()> {
-    debug!("processing lender account {}", key);
+pub(crate) fn lender_row(
+    key: Pubkey,
+    lender: &Lender,
+    slot: u64,
+    write_version: u64,
+) -> Result<MapleLender<'static>> {
+    Ok(MapleLender {
+        address: Owned(key.to_string()),
+        owner: Owned(lender.owner.to_string()),
+        pool: Owned(lender.pool.to_string()),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
+    })
+}
+
+pub(crate) async fn process_lender(
+    client: &Client,
+    key: Pubkey,
+    lender: &Lender,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
+    let row = lender_row(key, lender, slot, write_version)?;
+
+    client
+        .db()
+        .run(move |db| {
+            insert_into(maple_lenders::table)
+                .values(&row)
+                .on_conflict(maple_lenders::address)
+                .do_update()
+                .set(&row)
+                .if_newer()
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert Maple lender")?;
+
     Ok(())
 }
 
-pub async fn process_loan(_client: &Client, key: Pubkey, _loan: Loan, _slot: u64) -> Result<()> {
-    debug!("processing loan account {}", key);
+pub(crate) fn loan_row(
+    key: Pubkey,
+    loan: &Loan,
+    slot: u64,
+    write_version: u64,
+) -> Result<MapleLoan<'static>> {
+    Ok(MapleLoan {
+        address: Owned(key.to_string()),
+        pool: Owned(loan.pool.to_string()),
+        borrower: Owned(loan.borrower.to_string()),
+        principal: loan
+            .principal
+            .try_into()
+            .context("Loan principal was too big to store")?,
+        state: Owned(format!("{:?}", loan.state)),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
+    })
+}
+
+pub(crate) async fn process_loan(
+    client: &Client,
+    key: Pubkey,
+    loan: &Loan,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
+    let row = loan_row(key, loan, slot, write_version)?;
+
+    client
+        .db()
+        .run(move |db| {
+            insert_into(maple_loans::table)
+                .values(&row)
+                .on_conflict(maple_loans::address)
+                .do_update()
+                .set(&row)
+                .if_newer()
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert Maple loan")?;
+
     Ok(())
 }
 
-pub async fn process_open_term_loan(
-    _client: &Client,
+pub(crate) fn open_term_loan_row(
     key: Pubkey,
-    _loan: OpenTermLoan,
-    _slot: u64,
+    loan: &OpenTermLoan,
+    slot: u64,
+    write_version: u64,
+) -> Result<MapleOpenTermLoan<'static>> {
+    Ok(MapleOpenTermLoan {
+        address: Owned(key.to_string()),
+        pool: Owned(loan.pool.to_string()),
+        borrower: Owned(loan.borrower.to_string()),
+        principal: loan
+            .principal
+            .try_into()
+            .context("Loan principal was too big to store")?,
+        state: Owned(format!("{:?}", loan.state)),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
+    })
+}
+
+pub(crate) async fn process_open_term_loan(
+    client: &Client,
+    key: Pubkey,
+    loan: &OpenTermLoan,
+    slot: u64,
+    write_version: u64,
 ) -> Result<()> {
-    debug!("processing open term loan account {}", key);
+    let row = open_term_loan_row(key, loan, slot, write_version)?;
+
+    client
+        .db()
+        .run(move |db| {
+            insert_into(maple_open_term_loans::table)
+                .values(&row)
+                .on_conflict(maple_open_term_loans::address)
+                .do_update()
+                .set(&row)
+                .if_newer()
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert Maple open-term loan")?;
+
     Ok(())
 }
 
-pub async fn process_pool(_client: &Client, key: Pubkey, _pool: Pool, _slot: u64) -> Result<()> {
-    debug!("processing pool account {}", key);
+pub(crate) fn pool_row(
+    key: Pubkey,
+    pool: &Pool,
+    slot: u64,
+    write_version: u64,
+) -> Result<MaplePool<'static>> {
+    Ok(MaplePool {
+        address: Owned(key.to_string()),
+        globals: Owned(pool.globals.to_string()),
+        pool_delegate: Owned(pool.pool_delegate.to_string()),
+        base_mint: Owned(pool.base_mint.to_string()),
+        shares_mint: Owned(pool.shares_mint.to_string()),
+        locker: Owned(pool.locker.to_string()),
+        total_value: pool
+            .state
+            .total_value
+            .try_into()
+            .context("Pool value was too big to store")?,
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
+    })
+}
+
+pub(crate) async fn process_pool(
+    client: &Client,
+    key: Pubkey,
+    pool: &Pool,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
+    let row = pool_row(key, pool, slot, write_version)?;
+
+    client
+        .db()
+        .run(move |db| {
+            insert_into(maple_pools::table)
+                .values(&row)
+                .on_conflict(maple_pools::address)
+                .do_update()
+                .set(&row)
+                .if_newer()
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert Maple pool")?;
+
     Ok(())
 }
 
-pub async fn process_withdrawal_request(
-    _client: &Client,
+pub(crate) fn withdrawal_request_row(
     key: Pubkey,
-    _request: WithdrawalRequest,
-    _slot: u64,
+    request: &WithdrawalRequest,
+    slot: u64,
+    write_version: u64,
+) -> Result<MapleWithdrawalRequest<'static>> {
+    Ok(MapleWithdrawalRequest {
+        address: Owned(key.to_string()),
+        pool: Owned(request.pool.to_string()),
+        lender: Owned(request.lender.to_string()),
+        shares: request
+            .shares
+            .try_into()
+            .context("Withdrawal shares were too big to store")?,
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
+    })
+}
+
+pub(crate) async fn process_withdrawal_request(
+    client: &Client,
+    key: Pubkey,
+    request: &WithdrawalRequest,
+    slot: u64,
+    write_version: u64,
 ) -> Result<()> {
-    debug!("processing withdrawal request account {}", key);
+    let row = withdrawal_request_row(key, request, slot, write_version)?;
+
+    client
+        .db()
+        .run(move |db| {
+            insert_into(maple_withdrawal_requests::table)
+                .values(&row)
+                .on_conflict(maple_withdrawal_requests::address)
+                .do_update()
+                .set(&row)
+                .if_newer()
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert Maple withdrawal request")?;
+
     Ok(())
 }
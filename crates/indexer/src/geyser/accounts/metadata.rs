@@ -2,12 +2,16 @@ use indexer_core::{
     db::{
         custom_types::TokenStandardEnum,
         insert_into,
-        models::{FeedEventWallet, Metadata, MetadataCollectionKey, MetadataCreator, MintEvent},
+        models::{
+            FeedEventWallet, Metadata, MetadataCollectionKey, MetadataCreator,
+            MetadataProgrammableConfig, MintEvent,
+        },
         select,
         tables::{
             feed_event_wallets, feed_events, metadata_collection_keys, metadata_creators,
-            metadatas, mint_events,
+            metadata_programmable_configs, metadatas, mint_events,
         },
+        versioned::IfNewerDsl,
     },
     prelude::*,
     pubkeys::find_edition,
@@ -15,17 +19,21 @@ use indexer_core::{
 };
 use mpl_token_metadata::state::{Collection, Metadata as MetadataAccount, TokenStandard};
 
-use super::Client;
+use super::{super::programs::metadata::Programmable, Client};
 use crate::prelude::*;
 
 pub(crate) async fn process(
     client: &Client,
     key: Pubkey,
     meta: MetadataAccount,
+    programmable: Option<Programmable>,
     slot: u64,
     write_version: u64,
 ) -> Result<()> {
-    process_fungible(client, key, &meta, slot, write_version).await?;
+    if programmable.is_none() {
+        process_fungible(client, key, &meta, slot, write_version).await?;
+    }
+
     let addr = bs58::encode(key).into_string();
     let (edition_pda_key, _bump) = find_edition(meta.mint);
     let row = Metadata {
@@ -40,12 +48,16 @@ pub(crate) async fn process(
         is_mutable: meta.is_mutable,
         edition_nonce: meta.edition_nonce.map(Into::into),
         edition_pda: Owned(bs58::encode(edition_pda_key).into_string()),
-        token_standard: meta.token_standard.map(|ts| match ts {
-            TokenStandard::NonFungible => TokenStandardEnum::NonFungible,
-            TokenStandard::FungibleAsset => TokenStandardEnum::FungibleAsset,
-            TokenStandard::Fungible => TokenStandardEnum::Fungible,
-            TokenStandard::NonFungibleEdition => TokenStandardEnum::NonFungibleEdition,
-        }),
+        token_standard: if programmable.is_some() {
+            Some(TokenStandardEnum::ProgrammableNonFungible)
+        } else {
+            meta.token_standard.map(|ts| match ts {
+                TokenStandard::NonFungible => TokenStandardEnum::NonFungible,
+                TokenStandard::FungibleAsset => TokenStandardEnum::FungibleAsset,
+                TokenStandard::Fungible => TokenStandardEnum::Fungible,
+                TokenStandard::NonFungibleEdition => TokenStandardEnum::NonFungibleEdition,
+            })
+        },
         slot: Some(
             slot.try_into()
                 .context("Metadata slot was too big to store")?,
@@ -119,6 +131,10 @@ pub(crate) async fn process(
             .context("Failed to insert metadata creator")?;
     }
 
+    if let Some(programmable) = programmable {
+        index_programmable_config(client, addr.clone(), programmable, slot, write_version).await?;
+    }
+
     if meta.collection.is_some() {
         index_metadata_collection_key(client, addr, meta.collection.context("err!")?).await?;
     }
@@ -126,6 +142,37 @@ pub(crate) async fn process(
     Ok(())
 }
 
+async fn index_programmable_config(
+    client: &Client,
+    addr: String,
+    programmable: Programmable,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
+    let row = MetadataProgrammableConfig {
+        metadata_address: Owned(addr),
+        rule_set: programmable.rule_set.map(|r| Owned(r.to_string())),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
+    };
+
+    client
+        .db()
+        .run(move |db| {
+            insert_into(metadata_programmable_configs::table)
+                .values(&row)
+                .on_conflict(metadata_programmable_configs::metadata_address)
+                .do_update()
+                .set(&row)
+                .if_newer()
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert metadata programmable config")?;
+
+    Ok(())
+}
+
 async fn insert_with_event(
     client: &Client,
     addr: String,
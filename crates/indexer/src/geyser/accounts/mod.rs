@@ -1,9 +1,14 @@
+pub mod anchor;
 pub mod auction_cache;
 pub mod auction_data;
 pub mod auction_house;
+pub mod auctioneer;
+pub mod authority_record;
 pub mod bidder_metadata;
 pub mod bonding_change;
+pub mod candy_guard;
 pub mod candy_machine;
+pub mod candy_machine_v3;
 pub mod cardinal_paid_claim_approver;
 pub mod cardinal_time_invalidator;
 pub mod cardinal_token_manager;
@@ -14,6 +19,7 @@ pub mod govern;
 pub mod graph;
 pub mod hpl_reward_center;
 pub mod locked_voter;
+pub mod magic_eden;
 pub mod maple;
 pub mod metadata;
 pub mod mpl_store;
@@ -23,5 +29,8 @@ pub mod receipt;
 pub mod smart_wallet;
 pub mod spl_governance;
 pub mod token;
+pub mod token_2022;
+pub mod token_auth_rules;
+pub mod token_record;
 
 pub(self) use super::Client;
@@ -1,6 +1,9 @@
 use borsh::BorshDeserialize;
 use indexer_core::{
-    db::{insert_into, models::TwitterHandle, tables::twitter_handle_name_services, update},
+    db::{
+        insert_into, models::TwitterHandle, tables::twitter_handle_name_services, update,
+        versioned::IfNewerDsl,
+    },
     prelude::*,
 };
 
@@ -85,6 +88,7 @@ pub(crate) async fn process(
                         .on_conflict(twitter_handle_name_services::address)
                         .do_update()
                         .set(&values)
+                        .if_newer()
                         .execute(db)
                 })
                 .await
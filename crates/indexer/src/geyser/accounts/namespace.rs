@@ -4,6 +4,7 @@ use indexer_core::{
         models::{CardinalEntry, CardinalNamespace, TwitterHandle},
         tables::{cardinal_entries, cardinal_namespaces, twitter_handle_name_services},
         update,
+        versioned::IfNewerDsl,
     },
     pubkeys::CARDINAL_TWITTER_NAMESPACE,
 };
@@ -45,6 +46,7 @@ pub(crate) async fn process(
                 .on_conflict(cardinal_entries::address)
                 .do_update()
                 .set(&row)
+                .if_newer()
                 .execute(db)
         })
         .await
@@ -84,6 +86,7 @@ pub(crate) async fn process_namespace(
                 .on_conflict(cardinal_namespaces::address)
                 .do_update()
                 .set(&row)
+                .if_newer()
                 .execute(db)
         })
         .await
@@ -169,6 +172,7 @@ async fn process_twitter_namespace(
                         .on_conflict(twitter_handle_name_services::wallet_address)
                         .do_update()
                         .set(&values)
+                        .if_newer()
                         .execute(db)
                 })
                 .await
@@ -1,19 +1,13 @@
 use indexer_core::{
     db::{
-        custom_types::{ListingEventLifecycleEnum, OfferEventLifecycleEnum},
         insert_into,
         models::{
-            BidReceipt as DbBidReceipt, FeedEventWallet, Listing, ListingEvent,
-            ListingReceipt as DbListingReceipt, Offer, OfferEvent, Purchase, PurchaseEvent,
-            PurchaseReceipt as DbPurchaseReceipt,
+            BidReceipt as DbBidReceipt, Listing, ListingReceipt as DbListingReceipt, Offer,
+            Purchase, PurchaseReceipt as DbPurchaseReceipt,
         },
         on_constraint, select,
-        tables::{
-            bid_receipts, current_metadata_owners, feed_event_wallets, feed_events, listing_events,
-            listing_receipts, listings, metadatas, offer_events, offers, purchase_events,
-            purchase_receipts, purchases,
-        },
-        update, Error as DbError,
+        tables::{listings, offers, purchases},
+        update,
     },
     prelude::*,
     pubkeys, util,
@@ -21,7 +15,7 @@ use indexer_core::{
 };
 use mpl_auction_house::receipt::{BidReceipt, ListingReceipt, PurchaseReceipt};
 
-use super::Client;
+use super::{super::marketplace, Client};
 use crate::prelude::*;
 
 pub(crate) async fn process_listing_receipt(
@@ -49,6 +43,11 @@ pub(crate) async fn process_listing_receipt(
         write_version: write_version.try_into()?,
     };
 
+    client
+        .upsert(row.clone())
+        .await
+        .context("Failed to upsert listing receipt")?;
+
     client
         .db()
         .run(move |db| {
@@ -61,13 +60,6 @@ pub(crate) async fn process_listing_receipt(
             ))
             .get_result::<bool>(db)?;
 
-            insert_into(listing_receipts::table)
-                .values(&row)
-                .on_conflict(listing_receipts::address)
-                .do_update()
-                .set(&row)
-                .execute(db)?;
-
             let values = Listing {
                 id: None,
                 trade_state: row.trade_state.clone(),
@@ -98,35 +90,7 @@ pub(crate) async fn process_listing_receipt(
                 return Ok(());
             }
 
-            db.build_transaction().read_write().run(|| {
-                let feed_event_id = insert_into(feed_events::table)
-                    .default_values()
-                    .returning(feed_events::id)
-                    .get_result::<Uuid>(db)
-                    .context("Failed to insert feed event")?;
-
-                let listing_event = insert_into(listing_events::table)
-                    .values(&ListingEvent {
-                        feed_event_id,
-                        lifecycle: ListingEventLifecycleEnum::Created,
-                        listing_id,
-                    })
-                    .execute(db);
-
-                if Err(DbError::RollbackTransaction) == listing_event {
-                    return Ok(());
-                }
-
-                insert_into(feed_event_wallets::table)
-                    .values(&FeedEventWallet {
-                        wallet_address: row.seller,
-                        feed_event_id,
-                    })
-                    .execute(db)
-                    .context("Failed to insert listing feed event wallet")?;
-
-                Result::<_>::Ok(())
-            })
+            marketplace::insert_listing_created(db, listing_id, row.seller)
         })
         .await
         .context("Failed to insert listing receipt!")?;
@@ -174,19 +138,17 @@ pub(crate) async fn process_purchase_receipt(
                 ))
                 .get_result::<bool>(db)?;
 
-                insert_into(purchase_receipts::table)
-                    .values(&row)
-                    .on_conflict(purchase_receipts::address)
-                    .do_update()
-                    .set(&row)
-                    .execute(db)?;
-
                 Result::<bool>::Ok(purchase_exists)
             }
         })
         .await
         .context("failed to check if purchase receipt exists!")?;
 
+    client
+        .upsert(row.clone())
+        .await
+        .context("failed to insert purchase receipt")?;
+
     let purchase_id = upsert_into_purchases_table(client, row.clone()).await?;
 
     if purchase_exists {
@@ -195,48 +157,13 @@ pub(crate) async fn process_purchase_receipt(
 
     client
         .db()
-        .run(move |db| {
-            db.build_transaction().read_write().run(|| {
-                let feed_event_id = insert_into(feed_events::table)
-                    .default_values()
-                    .returning(feed_events::id)
-                    .get_result::<Uuid>(db)
-                    .context("Failed to insert feed event")?;
-
-                insert_into(purchase_events::table)
-                    .values(PurchaseEvent {
-                        purchase_id,
-                        feed_event_id,
-                    })
-                    .execute(db)
-                    .context("failed to insert purchase created event")?;
-
-                insert_into(feed_event_wallets::table)
-                    .values(&FeedEventWallet {
-                        wallet_address: row.seller,
-                        feed_event_id,
-                    })
-                    .execute(db)
-                    .context("Failed to insert purchase feed event wallet for seller")?;
-
-                insert_into(feed_event_wallets::table)
-                    .values(&FeedEventWallet {
-                        wallet_address: row.buyer,
-                        feed_event_id,
-                    })
-                    .execute(db)
-                    .context("Failed to insert purchase feed event wallet for buyer")?;
-
-                Result::<_>::Ok(())
-            })
-        })
+        .run(move |db| marketplace::insert_purchase_created(db, purchase_id, row.seller, row.buyer))
         .await
         .context("failed to insert purchase event")?;
 
     Ok(())
 }
 
-#[allow(clippy::too_many_lines)]
 pub(crate) async fn process_bid_receipt(
     client: &Client,
     key: Pubkey,
@@ -280,17 +207,15 @@ pub(crate) async fn process_bid_receipt(
                 ))
                 .get_result::<bool>(db)?;
 
-                insert_into(bid_receipts::table)
-                    .values(&row)
-                    .on_conflict(bid_receipts::address)
-                    .do_update()
-                    .set(&row)
-                    .execute(db)?;
-
                 Result::<bool>::Ok(offer_exists)
             }
         })
         .await
+        .context("failed to check if offer exists")?;
+
+    client
+        .upsert(row.clone())
+        .await
         .context("failed to insert bid reciept")?;
 
     let offer_id = upsert_into_offers_table(client, row.clone())
@@ -301,60 +226,15 @@ pub(crate) async fn process_bid_receipt(
         return Ok(());
     }
 
-    let offer_event = client
+    client
         .db()
-        .run(move |db| {
-            db.build_transaction().read_write().run(|| {
-                let metadata_owner: String = current_metadata_owners::table
-                    .inner_join(
-                        metadatas::table
-                            .on(metadatas::mint_address.eq(current_metadata_owners::mint_address)),
-                    )
-                    .select(current_metadata_owners::owner_address)
-                    .first(db)?;
-
-                let feed_event_id = insert_into(feed_events::table)
-                    .default_values()
-                    .returning(feed_events::id)
-                    .get_result::<Uuid>(db)
-                    .context("Failed to insert feed event")?;
-
-                insert_into(offer_events::table)
-                    .values(&OfferEvent {
-                        feed_event_id,
-                        lifecycle: OfferEventLifecycleEnum::Created,
-                        offer_id,
-                    })
-                    .execute(db)
-                    .context("failed to insert offer created event")?;
-
-                insert_into(feed_event_wallets::table)
-                    .values(&FeedEventWallet {
-                        wallet_address: row.buyer,
-                        feed_event_id,
-                    })
-                    .execute(db)
-                    .context("Failed to insert offer feed event wallet for buyer")?;
-
-                insert_into(feed_event_wallets::table)
-                    .values(&FeedEventWallet {
-                        wallet_address: Owned(metadata_owner),
-                        feed_event_id,
-                    })
-                    .execute(db)
-                    .context("Failed to insert offer feed event wallet for metadata owner")?;
-
-                Result::<_>::Ok(Some(feed_event_id))
-            })
-        })
+        .run(move |db| marketplace::insert_offer_created(db, offer_id, row.buyer, row.metadata))
         .await
         .context("Failed to insert bid receipt!")?;
 
-    if offer_event.is_some() {
-        client
-            .dispatch_dialect_offer_event(key, bid_receipt.metadata)
-            .await?;
-    }
+    client
+        .dispatch_dialect_offer_event(key, bid_receipt.metadata)
+        .await?;
 
     Ok(())
 }
@@ -25,6 +25,7 @@ use indexer_core::{
             realm_config_accounts, realm_configs, realms, signatory_records, token_owner_records,
             vote_record_v2_vote_approve_vote_choices, vote_records_v1, vote_records_v2,
         },
+        versioned::IfNewerDsl,
     },
     prelude::*,
     util::unix_timestamp,
@@ -507,6 +508,7 @@ pub(crate) async fn process_governance(
                 .on_conflict(governances::address)
                 .do_update()
                 .set(&row)
+                .if_newer()
                 .execute(db)
         })
         .await
@@ -544,6 +546,7 @@ pub(crate) async fn process_governance(
                 .on_conflict(governance_configs::governance_address)
                 .do_update()
                 .set(&config)
+                .if_newer()
                 .execute(db)
         })
         .await
@@ -581,6 +584,7 @@ pub(crate) async fn process_realmv2(
                 .on_conflict(realms::address)
                 .do_update()
                 .set(&row)
+                .if_newer()
                 .execute(db)
         })
         .await
@@ -615,6 +619,7 @@ pub(crate) async fn process_realmv2(
                 .on_conflict(realm_configs::realm_address)
                 .do_update()
                 .set(&config)
+                .if_newer()
                 .execute(db)
         })
         .await
@@ -660,6 +665,7 @@ pub(crate) async fn process_realm_config(
                 .on_conflict(realm_config_accounts::address)
                 .do_update()
                 .set(&row)
+                .if_newer()
                 .execute(db)
         })
         .await
@@ -702,6 +708,7 @@ pub(crate) async fn process_vote_record_v1(
                 .on_conflict(vote_records_v1::address)
                 .do_update()
                 .set(&row)
+                .if_newer()
                 .execute(db)
         })
         .await
@@ -739,6 +746,7 @@ pub(crate) async fn process_vote_record_v2(
                 .on_conflict(vote_records_v2::address)
                 .do_update()
                 .set(&row)
+                .if_newer()
                 .execute(db)
         })
         .await
@@ -766,6 +774,7 @@ pub(crate) async fn process_vote_record_v2(
                         ))
                         .do_update()
                         .set(&r)
+                        .if_newer()
                         .execute(db)
                 })
                 .await
@@ -809,6 +818,7 @@ pub(crate) async fn process_token_owner_record(
                 .on_conflict(token_owner_records::address)
                 .do_update()
                 .set(&row)
+                .if_newer()
                 .execute(db)
         })
         .await
@@ -844,6 +854,7 @@ pub(crate) async fn process_signatory_record(
                 .on_conflict(signatory_records::address)
                 .do_update()
                 .set(&row)
+                .if_newer()
                 .execute(db)
         })
         .await
@@ -911,6 +922,7 @@ pub(crate) async fn process_proposal_v1(
                 .on_conflict(proposals_v1::address)
                 .do_update()
                 .set(&row)
+                .if_newer()
                 .execute(db)
         })
         .await
@@ -986,6 +998,7 @@ pub(crate) async fn process_proposal_v2(
                 .on_conflict(proposals_v2::address)
                 .do_update()
                 .set(&row)
+                .if_newer()
                 .execute(db)
         })
         .await
@@ -1012,6 +1025,7 @@ pub(crate) async fn process_proposal_v2(
                     .on_conflict((proposal_options::proposal_address, proposal_options::label))
                     .do_update()
                     .set(&row)
+                    .if_newer()
                     .execute(db)
             })
             .await
@@ -1039,6 +1053,7 @@ pub(crate) async fn process_proposal_v2(
                     .on_conflict(proposal_vote_type_multi_choices::address)
                     .do_update()
                     .set(&row)
+                    .if_newer()
                     .execute(db)
             })
             .await
@@ -1078,6 +1093,7 @@ pub(crate) async fn process_proposal_transaction(
                 .on_conflict(proposal_transactions::address)
                 .do_update()
                 .set(&row)
+                .if_newer()
                 .execute(db)
         })
         .await
@@ -1108,6 +1124,7 @@ pub(crate) async fn process_proposal_transaction(
                     ))
                     .do_update()
                     .set(&row)
+                    .if_newer()
                     .execute(db)
             })
             .await
@@ -1145,6 +1162,7 @@ async fn process_instruction_accounts(
                     ))
                     .do_update()
                     .set(&row)
+                    .if_newer()
                     .execute(db)
             })
             .await
@@ -1,8 +1,8 @@
 use indexer_core::{
     db::{
-        insert_into,
         models::{AssociatedTokenAccount, CurrentMetadataOwner},
-        tables::{associated_token_accounts, current_metadata_owners},
+        mutations::batch,
+        tables::nft_transfers,
         update,
     },
     prelude::*,
@@ -46,63 +46,39 @@ pub async fn process(
 
     let owner = token_account.owner.to_string();
     let mint_address = token_account.mint.to_string();
-    let incoming_slot: i64 = slot.try_into()?;
 
     let values = CurrentMetadataOwner {
         mint_address: Owned(mint_address),
-        owner_address: Owned(owner),
-        token_account_address: Owned(pubkey),
-        slot: incoming_slot,
+        owner_address: Owned(owner.clone()),
+        token_account_address: Owned(pubkey.clone()),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
     };
 
+    // Owners are read back by transfers and listings, so they are never
+    // batched
+    client
+        .db()
+        .run(move |db| batch::current_metadata_owners(db, std::slice::from_ref(&values)))
+        .await
+        .context("failed to insert token metadata owner!")?;
+
+    // Transfers are recorded before the owner of the receiving account is
+    // known
     client
         .db()
         .run(move |db| {
-            let rows = current_metadata_owners::table
-                .select((
-                    current_metadata_owners::mint_address,
-                    current_metadata_owners::owner_address,
-                    current_metadata_owners::token_account_address,
-                    current_metadata_owners::slot,
-                ))
-                .filter(current_metadata_owners::mint_address.eq(token_account.mint.to_string()))
-                .load::<CurrentMetadataOwner>(db)
-                .context("failed to load metadata owner!")?;
-
-            match rows.get(0) {
-                Some(r) if incoming_slot > r.slot => {
-                    db.build_transaction().read_write().run(|| {
-                        update(
-                            current_metadata_owners::table
-                                .filter(current_metadata_owners::mint_address.eq(values.clone().mint_address)),
-                        )
-                        .set(&values)
-                        .execute(db)
-                        .context("transaction failed! unable to update metadata_owners when incoming slot > indexed slot")
-                        .map(|_| ())
-                    })
-                },
-                Some(_) => Ok(()),
-                None => {
-                    db.build_transaction()
-                        .read_write()
-                        .run(|| {
-                            insert_into(current_metadata_owners::table)
-                                .values(&values)
-                                .on_conflict(current_metadata_owners::mint_address)
-                                .do_update()
-                                .set(&values)
-                                .execute(db)
-                                .map(|_| ())
-                        })
-                        .context("transaction failed! unable to insert metadata owner")?;
-
-                    Ok(())
-                },
-            }
+            update(
+                nft_transfers::table
+                    .filter(nft_transfers::destination_token_account.eq(pubkey))
+                    .filter(nft_transfers::destination_owner.is_null()),
+            )
+            .set(nft_transfers::destination_owner.eq(owner))
+            .execute(db)
         })
         .await
-        .context("failed to insert token metadata owner!")?;
+        .context("failed to update NFT transfer destination owner")?;
+
     Ok(())
 }
 
@@ -144,15 +120,7 @@ async fn upsert_ata(
     };
 
     client
-        .db()
-        .run(move |db| {
-            insert_into(associated_token_accounts::table)
-                .values(&row)
-                .on_conflict(associated_token_accounts::address)
-                .do_update()
-                .set(&row)
-                .execute(db)
-        })
+        .upsert(row)
         .await
         .context("failed to insert token account")?;
 
@@ -0,0 +1,79 @@
+use indexer_core::{
+    bigdecimal::BigDecimal,
+    db::{
+        insert_into, models::Token2022MintExtensions, tables::token_2022_mint_extensions,
+        versioned::IfNewerDsl,
+    },
+    prelude::*,
+};
+
+use super::{super::programs::token_2022::MintExtensions, Client};
+use crate::prelude::*;
+
+pub(crate) async fn process_mint_extensions(
+    client: &Client,
+    key: Pubkey,
+    exts: MintExtensions,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
+    let MintExtensions {
+        transfer_fee_config,
+        non_transferable,
+        metadata_pointer,
+        permanent_delegate,
+    } = exts;
+
+    let row = Token2022MintExtensions {
+        mint_address: Owned(key.to_string()),
+        transfer_fee_config_authority: transfer_fee_config
+            .and_then(|c| c.transfer_fee_config_authority)
+            .map(|k| Owned(k.to_string())),
+        withdraw_withheld_authority: transfer_fee_config
+            .and_then(|c| c.withdraw_withheld_authority)
+            .map(|k| Owned(k.to_string())),
+        withheld_amount: transfer_fee_config.map(|c| BigDecimal::from(c.withheld_amount)),
+        older_transfer_fee_epoch: transfer_fee_config
+            .map(|c| c.older_transfer_fee.epoch.try_into())
+            .transpose()?,
+        older_maximum_fee: transfer_fee_config
+            .map(|c| BigDecimal::from(c.older_transfer_fee.maximum_fee)),
+        older_transfer_fee_basis_points: transfer_fee_config
+            .map(|c| c.older_transfer_fee.transfer_fee_basis_points.try_into())
+            .transpose()?,
+        newer_transfer_fee_epoch: transfer_fee_config
+            .map(|c| c.newer_transfer_fee.epoch.try_into())
+            .transpose()?,
+        newer_maximum_fee: transfer_fee_config
+            .map(|c| BigDecimal::from(c.newer_transfer_fee.maximum_fee)),
+        newer_transfer_fee_basis_points: transfer_fee_config
+            .map(|c| c.newer_transfer_fee.transfer_fee_basis_points.try_into())
+            .transpose()?,
+        non_transferable,
+        metadata_pointer_authority: metadata_pointer
+            .and_then(|p| p.authority)
+            .map(|k| Owned(k.to_string())),
+        metadata_pointer_address: metadata_pointer
+            .and_then(|p| p.metadata_address)
+            .map(|k| Owned(k.to_string())),
+        permanent_delegate: permanent_delegate.map(|k| Owned(k.to_string())),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
+    };
+
+    client
+        .db()
+        .run(move |db| {
+            insert_into(token_2022_mint_extensions::table)
+                .values(&row)
+                .on_conflict(token_2022_mint_extensions::mint_address)
+                .do_update()
+                .set(&row)
+                .if_newer()
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert Token-2022 mint extensions")?;
+
+    Ok(())
+}
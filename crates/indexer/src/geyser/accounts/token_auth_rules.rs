@@ -0,0 +1,42 @@
+use indexer_core::db::{
+    insert_into, models::RuleSet as DbRuleSet, tables::rule_sets, versioned::IfNewerDsl,
+};
+
+use super::{super::programs::token_auth_rules::RuleSet, Client};
+use crate::prelude::*;
+
+pub(crate) async fn process(
+    client: &Client,
+    key: Pubkey,
+    rule_set: RuleSet,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
+    let row = DbRuleSet {
+        address: Owned(key.to_string()),
+        owner: Owned(rule_set.owner.to_string()),
+        name: Owned(rule_set.name),
+        revision: rule_set
+            .revision
+            .try_into()
+            .context("Rule set revision was too big to store")?,
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
+    };
+
+    client
+        .db()
+        .run(move |db| {
+            insert_into(rule_sets::table)
+                .values(&row)
+                .on_conflict(rule_sets::address)
+                .do_update()
+                .set(&row)
+                .if_newer()
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert rule set")?;
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use indexer_core::db::{
+    custom_types::{TokenDelegateRoleEnum, TokenRecordStateEnum},
+    insert_into,
+    models::TokenRecord as DbTokenRecord,
+    tables::token_records,
+    versioned::IfNewerDsl,
+};
+
+use super::{
+    super::programs::metadata::{TokenDelegateRole, TokenRecord, TokenState},
+    Client,
+};
+use crate::prelude::*;
+
+pub(crate) async fn process(
+    client: &Client,
+    key: Pubkey,
+    record: TokenRecord,
+    slot: u64,
+    write_version: u64,
+) -> Result<()> {
+    let row = DbTokenRecord {
+        address: Owned(key.to_string()),
+        bump: record.bump.into(),
+        state: match record.state {
+            TokenState::Unlocked => TokenRecordStateEnum::Unlocked,
+            TokenState::Locked => TokenRecordStateEnum::Locked,
+            TokenState::Listed => TokenRecordStateEnum::Listed,
+        },
+        rule_set_revision: record
+            .rule_set_revision
+            .map(TryInto::try_into)
+            .transpose()
+            .context("Rule set revision was too big to store")?,
+        delegate: record.delegate.map(|d| Owned(d.to_string())),
+        delegate_role: record.delegate_role.map(|r| match r {
+            TokenDelegateRole::Sale => TokenDelegateRoleEnum::Sale,
+            TokenDelegateRole::Transfer => TokenDelegateRoleEnum::Transfer,
+            TokenDelegateRole::Utility => TokenDelegateRoleEnum::Utility,
+            TokenDelegateRole::Staking => TokenDelegateRoleEnum::Staking,
+            TokenDelegateRole::Standard => TokenDelegateRoleEnum::Standard,
+            TokenDelegateRole::LockedTransfer => TokenDelegateRoleEnum::LockedTransfer,
+            TokenDelegateRole::Migration => TokenDelegateRoleEnum::Migration,
+        }),
+        locked_transfer: record.locked_transfer.map(|k| Owned(k.to_string())),
+        slot: slot.try_into()?,
+        write_version: write_version.try_into()?,
+    };
+
+    client
+        .db()
+        .run(move |db| {
+            insert_into(token_records::table)
+                .values(&row)
+                .on_conflict(token_records::address)
+                .do_update()
+                .set(&row)
+                .if_newer()
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert token record")?;
+
+    Ok(())
+}
@@ -0,0 +1,447 @@
+//! Backfilling of program accounts from a Solana RPC node, for indexing
+//! accounts which have not changed since a program was added or fixed
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::PathBuf,
+    sync::Arc,
+};
+
+use futures_util::{stream, StreamExt};
+use indexer_core::clap;
+use indexer_rabbitmq::geyser::Message;
+use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
+use solana_client::{
+    rpc_client::RpcClient,
+    rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+    rpc_filter::{Memcmp, MemcmpEncodedBytes, RpcFilterType},
+    rpc_request::MAX_MULTIPLE_ACCOUNTS,
+};
+
+use super::{
+    process_message,
+    registry::{AccountFilter, AccountQuery},
+    AccountUpdate, Client, MessageId, Registry,
+};
+use crate::{prelude::*, ErrorKind, Params};
+
+/// Write version given to backfilled accounts
+///
+/// Accounts are fetched as they were at the end of a slot, so they replace
+/// any live update from the same slot and are replaced by any later one.
+const WRITE_VERSION: u64 = i64::MAX.unsigned_abs();
+
+/// Arguments for backfilling program accounts
+#[derive(Debug, clap::Args)]
+#[group(skip)]
+pub struct Args {
+    /// Solana RPC endpoint to fetch program accounts from
+    ///
+    /// The node must serve `getProgramAccounts` for the indexed programs, for
+    /// example a local `solana-test-validator` at `http://127.0.0.1:8899`.
+    #[arg(long, env)]
+    rpc_url: String,
+
+    /// File to record progress in, so an interrupted backfill can be resumed
+    /// by running it again with the same file
+    #[arg(long, env)]
+    progress_file: Option<PathBuf>,
+
+    /// Number of accounts to index between progress checkpoints
+    #[arg(long, env, default_value_t = 1000)]
+    page_size: usize,
+}
+
+/// Where a query was left off when the backfill was interrupted
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Checkpoint {
+    /// Every account returned by the query was attempted
+    done: bool,
+    /// Accounts up to and including this key were attempted
+    after: Option<String>,
+    /// Accounts which failed to index, retried when the backfill is resumed
+    failed: BTreeSet<String>,
+}
+
+#[derive(Debug)]
+struct Progress {
+    path: Option<PathBuf>,
+    queries: BTreeMap<String, Checkpoint>,
+}
+
+impl Progress {
+    fn load(path: Option<PathBuf>) -> Result<Self> {
+        let queries = match path {
+            Some(ref p) if p.exists() => {
+                let json =
+                    fs::read(p).with_context(|| format!("Failed to read progress file {p:?}"))?;
+                serde_json::from_slice(&json).context("Malformed progress file")?
+            },
+            _ => BTreeMap::new(),
+        };
+
+        Ok(Self { path, queries })
+    }
+
+    fn get(&self, query: &str) -> Option<&Checkpoint> {
+        self.queries.get(query)
+    }
+
+    fn set(&mut self, query: String, checkpoint: Checkpoint) -> Result<()> {
+        self.queries.insert(query, checkpoint);
+
+        let path = match self.path {
+            Some(ref p) => p,
+            None => return Ok(()),
+        };
+
+        // Write to a temporary file first so a crash can't corrupt the file
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, serde_json::to_vec_pretty(&self.queries)?)
+            .and_then(|()| fs::rename(&tmp, path))
+            .with_context(|| format!("Failed to write progress file {path:?}"))
+    }
+}
+
+/// Process an account update, retrying it as configured if it fails with a
+/// retryable error
+async fn process_account(
+    client: &Client,
+    registry: &Registry,
+    params: &Params,
+    update: AccountUpdate,
+) -> MessageResult<MessageId> {
+    let mut attempts = 1;
+
+    loop {
+        match process_message(Message::AccountUpdate(update.clone()), client, registry).await {
+            Err(e) if e.kind() == ErrorKind::Retryable => match params.retry_delay(attempts) {
+                Some(delay) => {
+                    debug!("Retrying {} in {:?}: {}", update.key, delay, e);
+
+                    tokio::time::sleep(delay).await;
+                    attempts += 1;
+                },
+                None => break Err(e),
+            },
+            r => break r,
+        }
+    }
+}
+
+/// Fetch and process a page of accounts, returning the keys of those which
+/// failed
+async fn process_page(
+    client: &Client,
+    registry: &Registry,
+    params: &Params,
+    rpc: &Arc<RpcClient>,
+    page: &[Pubkey],
+) -> Result<Vec<Pubkey>> {
+    let updates = tokio::task::spawn_blocking({
+        let rpc = Arc::clone(rpc);
+        let page = page.to_vec();
+        move || fetch_accounts(&rpc, &page)
+    })
+    .await
+    .context("RPC task panicked")??;
+
+    let results: Vec<_> = stream::iter(updates)
+        .map(|u| async move {
+            let key = u.key;
+            (key, process_account(client, registry, params, u).await)
+        })
+        .buffer_unordered(params.concurrency().max(1))
+        .collect()
+        .await;
+
+    // Buffered writes must land before the page is checkpointed
+    client.flush_writes().await?;
+
+    let mut failed = vec![];
+
+    for (key, res) in results {
+        if let Err(e) = res {
+            warn!("{}", e);
+            failed.push(key);
+        }
+    }
+
+    Ok(failed)
+}
+
+fn query_key(query: &AccountQuery) -> String {
+    format!(
+        "{}/{}",
+        query.id,
+        query.account_type.map_or("*", |t| t.name)
+    )
+}
+
+fn rpc_filter(filter: AccountFilter) -> Result<RpcFilterType> {
+    Ok(match filter {
+        AccountFilter::DataSize(s) => RpcFilterType::DataSize(s.try_into()?),
+        AccountFilter::Memcmp(offset, bytes) => RpcFilterType::Memcmp(Memcmp {
+            offset,
+            bytes: MemcmpEncodedBytes::Base58(bs58::encode(bytes).into_string()),
+            encoding: None,
+        }),
+    })
+}
+
+/// Fetch the keys of the accounts matching a query, sorted
+///
+/// Only the keys are transferred, so the data of accounts already indexed by
+/// an interrupted run is not fetched again.
+fn fetch_keys(rpc: &RpcClient, query: &AccountQuery) -> Result<Vec<Pubkey>> {
+    let filters = query
+        .account_type
+        .map(|t| t.filters.iter().copied().map(rpc_filter).collect())
+        .transpose()?;
+
+    let accounts = rpc
+        .get_program_accounts_with_config(&query.id, RpcProgramAccountsConfig {
+            filters,
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                data_slice: Some(UiDataSliceConfig {
+                    offset: 0,
+                    length: 0,
+                }),
+                ..RpcAccountInfoConfig::default()
+            },
+            ..RpcProgramAccountsConfig::default()
+        })
+        .with_context(|| format!("getProgramAccounts RPC call failed for {}", query.id))?;
+
+    let mut keys: Vec<_> = accounts.into_iter().map(|(k, _)| k).collect();
+    keys.sort_unstable();
+
+    Ok(keys)
+}
+
+/// Fetch the accounts with the given keys, skipping any closed since their
+/// keys were fetched
+fn fetch_accounts(rpc: &RpcClient, keys: &[Pubkey]) -> Result<Vec<AccountUpdate>> {
+    let mut updates = Vec::with_capacity(keys.len());
+
+    for chunk in keys.chunks(MAX_MULTIPLE_ACCOUNTS) {
+        let res = rpc
+            .get_multiple_accounts_with_config(chunk, RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..RpcAccountInfoConfig::default()
+            })
+            .context("getMultipleAccounts RPC call failed")?;
+        let slot = res.context.slot;
+
+        updates.extend(chunk.iter().zip(res.value).filter_map(|(&key, account)| {
+            account.map(|account| AccountUpdate {
+                key,
+                lamports: account.lamports,
+                owner: account.owner,
+                executable: account.executable,
+                rent_epoch: account.rent_epoch,
+                data: account.data,
+                write_version: WRITE_VERSION,
+                slot,
+                is_startup: true,
+            })
+        }));
+    }
+
+    Ok(updates)
+}
+
+/// Fetch every account indexed by a program enabled in the registry from a
+/// Solana RPC node, and process them as startup updates
+///
+/// Accounts which still fail after being retried are logged and saved in the
+/// progress file, and retried when the backfill is run again.
+///
+/// # Errors
+/// This function fails if an RPC call fails or progress cannot be saved.
+pub async fn backfill(
+    client: &Client,
+    registry: &Registry,
+    args: Args,
+    params: &Params,
+) -> Result<()> {
+    let Args {
+        rpc_url,
+        progress_file,
+        page_size,
+    } = args;
+
+    let rpc = Arc::new(RpcClient::new(rpc_url));
+    let mut progress = Progress::load(progress_file)?;
+    let (mut processed, mut failed) = (0_usize, 0_usize);
+
+    for query in registry.account_queries() {
+        if !registry.handles_account(&query.id, true) {
+            continue;
+        }
+
+        let key = query_key(&query);
+        let mut checkpoint = progress.get(&key).cloned().unwrap_or_default();
+
+        // Accounts which failed in an earlier run are retried first
+        let retries = checkpoint
+            .failed
+            .iter()
+            .map(|k| k.parse::<Pubkey>())
+            .collect::<Result<Vec<_>, _>>()
+            .context("Invalid failed key in progress file")?;
+
+        for page in retries.chunks(page_size.max(1)) {
+            let still_failed = process_page(client, registry, params, &rpc, page).await?;
+            processed += page.len();
+
+            for k in page {
+                if !still_failed.contains(k) {
+                    checkpoint.failed.remove(&k.to_string());
+                }
+            }
+
+            progress.set(key.clone(), checkpoint.clone())?;
+        }
+
+        if checkpoint.done {
+            debug!("Skipping completed query {}", key);
+            failed += checkpoint.failed.len();
+            continue;
+        }
+
+        let after = checkpoint
+            .after
+            .as_deref()
+            .map(str::parse::<Pubkey>)
+            .transpose()
+            .context("Invalid checkpoint")?;
+
+        info!("Fetching accounts for {}...", key);
+
+        let mut keys = tokio::task::spawn_blocking({
+            let rpc = Arc::clone(&rpc);
+            move || fetch_keys(&rpc, &query)
+        })
+        .await
+        .context("RPC task panicked")??;
+
+        if let Some(after) = after {
+            keys.retain(|k| *k > after);
+        }
+
+        info!("Indexing {} accounts for {}", keys.len(), key);
+
+        for page in keys.chunks(page_size.max(1)) {
+            let page_failed = process_page(client, registry, params, &rpc, page).await?;
+            processed += page.len();
+
+            // Failed accounts are saved along with the checkpoint so moving
+            // past them doesn't lose them
+            checkpoint
+                .failed
+                .extend(page_failed.iter().map(ToString::to_string));
+            checkpoint.after = page.last().map(ToString::to_string);
+            progress.set(key.clone(), checkpoint.clone())?;
+        }
+
+        checkpoint.done = true;
+        failed += checkpoint.failed.len();
+        progress.set(key, checkpoint)?;
+    }
+
+    info!(
+        "Backfill finished: {} accounts processed, {} failed",
+        processed, failed
+    );
+
+    if failed > 0 {
+        warn!("Run the backfill again with the same progress file to retry failed accounts");
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use indexer_core::pubkeys;
+
+    use super::*;
+
+    #[test]
+    fn test_rpc_filter() {
+        assert!(matches!(
+            rpc_filter(AccountFilter::DataSize(165)).unwrap(),
+            RpcFilterType::DataSize(165)
+        ));
+
+        match rpc_filter(AccountFilter::Memcmp(0, &[4])).unwrap() {
+            RpcFilterType::Memcmp(Memcmp {
+                offset: 0,
+                bytes: MemcmpEncodedBytes::Base58(b),
+                ..
+            }) => assert_eq!(b, "5"),
+            f => panic!("Unexpected filter {f:?}"),
+        }
+    }
+
+    #[test]
+    fn test_progress_resume() {
+        let path = std::env::temp_dir().join(format!(
+            "holaplex-indexer-backfill-{}.json",
+            std::process::id()
+        ));
+        fs::remove_file(&path).ok();
+
+        let checkpoint = Checkpoint {
+            done: false,
+            after: Some(Pubkey::new_unique().to_string()),
+            failed: [Pubkey::new_unique().to_string()].into_iter().collect(),
+        };
+
+        let mut progress = Progress::load(Some(path.clone())).unwrap();
+        assert!(progress.get("query").is_none());
+        progress.set("query".into(), checkpoint.clone()).unwrap();
+
+        // Failed keys survive a restart along with the checkpoint
+        let progress = Progress::load(Some(path.clone())).unwrap();
+        fs::remove_file(&path).ok();
+        assert_eq!(progress.get("query"), Some(&checkpoint));
+    }
+
+    /// Requires a `solana-test-validator` listening at `TEST_RPC_URL`, or
+    /// `http://127.0.0.1:8899` if it is unset
+    #[test]
+    #[ignore = "requires solana-test-validator"]
+    fn test_fetch_from_test_validator() {
+        let rpc = RpcClient::new(
+            std::env::var("TEST_RPC_URL").unwrap_or_else(|_| "http://127.0.0.1:8899".into()),
+        );
+
+        // The test validator deploys the SPL programs at genesis
+        let keys = fetch_keys(&rpc, &AccountQuery {
+            program: None,
+            id: solana_program::bpf_loader::id(),
+            account_type: None,
+        })
+        .unwrap();
+        assert!(keys.contains(&pubkeys::TOKEN));
+        assert!(keys.windows(2).all(|w| w[0] < w[1]));
+
+        // Accounts which don't exist are skipped
+        let missing = Pubkey::new_unique();
+        let updates = fetch_accounts(&rpc, &[pubkeys::TOKEN, missing]).unwrap();
+        assert_eq!(updates.len(), 1);
+
+        let token = &updates[0];
+        assert_eq!(token.key, pubkeys::TOKEN);
+        assert_eq!(token.owner, solana_program::bpf_loader::id());
+        assert!(token.executable);
+        assert!(token.is_startup);
+        assert_eq!(token.write_version, WRITE_VERSION);
+        assert!(!token.data.is_empty());
+    }
+}
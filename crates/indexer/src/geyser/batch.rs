@@ -0,0 +1,567 @@
+//! Write buffering for tables updated by frequently changing accounts
+//!
+//! Rows are collected per table, keeping only the newest version of each row,
+//! and written with a single multi-row upsert once a table's buffer is full or
+//! the flush interval elapses.  Messages which buffered a row are not
+//! acknowledged until the flush containing it has been committed.
+//!
+//! If a table fails to flush [`MAX_BATCH_FAILURES`] times in a row, its rows
+//! are written one at a time instead, and the messages of any row which still
+//! fails are requeued rather than held back forever.
+
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    future::Future,
+    sync::{Arc, Mutex, Weak},
+};
+
+use indexer_core::{
+    clap,
+    db::{
+        models::{AssociatedTokenAccount, BidReceipt, ListingReceipt, PurchaseReceipt},
+        mutations::batch,
+        PooledConnection,
+    },
+};
+use tokio::sync::oneshot;
+
+use super::Client;
+use crate::{db::Pool, prelude::*};
+
+/// The number of consecutive failed flushes of a table after which its rows
+/// are written one at a time
+pub(crate) const MAX_BATCH_FAILURES: u32 = 3;
+
+/// Arguments for batching database writes
+#[derive(Debug, Clone, Copy, clap::Args)]
+#[group(skip)]
+pub struct Args {
+    /// Buffer writes of token accounts and auction house receipts, and upsert
+    /// up to this many rows of each table at once
+    ///
+    /// Buffered rows are written within `--batch-interval-ms`, and the
+    /// messages they came from are acknowledged once they are written.  Zero
+    /// disables batching.
+    #[arg(long, env, default_value_t = 0)]
+    batch_size: usize,
+
+    /// The longest a buffered row waits before it is written, in milliseconds
+    #[arg(long, env, default_value_t = 1000)]
+    batch_interval_ms: u64,
+}
+
+/// A row which can be buffered and upserted as part of a batch
+pub(crate) trait Row: Clone + Send + Sync + 'static {
+    /// The name of the table the row belongs to
+    const TABLE: &'static str;
+
+    /// Get the primary key of the row
+    fn key(&self) -> String;
+
+    /// Returns true if this row should overwrite `old`, which has the same key
+    fn replaces(&self, old: &Self) -> bool;
+
+    /// Get the buffer holding rows of this type
+    fn buffer(batches: &Batches) -> &Buffer<Self>;
+
+    /// Upsert rows with distinct keys
+    ///
+    /// # Errors
+    /// This function fails if the upsert fails
+    fn upsert(db: &PooledConnection, rows: &[Self]) -> Result<()>;
+}
+
+/// Compare the slot and write version of two rows, as the batched upserts do
+fn newer(new: (i64, i64), old: (i64, i64)) -> bool {
+    new > old
+}
+
+impl Row for AssociatedTokenAccount<'static> {
+    const TABLE: &'static str = "associated_token_accounts";
+
+    fn key(&self) -> String {
+        self.address.to_string()
+    }
+
+    fn replaces(&self, old: &Self) -> bool {
+        newer(
+            (self.slot, self.write_version),
+            (old.slot, old.write_version),
+        )
+    }
+
+    fn buffer(batches: &Batches) -> &Buffer<Self> {
+        &batches.token_accounts
+    }
+
+    fn upsert(db: &PooledConnection, rows: &[Self]) -> Result<()> {
+        batch::associated_token_accounts(db, rows)
+    }
+}
+
+impl Row for ListingReceipt<'static> {
+    const TABLE: &'static str = "listing_receipts";
+
+    fn key(&self) -> String {
+        self.address.to_string()
+    }
+
+    fn replaces(&self, old: &Self) -> bool {
+        newer(
+            (self.slot, self.write_version),
+            (old.slot, old.write_version),
+        )
+    }
+
+    fn buffer(batches: &Batches) -> &Buffer<Self> {
+        &batches.listing_receipts
+    }
+
+    fn upsert(db: &PooledConnection, rows: &[Self]) -> Result<()> {
+        batch::listing_receipts(db, rows)
+    }
+}
+
+impl Row for BidReceipt<'static> {
+    const TABLE: &'static str = "bid_receipts";
+
+    fn key(&self) -> String {
+        self.address.to_string()
+    }
+
+    fn replaces(&self, old: &Self) -> bool {
+        newer(
+            (self.slot, self.write_version),
+            (old.slot, old.write_version),
+        )
+    }
+
+    fn buffer(batches: &Batches) -> &Buffer<Self> {
+        &batches.bid_receipts
+    }
+
+    fn upsert(db: &PooledConnection, rows: &[Self]) -> Result<()> {
+        batch::bid_receipts(db, rows)
+    }
+}
+
+impl Row for PurchaseReceipt<'static> {
+    const TABLE: &'static str = "purchase_receipts";
+
+    fn key(&self) -> String {
+        self.address.to_string()
+    }
+
+    fn replaces(&self, old: &Self) -> bool {
+        newer(
+            (self.slot, self.write_version),
+            (old.slot, old.write_version),
+        )
+    }
+
+    fn buffer(batches: &Batches) -> &Buffer<Self> {
+        &batches.purchase_receipts
+    }
+
+    fn upsert(db: &PooledConnection, rows: &[Self]) -> Result<()> {
+        batch::purchase_receipts(db, rows)
+    }
+}
+
+/// Senders to notify once the row with a given key is written
+type Waiters = HashMap<String, Vec<oneshot::Sender<()>>>;
+
+/// Rows waiting to be written, the senders to notify once they are, and the
+/// number of consecutive failed flushes
+struct Pending<R> {
+    rows: HashMap<String, R>,
+    waiters: Waiters,
+    failures: u32,
+}
+
+impl<R: Row> Pending<R> {
+    fn insert(&mut self, row: R) {
+        match self.rows.entry(row.key()) {
+            Entry::Occupied(mut e) => {
+                if row.replaces(e.get()) {
+                    e.insert(row);
+                }
+            },
+            Entry::Vacant(e) => {
+                e.insert(row);
+            },
+        }
+    }
+}
+
+/// Pending rows of a single table, keyed by primary key
+pub(crate) struct Buffer<R>(Mutex<Pending<R>>);
+
+impl<R> Default for Buffer<R> {
+    fn default() -> Self {
+        Self(Mutex::new(Pending {
+            rows: HashMap::new(),
+            waiters: HashMap::new(),
+            failures: 0,
+        }))
+    }
+}
+
+impl<R: Row> Buffer<R> {
+    /// Add a row unless a newer row with the same key is already buffered,
+    /// returning the number of rows buffered and a receiver notified once the
+    /// row (or a newer one) has been written
+    fn push(&self, row: R) -> Result<(usize, oneshot::Receiver<()>)> {
+        let mut pending = self
+            .0
+            .lock()
+            .map_err(|_| anyhow!("Batch buffer lock was poisoned"))?;
+        let (tx, rx) = oneshot::channel();
+
+        pending.waiters.entry(row.key()).or_default().push(tx);
+        pending.insert(row);
+
+        Ok((pending.rows.len(), rx))
+    }
+
+    /// Remove and return all buffered rows and their waiters, along with the
+    /// number of consecutive failed flushes
+    fn take(&self) -> Result<(Vec<R>, Waiters, u32)> {
+        let mut pending = self
+            .0
+            .lock()
+            .map_err(|_| anyhow!("Batch buffer lock was poisoned"))?;
+
+        Ok((
+            pending.rows.drain().map(|(_, r)| r).collect(),
+            std::mem::take(&mut pending.waiters),
+            std::mem::take(&mut pending.failures),
+        ))
+    }
+
+    /// Return rows which failed to be written to the buffer, keeping any newer
+    /// rows buffered since they were taken, and record the failed flush
+    fn restore(&self, rows: Vec<R>, waiters: Waiters, failures: u32) -> Result<()> {
+        let mut pending = self
+            .0
+            .lock()
+            .map_err(|_| anyhow!("Batch buffer lock was poisoned"))?;
+
+        for row in rows {
+            pending.insert(row);
+        }
+
+        for (key, senders) in waiters {
+            pending.waiters.entry(key).or_default().extend(senders);
+        }
+
+        pending.failures = failures + 1;
+
+        Ok(())
+    }
+}
+
+/// Write rows one at a time, notifying the waiters of each row written and
+/// dropping the waiters of each row which fails so that their messages are
+/// requeued.  Returns the number of rows which failed.
+async fn write_each<R: Row, F: Future<Output = Result<()>>>(
+    rows: Vec<R>,
+    mut waiters: Waiters,
+    write: impl Fn(R) -> F,
+) -> usize {
+    let mut failed = 0;
+
+    for row in rows {
+        let key = row.key();
+        let senders = waiters.remove(&key).unwrap_or_default();
+
+        match write(row).await {
+            Ok(()) => {
+                for sender in senders {
+                    // The message may have been given up on already
+                    sender.send(()).ok();
+                }
+            },
+            Err(e) => {
+                warn!(
+                    "Requeueing messages for batched {} row {}: {:?}",
+                    R::TABLE,
+                    key,
+                    e
+                );
+                failed += 1;
+            },
+        }
+    }
+
+    failed
+}
+
+/// Write buffers for each batched table
+pub(crate) struct Batches {
+    size: usize,
+    interval: StdDuration,
+    token_accounts: Buffer<AssociatedTokenAccount<'static>>,
+    listing_receipts: Buffer<ListingReceipt<'static>>,
+    bid_receipts: Buffer<BidReceipt<'static>>,
+    purchase_receipts: Buffer<PurchaseReceipt<'static>>,
+}
+
+impl Batches {
+    /// Create a set of empty buffers, or return `None` if batching is
+    /// disabled
+    pub fn new(args: Args) -> Option<Self> {
+        let Args {
+            batch_size,
+            batch_interval_ms,
+        } = args;
+
+        if batch_size == 0 {
+            return None;
+        }
+
+        info!(
+            "Batching writes of up to {} rows, flushed every {}ms",
+            batch_size, batch_interval_ms
+        );
+
+        Some(Self {
+            size: batch_size,
+            interval: StdDuration::from_millis(batch_interval_ms),
+            token_accounts: Buffer::default(),
+            listing_receipts: Buffer::default(),
+            bid_receipts: Buffer::default(),
+            purchase_receipts: Buffer::default(),
+        })
+    }
+
+    /// Buffer a row, flushing its table if the buffer is full.  The returned
+    /// receiver is notified once the row has been written.
+    ///
+    /// # Errors
+    /// This function fails if a flush is triggered and fails
+    pub async fn push<R: Row>(&self, db: &Pool, row: R) -> Result<oneshot::Receiver<()>> {
+        let (len, written) = R::buffer(self).push(row)?;
+
+        if len >= self.size {
+            self.flush::<R>(db).await?;
+        }
+
+        Ok(written)
+    }
+
+    /// Write all buffered rows of one table.  If the write fails the rows are
+    /// returned to the buffer to be retried by the next flush, unless the
+    /// table has already failed to flush [`MAX_BATCH_FAILURES`] times, in
+    /// which case each row is written separately.
+    async fn flush<R: Row>(&self, db: &Pool) -> Result<()> {
+        let buf = R::buffer(self);
+        let (rows, waiters, failures) = buf.take()?;
+
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        let len = rows.len();
+
+        if failures >= MAX_BATCH_FAILURES {
+            let failed = write_each(rows, waiters, |row| {
+                db.run(move |db| R::upsert(db, std::slice::from_ref(&row)))
+            })
+            .await;
+
+            if failed > 0 {
+                bail!(
+                    "Failed to write {} of {} batched {} rows individually",
+                    failed,
+                    len,
+                    R::TABLE
+                );
+            }
+
+            trace!("Flushed {} batched {} rows individually", len, R::TABLE);
+
+            return Ok(());
+        }
+
+        let rows = Arc::new(rows);
+        let res = db
+            .run({
+                let rows = Arc::clone(&rows);
+                move |db| R::upsert(db, &rows)
+            })
+            .await;
+
+        if let Err(e) = res {
+            buf.restore(
+                Arc::try_unwrap(rows).unwrap_or_else(|r| (*r).clone()),
+                waiters,
+                failures,
+            )?;
+
+            return Err(e.context(format!("Failed to flush batched {} rows", R::TABLE)));
+        }
+
+        for waiter in waiters.into_values().flatten() {
+            // The message may have been given up on already
+            waiter.send(()).ok();
+        }
+
+        trace!("Flushed {} batched {} rows", len, R::TABLE);
+
+        Ok(())
+    }
+
+    /// Write all buffered rows
+    ///
+    /// # Errors
+    /// This function fails if any table cannot be flushed
+    pub async fn flush_all(&self, db: &Pool) -> Result<()> {
+        self.flush::<AssociatedTokenAccount>(db).await?;
+        self.flush::<ListingReceipt>(db).await?;
+        self.flush::<BidReceipt>(db).await?;
+        self.flush::<PurchaseReceipt>(db).await?;
+
+        Ok(())
+    }
+
+    /// Spawn a task flushing the buffers of the given client every interval
+    /// until the client is dropped
+    pub fn spawn_flush(&self, client: Weak<Client>) {
+        let interval = self.interval;
+
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(interval).await;
+
+                let client = match client.upgrade() {
+                    Some(c) => c,
+                    None => break,
+                };
+
+                if let Err(e) = client.flush_writes().await {
+                    warn!("{:?}", e);
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indexer_core::db::models::AssociatedTokenAccount;
+
+    use super::{write_each, Buffer, MAX_BATCH_FAILURES};
+    use crate::prelude::*;
+
+    fn ata(slot: i64, write_version: i64, amount: i64) -> AssociatedTokenAccount<'static> {
+        ata_at("account", slot, write_version, amount)
+    }
+
+    fn ata_at(
+        address: &'static str,
+        slot: i64,
+        write_version: i64,
+        amount: i64,
+    ) -> AssociatedTokenAccount<'static> {
+        AssociatedTokenAccount {
+            address: address.into(),
+            mint: "mint".into(),
+            owner: "owner".into(),
+            amount,
+            slot,
+            write_version,
+        }
+    }
+
+    #[test]
+    fn test_buffer_keeps_newest() {
+        let buf = Buffer::default();
+
+        assert_eq!(buf.push(ata(10, 5, 1)).unwrap().0, 1);
+        assert_eq!(buf.push(ata(10, 5, 5)).unwrap().0, 1);
+        assert_eq!(buf.push(ata(10, 4, 2)).unwrap().0, 1);
+        assert_eq!(buf.push(ata(9, 9, 3)).unwrap().0, 1);
+
+        let (rows, waiters, _) = buf.take().unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].amount, 1);
+        assert_eq!(waiters["account"].len(), 4);
+
+        buf.push(ata(10, 5, 1)).unwrap();
+        buf.push(ata(11, 0, 4)).unwrap();
+
+        let (rows, ..) = buf.take().unwrap();
+        assert_eq!(rows[0].amount, 4);
+        assert!(buf.take().unwrap().0.is_empty());
+    }
+
+    #[test]
+    fn test_buffer_waiters() {
+        let buf = Buffer::default();
+
+        let (_, mut first) = buf.push(ata(10, 5, 1)).unwrap();
+
+        // A failed flush returns its rows and waiters without replacing newer
+        // rows pushed in the meantime
+        let (rows, waiters, failures) = buf.take().unwrap();
+        let (_, mut second) = buf.push(ata(11, 0, 2)).unwrap();
+        buf.restore(rows, waiters, failures).unwrap();
+        assert!(first.try_recv().is_err());
+
+        let (rows, waiters, failures) = buf.take().unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].amount, 2);
+        assert_eq!(failures, 1);
+
+        for waiter in waiters.into_values().flatten() {
+            waiter.send(()).unwrap();
+        }
+
+        assert!(first.try_recv().is_ok());
+        assert!(second.try_recv().is_ok());
+
+        // A successful flush resets the failure count
+        assert_eq!(buf.take().unwrap().2, 0);
+    }
+
+    #[tokio::test]
+    async fn test_failing_flush() {
+        let buf = Buffer::default();
+
+        let (_, mut good) = buf.push(ata_at("good", 1, 0, 1)).unwrap();
+        let (_, mut bad) = buf.push(ata_at("bad", 1, 0, 2)).unwrap();
+
+        // Failed batch flushes hold on to every waiter
+        for _ in 0..MAX_BATCH_FAILURES {
+            let (rows, waiters, failures) = buf.take().unwrap();
+            buf.restore(rows, waiters, failures).unwrap();
+        }
+
+        assert!(matches!(
+            good.try_recv(),
+            Err(tokio::sync::oneshot::error::TryRecvError::Empty)
+        ));
+
+        let (rows, waiters, failures) = buf.take().unwrap();
+        assert_eq!(failures, MAX_BATCH_FAILURES);
+
+        // Written one at a time, only the failing row's messages are requeued
+        let failed = write_each(rows, waiters, |row| async move {
+            if row.address == "bad" {
+                bail!("Constraint violation");
+            }
+
+            Ok(())
+        })
+        .await;
+
+        assert_eq!(failed, 1);
+        assert!(good.try_recv().is_ok());
+        assert!(matches!(
+            bad.try_recv(),
+            Err(tokio::sync::oneshot::error::TryRecvError::Closed)
+        ));
+        assert!(buf.take().unwrap().0.is_empty());
+    }
+}
@@ -1,9 +1,25 @@
-use std::{sync::Arc, time::Duration};
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
 
-use indexer_core::clap;
+use indexer_core::{
+    clap,
+    db::{custom_types::SlotStatusEnum, tables::slots},
+    util::unix_timestamp,
+};
 use indexer_rabbitmq::{fungible_indexer, http_indexer, search_indexer};
 
-use crate::{db::Pool, prelude::*, reqwest, search_dispatch};
+use super::{
+    batch::{self, Batches},
+    commitment::SlotStatusWatch,
+    Commitment,
+};
+use crate::{db::Pool, prelude::*, reqwest, search_dispatch, NotReady};
+
+/// The number of recent slots to remember the block time of
+const BLOCK_TIME_CACHE_SIZE: usize = 4096;
 
 struct HttpProducers {
     metadata_json: http_indexer::Producer<http_indexer::MetadataJson>,
@@ -22,8 +38,24 @@ pub struct Args {
     #[arg(long, env, requires("dialect_api_endpoint"))]
     dialect_api_key: Option<String>,
 
+    /// Solana RPC endpoint used to look up block times and token accounts
+    #[arg(long, env)]
+    solana_endpoint: Option<String>,
+
+    /// Minimum commitment a slot must reach before its updates are indexed
+    ///
+    /// Updates from slots below this level are staged in the database and
+    /// discarded if their fork dies.  This requires the Geyser plugin to
+    /// publish slot status updates; if none arrive for a minute, updates are
+    /// indexed without staging until they resume.
+    #[arg(long, env, default_value_t = Commitment::Processed)]
+    commitment: Commitment,
+
     #[command(flatten)]
     search: search_dispatch::Args,
+
+    #[command(flatten)]
+    batch: batch::Args,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -36,17 +68,28 @@ enum DialectEvent {
     },
 }
 
+#[derive(Debug, serde::Deserialize)]
+struct RpcResponse<T> {
+    result: Option<T>,
+    error: Option<serde_json::Value>,
+}
+
 // RpcClient doesn't implement Debug for some reason
 #[allow(missing_debug_implementations)]
 /// Wrapper for handling networking logic
 pub struct Client {
     db: Pool,
     http: reqwest::Client,
-    http_prod: HttpProducers,
+    http_prod: Option<HttpProducers>,
     search: search_dispatch::Client,
-    fungible_prod: fungible_indexer::Producer,
+    fungible_prod: Option<fungible_indexer::Producer>,
     dialect_api_endpoint: Option<String>,
     dialect_api_key: Option<String>,
+    solana_endpoint: Option<String>,
+    commitment: Commitment,
+    slot_statuses: SlotStatusWatch,
+    batches: Option<Batches>,
+    block_times: Mutex<BTreeMap<u64, NaiveDateTime>>,
 }
 
 impl Client {
@@ -62,36 +105,95 @@ impl Client {
         store_cfg_queue: http_indexer::QueueType<http_indexer::StoreConfig>,
         search_queue: search_indexer::QueueType,
         fungible_queue: fungible_indexer::QueueType,
+        args: Args,
+    ) -> Result<Arc<Self>> {
+        let http_prod = HttpProducers {
+            metadata_json: http_indexer::Producer::new(conn, meta_queue)
+                .await
+                .context("Couldn't create AMQP metadata JSON producer")?,
+            store_config: http_indexer::Producer::new(conn, store_cfg_queue)
+                .await
+                .context("Couldn't create AMQP store config producer")?,
+        };
+        let search = search_dispatch::Client::new(conn, search_queue, args.search.clone()).await?;
+        let fungible_prod = fungible_indexer::Producer::new(conn, fungible_queue)
+            .await
+            .context("Couldn't create AMQP fungible producer")?;
+
+        Self::new(db, Some(http_prod), search, Some(fungible_prod), args).map(Self::into_rc)
+    }
+
+    /// Construct a new client which does not dispatch any AMQP messages,
+    /// wrapped in an `Arc`.
+    ///
+    /// Off-chain metadata, search documents and fungible token updates are
+    /// discarded, so this is only suitable for replaying messages locally.
+    ///
+    /// # Errors
+    /// This function fails if the HTTP client cannot be created.
+    pub fn new_offline(db: Pool, args: Args) -> Result<Arc<Self>> {
+        warn!("No AMQP connection given, dispatches to other indexers will be skipped");
+
+        let search = search_dispatch::Client::offline(args.search.clone());
+
+        Self::new(db, None, search, None, args).map(Self::into_rc)
+    }
+
+    fn new(
+        db: Pool,
+        http_prod: Option<HttpProducers>,
+        search: search_dispatch::Client,
+        fungible_prod: Option<fungible_indexer::Producer>,
         Args {
             dialect_api_endpoint,
             dialect_api_key,
-            search,
+            solana_endpoint,
+            commitment,
+            search: _,
+            batch,
         }: Args,
-    ) -> Result<Arc<Self>> {
+    ) -> Result<Self> {
         if dialect_api_endpoint.is_none() {
             warn!("Disabling Dialect integration");
         } else {
             debug!("Dialect integration enabled");
         }
 
-        Ok(Arc::new(Self {
+        if solana_endpoint.is_none() {
+            warn!(
+                "No Solana RPC endpoint provided, events can only be timestamped from block times \
+                 already stored for their slot"
+            );
+        }
+
+        if commitment != Commitment::Processed {
+            info!("Staging updates until slots are {}", commitment);
+        }
+
+        Ok(Self {
             db,
             http: reqwest::Client::new(Duration::from_millis(500))?,
-            http_prod: HttpProducers {
-                metadata_json: http_indexer::Producer::new(conn, meta_queue)
-                    .await
-                    .context("Couldn't create AMQP metadata JSON producer")?,
-                store_config: http_indexer::Producer::new(conn, store_cfg_queue)
-                    .await
-                    .context("Couldn't create AMQP store config producer")?,
-            },
-            search: search_dispatch::Client::new(conn, search_queue, search).await?,
-            fungible_prod: fungible_indexer::Producer::new(conn, fungible_queue)
-                .await
-                .context("Couldn't create AMQP fungible producer")?,
+            http_prod,
+            search,
+            fungible_prod,
             dialect_api_endpoint,
             dialect_api_key,
-        }))
+            solana_endpoint,
+            commitment,
+            slot_statuses: SlotStatusWatch::new(),
+            batches: Batches::new(batch),
+            block_times: Mutex::new(BTreeMap::new()),
+        })
+    }
+
+    fn into_rc(self) -> Arc<Self> {
+        let rc = Arc::new(self);
+
+        if let Some(ref batches) = rc.batches {
+            batches.spawn_flush(Arc::downgrade(&rc));
+        }
+
+        rc
     }
 
     /// Get a reference to the database
@@ -100,6 +202,238 @@ impl Client {
         &self.db
     }
 
+    /// Upsert a row, or buffer it to be upserted later along with other rows
+    /// of its table if batching is enabled
+    ///
+    /// Buffered rows delay the acknowledgement of the AMQP message being
+    /// processed until they are written.
+    ///
+    /// # Errors
+    /// This function fails if the upsert fails, or if the row fills its
+    /// buffer and the buffer cannot be flushed.
+    pub(crate) async fn upsert<R: batch::Row>(&self, row: R) -> Result<()> {
+        match self.batches {
+            Some(ref b) => {
+                let written = b.push(&self.db, row).await?;
+
+                crate::defer_ack(async move {
+                    written
+                        .await
+                        .map_err(|_| anyhow!("Batched {} row was dropped unwritten", R::TABLE))
+                });
+
+                Ok(())
+            },
+            None => {
+                self.db
+                    .run(move |db| R::upsert(db, std::slice::from_ref(&row)))
+                    .await
+            },
+        }
+    }
+
+    /// Write any rows buffered for batching.  This should be called before
+    /// the client is dropped, or buffered rows may be lost.
+    ///
+    /// # Errors
+    /// This function fails if a buffered table cannot be written.
+    pub async fn flush_writes(&self) -> Result<()> {
+        match self.batches {
+            Some(ref b) => b.flush_all(&self.db).await,
+            None => Ok(()),
+        }
+    }
+
+    /// Get the commitment level slots must reach before they are indexed
+    #[must_use]
+    pub fn commitment(&self) -> Commitment {
+        self.commitment
+    }
+
+    /// Get the tracker for whether slot status updates are being received
+    pub(super) fn slot_statuses(&self) -> &SlotStatusWatch {
+        &self.slot_statuses
+    }
+
+    /// Remember the production time of the block in the given slot, so events
+    /// in it can be timestamped without a database or RPC lookup
+    ///
+    /// # Errors
+    /// This function fails if the cache lock is poisoned.
+    pub fn cache_block_time(&self, slot: u64, time: NaiveDateTime) -> Result<()> {
+        let mut times = self
+            .block_times
+            .lock()
+            .map_err(|_| anyhow!("Block time cache lock was poisoned"))?;
+
+        times.insert(slot, time);
+
+        while times.len() > BLOCK_TIME_CACHE_SIZE {
+            let oldest = match times.keys().next() {
+                Some(&s) => s,
+                None => break,
+            };
+
+            times.remove(&oldest);
+        }
+
+        Ok(())
+    }
+
+    /// Get the production time of the block in the given slot, if it was
+    /// seen recently
+    ///
+    /// # Errors
+    /// This function fails if the cache lock is poisoned.
+    pub fn cached_block_time(&self, slot: u64) -> Result<Option<NaiveDateTime>> {
+        let times = self
+            .block_times
+            .lock()
+            .map_err(|_| anyhow!("Block time cache lock was poisoned"))?;
+
+        Ok(times.get(&slot).copied())
+    }
+
+    /// Returns true if a Solana RPC endpoint was given
+    #[must_use]
+    pub fn has_solana_endpoint(&self) -> bool {
+        self.solana_endpoint.is_some()
+    }
+
+    /// Fetch the production time of the block in the given slot from the
+    /// Solana RPC.  Returns `None` if no endpoint is configured or the node
+    /// does not (yet) know the block time.
+    ///
+    /// # Errors
+    /// This function fails if the RPC call fails or returns a malformed
+    /// response.
+    pub async fn fetch_block_time(&self, slot: u64) -> Result<Option<NaiveDateTime>> {
+        let endpoint = if let Some(ref e) = self.solana_endpoint {
+            e
+        } else {
+            return Ok(None);
+        };
+
+        let req = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "getBlockTime",
+            "params": [slot],
+        });
+
+        let res: RpcResponse<i64> = self
+            .http
+            .run(|h| async move { h.post(endpoint).json(&req).send().await?.json().await })
+            .await
+            .context("getBlockTime RPC call failed")?;
+
+        if let Some(err) = res.error {
+            // Slots which were skipped or not yet confirmed have no block time
+            trace!("No block time available for slot {}: {}", slot, err);
+        }
+
+        let time = res.result.map(unix_timestamp).transpose()?;
+
+        if let Some(time) = time {
+            self.cache_block_time(slot, time)?;
+        }
+
+        Ok(time)
+    }
+
+    /// Fetch the fields of an SPL token account or mint from the Solana RPC,
+    /// as parsed by the node.  Returns `None` if no endpoint is configured, or
+    /// if the account does not exist or is not owned by a token program.
+    ///
+    /// # Errors
+    /// This function fails if the RPC call fails or the parsed fields do not
+    /// match the requested type.
+    pub async fn fetch_token_info<T: serde::de::DeserializeOwned>(
+        &self,
+        key: Pubkey,
+    ) -> Result<Option<T>> {
+        let endpoint = if let Some(ref e) = self.solana_endpoint {
+            e
+        } else {
+            return Ok(None);
+        };
+
+        let req = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "getAccountInfo",
+            "params": [key.to_string(), { "encoding": "jsonParsed" }],
+        });
+
+        let res: RpcResponse<serde_json::Value> = self
+            .http
+            .run(|h| async move { h.post(endpoint).json(&req).send().await?.json().await })
+            .await
+            .context("getAccountInfo RPC call failed")?;
+
+        if let Some(err) = res.error {
+            bail!("getAccountInfo for {} returned an error: {}", key, err);
+        }
+
+        res.result
+            .as_ref()
+            .and_then(|r| r.pointer("/value/data/parsed/info"))
+            .map(|i| serde_json::from_value(i.clone()))
+            .transpose()
+            .with_context(|| format!("Failed to parse token info for {key}"))
+    }
+
+    /// Get the time at which the given slot was produced, for timestamping
+    /// events that occurred in it.
+    ///
+    /// Recently seen block times are checked first, then the `slots` table,
+    /// then the Solana RPC.
+    ///
+    /// # Errors
+    /// This function fails if the database query fails, or with a retryable
+    /// [`NotReady`] error if the slot's block time is not known yet.
+    pub async fn slot_time(&self, slot: u64) -> Result<NaiveDateTime> {
+        if let Some(time) = self.cached_block_time(slot)? {
+            return Ok(time);
+        }
+
+        let slot_i64 = i64::try_from(slot)?;
+
+        let row = self
+            .db
+            .run(move |db| {
+                slots::table
+                    .filter(slots::slot.eq(slot_i64))
+                    .select((slots::status, slots::block_time))
+                    .first::<(SlotStatusEnum, Option<NaiveDateTime>)>(db)
+                    .optional()
+            })
+            .await
+            .context("Failed to load slot block time")?;
+
+        match row {
+            Some((_, Some(time))) => {
+                self.cache_block_time(slot, time)?;
+
+                return Ok(time);
+            },
+            // The block time is stored once the slot is confirmed, and the
+            // RPC node has none to give before then
+            Some((SlotStatusEnum::Processed, None)) => {
+                return Err(NotReady(format!("Slot {slot} is not confirmed yet")).into());
+            },
+            Some(_) | None => (),
+        }
+
+        match self.fetch_block_time(slot).await {
+            Ok(Some(time)) => Ok(time),
+            Ok(None) => {
+                Err(NotReady(format!("Block time for slot {slot} is not known yet")).into())
+            },
+            Err(e) => Err(e.context(format!("Failed to fetch block time for slot {slot}"))),
+        }
+    }
+
     /// Get a reference to the search index dispatcher
     #[must_use]
     pub fn search(&self) -> &search_dispatch::Client {
@@ -118,15 +452,18 @@ impl Client {
         uri: String,
         slot_info: (u64, u64),
     ) -> Result<(), indexer_rabbitmq::Error> {
-        self.http_prod
-            .metadata_json
-            .write(http_indexer::MetadataJson {
-                meta_address,
-                uri,
-                first_verified_creator,
-                slot_info,
-            })
-            .await
+        let prod = match self.http_prod {
+            Some(ref p) => &p.metadata_json,
+            None => return Ok(()),
+        };
+
+        prod.write(http_indexer::MetadataJson {
+            meta_address,
+            uri,
+            first_verified_creator,
+            slot_info,
+        })
+        .await
     }
 
     /// Dispatch an AMQP message to the HTTP indexer to request off-chain store
@@ -139,13 +476,16 @@ impl Client {
         config_address: Pubkey,
         uri: String,
     ) -> Result<(), indexer_rabbitmq::Error> {
-        self.http_prod
-            .store_config
-            .write(http_indexer::StoreConfig {
-                config_address,
-                uri,
-            })
-            .await
+        let prod = match self.http_prod {
+            Some(ref p) => &p.store_config,
+            None => return Ok(()),
+        };
+
+        prod.write(http_indexer::StoreConfig {
+            config_address,
+            uri,
+        })
+        .await
     }
 
     /// Dispatch an AMQP message to the Fungible Token indexer with a token account update
@@ -160,14 +500,18 @@ impl Client {
         amount: u64,
         // slot: u64,
     ) -> Result<(), indexer_rabbitmq::Error> {
-        self.fungible_prod
-            .write(fungible_indexer::Message::FungibleTokenAccountUpdate {
-                owner,
-                mint: token_mint,
-                address: token_account,
-                amount,
-            })
-            .await
+        let prod = match self.fungible_prod {
+            Some(ref p) => p,
+            None => return Ok(()),
+        };
+
+        prod.write(fungible_indexer::Message::FungibleTokenAccountUpdate {
+            owner,
+            mint: token_mint,
+            address: token_account,
+            amount,
+        })
+        .await
     }
 
     /// Dispatch an AMQP message to the Fungible Token indexer about a mint account update
@@ -182,14 +526,18 @@ impl Client {
         supply: u64,
         // slot: u64,
     ) -> Result<(), indexer_rabbitmq::Error> {
-        self.fungible_prod
-            .write(fungible_indexer::Message::FungibleMintAccountUpdate {
-                mint: token_mint,
-                authority: mint_authority,
-                decimals,
-                supply,
-            })
-            .await
+        let prod = match self.fungible_prod {
+            Some(ref p) => p,
+            None => return Ok(()),
+        };
+
+        prod.write(fungible_indexer::Message::FungibleMintAccountUpdate {
+            mint: token_mint,
+            authority: mint_authority,
+            decimals,
+            supply,
+        })
+        .await
     }
 
     /// Dispatch an AMQP message to the Fungible Token indexer about a metadata account update
@@ -204,15 +552,19 @@ impl Client {
         symbol: String,
         uri: String,
     ) -> Result<(), indexer_rabbitmq::Error> {
-        self.fungible_prod
-            .write(fungible_indexer::Message::FungibleMetadataUpdate {
-                address,
-                mint,
-                name,
-                symbol,
-                uri,
-            })
-            .await
+        let prod = match self.fungible_prod {
+            Some(ref p) => p,
+            None => return Ok(()),
+        };
+
+        prod.write(fungible_indexer::Message::FungibleMetadataUpdate {
+            address,
+            mint,
+            name,
+            symbol,
+            uri,
+        })
+        .await
     }
 
     /// Dispatch a POST request to Dialect
@@ -0,0 +1,381 @@
+//! Staging of messages from slots which have not yet reached the configured
+//! commitment level
+
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    time::Instant,
+};
+
+use indexer_core::db::{
+    custom_types::SlotStatusEnum,
+    delete, insert_into,
+    models::PendingMessage,
+    tables::{pending_messages, slots},
+};
+use indexer_rabbitmq::geyser::Message;
+
+use super::Client;
+use crate::prelude::*;
+
+/// How long to go without a slot status update before assuming they are not
+/// being delivered and indexing messages without staging them
+const SLOT_STATUS_TIMEOUT: StdDuration = StdDuration::from_secs(60);
+
+/// Minimum commitment level a slot must reach before messages produced in it
+/// are indexed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, strum::EnumString, strum::Display)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Commitment {
+    /// Index messages as soon as they arrive
+    Processed,
+    /// Wait for a slot to be confirmed by a supermajority of the cluster
+    Confirmed,
+    /// Wait for a slot to be rooted
+    Rooted,
+}
+
+impl From<Commitment> for SlotStatusEnum {
+    fn from(commitment: Commitment) -> Self {
+        match commitment {
+            Commitment::Processed => Self::Processed,
+            Commitment::Confirmed => Self::Confirmed,
+            Commitment::Rooted => Self::Rooted,
+        }
+    }
+}
+
+/// Tracks when the last slot status update was received.  Staged messages are
+/// only flushed by slot status updates, so if the Geyser plugin does not
+/// publish them staging is suspended rather than letting messages accumulate
+/// forever.
+#[derive(Debug)]
+pub(super) struct SlotStatusWatch {
+    last_seen: Mutex<Instant>,
+    stalled: AtomicBool,
+}
+
+impl SlotStatusWatch {
+    pub(super) fn new() -> Self {
+        Self {
+            last_seen: Mutex::new(Instant::now()),
+            stalled: AtomicBool::new(false),
+        }
+    }
+
+    /// Record that a slot status update was received
+    pub(super) fn received(&self) -> Result<()> {
+        *self
+            .last_seen
+            .lock()
+            .map_err(|_| anyhow!("Slot status watch lock was poisoned"))? = Instant::now();
+
+        if self.stalled.swap(false, Ordering::Relaxed) {
+            info!("Slot status updates resumed, staging messages again");
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if a slot status update was received recently enough that
+    /// messages can be staged
+    pub(super) fn is_live(&self) -> Result<bool> {
+        self.is_live_at(Instant::now())
+    }
+
+    fn is_live_at(&self, now: Instant) -> Result<bool> {
+        let last_seen = *self
+            .last_seen
+            .lock()
+            .map_err(|_| anyhow!("Slot status watch lock was poisoned"))?;
+
+        let live = now.saturating_duration_since(last_seen) < SLOT_STATUS_TIMEOUT;
+
+        if !live && !self.stalled.swap(true, Ordering::Relaxed) {
+            warn!(
+                "No slot status updates received in {:?}, indexing messages without waiting for \
+                 their slots to be committed.  Check that the Geyser plugin publishes slot \
+                 statuses.",
+                SLOT_STATUS_TIMEOUT
+            );
+        }
+
+        Ok(live)
+    }
+}
+
+/// Returns true if a slot with the given status satisfies the given
+/// commitment level
+pub(super) fn satisfies(commitment: Commitment, status: SlotStatusEnum) -> bool {
+    status >= commitment.into()
+}
+
+/// Returns true if the given slot has reached the client's commitment level
+pub(super) async fn is_committed(client: &Client, slot: u64) -> Result<bool> {
+    let slot = i64::try_from(slot)?;
+
+    let status = client
+        .db()
+        .run(move |db| {
+            slots::table
+                .filter(slots::slot.eq(slot))
+                .select(slots::status)
+                .first::<SlotStatusEnum>(db)
+                .optional()
+        })
+        .await
+        .context("Failed to load slot status")?;
+
+    Ok(status.map_or(false, |s| satisfies(client.commitment(), s)))
+}
+
+/// Get the slot a message must be staged under, or `None` if it can be
+/// indexed immediately
+pub(super) async fn pending_slot(client: &Client, msg: &Message) -> Result<Option<u64>> {
+    if client.commitment() == Commitment::Processed {
+        return Ok(None);
+    }
+
+    let slot = match msg {
+        // Startup updates are read from a rooted snapshot
+        Message::AccountUpdate(u) if u.is_startup => return Ok(None),
+        Message::AccountUpdate(u) => u.slot,
+        Message::InstructionNotify(i) => i.slot,
+        Message::SlotStatusUpdate(_) => return Ok(None),
+    };
+
+    if !client.slot_statuses().is_live()? {
+        return Ok(None);
+    }
+
+    if is_committed(client, slot).await? {
+        Ok(None)
+    } else {
+        Ok(Some(slot))
+    }
+}
+
+/// Hold a message until its slot reaches the client's commitment level
+pub(super) async fn stage(client: &Client, slot: u64, msg: &Message) -> Result<()> {
+    let message = serde_json::to_value(msg).context("Failed to serialize staged message")?;
+    let slot = i64::try_from(slot)?;
+
+    stage_raw(client, slot, vec![message]).await
+}
+
+async fn stage_raw(client: &Client, slot: i64, messages: Vec<serde_json::Value>) -> Result<()> {
+    let rows: Vec<_> = messages
+        .into_iter()
+        .map(|m| PendingMessage {
+            slot,
+            message: Owned(m),
+        })
+        .collect();
+
+    client
+        .db()
+        .run(move |db| {
+            insert_into(pending_messages::table)
+                .values(&rows)
+                .execute(db)
+        })
+        .await
+        .context("Failed to stage messages")?;
+
+    Ok(())
+}
+
+/// Remove and return all messages staged for the given slot, in the order
+/// they were received
+pub(super) async fn take(client: &Client, slot: u64) -> Result<Vec<serde_json::Value>> {
+    let slot = i64::try_from(slot)?;
+
+    let mut rows = client
+        .db()
+        .run(move |db| {
+            delete(pending_messages::table.filter(pending_messages::slot.eq(slot)))
+                .returning((pending_messages::id, pending_messages::message))
+                .get_results::<(i64, serde_json::Value)>(db)
+        })
+        .await
+        .context("Failed to claim staged messages")?;
+
+    rows.sort_unstable_by_key(|(id, _)| *id);
+
+    Ok(rows.into_iter().map(|(_, m)| m).collect())
+}
+
+/// Put back messages which failed to process so they are retried the next
+/// time their slot is flushed
+pub(super) async fn restage(
+    client: &Client,
+    slot: u64,
+    messages: Vec<serde_json::Value>,
+) -> Result<()> {
+    if messages.is_empty() {
+        return Ok(());
+    }
+
+    stage_raw(client, slot.try_into()?, messages).await
+}
+
+/// Resolve all staged slots below a newly-rooted slot.  Slots on the rooted
+/// fork are returned so their messages can be flushed, and messages from any
+/// other (dead) fork are discarded.
+pub(super) async fn prune(client: &Client, root: u64) -> Result<Vec<u64>> {
+    let root = i64::try_from(root)?;
+
+    let (live, dead) = client
+        .db()
+        .run(move |db| {
+            let pending: Vec<i64> = pending_messages::table
+                .filter(pending_messages::slot.lt(root))
+                .select(pending_messages::slot)
+                .distinct()
+                .load(db)
+                .context("Failed to load staged slots")?;
+
+            let lowest = match pending.iter().min() {
+                Some(s) => *s,
+                None => return Result::<_>::Ok((vec![], vec![])),
+            };
+
+            let known: HashMap<i64, (Option<i64>, SlotStatusEnum)> = slots::table
+                .filter(slots::slot.between(lowest, root))
+                .select((slots::slot, slots::parent_slot, slots::status))
+                .load::<(i64, Option<i64>, SlotStatusEnum)>(db)
+                .context("Failed to load slot ancestry")?
+                .into_iter()
+                .map(|(s, p, t)| (s, (p, t)))
+                .collect();
+
+            let (live, dead) = resolve_forks(pending, &known, root);
+
+            if !dead.is_empty() {
+                delete(pending_messages::table.filter(pending_messages::slot.eq(any(&dead))))
+                    .execute(db)
+                    .context("Failed to discard messages from dead forks")?;
+            }
+
+            Ok((live, dead))
+        })
+        .await?;
+
+    if !dead.is_empty() {
+        debug!("Discarded staged messages from dead slots {:?}", dead);
+    }
+
+    Ok(live
+        .into_iter()
+        .map(u64::try_from)
+        .collect::<Result<_, _>>()?)
+}
+
+/// Split staged slots into those on the fork of the given root, and those on
+/// dead forks, using the parent and status of each known slot
+fn resolve_forks(
+    pending: Vec<i64>,
+    known: &HashMap<i64, (Option<i64>, SlotStatusEnum)>,
+    root: i64,
+) -> (Vec<i64>, Vec<i64>) {
+    let lowest = match pending.iter().min() {
+        Some(s) => *s,
+        None => return (vec![], vec![]),
+    };
+
+    let mut ancestors = HashSet::new();
+    let mut curr = Some(root);
+
+    while let Some(slot) = curr.filter(|s| *s >= lowest) {
+        ancestors.insert(slot);
+        curr = known.get(&slot).and_then(|(p, _)| *p).filter(|p| *p < slot);
+    }
+
+    // Rooted slots are canonical even if a gap in the parent chain hides them
+    // from the walk above
+    pending.into_iter().partition(|s| {
+        ancestors.contains(s)
+            || known
+                .get(s)
+                .map_or(false, |(_, t)| *t == SlotStatusEnum::Rooted)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_satisfies() {
+        use SlotStatusEnum::{Confirmed, Processed, Rooted};
+
+        assert!(satisfies(Commitment::Processed, Processed));
+        assert!(satisfies(Commitment::Processed, Rooted));
+
+        assert!(!satisfies(Commitment::Confirmed, Processed));
+        assert!(satisfies(Commitment::Confirmed, Confirmed));
+        assert!(satisfies(Commitment::Confirmed, Rooted));
+
+        assert!(!satisfies(Commitment::Rooted, Processed));
+        assert!(!satisfies(Commitment::Rooted, Confirmed));
+        assert!(satisfies(Commitment::Rooted, Rooted));
+    }
+
+    #[test]
+    fn test_resolve_forks() {
+        use SlotStatusEnum::{Confirmed, Processed, Rooted};
+
+        // 10 <- 11 <- 13 <- 14 is the rooted fork, 12 forked off 11
+        let known = [
+            (10, (Some(9), Rooted)),
+            (11, (Some(10), Confirmed)),
+            (12, (Some(11), Processed)),
+            (13, (Some(11), Confirmed)),
+            (14, (Some(13), Rooted)),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            resolve_forks(vec![10, 11, 12, 13], &known, 14),
+            (vec![10, 11, 13], vec![12])
+        );
+        assert_eq!(resolve_forks(vec![], &known, 14), (vec![], vec![]));
+    }
+
+    #[test]
+    fn test_resolve_forks_gap() {
+        use SlotStatusEnum::{Processed, Rooted};
+
+        // The parent of 14 was never seen, so the walk stops short of 10
+        let known = [
+            (10, (Some(9), Rooted)),
+            (11, (Some(10), Processed)),
+            (14, (Some(13), Rooted)),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            resolve_forks(vec![10, 11, 14], &known, 14),
+            (vec![10, 14], vec![11])
+        );
+    }
+
+    #[test]
+    fn test_slot_status_watch() {
+        let watch = SlotStatusWatch::new();
+        let start = *watch.last_seen.lock().unwrap();
+
+        assert!(watch.is_live_at(start).unwrap());
+        assert!(!watch.is_live_at(start + SLOT_STATUS_TIMEOUT).unwrap());
+        assert!(watch.stalled.load(Ordering::Relaxed));
+
+        watch.received().unwrap();
+        assert!(!watch.stalled.load(Ordering::Relaxed));
+        assert!(watch.is_live().unwrap());
+    }
+}
@@ -0,0 +1,590 @@
+//! Consumption of Geyser updates directly from a Yellowstone-compatible gRPC
+//! stream, as an alternative to the AMQP queue published by the Geyser plugin
+
+mod proto;
+
+use std::collections::BTreeMap;
+
+use futures_util::{
+    stream::{self, FuturesUnordered},
+    StreamExt,
+};
+use indexer_core::clap;
+use indexer_rabbitmq::geyser::{Message, SlotStatus};
+use tonic::{
+    metadata::{Ascii, MetadataValue},
+    transport::{ClientTlsConfig, Endpoint},
+    Request, Streaming,
+};
+
+use self::proto::{
+    geyser::{geyser_client::GeyserClient, SubscribeRequest, SubscribeUpdate},
+    Update,
+};
+use super::{message_slot, process_message, slots, Client, MessageId, Registry};
+use crate::prelude::*;
+
+/// Delay before the first reconnection attempt
+const MIN_BACKOFF: StdDuration = StdDuration::from_secs(1);
+/// Longest delay between reconnection attempts
+const MAX_BACKOFF: StdDuration = StdDuration::from_secs(30);
+/// Interval of HTTP/2 pings used to detect dead connections
+const KEEPALIVE: StdDuration = StdDuration::from_secs(30);
+
+/// Arguments for consuming updates from a gRPC Geyser server
+#[derive(Debug, clap::Args)]
+#[group(skip)]
+pub struct Args {
+    /// Endpoint of a Yellowstone gRPC Geyser server to consume updates from
+    /// instead of an AMQP queue
+    #[arg(long, env)]
+    grpc_url: Option<String>,
+
+    /// Access token for the gRPC server, sent in the `x-token` header
+    #[arg(long, env, requires("grpc_url"))]
+    grpc_x_token: Option<String>,
+
+    /// The slot to start streaming from, if the server still retains it
+    ///
+    /// When the stream reconnects it resumes from the last slot status
+    /// received instead.
+    #[arg(long, env, requires("grpc_url"))]
+    grpc_from_slot: Option<u64>,
+}
+
+impl Args {
+    /// Returns true if a gRPC endpoint was given
+    #[must_use]
+    pub fn enabled(&self) -> bool {
+        self.grpc_url.is_some()
+    }
+}
+
+/// Messages waiting for the block time of their slot
+///
+/// The stream sends the block metadata of a slot after its transactions, so
+/// events in the slot can't be timestamped until it arrives.
+#[derive(Debug, Default)]
+struct Held(BTreeMap<u64, Vec<Message>>);
+
+impl Held {
+    /// Return the messages which can be processed now, holding the rest until
+    /// the block time of their slot is known
+    ///
+    /// A rooted slot also releases everything held for it and earlier slots,
+    /// as block metadata still missing by then is never going to arrive.
+    fn sort(
+        &mut self,
+        msgs: Vec<Message>,
+        mut has_block_time: impl FnMut(u64) -> Result<bool>,
+    ) -> Result<Vec<Message>> {
+        let mut ready = vec![];
+
+        for msg in msgs {
+            match msg {
+                Message::SlotStatusUpdate(ref s) => {
+                    if matches!(s.status, SlotStatus::Rooted) {
+                        let later = self.0.split_off(&(s.slot + 1));
+
+                        for (slot, msgs) in std::mem::replace(&mut self.0, later) {
+                            warn!(
+                                "No block time received for slot {}, processing its {} held \
+                                 message(s) anyway",
+                                slot,
+                                msgs.len()
+                            );
+
+                            ready.extend(msgs);
+                        }
+                    }
+
+                    ready.push(msg);
+                },
+                msg => {
+                    let slot = message_slot(&msg);
+
+                    if has_block_time(slot)? {
+                        ready.push(msg);
+                    } else {
+                        self.0.entry(slot).or_default().push(msg);
+                    }
+                },
+            }
+        }
+
+        Ok(ready)
+    }
+
+    /// Release the messages held for a slot whose block time has arrived
+    fn release(&mut self, slot: u64) -> Vec<Message> {
+        self.0.remove(&slot).unwrap_or_default()
+    }
+
+    /// Get the earliest slot with held messages
+    fn oldest(&self) -> Option<u64> {
+        self.0.keys().next().copied()
+    }
+}
+
+/// A gRPC server endpoint and the filters to subscribe to it with
+struct Subscriber {
+    endpoint: Endpoint,
+    x_token: Option<MetadataValue<Ascii>>,
+    request: SubscribeRequest,
+}
+
+impl Subscriber {
+    fn new(url: String, x_token: Option<String>, request: SubscribeRequest) -> Result<Self> {
+        let tls = url.starts_with("https://");
+        let mut endpoint = Endpoint::from_shared(url)
+            .context("Invalid gRPC endpoint")?
+            .http2_keep_alive_interval(KEEPALIVE)
+            .keep_alive_while_idle(true);
+
+        if tls {
+            endpoint = endpoint
+                .tls_config(ClientTlsConfig::new())
+                .context("Failed to configure gRPC TLS")?;
+        }
+
+        let x_token = x_token
+            .map(|t| t.parse())
+            .transpose()
+            .context("Invalid gRPC access token")?;
+
+        Ok(Self {
+            endpoint,
+            x_token,
+            request,
+        })
+    }
+
+    async fn connect(&self) -> Result<Streaming<SubscribeUpdate>> {
+        let channel = self.endpoint.connect().await.with_context(|| {
+            format!(
+                "Failed to connect to gRPC server at {}",
+                self.endpoint.uri()
+            )
+        })?;
+
+        // The subscription is closed once the request stream ends, so keep
+        // it open after sending the filters
+        let mut req = Request::new(stream::iter([self.request.clone()]).chain(stream::pending()));
+
+        if let Some(ref token) = self.x_token {
+            req.metadata_mut().insert("x-token", token.clone());
+        }
+
+        let res = GeyserClient::new(channel)
+            .subscribe(req)
+            .await
+            .context("gRPC server rejected the subscription")?;
+
+        Ok(res.into_inner())
+    }
+
+    /// Subscribe and index updates until the stream ends, then wait for any
+    /// messages still being processed
+    async fn run(
+        &mut self,
+        client: &Client,
+        registry: &Registry,
+        concurrency: usize,
+        backoff: &mut StdDuration,
+    ) -> Result<()> {
+        fn log(res: MessageResult<MessageId>) {
+            if let Err(e) = res {
+                warn!("{}", e);
+            }
+        }
+
+        let mut stream = self.connect().await?;
+        let mut pending = FuturesUnordered::new();
+        let mut held = Held::default();
+
+        info!("Subscribed to gRPC server at {}", self.endpoint.uri());
+
+        let res = loop {
+            let update = tokio::select! {
+                Some(r) = pending.next(), if !pending.is_empty() => {
+                    log(r);
+                    continue;
+                },
+                u = stream.next() => u,
+            };
+
+            let update = match update {
+                Some(Ok(u)) => u,
+                None => break Ok(()),
+                Some(Err(s)) => {
+                    break Err(anyhow!(
+                        "gRPC server returned status {:?}: {}",
+                        s.code(),
+                        s.message()
+                    ));
+                },
+            };
+
+            *backoff = MIN_BACKOFF;
+
+            let msgs = match proto::convert_update(update) {
+                Ok(Update::Messages(m)) => {
+                    match held.sort(m, |s| Ok(client.cached_block_time(s)?.is_some())) {
+                        Ok(m) => m,
+                        Err(e) => break Err(e),
+                    }
+                },
+                Ok(Update::BlockTime { slot, parent, time }) => {
+                    if let Err(e) = slots::process_block_time(client, slot, parent, time).await {
+                        warn!("Failed to record block time for slot {}: {:?}", slot, e);
+                    }
+
+                    held.release(slot)
+                },
+                Err(e) => {
+                    warn!("Skipping malformed gRPC update: {:?}", e);
+                    continue;
+                },
+            };
+
+            for msg in msgs {
+                let slot = match msg {
+                    Message::SlotStatusUpdate(ref s) => s.slot,
+                    msg => {
+                        if pending.len() >= concurrency.max(1) {
+                            if let Some(r) = pending.next().await {
+                                log(r);
+                            }
+                        }
+
+                        pending.push(process_message(msg, client, registry));
+                        continue;
+                    },
+                };
+
+                // Updates for a slot must be staged before its status is
+                // processed
+                while let Some(r) = pending.next().await {
+                    log(r);
+                }
+
+                log(process_message(msg, client, registry).await);
+
+                // Resume from the earliest slot with unprocessed messages
+                self.request.from_slot = Some(held.oldest().map_or(slot, |h| h.min(slot)));
+            }
+        };
+
+        while let Some(r) = pending.next().await {
+            log(r);
+        }
+
+        res
+    }
+}
+
+/// Subscribe to a Yellowstone-compatible gRPC Geyser server for the accounts
+/// and instructions of every program enabled in the registry, and index them
+/// until interrupted
+///
+/// Dropped connections are retried with exponential backoff, resuming from
+/// the last slot status received.  Messages which fail to process are logged
+/// and skipped.
+///
+/// # Errors
+/// This function fails if no gRPC endpoint was given or the stop signal
+/// cannot be received.
+pub async fn consume(
+    client: &Client,
+    registry: &Registry,
+    args: Args,
+    concurrency: usize,
+) -> Result<()> {
+    let Args {
+        grpc_url,
+        grpc_x_token,
+        grpc_from_slot,
+    } = args;
+
+    let url = grpc_url.context("No gRPC endpoint was given")?;
+
+    let mut subscriber = Subscriber::new(
+        url,
+        grpc_x_token,
+        proto::subscribe_request(
+            &registry.account_owners(),
+            &registry.instruction_programs(),
+            grpc_from_slot,
+        ),
+    )?;
+
+    let mut backoff = MIN_BACKOFF;
+
+    loop {
+        let res = tokio::select! {
+            r = subscriber.run(client, registry, concurrency, &mut backoff) => r,
+            r = tokio::signal::ctrl_c() => {
+                r.context("Failed to wait for stop signal")?;
+                break;
+            },
+        };
+
+        match res {
+            Ok(()) => warn!("gRPC server ended the stream"),
+            Err(e) => warn!("gRPC stream failed: {:?}", e),
+        }
+
+        info!("Reconnecting in {:?}...", backoff);
+
+        tokio::select! {
+            () = tokio::time::sleep(backoff) => (),
+            r = tokio::signal::ctrl_c() => {
+                r.context("Failed to wait for stop signal")?;
+                break;
+            },
+        }
+
+        backoff = (backoff * 2).min(MAX_BACKOFF);
+    }
+
+    client.flush_writes().await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, pin::Pin, sync::Mutex};
+
+    use indexer_rabbitmq::geyser::SlotStatusUpdate;
+    use tokio::{net::TcpListener, sync::oneshot};
+    use tokio_stream::wrappers::TcpListenerStream;
+    use tonic::{transport::Server, Response, Status};
+
+    use super::{
+        proto::{
+            geyser::{
+                geyser_server::{Geyser, GeyserServer},
+                GetBlockHeightRequest, GetBlockHeightResponse, GetLatestBlockhashRequest,
+                GetLatestBlockhashResponse, GetSlotRequest, GetSlotResponse, GetVersionRequest,
+                GetVersionResponse, IsBlockhashValidRequest, IsBlockhashValidResponse, PingRequest,
+                PongResponse, SlotStatus as GrpcSlotStatus, SubscribeReplayInfoRequest,
+                SubscribeReplayInfoResponse,
+            },
+            tests as updates,
+        },
+        *,
+    };
+
+    type Updates = Vec<Result<SubscribeUpdate, Status>>;
+    type Received = (Option<String>, SubscribeRequest);
+
+    /// Serves a single subscription, streaming the given updates and then
+    /// ending the stream
+    struct MockGeyser(Mutex<Option<(oneshot::Sender<Received>, Updates)>>);
+
+    #[tonic::async_trait]
+    impl Geyser for MockGeyser {
+        type SubscribeStream =
+            Pin<Box<dyn futures_util::Stream<Item = Result<SubscribeUpdate, Status>> + Send>>;
+
+        async fn subscribe(
+            &self,
+            req: Request<Streaming<SubscribeRequest>>,
+        ) -> Result<Response<Self::SubscribeStream>, Status> {
+            let (tx, updates) = self
+                .0
+                .lock()
+                .unwrap()
+                .take()
+                .expect("Only one subscription was expected");
+
+            let x_token = req
+                .metadata()
+                .get("x-token")
+                .map(|t| t.to_str().unwrap().to_owned());
+            let filters = req.into_inner().message().await?.unwrap();
+            tx.send((x_token, filters)).unwrap();
+
+            Ok(Response::new(Box::pin(stream::iter(updates))))
+        }
+
+        async fn subscribe_replay_info(
+            &self,
+            _: Request<SubscribeReplayInfoRequest>,
+        ) -> Result<Response<SubscribeReplayInfoResponse>, Status> {
+            Err(Status::unimplemented("mock"))
+        }
+
+        async fn ping(&self, _: Request<PingRequest>) -> Result<Response<PongResponse>, Status> {
+            Err(Status::unimplemented("mock"))
+        }
+
+        async fn get_latest_blockhash(
+            &self,
+            _: Request<GetLatestBlockhashRequest>,
+        ) -> Result<Response<GetLatestBlockhashResponse>, Status> {
+            Err(Status::unimplemented("mock"))
+        }
+
+        async fn get_block_height(
+            &self,
+            _: Request<GetBlockHeightRequest>,
+        ) -> Result<Response<GetBlockHeightResponse>, Status> {
+            Err(Status::unimplemented("mock"))
+        }
+
+        async fn get_slot(
+            &self,
+            _: Request<GetSlotRequest>,
+        ) -> Result<Response<GetSlotResponse>, Status> {
+            Err(Status::unimplemented("mock"))
+        }
+
+        async fn is_blockhash_valid(
+            &self,
+            _: Request<IsBlockhashValidRequest>,
+        ) -> Result<Response<IsBlockhashValidResponse>, Status> {
+            Err(Status::unimplemented("mock"))
+        }
+
+        async fn get_version(
+            &self,
+            _: Request<GetVersionRequest>,
+        ) -> Result<Response<GetVersionResponse>, Status> {
+            Err(Status::unimplemented("mock"))
+        }
+    }
+
+    /// Start a mock Geyser server streaming the given updates
+    ///
+    /// Returns the URL of the server and the token and filters it receives.
+    async fn mock_server(updates: Updates) -> (String, oneshot::Receiver<Received>) {
+        let (tx, rx) = oneshot::channel();
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(
+            Server::builder()
+                .add_service(GeyserServer::new(MockGeyser(Mutex::new(Some((
+                    tx, updates,
+                ))))))
+                .serve_with_incoming(TcpListenerStream::new(listener)),
+        );
+
+        (url, rx)
+    }
+
+    #[tokio::test]
+    async fn test_subscribe() {
+        let (key, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (url, rx) = mock_server(vec![
+            Ok(updates::account_update(key, owner, &[1, 2, 3], 5)),
+            Ok(updates::slot_update(5, GrpcSlotStatus::SlotConfirmed)),
+        ])
+        .await;
+
+        let subscriber = Subscriber::new(
+            url,
+            Some("secret".into()),
+            proto::subscribe_request(&[owner], &[], Some(3)),
+        )
+        .unwrap();
+        let mut stream = subscriber.connect().await.unwrap();
+
+        let (x_token, filters) = rx.await.unwrap();
+        assert_eq!(x_token.as_deref(), Some("secret"));
+        assert_eq!(filters, subscriber.request);
+
+        let mut msgs = vec![];
+        while let Some(update) = stream.next().await {
+            match proto::convert_update(update.unwrap()).unwrap() {
+                Update::Messages(m) => msgs.extend(m),
+                u => panic!("Unexpected update {u:?}"),
+            }
+        }
+
+        match msgs.as_slice() {
+            [
+                Message::AccountUpdate(u),
+                Message::SlotStatusUpdate(SlotStatusUpdate {
+                    slot: 5,
+                    status: SlotStatus::Confirmed,
+                    ..
+                }),
+            ] => {
+                assert_eq!(u.key, key);
+                assert_eq!(u.owner, owner);
+                assert_eq!(u.data, [1, 2, 3]);
+            },
+            m => panic!("Unexpected messages {m:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hold_until_block_meta() {
+        let program = Pubkey::new_unique();
+        let (url, _rx) = mock_server(vec![
+            Ok(updates::transaction_update(program, &[1], 7)),
+            Ok(updates::slot_update(7, GrpcSlotStatus::SlotProcessed)),
+            Ok(updates::block_meta_update(7, 1_600_000_000)),
+            Ok(updates::transaction_update(program, &[2], 8)),
+            Ok(updates::slot_update(8, GrpcSlotStatus::SlotFinalized)),
+        ])
+        .await;
+
+        let subscriber =
+            Subscriber::new(url, None, proto::subscribe_request(&[], &[program], None)).unwrap();
+        let mut stream = subscriber.connect().await.unwrap();
+
+        let mut held = Held::default();
+        let mut block_times = HashSet::new();
+        let mut msgs = vec![];
+
+        while let Some(update) = stream.next().await {
+            msgs.extend(match proto::convert_update(update.unwrap()).unwrap() {
+                Update::Messages(m) => held.sort(m, |s| Ok(block_times.contains(&s))).unwrap(),
+                Update::BlockTime { slot, .. } => {
+                    block_times.insert(slot);
+                    held.release(slot)
+                },
+            });
+        }
+
+        // The transaction waits for its block meta, and the one whose block
+        // meta never arrives is released when its slot is rooted
+        match msgs.as_slice() {
+            [
+                Message::SlotStatusUpdate(SlotStatusUpdate { slot: 7, .. }),
+                Message::InstructionNotify(a),
+                Message::InstructionNotify(b),
+                Message::SlotStatusUpdate(SlotStatusUpdate {
+                    slot: 8,
+                    status: SlotStatus::Rooted,
+                    ..
+                }),
+            ] => {
+                assert_eq!((a.slot, a.data.as_slice()), (7, &[1][..]));
+                assert_eq!((b.slot, b.data.as_slice()), (8, &[2][..]));
+            },
+            m => panic!("Unexpected messages {m:?}"),
+        }
+
+        assert_eq!(held.oldest(), None);
+    }
+
+    #[tokio::test]
+    async fn test_error_status() {
+        let (url, _rx) = mock_server(vec![
+            Ok(updates::slot_update(5, GrpcSlotStatus::SlotProcessed)),
+            Err(Status::unavailable("shutting down")),
+        ])
+        .await;
+
+        let subscriber =
+            Subscriber::new(url, None, proto::subscribe_request(&[], &[], None)).unwrap();
+        let mut stream = subscriber.connect().await.unwrap();
+
+        assert!(stream.next().await.unwrap().is_ok());
+        let status = stream.next().await.unwrap().unwrap_err();
+        assert_eq!(status.code(), tonic::Code::Unavailable);
+    }
+}
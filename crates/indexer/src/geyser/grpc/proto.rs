@@ -0,0 +1,469 @@
+//! Conversion between the Yellowstone `geyser.proto` messages and the
+//! messages the Geyser plugin publishes to AMQP.
+//!
+//! The protobuf types and the gRPC client are generated from `proto/` by the
+//! build script.
+
+use std::collections::HashMap;
+
+use indexer_core::util;
+use indexer_rabbitmq::geyser::{InstructionNotify, Message, SlotStatus, SlotStatusUpdate};
+
+use self::{
+    geyser::{
+        subscribe_update::UpdateOneof, SubscribeRequest, SubscribeRequestFilterAccounts,
+        SubscribeRequestFilterBlocksMeta, SubscribeRequestFilterSlots,
+        SubscribeRequestFilterTransactions, SubscribeUpdate, SubscribeUpdateAccount,
+        SubscribeUpdateSlot, SubscribeUpdateTransaction,
+    },
+    solana::storage::confirmed_block::{CompiledInstruction, InnerInstruction},
+};
+use super::super::AccountUpdate;
+use crate::prelude::*;
+
+#[allow(
+    dead_code,
+    missing_copy_implementations,
+    missing_debug_implementations,
+    missing_docs,
+    clippy::pedantic,
+    clippy::style
+)]
+pub(super) mod geyser {
+    tonic::include_proto!("geyser");
+}
+
+#[allow(
+    dead_code,
+    missing_copy_implementations,
+    missing_debug_implementations,
+    missing_docs,
+    clippy::pedantic,
+    clippy::style
+)]
+pub(super) mod solana {
+    pub mod storage {
+        pub mod confirmed_block {
+            tonic::include_proto!("solana.storage.confirmed_block");
+        }
+    }
+}
+
+/// Name of the filters the indexer subscribes with
+const FILTER: &str = "holaplex-indexer";
+
+fn filter<T>(filter: T) -> HashMap<String, T> {
+    [(FILTER.to_owned(), filter)].into_iter().collect()
+}
+
+/// Build the request subscribing to the accounts owned by, and the
+/// transactions of, the given programs, along with slot statuses and block
+/// metadata
+pub(super) fn subscribe_request(
+    account_owners: &[Pubkey],
+    transaction_programs: &[Pubkey],
+    from_slot: Option<u64>,
+) -> SubscribeRequest {
+    SubscribeRequest {
+        // An empty filter matches everything, so leave it out instead
+        accounts: if account_owners.is_empty() {
+            HashMap::new()
+        } else {
+            filter(SubscribeRequestFilterAccounts {
+                owner: account_owners.iter().map(ToString::to_string).collect(),
+                ..SubscribeRequestFilterAccounts::default()
+            })
+        },
+        slots: filter(SubscribeRequestFilterSlots::default()),
+        transactions: if transaction_programs.is_empty() {
+            HashMap::new()
+        } else {
+            filter(SubscribeRequestFilterTransactions {
+                vote: Some(false),
+                failed: Some(false),
+                account_include: transaction_programs
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect(),
+                ..SubscribeRequestFilterTransactions::default()
+            })
+        },
+        blocks_meta: filter(SubscribeRequestFilterBlocksMeta::default()),
+        // Commitment is handled by the indexer from the slot updates
+        commitment: Some(geyser::CommitmentLevel::Processed.into()),
+        from_slot,
+        ..SubscribeRequest::default()
+    }
+}
+
+/// An update received from the gRPC stream
+#[derive(Debug)]
+pub(super) enum Update {
+    /// Messages the Geyser plugin would have published for the update
+    Messages(Vec<Message>),
+    /// The production time of the block in a slot
+    BlockTime {
+        slot: u64,
+        parent: u64,
+        time: NaiveDateTime,
+    },
+}
+
+/// Convert a `SubscribeUpdate` into the messages the Geyser plugin would have
+/// published for it, or the block time it reports
+pub(super) fn convert_update(update: SubscribeUpdate) -> Result<Update> {
+    Ok(match update.update_oneof {
+        Some(UpdateOneof::Account(a)) => {
+            Update::Messages(vec![Message::AccountUpdate(convert_account(a)?)])
+        },
+        Some(UpdateOneof::Slot(s)) => Update::Messages(convert_slot(&s).into_iter().collect()),
+        Some(UpdateOneof::Transaction(t)) => Update::Messages(convert_transaction(t)?),
+        Some(UpdateOneof::BlockMeta(m)) => match m.block_time {
+            Some(t) => Update::BlockTime {
+                slot: m.slot,
+                parent: m.parent_slot,
+                time: util::unix_timestamp(t.timestamp)?,
+            },
+            None => Update::Messages(vec![]),
+        },
+        // Pings and updates the indexer did not subscribe to
+        _ => Update::Messages(vec![]),
+    })
+}
+
+fn pubkey(bytes: &[u8]) -> Result<Pubkey> {
+    Ok(Pubkey::new_from_array(bytes.try_into().map_err(|_| {
+        anyhow!("Invalid pubkey length {}", bytes.len())
+    })?))
+}
+
+fn convert_account(update: SubscribeUpdateAccount) -> Result<AccountUpdate> {
+    let SubscribeUpdateAccount {
+        account,
+        slot,
+        is_startup,
+    } = update;
+    let account = account.context("Account update was missing its account")?;
+
+    Ok(AccountUpdate {
+        key: pubkey(&account.pubkey)?,
+        lamports: account.lamports,
+        owner: pubkey(&account.owner)?,
+        executable: account.executable,
+        rent_epoch: account.rent_epoch,
+        data: account.data,
+        write_version: account.write_version,
+        slot,
+        is_startup,
+    })
+}
+
+fn convert_slot(update: &SubscribeUpdateSlot) -> Option<Message> {
+    let status = match geyser::SlotStatus::from_i32(update.status)? {
+        geyser::SlotStatus::SlotProcessed => SlotStatus::Processed,
+        geyser::SlotStatus::SlotConfirmed => SlotStatus::Confirmed,
+        geyser::SlotStatus::SlotFinalized => SlotStatus::Rooted,
+        // Intermediate statuses reported by newer servers
+        _ => return None,
+    };
+
+    Some(Message::SlotStatusUpdate(SlotStatusUpdate {
+        slot: update.slot,
+        parent: update.parent,
+        status,
+    }))
+}
+
+/// A compiled instruction, referencing its program and accounts by their
+/// index in the transaction's account keys
+trait Compiled {
+    fn program(&self) -> u32;
+    fn accounts(&self) -> &[u8];
+    fn data(&self) -> &[u8];
+}
+
+impl Compiled for CompiledInstruction {
+    fn program(&self) -> u32 {
+        self.program_id_index
+    }
+
+    fn accounts(&self) -> &[u8] {
+        &self.accounts
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl Compiled for InnerInstruction {
+    fn program(&self) -> u32 {
+        self.program_id_index
+    }
+
+    fn accounts(&self) -> &[u8] {
+        &self.accounts
+    }
+
+    fn data(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+fn convert_transaction(update: SubscribeUpdateTransaction) -> Result<Vec<Message>> {
+    let SubscribeUpdateTransaction { transaction, slot } = update;
+    let info = transaction.context("Transaction update was missing its transaction")?;
+    let message = info
+        .transaction
+        .and_then(|t| t.message)
+        .context("Transaction update was missing its message")?;
+    let meta = info.meta.unwrap_or_default();
+
+    // Accounts loaded from lookup tables follow the static keys
+    let keys = message
+        .account_keys
+        .iter()
+        .chain(&meta.loaded_writable_addresses)
+        .chain(&meta.loaded_readonly_addresses)
+        .map(|k| pubkey(k))
+        .collect::<Result<Vec<_>>>()?;
+
+    let key = |i: u32| -> Result<Pubkey> {
+        usize::try_from(i)
+            .ok()
+            .and_then(|i| keys.get(i).copied())
+            .ok_or_else(|| anyhow!("Account index {} out of range", i))
+    };
+
+    let notify = |ins: &dyn Compiled| -> Result<Message> {
+        Ok(Message::InstructionNotify(InstructionNotify {
+            program: key(ins.program())?,
+            data: ins.data().to_vec(),
+            accounts: ins
+                .accounts()
+                .iter()
+                .map(|&i| key(i.into()))
+                .collect::<Result<_>>()?,
+            slot,
+        }))
+    };
+
+    let mut messages = vec![];
+
+    for (i, ins) in message.instructions.iter().enumerate() {
+        messages.push(notify(ins)?);
+
+        for inner in meta
+            .inner_instructions
+            .iter()
+            .filter(|inner| usize::try_from(inner.index) == Ok(i))
+        {
+            for ins in &inner.instructions {
+                messages.push(notify(ins)?);
+            }
+        }
+    }
+
+    Ok(messages)
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::{
+        geyser::{
+            SubscribeUpdateAccountInfo, SubscribeUpdateBlockMeta, SubscribeUpdateTransactionInfo,
+        },
+        solana::storage::confirmed_block::{
+            InnerInstructions, Message as TxMessage, Transaction, TransactionStatusMeta,
+            UnixTimestamp,
+        },
+        *,
+    };
+
+    fn update(update: UpdateOneof) -> SubscribeUpdate {
+        SubscribeUpdate {
+            filters: vec![FILTER.into()],
+            update_oneof: Some(update),
+            created_at: None,
+        }
+    }
+
+    /// Build a `SubscribeUpdate` for an account
+    pub fn account_update(key: Pubkey, owner: Pubkey, data: &[u8], slot: u64) -> SubscribeUpdate {
+        update(UpdateOneof::Account(SubscribeUpdateAccount {
+            account: Some(SubscribeUpdateAccountInfo {
+                pubkey: key.to_bytes().into(),
+                lamports: 1_000,
+                owner: owner.to_bytes().into(),
+                data: data.into(),
+                write_version: 42,
+                ..SubscribeUpdateAccountInfo::default()
+            }),
+            slot,
+            is_startup: false,
+        }))
+    }
+
+    /// Build a `SubscribeUpdate` for a slot status
+    pub fn slot_update(slot: u64, status: geyser::SlotStatus) -> SubscribeUpdate {
+        update(UpdateOneof::Slot(SubscribeUpdateSlot {
+            slot,
+            parent: Some(slot - 1),
+            status: status.into(),
+            dead_error: None,
+        }))
+    }
+
+    /// Build a `SubscribeUpdate` for a transaction with a single instruction
+    /// of the given program
+    pub fn transaction_update(program: Pubkey, data: &[u8], slot: u64) -> SubscribeUpdate {
+        update(UpdateOneof::Transaction(SubscribeUpdateTransaction {
+            transaction: Some(SubscribeUpdateTransactionInfo {
+                transaction: Some(Transaction {
+                    signatures: vec![],
+                    message: Some(TxMessage {
+                        account_keys: vec![program.to_bytes().into()],
+                        instructions: vec![CompiledInstruction {
+                            program_id_index: 0,
+                            accounts: vec![],
+                            data: data.into(),
+                        }],
+                        ..TxMessage::default()
+                    }),
+                }),
+                ..SubscribeUpdateTransactionInfo::default()
+            }),
+            slot,
+        }))
+    }
+
+    /// Build a `SubscribeUpdate` for the block metadata of a slot
+    pub fn block_meta_update(slot: u64, timestamp: i64) -> SubscribeUpdate {
+        update(UpdateOneof::BlockMeta(SubscribeUpdateBlockMeta {
+            slot,
+            parent_slot: slot - 1,
+            block_time: Some(UnixTimestamp { timestamp }),
+            ..SubscribeUpdateBlockMeta::default()
+        }))
+    }
+
+    fn messages(update: SubscribeUpdate) -> Vec<Message> {
+        match convert_update(update).unwrap() {
+            Update::Messages(m) => m,
+            u => panic!("Unexpected update {u:?}"),
+        }
+    }
+
+    #[test]
+    fn test_convert_account() {
+        let (key, owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        match messages(account_update(key, owner, &[1, 2, 3], 7)).as_slice() {
+            [Message::AccountUpdate(u)] => {
+                assert_eq!(u.key, key);
+                assert_eq!(u.owner, owner);
+                assert_eq!(u.lamports, 1_000);
+                assert_eq!(u.data, [1, 2, 3]);
+                assert_eq!(u.write_version, 42);
+                assert_eq!(u.slot, 7);
+                assert!(!u.is_startup);
+            },
+            m => panic!("Unexpected messages {m:?}"),
+        }
+    }
+
+    #[test]
+    fn test_convert_slot() {
+        match messages(slot_update(10, geyser::SlotStatus::SlotFinalized)).as_slice() {
+            [
+                Message::SlotStatusUpdate(SlotStatusUpdate {
+                    slot: 10,
+                    parent: Some(9),
+                    status: SlotStatus::Rooted,
+                }),
+            ] => (),
+            m => panic!("Unexpected messages {m:?}"),
+        }
+
+        // Statuses the indexer does not track are dropped
+        assert!(messages(slot_update(10, geyser::SlotStatus::SlotCompleted)).is_empty());
+    }
+
+    #[test]
+    fn test_convert_block_meta() {
+        match convert_update(block_meta_update(12, 1_600_000_000)).unwrap() {
+            Update::BlockTime { slot, parent, time } => {
+                assert_eq!(slot, 12);
+                assert_eq!(parent, 11);
+                assert_eq!(time, util::unix_timestamp(1_600_000_000).unwrap());
+            },
+            u => panic!("Unexpected update {u:?}"),
+        }
+    }
+
+    #[test]
+    fn test_convert_transaction() {
+        let keys: Vec<_> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let loaded = Pubkey::new_unique();
+
+        let tx = update(UpdateOneof::Transaction(SubscribeUpdateTransaction {
+            transaction: Some(SubscribeUpdateTransactionInfo {
+                transaction: Some(Transaction {
+                    signatures: vec![],
+                    message: Some(TxMessage {
+                        account_keys: keys.iter().map(|k| k.to_bytes().into()).collect(),
+                        instructions: vec![CompiledInstruction {
+                            program_id_index: 3,
+                            accounts: vec![0, 4],
+                            data: vec![9],
+                        }],
+                        ..TxMessage::default()
+                    }),
+                }),
+                meta: Some(TransactionStatusMeta {
+                    inner_instructions: vec![InnerInstructions {
+                        index: 0,
+                        instructions: vec![InnerInstruction {
+                            program_id_index: 2,
+                            accounts: vec![1],
+                            data: vec![8],
+                            stack_height: None,
+                        }],
+                    }],
+                    loaded_writable_addresses: vec![loaded.to_bytes().into()],
+                    ..TransactionStatusMeta::default()
+                }),
+                ..SubscribeUpdateTransactionInfo::default()
+            }),
+            slot: 99,
+        }));
+
+        match messages(tx).as_slice() {
+            [
+                Message::InstructionNotify(outer),
+                Message::InstructionNotify(inner),
+            ] => {
+                assert_eq!(outer.program, keys[3]);
+                assert_eq!(outer.accounts, [keys[0], loaded]);
+                assert_eq!(outer.data, [9]);
+                assert_eq!(outer.slot, 99);
+                assert_eq!(inner.program, keys[2]);
+                assert_eq!(inner.accounts, [keys[1]]);
+                assert_eq!(inner.data, [8]);
+            },
+            m => panic!("Unexpected messages {m:?}"),
+        }
+    }
+
+    #[test]
+    fn test_subscribe_request() {
+        let program = Pubkey::new_unique();
+        let req = subscribe_request(&[program], &[], Some(5));
+
+        assert_eq!(req.accounts[FILTER].owner, [program.to_string()]);
+        assert!(req.slots.contains_key(FILTER));
+        assert!(req.blocks_meta.contains_key(FILTER));
+        assert!(req.transactions.is_empty());
+        assert_eq!(req.from_slot, Some(5));
+    }
+}
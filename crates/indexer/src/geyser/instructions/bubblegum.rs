@@ -0,0 +1,446 @@
+use indexer_core::{
+    db::{
+        custom_types::TokenStandardEnum,
+        delete, insert_into,
+        models::{
+            CompressedNftCreator, CompressedNftLeaf, CompressedNftMetadata, CurrentMetadataOwner,
+            Metadata, MetadataCollectionKey, MetadataCreator,
+        },
+        select,
+        tables::{
+            compressed_nft_creators, compressed_nft_leaves, compressed_nft_metadatas,
+            current_metadata_owners, metadata_collection_keys, metadata_creators, metadatas,
+        },
+        update,
+        versioned::IfNewerDsl,
+        Connection,
+    },
+    prelude::*,
+    pubkeys::{find_compressed_asset_id, find_edition},
+};
+
+use super::Client;
+use crate::prelude::*;
+
+/// Metadata decoded from a Bubblegum instruction, keyed by its merkle tree and
+/// leaf data hash
+#[derive(Debug, Clone)]
+pub(crate) struct CompressedMetadata {
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+    pub merkle_tree: Pubkey,
+    pub tree_delegate: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub seller_fee_basis_points: u16,
+    pub primary_sale_happened: bool,
+    pub is_mutable: bool,
+    pub edition_nonce: Option<u8>,
+    pub token_standard: Option<TokenStandardEnum>,
+    /// The collection key and whether it is verified
+    pub collection: Option<(Pubkey, bool)>,
+    /// The address, verified flag and share of each creator
+    pub creators: Vec<(Pubkey, bool, u8)>,
+}
+
+/// The contents of a leaf in a Bubblegum merkle tree
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Leaf {
+    pub merkle_tree: Pubkey,
+    pub leaf_index: u64,
+    pub asset_id: Pubkey,
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub nonce: u64,
+    pub data_hash: [u8; 32],
+    pub creator_hash: [u8; 32],
+}
+
+fn hash_str(hash: [u8; 32]) -> String {
+    bs58::encode(hash).into_string()
+}
+
+fn leaf_row(
+    leaf: Leaf,
+    burned: bool,
+    redeemed: bool,
+    slot: u64,
+) -> Result<CompressedNftLeaf<'static>> {
+    Ok(CompressedNftLeaf {
+        merkle_tree: Owned(leaf.merkle_tree.to_string()),
+        leaf_index: leaf.leaf_index.try_into()?,
+        asset_id: Owned(leaf.asset_id.to_string()),
+        owner: Owned(leaf.owner.to_string()),
+        delegate: Owned(leaf.delegate.to_string()),
+        nonce: leaf.nonce.try_into()?,
+        data_hash: Owned(hash_str(leaf.data_hash)),
+        creator_hash: Owned(hash_str(leaf.creator_hash)),
+        burned,
+        redeemed,
+        slot: slot.try_into()?,
+    })
+}
+
+/// Copy the current state of a compressed asset into the `metadatas` family of
+/// tables so it can be queried like any other NFT
+///
+/// Returns the URI and first verified creator of the asset if it was indexed
+/// for the first time, or `None` if it was already known or its metadata has
+/// not been indexed yet.
+fn index_asset(db: &Connection, asset_id: &str) -> Result<Option<(String, Option<String>)>> {
+    let leaf: CompressedNftLeaf = compressed_nft_leaves::table
+        .filter(compressed_nft_leaves::asset_id.eq(asset_id))
+        .first(db)
+        .context("Failed to load compressed NFT leaf")?;
+
+    if leaf.burned {
+        return Ok(None);
+    }
+
+    let meta: Option<CompressedNftMetadata> = compressed_nft_metadatas::table
+        .filter(compressed_nft_metadatas::merkle_tree.eq(&leaf.merkle_tree))
+        .filter(compressed_nft_metadatas::data_hash.eq(&leaf.data_hash))
+        .first(db)
+        .optional()
+        .context("Failed to load compressed NFT metadata")?;
+
+    let meta = match meta {
+        Some(m) => m,
+        None => return Ok(None),
+    };
+
+    let creators: Vec<CompressedNftCreator> = compressed_nft_creators::table
+        .filter(compressed_nft_creators::merkle_tree.eq(&leaf.merkle_tree))
+        .filter(compressed_nft_creators::data_hash.eq(&leaf.data_hash))
+        .order(compressed_nft_creators::position.asc())
+        .load(db)
+        .context("Failed to load compressed NFT creators")?;
+
+    let asset: Pubkey = asset_id.parse()?;
+    let (edition_pda, _bump) = find_edition(asset);
+
+    let row = Metadata {
+        address: Owned(asset_id.to_owned()),
+        name: meta.name,
+        symbol: meta.symbol,
+        uri: meta.uri.clone(),
+        seller_fee_basis_points: meta.seller_fee_basis_points,
+        update_authority_address: meta.tree_delegate,
+        mint_address: Owned(asset_id.to_owned()),
+        primary_sale_happened: meta.primary_sale_happened,
+        is_mutable: meta.is_mutable,
+        edition_nonce: meta.edition_nonce,
+        edition_pda: Owned(edition_pda.to_string()),
+        token_standard: meta.token_standard,
+        slot: Some(leaf.slot),
+        burned_at: None,
+    };
+
+    db.build_transaction().read_write().run(|| {
+        let metadata_exists = select(exists(
+            metadatas::table.filter(metadatas::address.eq(asset_id)),
+        ))
+        .get_result::<bool>(db)?;
+
+        insert_into(metadatas::table)
+            .values(&row)
+            .on_conflict(metadatas::address)
+            .do_update()
+            .set(&row)
+            .execute(db)
+            .context("Failed to insert compressed NFT metadata")?;
+
+        let owner = CurrentMetadataOwner {
+            mint_address: Owned(asset_id.to_owned()),
+            owner_address: leaf.owner.clone(),
+            // Compressed assets have no token account, so the tree stands in
+            token_account_address: leaf.merkle_tree.clone(),
+            slot: leaf.slot,
+            write_version: -1,
+        };
+
+        insert_into(current_metadata_owners::table)
+            .values(&owner)
+            .on_conflict(current_metadata_owners::mint_address)
+            .do_update()
+            .set(&owner)
+            .if_newer()
+            .execute(db)
+            .context("Failed to insert compressed NFT owner")?;
+
+        delete(metadata_creators::table.filter(metadata_creators::metadata_address.eq(asset_id)))
+            .execute(db)
+            .context("Failed to clear compressed NFT creators")?;
+
+        for creator in &creators {
+            insert_into(metadata_creators::table)
+                .values(&MetadataCreator {
+                    metadata_address: Owned(asset_id.to_owned()),
+                    creator_address: creator.creator_address.clone(),
+                    share: creator.share,
+                    verified: creator.verified,
+                    position: Some(creator.position),
+                })
+                .execute(db)
+                .context("Failed to insert compressed NFT creator")?;
+        }
+
+        if let Some(collection_address) = meta.collection_address {
+            let key = MetadataCollectionKey {
+                metadata_address: Owned(asset_id.to_owned()),
+                collection_address,
+                verified: meta.collection_verified,
+            };
+
+            insert_into(metadata_collection_keys::table)
+                .values(&key)
+                .on_conflict((
+                    metadata_collection_keys::metadata_address,
+                    metadata_collection_keys::collection_address,
+                ))
+                .do_update()
+                .set(&key)
+                .execute(db)
+                .context("Failed to insert compressed NFT collection key")?;
+        }
+
+        if metadata_exists {
+            return Ok(None);
+        }
+
+        let first_verified_creator = creators
+            .iter()
+            .find(|c| c.verified)
+            .map(|c| c.creator_address.clone().into_owned());
+
+        Result::<_>::Ok(Some((meta.uri.into_owned(), first_verified_creator)))
+    })
+}
+
+async fn index_assets(client: &Client, asset_ids: Vec<String>, slot: u64) -> Result<()> {
+    for asset_id in asset_ids {
+        let indexed = client
+            .db()
+            .run({
+                let asset_id = asset_id.clone();
+                move |db| index_asset(db, &asset_id)
+            })
+            .await
+            .with_context(|| format!("Failed to index compressed asset {asset_id}"))?;
+
+        if let Some((uri, first_verified_creator)) = indexed {
+            client
+                .dispatch_metadata_json(
+                    asset_id.parse()?,
+                    first_verified_creator.map(|c| c.parse()).transpose()?,
+                    uri,
+                    (slot, 0),
+                )
+                .await
+                .context("Failed to dispatch compressed NFT metadata JSON job")?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Upsert the metadata of a compressed NFT, indexing any leaves already known
+/// to hold it
+pub(crate) async fn upsert_metadata(
+    client: &Client,
+    meta: CompressedMetadata,
+    slot: u64,
+) -> Result<()> {
+    let data_hash = hash_str(meta.data_hash);
+    let merkle_tree = meta.merkle_tree.to_string();
+
+    let row = CompressedNftMetadata {
+        data_hash: Owned(data_hash.clone()),
+        creator_hash: Owned(hash_str(meta.creator_hash)),
+        merkle_tree: Owned(merkle_tree.clone()),
+        tree_delegate: Owned(meta.tree_delegate.to_string()),
+        name: Owned(meta.name),
+        symbol: Owned(meta.symbol),
+        uri: Owned(meta.uri),
+        seller_fee_basis_points: meta.seller_fee_basis_points.into(),
+        primary_sale_happened: meta.primary_sale_happened,
+        is_mutable: meta.is_mutable,
+        edition_nonce: meta.edition_nonce.map(Into::into),
+        token_standard: meta.token_standard,
+        collection_address: meta.collection.map(|(k, _)| Owned(k.to_string())),
+        collection_verified: meta.collection.map_or(false, |(_, v)| v),
+        slot: slot.try_into()?,
+    };
+
+    let creators = meta
+        .creators
+        .iter()
+        .enumerate()
+        .map(|(position, &(address, verified, share))| {
+            Ok(CompressedNftCreator {
+                merkle_tree: Owned(merkle_tree.clone()),
+                data_hash: Owned(data_hash.clone()),
+                creator_address: Owned(address.to_string()),
+                share: share.into(),
+                verified,
+                position: position
+                    .try_into()
+                    .context("Position was too big to store")?,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let asset_ids = client
+        .db()
+        .run(move |db| {
+            db.build_transaction().read_write().run(|| {
+                insert_into(compressed_nft_metadatas::table)
+                    .values(&row)
+                    .on_conflict((
+                        compressed_nft_metadatas::merkle_tree,
+                        compressed_nft_metadatas::data_hash,
+                    ))
+                    .do_update()
+                    .set(&row)
+                    .execute(db)
+                    .context("Failed to insert compressed NFT metadata")?;
+
+                for creator in &creators {
+                    insert_into(compressed_nft_creators::table)
+                        .values(creator)
+                        .on_conflict((
+                            compressed_nft_creators::merkle_tree,
+                            compressed_nft_creators::data_hash,
+                            compressed_nft_creators::creator_address,
+                        ))
+                        .do_update()
+                        .set(creator)
+                        .execute(db)
+                        .context("Failed to insert compressed NFT creator")?;
+                }
+
+                compressed_nft_leaves::table
+                    .filter(compressed_nft_leaves::merkle_tree.eq(&merkle_tree))
+                    .filter(compressed_nft_leaves::data_hash.eq(&data_hash))
+                    .filter(not(compressed_nft_leaves::burned))
+                    .select(compressed_nft_leaves::asset_id)
+                    .load::<String>(db)
+                    .context("Failed to load compressed NFT leaves")
+            })
+        })
+        .await?;
+
+    index_assets(client, asset_ids, slot).await
+}
+
+/// Upsert the current contents of a leaf and index its asset
+pub(crate) async fn upsert_leaf(client: &Client, leaf: Leaf, slot: u64) -> Result<()> {
+    let row = leaf_row(leaf, false, false, slot)?;
+
+    client
+        .db()
+        .run(move |db| {
+            insert_into(compressed_nft_leaves::table)
+                .values(&row)
+                .on_conflict((
+                    compressed_nft_leaves::merkle_tree,
+                    compressed_nft_leaves::leaf_index,
+                ))
+                .do_update()
+                .set(&row)
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert compressed NFT leaf")?;
+
+    index_assets(client, vec![leaf.asset_id.to_string()], slot).await
+}
+
+/// Mark a leaf as burned, or as redeemed if it is being decompressed
+pub(crate) async fn burn_leaf(
+    client: &Client,
+    leaf: Leaf,
+    redeemed: bool,
+    slot: u64,
+) -> Result<()> {
+    let row = leaf_row(leaf, true, redeemed, slot)?;
+    let asset_id = leaf.asset_id.to_string();
+    let burned_at = client.slot_time(slot).await?;
+    let slot = i64::try_from(slot)?;
+
+    client
+        .db()
+        .run(move |db| {
+            insert_into(compressed_nft_leaves::table)
+                .values(&row)
+                .on_conflict((
+                    compressed_nft_leaves::merkle_tree,
+                    compressed_nft_leaves::leaf_index,
+                ))
+                .do_update()
+                .set(&row)
+                .execute(db)
+                .context("Failed to update compressed NFT leaf")?;
+
+            // A redeemed asset is re-indexed under its decompressed metadata
+            // account, so the compressed copy is retired either way
+            update(metadatas::table.filter(metadatas::address.eq(asset_id)))
+                .set((
+                    metadatas::burned_at.eq(Some(burned_at)),
+                    metadatas::slot.eq(slot),
+                ))
+                .execute(db)
+                .context("Failed to update compressed NFT metadata")?;
+
+            Result::<_>::Ok(())
+        })
+        .await
+        .context("Failed to burn compressed NFT")?;
+
+    Ok(())
+}
+
+/// Find the merkle tree holding the given asset, either from its existing leaf
+/// or from the trees its metadata was minted into
+pub(crate) async fn find_merkle_tree(
+    client: &Client,
+    asset_id: Pubkey,
+    nonce: u64,
+    data_hash: [u8; 32],
+) -> Result<Option<Pubkey>> {
+    let asset = asset_id.to_string();
+    let data_hash = hash_str(data_hash);
+
+    let trees = client
+        .db()
+        .run(move |db| {
+            let tree = compressed_nft_leaves::table
+                .filter(compressed_nft_leaves::asset_id.eq(asset))
+                .select(compressed_nft_leaves::merkle_tree)
+                .first::<String>(db)
+                .optional()?;
+
+            match tree {
+                Some(t) => Ok(vec![t]),
+                None => compressed_nft_metadatas::table
+                    .filter(compressed_nft_metadatas::data_hash.eq(data_hash))
+                    .select(compressed_nft_metadatas::merkle_tree)
+                    .load::<String>(db),
+            }
+        })
+        .await
+        .context("Failed to look up compressed NFT merkle tree")?;
+
+    // The same metadata may have been minted into several trees, but only one
+    // of them derives the asset ID from this nonce
+    for tree in trees {
+        let tree: Pubkey = tree.parse().context("Invalid merkle tree address")?;
+
+        if find_compressed_asset_id(tree, nonce).0 == asset_id {
+            return Ok(Some(tree));
+        }
+    }
+
+    Ok(None)
+}
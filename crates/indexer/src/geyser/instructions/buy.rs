@@ -1,18 +1,5 @@
 use borsh::BorshDeserialize;
-use indexer_core::{
-    db::{
-        custom_types::OfferEventLifecycleEnum,
-        insert_into,
-        models::{BuyInstruction, FeedEventWallet, Offer, OfferEvent},
-        on_constraint, select,
-        tables::{
-            buy_instructions, current_metadata_owners, feed_event_wallets, feed_events, metadatas,
-            offer_events, offers, purchases,
-        },
-    },
-    pubkeys,
-    uuid::Uuid,
-};
+use indexer_core::db::{insert_into, models::BuyInstruction, tables::buy_instructions};
 use mpl_auction_house::instruction::Buy;
 
 use super::Client;
@@ -49,142 +36,18 @@ pub(crate) async fn process(
         escrow_payment_bump: params.escrow_payment_bump.try_into()?,
         buyer_price: params.buyer_price.try_into()?,
         token_size: params.token_size.try_into()?,
-        created_at: Utc::now().naive_utc(),
+        created_at: client.slot_time(slot).await?,
         slot: slot.try_into()?,
     };
 
-    let values = row.clone();
-
-    let purchase_id = client
-        .db()
-        .run({
-            let row = row.clone();
-            move |db| {
-                purchases::table
-                    .filter(
-                        purchases::buyer
-                            .eq(row.wallet.clone())
-                            .and(purchases::auction_house.eq(row.auction_house))
-                            .and(purchases::metadata.eq(row.metadata))
-                            .and(purchases::price.eq(row.buyer_price))
-                            .and(
-                                purchases::token_size
-                                    .eq(row.token_size)
-                                    .and(purchases::slot.eq(row.slot)),
-                            ),
-                    )
-                    .select(purchases::id)
-                    .first::<Uuid>(db)
-                    .optional()
-                    .context("failed to get purchase ids")
-            }
-        })
-        .await?;
-
-    upsert_into_offers_table(client, Offer {
-        id: None,
-        trade_state: row.buyer_trade_state,
-        auction_house: row.auction_house,
-        marketplace_program: Owned(pubkeys::AUCTION_HOUSE.to_string()),
-        buyer: row.wallet,
-        metadata: row.metadata,
-        token_account: Some(row.token_account),
-        purchase_id,
-        price: row.buyer_price,
-        token_size: row.token_size,
-        trade_state_bump: row.trade_state_bump,
-        created_at: row.created_at,
-        canceled_at: None,
-        slot: row.slot,
-        write_version: None,
-        expiry: None,
-    })
-    .await
-    .context("failed to insert offer")?;
-
     client
         .db()
         .run(move |db| {
             insert_into(buy_instructions::table)
-                .values(&values)
+                .values(&row)
                 .execute(db)
         })
         .await
         .context("failed to insert buy instruction ")?;
     Ok(())
 }
-
-pub async fn upsert_into_offers_table<'a>(client: &Client, data: Offer<'static>) -> Result<()> {
-    client
-        .db()
-        .run(move |db| {
-            let offer_exists = select(exists(
-                offers::table.filter(
-                    offers::trade_state
-                        .eq(data.trade_state.clone())
-                        .and(offers::metadata.eq(data.metadata.clone())),
-                ),
-            ))
-            .get_result::<bool>(db)?;
-
-            let offer_id = insert_into(offers::table)
-                .values(&data)
-                .on_conflict(on_constraint("offers_unique_fields"))
-                .do_update()
-                .set(&data)
-                .returning(offers::id)
-                .get_result::<Uuid>(db)?;
-
-            if offer_exists {
-                return Ok(());
-            }
-
-            db.build_transaction().read_write().run(|| {
-                let metadata_owner: String = current_metadata_owners::table
-                    .inner_join(
-                        metadatas::table
-                            .on(metadatas::mint_address.eq(current_metadata_owners::mint_address)),
-                    )
-                    .filter(metadatas::address.eq(data.metadata.clone()))
-                    .select(current_metadata_owners::owner_address)
-                    .first(db)?;
-
-                let feed_event_id = insert_into(feed_events::table)
-                    .default_values()
-                    .returning(feed_events::id)
-                    .get_result::<Uuid>(db)
-                    .context("Failed to insert feed event")?;
-
-                insert_into(offer_events::table)
-                    .values(&OfferEvent {
-                        feed_event_id,
-                        lifecycle: OfferEventLifecycleEnum::Created,
-                        offer_id,
-                    })
-                    .execute(db)
-                    .context("failed to insert offer created event")?;
-
-                insert_into(feed_event_wallets::table)
-                    .values(&FeedEventWallet {
-                        wallet_address: data.buyer,
-                        feed_event_id,
-                    })
-                    .execute(db)
-                    .context("Failed to insert offer feed event wallet for buyer")?;
-
-                insert_into(feed_event_wallets::table)
-                    .values(&FeedEventWallet {
-                        wallet_address: Owned(metadata_owner),
-                        feed_event_id,
-                    })
-                    .execute(db)
-                    .context("Failed to insert offer feed event wallet for metadata owner")?;
-
-                Result::<_>::Ok(())
-            })
-        })
-        .await
-        .context("Failed to insert offer")?;
-
-    Ok(())
-}
@@ -1,11 +1,5 @@
 use borsh::BorshDeserialize;
-use indexer_core::db::{
-    insert_into,
-    models::CancelInstruction,
-    select,
-    tables::{cancel_instructions, listings, offers},
-    update,
-};
+use indexer_core::db::{insert_into, models::CancelInstruction, tables::cancel_instructions};
 use mpl_auction_house::instruction::Cancel;
 
 use super::Client;
@@ -36,7 +30,7 @@ pub(crate) async fn process(
         trade_state: Owned(accts[6].clone()),
         buyer_price: params.buyer_price.try_into()?,
         token_size: params.token_size.try_into()?,
-        created_at: Utc::now().naive_utc(),
+        created_at: client.slot_time(slot).await?,
         slot: slot.try_into()?,
     };
 
@@ -45,48 +39,7 @@ pub(crate) async fn process(
         .run(move |db| {
             insert_into(cancel_instructions::table)
                 .values(&row)
-                .execute(db)?;
-            db.build_transaction().read_write().run(|| {
-                let listing_trade_state = select(exists(
-                    listings::table.filter(
-                        listings::trade_state
-                            .eq(row.trade_state.clone())
-                            .and(listings::purchase_id.is_null())
-                            .and(listings::canceled_at.is_null()),
-                    ),
-                ))
-                .get_result::<bool>(db);
-
-                if Ok(true) == listing_trade_state {
-                    update(
-                        listings::table.filter(
-                            listings::trade_state
-                                .eq(row.trade_state.clone())
-                                .and(listings::purchase_id.is_null())
-                                .and(listings::canceled_at.is_null()),
-                        ),
-                    )
-                    .set((
-                        listings::canceled_at.eq(Some(row.created_at)),
-                        listings::slot.eq(row.slot),
-                    ))
-                    .execute(db)
-                } else {
-                    update(
-                        offers::table.filter(
-                            offers::trade_state
-                                .eq(row.trade_state.clone())
-                                .and(offers::purchase_id.is_null())
-                                .and(offers::canceled_at.is_null()),
-                        ),
-                    )
-                    .set((
-                        offers::canceled_at.eq(Some(row.created_at)),
-                        offers::slot.eq(row.slot),
-                    ))
-                    .execute(db)
-                }
-            })
+                .execute(db)
         })
         .await
         .context("failed to insert cancel instruction ")?;
@@ -1,7 +1,7 @@
 use indexer_core::db::{
     insert_into,
     models::HplRewardCenterCloseListing,
-    tables::{hpl_reward_center_close_listing_ins, listings, rewards_listings},
+    tables::{hpl_reward_center_close_listing_ins, rewards_listings},
     update,
 };
 use solana_program::pubkey::Pubkey;
@@ -17,8 +17,8 @@ pub(crate) async fn process(
 ) -> Result<()> {
     let accts: Vec<_> = accounts.iter().map(ToString::to_string).collect();
     let listing_address = accts[1].clone();
-    let trade_state = accts[9].clone();
-    let closed_at = Some(Utc::now().naive_utc());
+    let created_at = client.slot_time(slot).await?;
+    let closed_at = Some(created_at);
     let slot: i64 = slot.try_into()?;
 
     client
@@ -43,7 +43,7 @@ pub(crate) async fn process(
                 trade_state: Owned(accts[9].clone()),
                 ah_auctioneer_pda: Owned(accts[10].clone()),
                 token_size: token_size.unwrap_or_default(),
-                created_at: Utc::now().naive_utc(),
+                created_at,
                 slot,
             };
 
@@ -57,23 +57,15 @@ pub(crate) async fn process(
     client
         .db()
         .run(move |db| {
-            db.build_transaction().read_write().run(|| {
-                update(
-                    rewards_listings::table.filter(rewards_listings::address.eq(listing_address)),
-                )
+            update(rewards_listings::table.filter(rewards_listings::address.eq(listing_address)))
                 .set((
                     rewards_listings::closed_at.eq(closed_at),
                     rewards_listings::slot.eq(slot),
                 ))
-                .execute(db)?;
-
-                update(listings::table.filter(listings::trade_state.eq(trade_state)))
-                    .set((listings::canceled_at.eq(closed_at), listings::slot.eq(slot)))
-                    .execute(db)
-            })
+                .execute(db)
         })
         .await
-        .context("failed to update rewards listing closed at or general listing canceled at")?;
+        .context("failed to update rewards listing closed at")?;
 
     Ok(())
 }
@@ -3,7 +3,7 @@ use hpl_reward_center::offers::close::CloseOfferParams;
 use indexer_core::db::{
     insert_into,
     models::HplRewardCenterCloseoffer,
-    tables::{hpl_reward_center_close_offer_ins, offers, rewards_offers},
+    tables::{hpl_reward_center_close_offer_ins, rewards_offers},
     update,
 };
 use solana_program::pubkey::Pubkey;
@@ -22,8 +22,8 @@ pub(crate) async fn process(
 
     let accts: Vec<_> = accounts.iter().map(ToString::to_string).collect();
     let offer_address = accts[1].clone();
-    let trade_state = accts[12].clone();
-    let closed_at = Some(Utc::now().naive_utc());
+    let created_at = client.slot_time(slot).await?;
+    let closed_at = Some(created_at);
     let slot: i64 = slot.try_into()?;
     let escrow_payment_bump = params.escrow_payment_bump.try_into()?;
 
@@ -55,7 +55,7 @@ pub(crate) async fn process(
                     escrow_payment_bump,
                     buyer_price,
                     token_size,
-                    created_at: Utc::now().naive_utc(),
+                    created_at,
                     slot,
                 };
 
@@ -70,21 +70,15 @@ pub(crate) async fn process(
     client
         .db()
         .run(move |db| {
-            db.build_transaction().read_write().run(|| {
-                update(rewards_offers::table.filter(rewards_offers::address.eq(offer_address)))
-                    .set((
-                        rewards_offers::closed_at.eq(closed_at),
-                        rewards_offers::slot.eq(slot),
-                    ))
-                    .execute(db)?;
-
-                update(offers::table.filter(offers::trade_state.eq(trade_state)))
-                    .set((offers::canceled_at.eq(closed_at), offers::slot.eq(slot)))
-                    .execute(db)
-            })
+            update(rewards_offers::table.filter(rewards_offers::address.eq(offer_address)))
+                .set((
+                    rewards_offers::closed_at.eq(closed_at),
+                    rewards_offers::slot.eq(slot),
+                ))
+                .execute(db)
         })
         .await
-        .context("failed to update rewards offer closed at or general offer canceled at")?;
+        .context("failed to update rewards offer closed at")?;
 
     Ok(())
 }
@@ -5,22 +5,21 @@ use indexer_core::{
     db::{
         custom_types::PayoutOperationEnum,
         insert_into,
-        models::{
-            FeedEventWallet, HplRewardCenterExecuteSale, Purchase, PurchaseEvent,
-            RewardCenter as DbRewardCenter, RewardPayout,
-        },
-        on_constraint, select,
+        models::{HplRewardCenterExecuteSale, RewardCenter as DbRewardCenter, RewardPayout},
         tables::{
-            feed_event_wallets, feed_events, hpl_reward_center_execute_sale_ins, listings, offers,
-            purchase_events, purchases, reward_centers, reward_payouts, rewards_listings,
+            hpl_reward_center_execute_sale_ins, reward_centers, reward_payouts, rewards_listings,
         },
-        update,
     },
-    pubkeys,
     uuid::Uuid,
 };
 
-use super::super::Client;
+use super::super::{
+    super::{
+        marketplace::{self, Event, Sale},
+        programs::reward_center::RewardCenter as RewardCenterProgram,
+    },
+    Client,
+};
 use crate::prelude::*;
 
 #[allow(clippy::pedantic)]
@@ -63,7 +62,7 @@ pub(crate) async fn process(
         escrow_payment_bump: params.escrow_payment_bump.try_into()?,
         free_trade_state_bump: params.free_trade_state_bump.try_into()?,
         program_as_signer_bump: params.program_as_signer_bump.try_into()?,
-        created_at: Utc::now().naive_utc(),
+        created_at: client.slot_time(slot).await?,
         slot: slot.try_into()?,
     };
 
@@ -75,7 +74,7 @@ pub(crate) async fn process(
                 rewards_listings::table
                     .select((rewards_listings::token_size, rewards_listings::price))
                     .filter(rewards_listings::address.eq(listing.to_string()))
-                    .first(db)
+                    .first::<(i64, i64)>(db)
                     .optional()
             }
         })
@@ -83,27 +82,30 @@ pub(crate) async fn process(
         .context("failed to load rewards listing!")?;
 
     if let Some((token_size, price)) = listing {
-        upsert_into_purchases_table(
+        let sale = Sale {
+            buyer: accounts[0],
+            seller: accounts[2],
+            auction_house: accounts[15],
+            metadata: accounts[9],
+            buyer_trade_state: accounts[18],
+            seller_trade_state: accounts[19],
+            price: price.try_into()?,
+            token_size: token_size.try_into()?,
+        };
+
+        let purchase = marketplace::process_event::<RewardCenterProgram>(
             client,
-            Purchase {
-                id: None,
-                buyer: row.buyer.clone(),
-                seller: row.seller.clone(),
-                auction_house: row.auction_house.clone(),
-                marketplace_program: Owned(pubkeys::AUCTION_HOUSE.to_string()),
-                metadata: row.metadata.clone(),
-                token_size,
-                price,
-                created_at: row.created_at,
-                slot: row.slot,
-                write_version: None,
-            },
-            accts[13].clone(),
-            accts[14].clone(),
-            row.reward_center.to_string(),
+            Event::Sold(sale.clone()),
+            slot,
         )
         .await
         .context("failed to insert purchase!")?;
+
+        if let Some(purchase) = purchase.filter(|p| p.created) {
+            insert_reward_payout(client, purchase.id, sale.price, &row)
+                .await
+                .context("Failed to insert reward payout")?;
+        }
     }
 
     client
@@ -118,64 +120,22 @@ pub(crate) async fn process(
     Ok(())
 }
 
-#[allow(clippy::too_many_lines)]
-pub(crate) async fn upsert_into_purchases_table<'a>(
+async fn insert_reward_payout(
     client: &Client,
-    data: Purchase<'static>,
-    buyer_trade_state: String,
-    seller_trade_state: String,
-    reward_center_address: String,
+    purchase_id: Uuid,
+    price: u64,
+    sale: &HplRewardCenterExecuteSale<'static>,
 ) -> Result<()> {
+    let reward_center_address = sale.reward_center.to_string();
+    let metadata = sale.metadata.to_string();
+    let buyer = sale.buyer.to_string();
+    let seller = sale.seller.to_string();
+    let created_at = sale.created_at;
+    let slot = sale.slot;
+
     client
         .db()
         .run(move |db| {
-            let purchase_exists = select(exists(
-                purchases::table.filter(
-                    purchases::buyer
-                        .eq(data.buyer.clone())
-                        .and(purchases::seller.eq(data.seller.clone()))
-                        .and(purchases::auction_house.eq(data.auction_house.clone()))
-                        .and(purchases::metadata.eq(data.metadata.clone()))
-                        .and(purchases::price.eq(data.price))
-                        .and(purchases::token_size.eq(data.token_size)),
-                ),
-            ))
-            .get_result::<bool>(db)?;
-
-            let purchase_id = insert_into(purchases::table)
-                .values(&data)
-                .on_conflict(on_constraint("purchases_unique_fields"))
-                .do_update()
-                .set(&data)
-                .returning(purchases::id)
-                .get_result::<Uuid>(db)?;
-
-            update(
-                listings::table.filter(
-                    listings::trade_state
-                        .eq(seller_trade_state.clone())
-                        .and(listings::purchase_id.is_null())
-                        .and(listings::canceled_at.is_null()),
-                ),
-            )
-            .set(listings::purchase_id.eq(Some(purchase_id)))
-            .execute(db)?;
-
-            update(
-                offers::table.filter(
-                    offers::trade_state
-                        .eq(buyer_trade_state.clone())
-                        .and(offers::purchase_id.is_null())
-                        .and(offers::canceled_at.is_null()),
-                ),
-            )
-            .set(offers::purchase_id.eq(Some(purchase_id)))
-            .execute(db)?;
-
-            if purchase_exists {
-                return Ok(());
-            }
-
             let reward_center = reward_centers::table
                 .select(reward_centers::all_columns)
                 .filter(reward_centers::address.eq(reward_center_address.clone()))
@@ -183,18 +143,18 @@ pub(crate) async fn upsert_into_purchases_table<'a>(
                 .optional()?;
 
             if let Some(r) = reward_center {
-                let (buyer_reward, seller_reward) = calculate_payout(data.price.try_into()?, &r)?;
+                let (buyer_reward, seller_reward) = calculate_payout(price, &r)?;
 
                 let reward_payout = RewardPayout {
                     purchase_id,
-                    metadata: Owned(data.metadata.to_string()),
+                    metadata: Owned(metadata),
                     reward_center: Owned(reward_center_address),
-                    buyer: Owned(data.buyer.to_string()),
+                    buyer: Owned(buyer),
                     buyer_reward,
-                    seller: Owned(data.seller.to_string()),
+                    seller: Owned(seller),
                     seller_reward,
-                    created_at: data.created_at,
-                    slot: data.slot,
+                    created_at,
+                    slot,
                     write_version: -1,
                 };
 
@@ -204,44 +164,9 @@ pub(crate) async fn upsert_into_purchases_table<'a>(
                     .execute(db)?;
             }
 
-            db.build_transaction().read_write().run(|| {
-                let feed_event_id = insert_into(feed_events::table)
-                    .default_values()
-                    .returning(feed_events::id)
-                    .get_result::<Uuid>(db)
-                    .context("Failed to insert feed event")?;
-
-                insert_into(purchase_events::table)
-                    .values(PurchaseEvent {
-                        purchase_id,
-                        feed_event_id,
-                    })
-                    .execute(db)
-                    .context("failed to insert purchase created event")?;
-
-                insert_into(feed_event_wallets::table)
-                    .values(&FeedEventWallet {
-                        wallet_address: data.seller,
-                        feed_event_id,
-                    })
-                    .execute(db)
-                    .context("Failed to insert purchase feed event wallet for seller")?;
-
-                insert_into(feed_event_wallets::table)
-                    .values(&FeedEventWallet {
-                        wallet_address: data.buyer,
-                        feed_event_id,
-                    })
-                    .execute(db)
-                    .context("Failed to insert purchase feed event wallet for buyer")?;
-
-                Result::<_>::Ok(())
-            })
+            Result::<_>::Ok(())
         })
         .await
-        .context("Failed to insert purchase!")?;
-
-    Ok(())
 }
 
 fn calculate_payout(price: u64, r: &DbRewardCenter) -> Result<(BigDecimal, BigDecimal)> {
@@ -0,0 +1,480 @@
+use indexer_core::{
+    db::{
+        delete, excluded, insert_into,
+        models::{
+            BurnInstruction, CollectionAuthorityRecord, EditionMarker,
+            SetAndVerifySizedCollectionItemInstruction, SignMetadataInstruction,
+            UnverifyCollectionInstruction, UpdateMetadataAccountInstruction, UseAuthorityRecord,
+            VerifyCollectionInstruction,
+        },
+        tables::{
+            burn_instructions, collection_authority_records, edition_markers,
+            set_and_verify_sized_collection_item_instructions, sign_metadata_instructions,
+            unverify_collection_instructions, update_metadata_account_instructions,
+            use_authority_records, verify_collection_instructions,
+        },
+    },
+    prelude::*,
+    pubkeys,
+};
+use mpl_token_metadata::instruction::UpdateMetadataAccountArgsV2;
+
+use super::Client;
+use crate::prelude::*;
+
+/// Number of editions tracked by a single edition marker account
+const EDITION_MARKER_BIT_SIZE: u64 = 248;
+
+fn key_str(key: &Pubkey) -> Cow<'static, str> {
+    Owned(key.to_string())
+}
+
+/// Look up an optional account which may be left off the end of the account
+/// list, or passed as the metadata program ID to mark it as absent
+fn optional_account(accounts: &[Pubkey], index: usize) -> Option<Cow<'static, str>> {
+    accounts
+        .get(index)
+        .filter(|k| **k != pubkeys::METADATA)
+        .map(key_str)
+}
+
+pub(crate) async fn process_update_metadata_account(
+    client: &Client,
+    accounts: &[Pubkey],
+    args: UpdateMetadataAccountArgsV2,
+    slot: u64,
+) -> Result<()> {
+    if accounts.len() < 2 {
+        bail!("Invalid UpdateMetadataAccountV2 instruction");
+    }
+
+    let UpdateMetadataAccountArgsV2 {
+        data,
+        update_authority,
+        primary_sale_happened,
+        is_mutable,
+    } = args;
+    let trim = |s: &str| Some(Owned(s.trim_end_matches('\0').to_owned()));
+    let (name, symbol, uri, seller_fee_basis_points) = match data {
+        Some(d) => (
+            trim(&d.name),
+            trim(&d.symbol),
+            trim(&d.uri),
+            Some(d.seller_fee_basis_points.into()),
+        ),
+        None => (None, None, None, None),
+    };
+
+    let row = UpdateMetadataAccountInstruction {
+        metadata: key_str(&accounts[0]),
+        update_authority: key_str(&accounts[1]),
+        name,
+        symbol,
+        uri,
+        seller_fee_basis_points,
+        new_update_authority: update_authority.as_ref().map(key_str),
+        primary_sale_happened,
+        is_mutable,
+        created_at: client.slot_time(slot).await?,
+        slot: slot.try_into()?,
+    };
+
+    client
+        .db()
+        .run(move |db| {
+            insert_into(update_metadata_account_instructions::table)
+                .values(&row)
+                .on_conflict_do_nothing()
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert UpdateMetadataAccountV2 instruction")?;
+
+    Ok(())
+}
+
+pub(crate) async fn process_verify_collection(
+    client: &Client,
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    if accounts.len() < 6 {
+        bail!("Invalid VerifyCollection instruction");
+    }
+
+    let row = VerifyCollectionInstruction {
+        metadata: key_str(&accounts[0]),
+        collection_authority: key_str(&accounts[1]),
+        payer: key_str(&accounts[2]),
+        collection_mint: key_str(&accounts[3]),
+        collection_metadata: key_str(&accounts[4]),
+        collection_master_edition: key_str(&accounts[5]),
+        collection_authority_record: optional_account(accounts, 6),
+        created_at: client.slot_time(slot).await?,
+        slot: slot.try_into()?,
+    };
+
+    client
+        .db()
+        .run(move |db| {
+            insert_into(verify_collection_instructions::table)
+                .values(&row)
+                .on_conflict_do_nothing()
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert VerifyCollection instruction")?;
+
+    Ok(())
+}
+
+pub(crate) async fn process_set_and_verify_sized_collection_item(
+    client: &Client,
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    if accounts.len() < 7 {
+        bail!("Invalid SetAndVerifySizedCollectionItem instruction");
+    }
+
+    let row = SetAndVerifySizedCollectionItemInstruction {
+        metadata: key_str(&accounts[0]),
+        collection_authority: key_str(&accounts[1]),
+        payer: key_str(&accounts[2]),
+        update_authority: key_str(&accounts[3]),
+        collection_mint: key_str(&accounts[4]),
+        collection_metadata: key_str(&accounts[5]),
+        collection_master_edition: key_str(&accounts[6]),
+        collection_authority_record: optional_account(accounts, 7),
+        created_at: client.slot_time(slot).await?,
+        slot: slot.try_into()?,
+    };
+
+    client
+        .db()
+        .run(move |db| {
+            insert_into(set_and_verify_sized_collection_item_instructions::table)
+                .values(&row)
+                .on_conflict_do_nothing()
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert SetAndVerifySizedCollectionItem instruction")?;
+
+    Ok(())
+}
+
+pub(crate) async fn process_unverify_collection(
+    client: &Client,
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    if accounts.len() < 5 {
+        bail!("Invalid UnverifyCollection instruction");
+    }
+
+    let row = UnverifyCollectionInstruction {
+        metadata: key_str(&accounts[0]),
+        collection_authority: key_str(&accounts[1]),
+        collection_mint: key_str(&accounts[2]),
+        collection_metadata: key_str(&accounts[3]),
+        collection_master_edition: key_str(&accounts[4]),
+        collection_authority_record: optional_account(accounts, 5),
+        created_at: client.slot_time(slot).await?,
+        slot: slot.try_into()?,
+    };
+
+    client
+        .db()
+        .run(move |db| {
+            insert_into(unverify_collection_instructions::table)
+                .values(&row)
+                .on_conflict_do_nothing()
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert UnverifyCollection instruction")?;
+
+    Ok(())
+}
+
+pub(crate) async fn process_sign_metadata(
+    client: &Client,
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    if accounts.len() < 2 {
+        bail!("Invalid SignMetadata instruction");
+    }
+
+    let row = SignMetadataInstruction {
+        metadata: key_str(&accounts[0]),
+        creator: key_str(&accounts[1]),
+        created_at: client.slot_time(slot).await?,
+        slot: slot.try_into()?,
+    };
+
+    client
+        .db()
+        .run(move |db| {
+            insert_into(sign_metadata_instructions::table)
+                .values(&row)
+                .on_conflict_do_nothing()
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert SignMetadata instruction")?;
+
+    Ok(())
+}
+
+async fn insert_burn(client: &Client, row: BurnInstruction<'static>) -> Result<()> {
+    client
+        .db()
+        .run(move |db| {
+            insert_into(burn_instructions::table)
+                .values(&row)
+                .on_conflict_do_nothing()
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert burn instruction")?;
+
+    Ok(())
+}
+
+pub(crate) async fn process_burn_nft(
+    client: &Client,
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    if accounts.len() < 5 {
+        bail!("Invalid BurnNft instruction");
+    }
+
+    insert_burn(client, BurnInstruction {
+        metadata: key_str(&accounts[0]),
+        owner: key_str(&accounts[1]),
+        mint: key_str(&accounts[2]),
+        token_account: key_str(&accounts[3]),
+        edition: Some(key_str(&accounts[4])),
+        collection_metadata: optional_account(accounts, 6),
+        amount: 1,
+        created_at: client.slot_time(slot).await?,
+        slot: slot.try_into()?,
+    })
+    .await
+}
+
+pub(crate) async fn process_burn(
+    client: &Client,
+    accounts: &[Pubkey],
+    amount: u64,
+    slot: u64,
+) -> Result<()> {
+    if accounts.len() < 6 {
+        bail!("Invalid Burn instruction");
+    }
+
+    insert_burn(client, BurnInstruction {
+        metadata: key_str(&accounts[2]),
+        owner: key_str(&accounts[0]),
+        mint: key_str(&accounts[4]),
+        token_account: key_str(&accounts[5]),
+        edition: optional_account(accounts, 3),
+        collection_metadata: optional_account(accounts, 1),
+        amount: amount.try_into()?,
+        created_at: client.slot_time(slot).await?,
+        slot: slot.try_into()?,
+    })
+    .await
+}
+
+pub(crate) async fn process_approve_collection_authority(
+    client: &Client,
+    accounts: &[Pubkey],
+) -> Result<()> {
+    if accounts.len() < 6 {
+        bail!("Invalid ApproveCollectionAuthority instruction");
+    }
+
+    let row = CollectionAuthorityRecord {
+        address: Owned(accounts[0].to_string()),
+        bump: None,
+        collection_authority: Some(Owned(accounts[1].to_string())),
+        metadata_address: Some(Owned(accounts[4].to_string())),
+        mint_address: Some(Owned(accounts[5].to_string())),
+        slot: None,
+        write_version: None,
+    };
+
+    client
+        .db()
+        .run(move |db| {
+            insert_into(collection_authority_records::table)
+                .values(&row)
+                .on_conflict(collection_authority_records::address)
+                .do_update()
+                .set((
+                    collection_authority_records::collection_authority
+                        .eq(excluded(collection_authority_records::collection_authority)),
+                    collection_authority_records::metadata_address
+                        .eq(excluded(collection_authority_records::metadata_address)),
+                    collection_authority_records::mint_address
+                        .eq(excluded(collection_authority_records::mint_address)),
+                ))
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert collection authority record link")?;
+
+    Ok(())
+}
+
+pub(crate) async fn process_revoke_collection_authority(
+    client: &Client,
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    let address = accounts
+        .first()
+        .context("Invalid RevokeCollectionAuthority instruction")?
+        .to_string();
+    let slot = i64::try_from(slot)?;
+
+    client
+        .db()
+        .run(move |db| {
+            delete(
+                collection_authority_records::table
+                    .filter(collection_authority_records::address.eq(address))
+                    .filter(
+                        collection_authority_records::slot
+                            .is_null()
+                            .or(collection_authority_records::slot.le(slot)),
+                    ),
+            )
+            .execute(db)
+        })
+        .await
+        .context("Failed to delete collection authority record")?;
+
+    Ok(())
+}
+
+pub(crate) async fn process_approve_use_authority(
+    client: &Client,
+    accounts: &[Pubkey],
+) -> Result<()> {
+    if accounts.len() < 7 {
+        bail!("Invalid ApproveUseAuthority instruction");
+    }
+
+    let row = UseAuthorityRecord {
+        address: Owned(accounts[0].to_string()),
+        allowed_uses: None,
+        bump: None,
+        use_authority: Some(Owned(accounts[3].to_string())),
+        owner: Some(Owned(accounts[1].to_string())),
+        metadata_address: Some(Owned(accounts[5].to_string())),
+        mint_address: Some(Owned(accounts[6].to_string())),
+        slot: None,
+        write_version: None,
+    };
+
+    client
+        .db()
+        .run(move |db| {
+            insert_into(use_authority_records::table)
+                .values(&row)
+                .on_conflict(use_authority_records::address)
+                .do_update()
+                .set((
+                    use_authority_records::use_authority
+                        .eq(excluded(use_authority_records::use_authority)),
+                    use_authority_records::owner.eq(excluded(use_authority_records::owner)),
+                    use_authority_records::metadata_address
+                        .eq(excluded(use_authority_records::metadata_address)),
+                    use_authority_records::mint_address
+                        .eq(excluded(use_authority_records::mint_address)),
+                ))
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert use authority record link")?;
+
+    Ok(())
+}
+
+pub(crate) async fn process_revoke_use_authority(
+    client: &Client,
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    let address = accounts
+        .first()
+        .context("Invalid RevokeUseAuthority instruction")?
+        .to_string();
+    let slot = i64::try_from(slot)?;
+
+    client
+        .db()
+        .run(move |db| {
+            delete(
+                use_authority_records::table
+                    .filter(use_authority_records::address.eq(address))
+                    .filter(
+                        use_authority_records::slot
+                            .is_null()
+                            .or(use_authority_records::slot.le(slot)),
+                    ),
+            )
+            .execute(db)
+        })
+        .await
+        .context("Failed to delete use authority record")?;
+
+    Ok(())
+}
+
+pub(crate) async fn process_print_edition(
+    client: &Client,
+    accounts: &[Pubkey],
+    edition: u64,
+) -> Result<()> {
+    if accounts.len() < 5 {
+        bail!("Invalid MintNewEditionFromMasterEdition instruction");
+    }
+
+    let row = EditionMarker {
+        address: Owned(accounts[4].to_string()),
+        ledger: None,
+        master_edition_address: Some(Owned(accounts[2].to_string())),
+        marker_index: Some(
+            (edition / EDITION_MARKER_BIT_SIZE)
+                .try_into()
+                .context("Edition marker index was too big to store")?,
+        ),
+        slot: None,
+        write_version: None,
+    };
+
+    client
+        .db()
+        .run(move |db| {
+            insert_into(edition_markers::table)
+                .values(&row)
+                .on_conflict(edition_markers::address)
+                .do_update()
+                .set((
+                    edition_markers::master_edition_address
+                        .eq(excluded(edition_markers::master_edition_address)),
+                    edition_markers::marker_index.eq(excluded(edition_markers::marker_index)),
+                ))
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert edition marker link")?;
+
+    Ok(())
+}
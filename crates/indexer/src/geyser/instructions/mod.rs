@@ -1,9 +1,10 @@
+pub mod bubblegum;
 pub mod buy;
 pub mod cancel;
 pub mod deposit;
 pub mod execute_sale;
 pub mod hpl_reward_center;
-pub mod maple;
+pub mod metadata;
 pub mod public_buy;
 pub mod sell;
 pub mod token;
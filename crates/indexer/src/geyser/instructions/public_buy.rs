@@ -1,17 +1,6 @@
 use borsh::BorshDeserialize;
-use indexer_core::{
-    db::{
-        custom_types::OfferEventLifecycleEnum,
-        insert_into,
-        models::{FeedEventWallet, Offer, OfferEvent, PublicBuyInstruction},
-        on_constraint, select,
-        tables::{
-            current_metadata_owners, feed_event_wallets, feed_events, metadatas, offer_events,
-            offers, public_buy_instructions,
-        },
-    },
-    pubkeys,
-    uuid::Uuid,
+use indexer_core::db::{
+    insert_into, models::PublicBuyInstruction, tables::public_buy_instructions,
 };
 use mpl_auction_house::instruction::PublicBuy;
 
@@ -49,14 +38,10 @@ pub(crate) async fn process(
         escrow_payment_bump: params.escrow_payment_bump.try_into()?,
         buyer_price: params.buyer_price.try_into()?,
         token_size: params.token_size.try_into()?,
-        created_at: Utc::now().naive_utc(),
+        created_at: client.slot_time(slot).await?,
         slot: slot.try_into()?,
     };
 
-    upsert_into_offers_table(client, row.clone())
-        .await
-        .context("failed to insert offer")?;
-
     client
         .db()
         .run(move |db| {
@@ -68,100 +53,3 @@ pub(crate) async fn process(
         .context("failed to insert public buy instruction ")?;
     Ok(())
 }
-
-async fn upsert_into_offers_table<'a>(
-    client: &Client,
-    data: PublicBuyInstruction<'static>,
-) -> Result<()> {
-    let row = Offer {
-        id: None,
-        trade_state: data.buyer_trade_state,
-        auction_house: data.auction_house,
-        marketplace_program: Owned(pubkeys::AUCTION_HOUSE.to_string()),
-        buyer: data.wallet,
-        metadata: data.metadata,
-        token_account: Some(data.token_account),
-        purchase_id: None,
-        price: data.buyer_price,
-        token_size: data.token_size,
-        trade_state_bump: data.trade_state_bump,
-        created_at: data.created_at,
-        canceled_at: None,
-        slot: data.slot,
-        write_version: None,
-        expiry: None,
-    };
-
-    client
-        .db()
-        .run(move |db| {
-            let offer_exists = select(exists(
-                offers::table.filter(
-                    offers::trade_state
-                        .eq(row.trade_state.clone())
-                        .and(offers::metadata.eq(row.metadata.clone())),
-                ),
-            ))
-            .get_result::<bool>(db)?;
-
-            let offer_id = insert_into(offers::table)
-                .values(&row)
-                .on_conflict(on_constraint("offers_unique_fields"))
-                .do_update()
-                .set(&row)
-                .returning(offers::id)
-                .get_result::<Uuid>(db)?;
-
-            if offer_exists {
-                return Ok(());
-            }
-
-            db.build_transaction().read_write().run(|| {
-                let metadata_owner: String = current_metadata_owners::table
-                    .inner_join(
-                        metadatas::table
-                            .on(metadatas::mint_address.eq(current_metadata_owners::mint_address)),
-                    )
-                    .filter(metadatas::address.eq(row.metadata.clone()))
-                    .select(current_metadata_owners::owner_address)
-                    .first(db)?;
-
-                let feed_event_id = insert_into(feed_events::table)
-                    .default_values()
-                    .returning(feed_events::id)
-                    .get_result::<Uuid>(db)
-                    .context("Failed to insert feed event")?;
-
-                insert_into(offer_events::table)
-                    .values(&OfferEvent {
-                        feed_event_id,
-                        lifecycle: OfferEventLifecycleEnum::Created,
-                        offer_id,
-                    })
-                    .execute(db)
-                    .context("failed to insert offer created event")?;
-
-                insert_into(feed_event_wallets::table)
-                    .values(&FeedEventWallet {
-                        wallet_address: row.buyer,
-                        feed_event_id,
-                    })
-                    .execute(db)
-                    .context("Failed to insert offer feed event wallet for buyer")?;
-
-                insert_into(feed_event_wallets::table)
-                    .values(&FeedEventWallet {
-                        wallet_address: Owned(metadata_owner),
-                        feed_event_id,
-                    })
-                    .execute(db)
-                    .context("Failed to insert offer feed event wallet for metadata owner")?;
-
-                Result::<_>::Ok(())
-            })
-        })
-        .await
-        .context("Failed to insert offer!")?;
-
-    Ok(())
-}
@@ -1,5 +1,12 @@
 use indexer_core::{
-    db::{tables::metadatas, update},
+    db::{
+        custom_types::TokenStandardEnum,
+        insert_into,
+        models::NftTransfer,
+        select,
+        tables::{current_metadata_owners, metadatas, nft_transfers},
+        update,
+    },
     prelude::*,
 };
 
@@ -12,6 +19,7 @@ pub(crate) async fn process_burn_instruction(
     slot: u64,
 ) -> Result<()> {
     let mint = accounts[1].to_string();
+    let burned_at = client.slot_time(slot).await?;
     let slot = i64::try_from(slot)?;
 
     client
@@ -19,7 +27,7 @@ pub(crate) async fn process_burn_instruction(
         .run(move |db| {
             update(metadatas::table.filter(metadatas::mint_address.eq(mint)))
                 .set((
-                    metadatas::burned_at.eq(Some(Local::now().naive_utc())),
+                    metadatas::burned_at.eq(Some(burned_at)),
                     metadatas::slot.eq(slot),
                 ))
                 .execute(db)
@@ -29,3 +37,159 @@ pub(crate) async fn process_burn_instruction(
 
     Ok(())
 }
+
+/// Accounts of a `Transfer` or `TransferChecked` instruction
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Transfer {
+    pub source: Pubkey,
+    /// Only provided by `TransferChecked`
+    pub mint: Option<Pubkey>,
+    pub destination: Pubkey,
+    pub authority: Pubkey,
+}
+
+/// Mint fields as parsed by the Solana RPC
+#[derive(Debug, serde::Deserialize)]
+struct MintInfo {
+    decimals: u8,
+    supply: String,
+}
+
+/// Token account fields as parsed by the Solana RPC
+#[derive(Debug, serde::Deserialize)]
+struct TokenAccountInfo {
+    owner: String,
+}
+
+pub(crate) async fn process_transfer_instruction(
+    client: &Client,
+    transfer: Transfer,
+    slot: u64,
+) -> Result<()> {
+    let Transfer {
+        source,
+        mint,
+        destination,
+        authority,
+    } = transfer;
+    let created_at = client.slot_time(slot).await?;
+    let slot = i64::try_from(slot)?;
+
+    let nft = client
+        .db()
+        .run({
+            let source = source.to_string();
+            let destination = destination.to_string();
+            let mint = mint.map(|m| m.to_string());
+
+            move |db| {
+                // The owner of an NFT may already point at either side of the
+                // transfer depending on which token account update arrived
+                // first
+                let mut query = current_metadata_owners::table
+                    .filter(
+                        current_metadata_owners::token_account_address
+                            .eq(&source)
+                            .or(current_metadata_owners::token_account_address.eq(&destination)),
+                    )
+                    .select((
+                        current_metadata_owners::mint_address,
+                        current_metadata_owners::owner_address,
+                        current_metadata_owners::token_account_address,
+                    ))
+                    .into_boxed();
+
+                if let Some(ref mint) = mint {
+                    query = query.filter(current_metadata_owners::mint_address.eq(mint));
+                }
+
+                let owner = query
+                    .first::<(String, String, String)>(db)
+                    .optional()
+                    .context("Failed to load NFT owner")?;
+
+                let owner = match owner {
+                    Some(o) => o,
+                    None => return Ok(None),
+                };
+
+                let has_metadata = select(exists(
+                    metadatas::table
+                        .filter(metadatas::mint_address.eq(&owner.0))
+                        .filter(
+                            metadatas::token_standard
+                                .is_null()
+                                .or(metadatas::token_standard
+                                    .ne(TokenStandardEnum::Fungible)
+                                    .and(
+                                        metadatas::token_standard
+                                            .ne(TokenStandardEnum::FungibleAsset),
+                                    )),
+                        ),
+                ))
+                .get_result::<bool>(db)
+                .context("Failed to check NFT metadata")?;
+
+                Result::<_>::Ok(has_metadata.then_some(owner))
+            }
+        })
+        .await
+        .context("Failed to look up transferred NFT")?;
+
+    let (mint_address, owner, token_account) = match nft {
+        Some(n) => n,
+        None => return Ok(()),
+    };
+
+    // Fungible tokens can carry metadata without a token standard, so only
+    // count single-token mints without decimals as NFTs
+    match client
+        .fetch_token_info::<MintInfo>(mint_address.parse()?)
+        .await?
+    {
+        Some(MintInfo {
+            decimals: 0,
+            supply,
+        }) if supply == "1" => (),
+        Some(_) => return Ok(()),
+        None => {
+            debug!("Skipping transfer of unknown mint {}", mint_address);
+            return Ok(());
+        },
+    }
+
+    let (source_owner, destination_owner) = if token_account == source.to_string() {
+        (Some(owner), None)
+    } else {
+        // The destination account was indexed first, so read the source
+        // owner from its token account, which survives the transfer unless
+        // it was closed in the same transaction
+        let source_info = client.fetch_token_info::<TokenAccountInfo>(source).await?;
+
+        (source_info.map(|i| i.owner), Some(owner))
+    };
+
+    let row = NftTransfer {
+        mint_address: Owned(mint_address),
+        source_token_account: Owned(source.to_string()),
+        destination_token_account: Owned(destination.to_string()),
+        source_owner: source_owner.map(Owned),
+        destination_owner: destination_owner.map(Owned),
+        authority: Owned(authority.to_string()),
+        created_at,
+        slot,
+    };
+
+    client
+        .db()
+        .run(move |db| {
+            insert_into(nft_transfers::table)
+                .values(&row)
+                .on_conflict_do_nothing()
+                .execute(db)
+        })
+        .await
+        .context("Failed to insert NFT transfer")?;
+
+    Ok(())
+}
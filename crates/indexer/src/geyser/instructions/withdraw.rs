@@ -30,7 +30,7 @@ pub(crate) async fn process(
         auction_house_fee_account: Owned(accts[6].clone()),
         escrow_payment_bump: params.escrow_payment_bump.try_into()?,
         amount: params.amount.try_into()?,
-        created_at: Utc::now().naive_utc(),
+        created_at: client.slot_time(slot).await?,
         slot: slot.try_into()?,
     };
 
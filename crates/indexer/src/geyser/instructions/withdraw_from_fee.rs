@@ -28,7 +28,7 @@ pub(crate) async fn process(
         auction_house_fee_account: Owned(accts[2].clone()),
         auction_house: Owned(accts[3].clone()),
         amount: params.amount.try_into()?,
-        created_at: Utc::now().naive_utc(),
+        created_at: client.slot_time(slot).await?,
         slot: slot.try_into()?,
     };
 
@@ -29,7 +29,7 @@ pub(crate) async fn process(
         auction_house_treasury: Owned(accts[3].clone()),
         auction_house: Owned(accts[4].clone()),
         amount: params.amount.try_into()?,
-        created_at: Utc::now().naive_utc(),
+        created_at: client.slot_time(slot).await?,
         slot: slot.try_into()?,
     };
 
@@ -0,0 +1,727 @@
+//! Shared indexing of marketplace listings, offers and sales
+//!
+//! Marketplace programs describe their instructions as normalized [`Event`]s,
+//! and the functions here write them to the `listings`, `offers` and
+//! `purchases` tables along with their feed and lifecycle events.
+
+use indexer_core::{
+    db::{
+        custom_types::{ListingEventLifecycleEnum, OfferEventLifecycleEnum},
+        insert_into,
+        models::{
+            FeedEventWallet, Listing, ListingEvent, Offer, OfferEvent, Purchase, PurchaseEvent,
+        },
+        mutations, on_constraint, select,
+        tables::{
+            current_metadata_owners, feed_event_wallets, feed_events, listing_events, listings,
+            metadatas, offer_events, offers, purchase_events, purchases,
+        },
+        update, Error as DbError, PooledConnection,
+    },
+    util,
+    uuid::Uuid,
+};
+
+use super::Client;
+use crate::prelude::*;
+
+/// A marketplace program whose activity is indexed into the shared listing,
+/// offer and purchase tables
+#[async_trait::async_trait]
+pub(crate) trait Marketplace {
+    /// The program recorded as the `marketplace_program` of indexed rows
+    fn program() -> Pubkey;
+
+    /// Map an instruction from this program to the events it produces
+    ///
+    /// # Errors
+    /// This function should fail if the instruction data cannot be decoded.
+    fn decode_instruction(data: &[u8], accounts: &[Pubkey]) -> Result<Vec<Event>>;
+
+    /// Update any search documents affected by an event once it has been
+    /// written.  The default implementation dispatches nothing.
+    ///
+    /// # Errors
+    /// This function should fail if a search upsert cannot be sent.
+    async fn dispatch_search(_client: &Client, _event: &Event) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// A new listing for an NFT
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct NewListing {
+    pub trade_state: Pubkey,
+    pub auction_house: Pubkey,
+    pub seller: Pubkey,
+    pub metadata: Pubkey,
+    pub price: u64,
+    pub token_size: u64,
+    pub trade_state_bump: u8,
+    /// Unix timestamp after which the listing is no longer valid
+    pub expiry: Option<i64>,
+}
+
+/// A new offer on an NFT
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct NewOffer {
+    pub trade_state: Pubkey,
+    pub auction_house: Pubkey,
+    pub buyer: Pubkey,
+    pub metadata: Pubkey,
+    pub token_account: Option<Pubkey>,
+    pub price: u64,
+    pub token_size: u64,
+    pub trade_state_bump: u8,
+    /// Unix timestamp after which the offer is no longer valid
+    pub expiry: Option<i64>,
+}
+
+/// A completed sale of an NFT
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Sale {
+    pub buyer: Pubkey,
+    pub seller: Pubkey,
+    pub auction_house: Pubkey,
+    pub metadata: Pubkey,
+    pub buyer_trade_state: Pubkey,
+    pub seller_trade_state: Pubkey,
+    pub price: u64,
+    pub token_size: u64,
+}
+
+/// A normalized marketplace event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Event {
+    /// An NFT was listed for sale
+    Listed(NewListing),
+    /// An offer was made on an NFT
+    Offered(NewOffer),
+    /// An NFT was sold
+    Sold(Sale),
+    /// The listing or offer backed by the given trade state was canceled
+    Canceled(Pubkey),
+}
+
+/// The outcome of upserting a marketplace row
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Upserted {
+    /// The ID of the upserted row
+    pub id: Uuid,
+    /// True if the row did not exist before
+    pub created: bool,
+}
+
+fn expiry(expiry: Option<i64>) -> Result<Option<NaiveDateTime>> {
+    expiry
+        .filter(|e| *e > 0)
+        .map(util::unix_timestamp)
+        .transpose()
+}
+
+impl NewListing {
+    pub(crate) fn into_row(
+        self,
+        program: Pubkey,
+        created_at: NaiveDateTime,
+        slot: i64,
+        write_version: Option<i64>,
+    ) -> Result<Listing<'static>> {
+        Ok(Listing {
+            id: None,
+            trade_state: Owned(self.trade_state.to_string()),
+            auction_house: Owned(self.auction_house.to_string()),
+            marketplace_program: Owned(program.to_string()),
+            seller: Owned(self.seller.to_string()),
+            metadata: Owned(self.metadata.to_string()),
+            purchase_id: None,
+            price: self
+                .price
+                .try_into()
+                .context("Price was too big to store")?,
+            token_size: self
+                .token_size
+                .try_into()
+                .context("Token size was too big to store")?,
+            trade_state_bump: self.trade_state_bump.into(),
+            created_at,
+            canceled_at: None,
+            slot,
+            write_version,
+            expiry: expiry(self.expiry)?,
+        })
+    }
+}
+
+impl NewOffer {
+    pub(crate) fn into_row(
+        self,
+        program: Pubkey,
+        created_at: NaiveDateTime,
+        slot: i64,
+        write_version: Option<i64>,
+    ) -> Result<Offer<'static>> {
+        Ok(Offer {
+            id: None,
+            trade_state: Owned(self.trade_state.to_string()),
+            auction_house: Owned(self.auction_house.to_string()),
+            marketplace_program: Owned(program.to_string()),
+            buyer: Owned(self.buyer.to_string()),
+            metadata: Owned(self.metadata.to_string()),
+            token_account: self.token_account.map(|t| Owned(t.to_string())),
+            purchase_id: None,
+            price: self
+                .price
+                .try_into()
+                .context("Price was too big to store")?,
+            token_size: self
+                .token_size
+                .try_into()
+                .context("Token size was too big to store")?,
+            trade_state_bump: self.trade_state_bump.into(),
+            created_at,
+            canceled_at: None,
+            slot,
+            write_version,
+            expiry: expiry(self.expiry)?,
+        })
+    }
+}
+
+/// Decode and index an instruction from a marketplace program
+///
+/// # Errors
+/// This function fails if the instruction cannot be decoded or an event
+/// cannot be written.
+pub(crate) async fn process_instruction<M: Marketplace>(
+    client: &Client,
+    data: &[u8],
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    for event in M::decode_instruction(data, accounts)? {
+        process_event::<M>(client, event, slot).await?;
+    }
+
+    Ok(())
+}
+
+/// Index a single event produced by a marketplace instruction, returning the
+/// upserted row for listings, offers and sales
+///
+/// # Errors
+/// This function fails if the event cannot be written or dispatched to
+/// search.
+pub(crate) async fn process_event<M: Marketplace>(
+    client: &Client,
+    event: Event,
+    slot: u64,
+) -> Result<Option<Upserted>> {
+    let program = M::program();
+    let created_at = client.slot_time(slot).await?;
+    let slot = i64::try_from(slot)?;
+
+    let upserted = match event.clone() {
+        Event::Listed(l) => {
+            Some(upsert_listing(client, l.into_row(program, created_at, slot, None)?).await?)
+        },
+        Event::Offered(o) => {
+            Some(upsert_offer(client, o.into_row(program, created_at, slot, None)?).await?)
+        },
+        Event::Sold(s) => Some(
+            upsert_purchase(
+                client,
+                Purchase {
+                    id: None,
+                    buyer: Owned(s.buyer.to_string()),
+                    seller: Owned(s.seller.to_string()),
+                    auction_house: Owned(s.auction_house.to_string()),
+                    marketplace_program: Owned(program.to_string()),
+                    metadata: Owned(s.metadata.to_string()),
+                    token_size: s
+                        .token_size
+                        .try_into()
+                        .context("Token size was too big to store")?,
+                    price: s.price.try_into().context("Price was too big to store")?,
+                    created_at,
+                    slot,
+                    write_version: None,
+                },
+                s.buyer_trade_state.to_string(),
+                s.seller_trade_state.to_string(),
+            )
+            .await?,
+        ),
+        Event::Canceled(trade_state) => {
+            cancel(client, trade_state.to_string(), created_at, slot).await?;
+            None
+        },
+    };
+
+    M::dispatch_search(client, &event).await?;
+
+    Ok(upserted)
+}
+
+/// Upsert a listing, linking it to a sale of the same NFT which was indexed
+/// first and recording a feed event if the listing is new
+///
+/// # Errors
+/// This function fails if the listing or its events cannot be written.
+pub(crate) async fn upsert_listing(client: &Client, mut row: Listing<'static>) -> Result<Upserted> {
+    client
+        .db()
+        .run(move |db| {
+            if row.purchase_id.is_none() {
+                row.purchase_id = purchases::table
+                    .filter(
+                        purchases::seller
+                            .eq(&row.seller)
+                            .and(purchases::auction_house.eq(&row.auction_house))
+                            .and(purchases::metadata.eq(&row.metadata))
+                            .and(purchases::price.eq(row.price))
+                            .and(purchases::token_size.eq(row.token_size))
+                            .and(purchases::slot.eq(row.slot)),
+                    )
+                    .select(purchases::id)
+                    .first::<Uuid>(db)
+                    .optional()
+                    .context("Failed to get purchase ID for listing")?;
+            }
+
+            let listing_exists = select(exists(
+                listings::table.filter(
+                    listings::trade_state
+                        .eq(&row.trade_state)
+                        .and(listings::metadata.eq(&row.metadata)),
+                ),
+            ))
+            .get_result::<bool>(db)?;
+
+            let id = mutations::listing::insert(db, &row)?;
+
+            if !listing_exists {
+                insert_listing_created(db, id, row.seller)?;
+            }
+
+            Result::<_>::Ok(Upserted {
+                id,
+                created: !listing_exists,
+            })
+        })
+        .await
+        .context("Failed to insert listing")
+}
+
+/// Upsert an offer, linking it to a sale of the same NFT which was indexed
+/// first and recording a feed event if the offer is new
+///
+/// # Errors
+/// This function fails if the offer or its events cannot be written.
+pub(crate) async fn upsert_offer(client: &Client, mut row: Offer<'static>) -> Result<Upserted> {
+    client
+        .db()
+        .run(move |db| {
+            if row.purchase_id.is_none() {
+                row.purchase_id = purchases::table
+                    .filter(
+                        purchases::buyer
+                            .eq(&row.buyer)
+                            .and(purchases::auction_house.eq(&row.auction_house))
+                            .and(purchases::metadata.eq(&row.metadata))
+                            .and(purchases::price.eq(row.price))
+                            .and(purchases::token_size.eq(row.token_size))
+                            .and(purchases::slot.eq(row.slot)),
+                    )
+                    .select(purchases::id)
+                    .first::<Uuid>(db)
+                    .optional()
+                    .context("Failed to get purchase ID for offer")?;
+            }
+
+            let offer_exists = select(exists(
+                offers::table.filter(
+                    offers::trade_state
+                        .eq(&row.trade_state)
+                        .and(offers::metadata.eq(&row.metadata)),
+                ),
+            ))
+            .get_result::<bool>(db)?;
+
+            let id = mutations::offer::insert(db, &row)?;
+
+            if !offer_exists {
+                insert_offer_created(db, id, row.buyer, row.metadata)?;
+            }
+
+            Result::<_>::Ok(Upserted {
+                id,
+                created: !offer_exists,
+            })
+        })
+        .await
+        .context("Failed to insert offer")
+}
+
+/// Upsert a purchase and link it to the listing and offer backed by the
+/// given trade states, recording a feed event if the purchase is new
+///
+/// # Errors
+/// This function fails if the purchase or its events cannot be written.
+pub(crate) async fn upsert_purchase(
+    client: &Client,
+    row: Purchase<'static>,
+    buyer_trade_state: String,
+    seller_trade_state: String,
+) -> Result<Upserted> {
+    client
+        .db()
+        .run(move |db| {
+            let purchase_exists = select(exists(
+                purchases::table.filter(
+                    purchases::buyer
+                        .eq(&row.buyer)
+                        .and(purchases::seller.eq(&row.seller))
+                        .and(purchases::auction_house.eq(&row.auction_house))
+                        .and(purchases::metadata.eq(&row.metadata))
+                        .and(purchases::price.eq(row.price))
+                        .and(purchases::token_size.eq(row.token_size)),
+                ),
+            ))
+            .get_result::<bool>(db)?;
+
+            let id = insert_into(purchases::table)
+                .values(&row)
+                .on_conflict(on_constraint("purchases_unique_fields"))
+                .do_update()
+                .set(&row)
+                .returning(purchases::id)
+                .get_result::<Uuid>(db)?;
+
+            // A sale closes its trade states, so undo any cancellation made
+            // by seeing the closure before the sale itself
+            reopen_closed(db, &row, &buyer_trade_state, &seller_trade_state)?;
+
+            update(
+                listings::table.filter(
+                    listings::trade_state
+                        .eq(&seller_trade_state)
+                        .and(listings::purchase_id.is_null())
+                        .and(listings::canceled_at.is_null()),
+                ),
+            )
+            .set(listings::purchase_id.eq(Some(id)))
+            .execute(db)?;
+
+            update(
+                offers::table.filter(
+                    offers::trade_state
+                        .eq(&buyer_trade_state)
+                        .and(offers::purchase_id.is_null())
+                        .and(offers::canceled_at.is_null()),
+                ),
+            )
+            .set(offers::purchase_id.eq(Some(id)))
+            .execute(db)?;
+
+            if !purchase_exists {
+                insert_purchase_created(db, id, row.seller, row.buyer)?;
+            }
+
+            Result::<_>::Ok(Upserted {
+                id,
+                created: !purchase_exists,
+            })
+        })
+        .await
+        .context("Failed to insert purchase")
+}
+
+fn reopen_closed(
+    db: &PooledConnection,
+    purchase: &Purchase,
+    buyer_trade_state: &str,
+    seller_trade_state: &str,
+) -> Result<()> {
+    update(
+        listings::table.filter(
+            listings::trade_state
+                .eq(seller_trade_state)
+                .and(listings::marketplace_program.eq(&purchase.marketplace_program))
+                .and(listings::purchase_id.is_null())
+                .and(listings::canceled_at.is_not_null())
+                .and(listings::slot.eq(purchase.slot)),
+        ),
+    )
+    .set(listings::canceled_at.eq(None::<NaiveDateTime>))
+    .execute(db)?;
+
+    update(
+        offers::table.filter(
+            offers::trade_state
+                .eq(buyer_trade_state)
+                .and(offers::marketplace_program.eq(&purchase.marketplace_program))
+                .and(offers::purchase_id.is_null())
+                .and(offers::canceled_at.is_not_null())
+                .and(offers::slot.eq(purchase.slot)),
+        ),
+    )
+    .set(offers::canceled_at.eq(None::<NaiveDateTime>))
+    .execute(db)?;
+
+    Ok(())
+}
+
+/// Cancel the open listing or offer backed by the given trade state
+///
+/// # Errors
+/// This function fails if the listing or offer cannot be updated.
+pub(crate) async fn cancel(
+    client: &Client,
+    trade_state: String,
+    canceled_at: NaiveDateTime,
+    slot: i64,
+) -> Result<()> {
+    client
+        .db()
+        .run(move |db| {
+            // Trade states are unique to either a listing or an offer
+            update(
+                listings::table.filter(
+                    listings::trade_state
+                        .eq(&trade_state)
+                        .and(listings::purchase_id.is_null())
+                        .and(listings::canceled_at.is_null()),
+                ),
+            )
+            .set((
+                listings::canceled_at.eq(Some(canceled_at)),
+                listings::slot.eq(slot),
+            ))
+            .execute(db)?;
+
+            update(
+                offers::table.filter(
+                    offers::trade_state
+                        .eq(&trade_state)
+                        .and(offers::purchase_id.is_null())
+                        .and(offers::canceled_at.is_null()),
+                ),
+            )
+            .set((
+                offers::canceled_at.eq(Some(canceled_at)),
+                offers::slot.eq(slot),
+            ))
+            .execute(db)
+        })
+        .await
+        .context("Failed to cancel listing or offer")?;
+
+    Ok(())
+}
+
+/// Cancel the open listing or offer of a marketplace program whose trade
+/// state account was closed
+///
+/// # Errors
+/// This function fails if the listing or offer cannot be updated.
+pub(crate) async fn close_trade_state(
+    client: &Client,
+    program: Pubkey,
+    trade_state: Pubkey,
+    slot: u64,
+) -> Result<()> {
+    let trade_state = trade_state.to_string();
+    let program = program.to_string();
+    let canceled_at = client.slot_time(slot).await?;
+    let slot = i64::try_from(slot)?;
+
+    client
+        .db()
+        .run(move |db| {
+            // Rows opened after the closure belong to a newer trade state at
+            // the same address, so leave them alone
+            update(
+                listings::table.filter(
+                    listings::trade_state
+                        .eq(&trade_state)
+                        .and(listings::marketplace_program.eq(&program))
+                        .and(listings::purchase_id.is_null())
+                        .and(listings::canceled_at.is_null())
+                        .and(listings::slot.le(slot)),
+                ),
+            )
+            .set((
+                listings::canceled_at.eq(Some(canceled_at)),
+                listings::slot.eq(slot),
+            ))
+            .execute(db)?;
+
+            update(
+                offers::table.filter(
+                    offers::trade_state
+                        .eq(&trade_state)
+                        .and(offers::marketplace_program.eq(&program))
+                        .and(offers::purchase_id.is_null())
+                        .and(offers::canceled_at.is_null())
+                        .and(offers::slot.le(slot)),
+                ),
+            )
+            .set((
+                offers::canceled_at.eq(Some(canceled_at)),
+                offers::slot.eq(slot),
+            ))
+            .execute(db)
+        })
+        .await
+        .context("Failed to close listing or offer")?;
+
+    Ok(())
+}
+
+/// Record the feed event for a newly-created listing
+///
+/// # Errors
+/// This function fails if the feed event cannot be written.
+pub(crate) fn insert_listing_created(
+    db: &PooledConnection,
+    listing_id: Uuid,
+    seller: Cow<str>,
+) -> Result<()> {
+    db.build_transaction().read_write().run(|| {
+        let feed_event_id = insert_into(feed_events::table)
+            .default_values()
+            .returning(feed_events::id)
+            .get_result::<Uuid>(db)
+            .context("Failed to insert feed event")?;
+
+        let listing_event = insert_into(listing_events::table)
+            .values(&ListingEvent {
+                feed_event_id,
+                lifecycle: ListingEventLifecycleEnum::Created,
+                listing_id,
+            })
+            .execute(db);
+
+        if Err(DbError::RollbackTransaction) == listing_event {
+            return Ok(());
+        }
+
+        insert_into(feed_event_wallets::table)
+            .values(&FeedEventWallet {
+                wallet_address: seller,
+                feed_event_id,
+            })
+            .execute(db)
+            .context("Failed to insert listing feed event wallet")?;
+
+        Ok(())
+    })
+}
+
+/// Record the feed event for a newly-created offer, notifying both the buyer
+/// and the current owner of the NFT
+///
+/// # Errors
+/// This function fails if the feed event cannot be written.
+pub(crate) fn insert_offer_created(
+    db: &PooledConnection,
+    offer_id: Uuid,
+    buyer: Cow<str>,
+    metadata: Cow<str>,
+) -> Result<()> {
+    db.build_transaction().read_write().run(|| {
+        // The owner may not be indexed yet, in which case only the buyer is
+        // notified
+        let metadata_owner: Option<String> = current_metadata_owners::table
+            .inner_join(
+                metadatas::table
+                    .on(metadatas::mint_address.eq(current_metadata_owners::mint_address)),
+            )
+            .filter(metadatas::address.eq(&metadata))
+            .select(current_metadata_owners::owner_address)
+            .first(db)
+            .optional()?;
+
+        let feed_event_id = insert_into(feed_events::table)
+            .default_values()
+            .returning(feed_events::id)
+            .get_result::<Uuid>(db)
+            .context("Failed to insert feed event")?;
+
+        insert_into(offer_events::table)
+            .values(&OfferEvent {
+                feed_event_id,
+                lifecycle: OfferEventLifecycleEnum::Created,
+                offer_id,
+            })
+            .execute(db)
+            .context("failed to insert offer created event")?;
+
+        insert_into(feed_event_wallets::table)
+            .values(&FeedEventWallet {
+                wallet_address: buyer,
+                feed_event_id,
+            })
+            .execute(db)
+            .context("Failed to insert offer feed event wallet for buyer")?;
+
+        if let Some(metadata_owner) = metadata_owner {
+            insert_into(feed_event_wallets::table)
+                .values(&FeedEventWallet {
+                    wallet_address: Owned(metadata_owner),
+                    feed_event_id,
+                })
+                .execute(db)
+                .context("Failed to insert offer feed event wallet for metadata owner")?;
+        }
+
+        Ok(())
+    })
+}
+
+/// Record the feed event for a newly-created purchase, notifying both the
+/// seller and the buyer
+///
+/// # Errors
+/// This function fails if the feed event cannot be written.
+pub(crate) fn insert_purchase_created(
+    db: &PooledConnection,
+    purchase_id: Uuid,
+    seller: Cow<str>,
+    buyer: Cow<str>,
+) -> Result<()> {
+    db.build_transaction().read_write().run(|| {
+        let feed_event_id = insert_into(feed_events::table)
+            .default_values()
+            .returning(feed_events::id)
+            .get_result::<Uuid>(db)
+            .context("Failed to insert feed event")?;
+
+        insert_into(purchase_events::table)
+            .values(PurchaseEvent {
+                purchase_id,
+                feed_event_id,
+            })
+            .execute(db)
+            .context("failed to insert purchase created event")?;
+
+        insert_into(feed_event_wallets::table)
+            .values(&FeedEventWallet {
+                wallet_address: seller,
+                feed_event_id,
+            })
+            .execute(db)
+            .context("Failed to insert purchase feed event wallet for seller")?;
+
+        insert_into(feed_event_wallets::table)
+            .values(&FeedEventWallet {
+                wallet_address: buyer,
+                feed_event_id,
+            })
+            .execute(db)
+            .context("Failed to insert purchase feed event wallet for buyer")?;
+
+        Ok(())
+    })
+}
@@ -1,28 +1,52 @@
 //! Support features for the Geyser indexer
 
 mod accounts;
+#[cfg(feature = "backfill")]
+mod backfill;
+mod batch;
 mod client;
+mod commitment;
+mod grpc;
 mod instructions;
+mod marketplace;
 mod programs;
+mod registry;
+mod replay;
+mod slots;
+#[cfg(feature = "snapshot")]
+mod snapshot;
+#[cfg(test)]
+pub(crate) mod testing;
 
-use std::{collections::HashSet, fmt, sync::Arc};
+use std::fmt;
 
+#[cfg(feature = "backfill")]
+pub use backfill::{backfill, Args as BackfillArgs};
 pub use client::{Args as ClientArgs, Client};
-use indexer_core::pubkeys;
+pub use commitment::Commitment;
+pub use grpc::{consume as grpc_consume, Args as GrpcArgs};
+use indexer_core::{db::custom_types::SlotStatusEnum, pubkeys};
 pub(self) use indexer_rabbitmq::geyser::AccountUpdate;
-use indexer_rabbitmq::geyser::Message;
+use indexer_rabbitmq::geyser::{Message, SlotStatusUpdate};
+pub use registry::{
+    AccountFilter, AccountQuery, AccountType, AnchorIdl, Args as RegistryArgs, ProgramName,
+    Registry,
+};
+pub use replay::{message_slot, replay, Recorder, SlotRange};
+#[cfg(feature = "snapshot")]
+pub use snapshot::load as load_snapshot;
 
-use crate::prelude::*;
+use crate::{prelude::*, ErrorKind};
 
 /// A value indicating a specific topic to ignore
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, strum::EnumString, strum::Display)]
 #[strum(serialize_all = "kebab-case")]
 pub enum IgnoreType {
-    /// Ignore the metadata program
+    /// Ignore the metadata and token-auth-rules programs
     Metadata,
-    /// Ignore the Metaplex candy machine program
+    /// Ignore the Metaplex candy machine and candy guard programs
     CandyMachine,
-    /// Ignore the SPL token program
+    /// Ignore the SPL token and Token-2022 programs
     Tokens,
 }
 
@@ -47,138 +71,218 @@ impl fmt::Display for MessageId {
     }
 }
 
+impl MessageLabel for Message {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::AccountUpdate(_) => "account_update",
+            Self::InstructionNotify(_) => "instruction",
+            Self::SlotStatusUpdate(_) => "slot_status",
+        }
+    }
+}
+
+/// Get the key of the entity a message updates, so updates of the same
+/// entity can be processed in order
+///
+/// Accounts are keyed by their address, except that auction house listing and
+/// bid receipts are keyed by their trade state, as are the auction house
+/// instructions which write to the same listing or offer.  Other instructions
+/// and slot statuses have no key.
+#[must_use]
+pub fn partition_key(msg: &Message) -> Option<Pubkey> {
+    match msg {
+        Message::AccountUpdate(u) if u.owner == pubkeys::AUCTION_HOUSE => {
+            // Receipts are never closed, so their data is always available to
+            // key them by
+            Some(programs::auction_house::receipt_trade_state(u).unwrap_or(u.key))
+        },
+        Message::AccountUpdate(u) => Some(u.key),
+        Message::InstructionNotify(i) if i.program == pubkeys::AUCTION_HOUSE => {
+            programs::auction_house::instruction_trade_state(&i.data, &i.accounts)
+        },
+        Message::InstructionNotify(_) | Message::SlotStatusUpdate(_) => None,
+    }
+}
+
 /// Process a message from a Geyser RabbitMQ queue
 ///
 /// # Errors
 /// This function fails if an error occurs processing the message body.
-#[allow(clippy::too_many_lines)]
-pub async fn process_message<H: std::hash::BuildHasher>(
+pub async fn process_message(
     msg: Message,
     client: &Client,
-    ignore_on_startup: Arc<HashSet<IgnoreType, H>>,
+    registry: &Registry,
 ) -> MessageResult<MessageId> {
-    let check_ignore =
-        |ty, update: &AccountUpdate| !(update.is_startup && ignore_on_startup.contains(&ty));
-
     let id = match msg {
         Message::AccountUpdate(ref u) => MessageId::AccountUpdate(u.key),
         Message::InstructionNotify(ref i) => MessageId::Instruction(i.program),
         Message::SlotStatusUpdate(ref s) => MessageId::SlotStatus(s.slot),
     };
 
-    match msg {
-        // Accounts
-        Message::AccountUpdate(update)
-            if update.owner == pubkeys::METADATA && check_ignore(IgnoreType::Metadata, &update) =>
-        {
-            programs::metadata::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::REWARD_CENTER => {
-            programs::reward_center::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::MAPLE => {
-            programs::maple::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::AUCTION => {
-            programs::auction::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::METAPLEX => {
-            programs::metaplex::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::AUCTION_HOUSE => {
-            programs::auction_house::process(client, update).await
-        },
-        Message::AccountUpdate(update)
-            if update.owner == pubkeys::TOKEN && check_ignore(IgnoreType::Tokens, &update) =>
-        {
-            programs::token::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::GRAPH_PROGRAM => {
-            programs::graph::process(client, update).await
-        },
-        Message::AccountUpdate(update)
-            if update.owner == pubkeys::CANDY_MACHINE
-                && check_ignore(IgnoreType::CandyMachine, &update) =>
-        {
-            programs::candy_machine::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::NAME_SERVICE => {
-            programs::name_service::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::CARDINAL_TOKEN_MANAGER => {
-            programs::cardinal_token_manager::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::CARDINAL_TIME_INVALIDATOR => {
-            programs::cardinal_time_invalidator::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::CARDINAL_USE_INVALIDATOR => {
-            programs::cardinal_use_invalidator::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::CARDINAL_PAID_CLAIM_APPROVER => {
-            programs::cardinal_paid_claim_approver::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::GOKI_SMART_WALLET => {
-            programs::goki_smart_wallet::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::TRIBECA_LOCKED_VOTER => {
-            programs::tribeca_locked_voter::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::TRIBECA_GOVERN => {
-            programs::tribeca_govern::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::NAMESPACES => {
-            programs::namespaces::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == pubkeys::TOKEN_BONDING => {
-            programs::token_bonding::process(client, update).await
-        },
-        Message::AccountUpdate(update) if pubkeys::SPL_GOVERNANCE.contains(&update.owner) => {
-            programs::spl_governance::process(client, update).await
-        },
-        Message::AccountUpdate(update) if update.owner == genostub::ID => {
-            programs::genopets::process(client, update).await
-        },
+    async {
+        match msg {
+            Message::SlotStatusUpdate(update) => {
+                process_slot_status(client, update, registry).await
+            },
+            // Don't stage messages no enabled program will index
+            msg if !registry.handles(&msg) => Ok(()),
+            msg => {
+                let slot = match commitment::pending_slot(client, &msg).await? {
+                    Some(s) => s,
+                    None => return registry.process(client, msg).await,
+                };
 
-        // Instructions
-        Message::InstructionNotify(ins) if ins.program == pubkeys::AUCTION_HOUSE => {
-            programs::auction_house::process_instruction(client, &ins.data, &ins.accounts, ins.slot)
-                .await
-        },
-        Message::InstructionNotify(ins) if ins.program == pubkeys::REWARD_CENTER => {
-            programs::reward_center::process_instruction(client, &ins.data, &ins.accounts, ins.slot)
-                .await
-        },
-        Message::InstructionNotify(ins) if ins.program == pubkeys::ME_HAUS => {
-            programs::magic_eden_haus::process_instruction(
-                client,
-                &ins.data,
-                &ins.accounts,
-                ins.slot,
-            )
-            .await
-        },
-        Message::InstructionNotify(ins) if ins.program == pubkeys::TOKEN => {
-            programs::token::process_instruction(client, &ins.data, &ins.accounts, ins.slot).await
-        },
-        Message::InstructionNotify(ins) if ins.program == pubkeys::MAPLE => {
-            programs::maple::process_instruction(client, &ins.data, &ins.accounts, ins.slot).await
-        },
+                commitment::stage(client, slot, &msg).await?;
 
-        // Other
-        Message::SlotStatusUpdate(slot) => {
-            debug!("Slot status update: {:?}", slot);
-            Ok(())
-        },
+                // The slot may have been committed while the message was
+                // being staged
+                if commitment::is_committed(client, slot).await? {
+                    flush(client, slot, registry).await?;
+                }
 
-        // Fallbacks
-        Message::AccountUpdate(update) => {
-            debug!(
-                "Unhandled account update for program {}",
-                bs58::encode(update.owner).into_string()
-            );
-            Ok(())
-        },
-        Message::InstructionNotify { .. } => Ok(()),
+                Ok(())
+            },
+        }
     }
+    .await
     .map_err(|e| MessageError::new(e, id))
 }
+
+async fn process_slot_status(
+    client: &Client,
+    update: SlotStatusUpdate,
+    registry: &Registry,
+) -> Result<()> {
+    let slot = update.slot;
+    let status = slots::process(client, update).await?;
+
+    if client.commitment() == Commitment::Processed {
+        return Ok(());
+    }
+
+    client.slot_statuses().received()?;
+
+    if commitment::satisfies(client.commitment(), status) {
+        flush(client, slot, registry).await?;
+    }
+
+    if status == SlotStatusEnum::Rooted {
+        let mut err = None;
+
+        // Keep flushing the other slots if one of them fails
+        for slot in commitment::prune(client, slot).await? {
+            if let Err(e) = flush(client, slot, registry).await {
+                err.get_or_insert(e);
+            }
+        }
+
+        return err.map_or(Ok(()), Err);
+    }
+
+    Ok(())
+}
+
+/// Index all messages staged for a slot which has reached the configured
+/// commitment level
+async fn flush(client: &Client, slot: u64, registry: &Registry) -> Result<()> {
+    let mut failed = vec![];
+    let mut err = None;
+
+    for value in commitment::take(client, slot).await? {
+        let msg = match serde_json::from_value(value.clone()) {
+            Ok(m) => m,
+            Err(e) => {
+                error!(
+                    "Discarding malformed staged message for slot {}: {}",
+                    slot, e
+                );
+                continue;
+            },
+        };
+
+        match registry.process(client, msg).await {
+            Ok(()) => (),
+            Err(e) if ErrorKind::of(&e) == ErrorKind::Retryable => {
+                failed.push(value);
+                err.get_or_insert(e);
+            },
+            // Restaging this would fail every later flush of the slot
+            Err(e) => error!("Dropping staged message for slot {}: {:?}", slot, e),
+        }
+    }
+
+    // Retryable failures are retried along with the slot status update
+    commitment::restage(client, slot, failed).await?;
+
+    err.map_or(Ok(()), Err)
+}
+
+#[cfg(test)]
+mod tests {
+    use indexer_rabbitmq::geyser::InstructionNotify;
+
+    use super::*;
+
+    fn account(owner: Pubkey, data: Vec<u8>) -> Message {
+        Message::AccountUpdate(AccountUpdate {
+            key: Pubkey::new_unique(),
+            lamports: 1,
+            owner,
+            executable: false,
+            rent_epoch: 0,
+            data,
+            write_version: 0,
+            slot: 1,
+            is_startup: false,
+        })
+    }
+
+    #[test]
+    fn test_partition_key() {
+        // Token accounts are keyed by their own address, even once closed
+        let msg = account(pubkeys::TOKEN, vec![0; 165]);
+        let key = match msg {
+            Message::AccountUpdate(ref u) => u.key,
+            _ => unreachable!(),
+        };
+        assert_eq!(partition_key(&msg), Some(key));
+
+        let msg = match msg {
+            Message::AccountUpdate(u) => {
+                Message::AccountUpdate(AccountUpdate { data: vec![], ..u })
+            },
+            _ => unreachable!(),
+        };
+        assert_eq!(partition_key(&msg), Some(key));
+
+        // Receipts and the instructions for their trade state share a key
+        let trade_state = Pubkey::new_unique();
+        let mut receipt = vec![0; 8];
+        receipt.extend_from_slice(trade_state.as_ref());
+        receipt.resize(mpl_auction_house::receipt::LISTING_RECEIPT_SIZE, 0);
+        assert_eq!(
+            partition_key(&account(pubkeys::AUCTION_HOUSE, receipt)),
+            Some(trade_state)
+        );
+
+        let mut accounts: Vec<_> = (0..12).map(|_| Pubkey::new_unique()).collect();
+        accounts[6] = trade_state;
+        // An auction house Sell instruction
+        let sell = Message::InstructionNotify(InstructionNotify {
+            program: pubkeys::AUCTION_HOUSE,
+            data: vec![51, 230, 133, 164, 1, 127, 131, 173],
+            accounts,
+            slot: 1,
+        });
+        assert_eq!(partition_key(&sell), Some(trade_state));
+
+        assert_eq!(
+            partition_key(&Message::SlotStatusUpdate(SlotStatusUpdate {
+                slot: 1,
+                parent: None,
+                status: indexer_rabbitmq::geyser::SlotStatus::Rooted,
+            })),
+            None
+        );
+    }
+}
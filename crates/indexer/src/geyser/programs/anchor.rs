@@ -0,0 +1,435 @@
+//! Generic decoder for accounts of any Anchor program, driven by the
+//! program's IDL
+
+use std::{collections::HashMap, fs::File, io::BufReader, path::Path};
+
+use serde::Deserialize;
+use serde_json::{Map, Number, Value};
+use solana_program::hash::hashv;
+
+use super::{accounts::anchor, AccountUpdate, Client};
+use crate::prelude::*;
+
+/// The subset of an Anchor IDL needed to decode accounts
+#[derive(Debug, Deserialize)]
+struct IdlFile {
+    #[serde(default)]
+    accounts: Vec<IdlTypeDef>,
+    #[serde(default)]
+    types: Vec<IdlTypeDef>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct IdlTypeDef {
+    name: String,
+    #[serde(rename = "type")]
+    ty: IdlTypeDefTy,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+enum IdlTypeDefTy {
+    Struct { fields: Vec<IdlField> },
+    Enum { variants: Vec<IdlEnumVariant> },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct IdlField {
+    name: String,
+    #[serde(rename = "type")]
+    ty: IdlType,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct IdlEnumVariant {
+    name: String,
+    #[serde(default)]
+    fields: Option<IdlEnumFields>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum IdlEnumFields {
+    Named(Vec<IdlField>),
+    Tuple(Vec<IdlType>),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum IdlType {
+    Primitive(IdlPrimitive),
+    Vec { vec: Box<IdlType> },
+    Option { option: Box<IdlType> },
+    COption { coption: Box<IdlType> },
+    Array { array: (Box<IdlType>, usize) },
+    Defined { defined: String },
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum IdlPrimitive {
+    Bool,
+    U8,
+    I8,
+    U16,
+    I16,
+    U32,
+    I32,
+    U64,
+    I64,
+    U128,
+    I128,
+    F32,
+    F64,
+    Bytes,
+    String,
+    #[serde(alias = "pubkey")]
+    PublicKey,
+}
+
+/// Account decoder for a single Anchor program
+#[derive(Debug)]
+pub(crate) struct Idl {
+    program: Pubkey,
+    accounts: HashMap<[u8; 8], IdlTypeDef>,
+    types: HashMap<String, IdlTypeDefTy>,
+}
+
+impl Idl {
+    /// Load the IDL of the given program from a JSON file
+    ///
+    /// # Errors
+    /// This function fails if the file cannot be read or is not a valid
+    /// Anchor IDL.
+    pub(crate) fn load(program: Pubkey, path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let file = File::open(path).with_context(|| format!("Failed to open IDL {path:?}"))?;
+        let idl: IdlFile = serde_json::from_reader(BufReader::new(file))
+            .with_context(|| format!("Failed to parse IDL {path:?}"))?;
+
+        Ok(Self::new(program, idl))
+    }
+
+    fn new(program: Pubkey, idl: IdlFile) -> Self {
+        let accounts = idl
+            .accounts
+            .iter()
+            .map(|a| (discriminator(&a.name), a.clone()))
+            .collect();
+
+        // Account types may be referenced as fields of other types
+        let types = idl
+            .types
+            .into_iter()
+            .chain(idl.accounts)
+            .map(|t| (t.name, t.ty))
+            .collect();
+
+        Self {
+            program,
+            accounts,
+            types,
+        }
+    }
+
+    /// The program this IDL describes
+    pub(crate) fn program(&self) -> Pubkey {
+        self.program
+    }
+
+    /// Decode an account into its IDL type name and a JSON value, or return
+    /// `None` if its discriminator does not match any account in the IDL
+    ///
+    /// # Errors
+    /// This function fails if the account data does not match its type.
+    pub(crate) fn decode_account<'a>(&'a self, data: &[u8]) -> Result<Option<(&'a str, Value)>> {
+        let def = match data
+            .get(..8)
+            .and_then(|d| self.accounts.get(<&[u8; 8]>::try_from(d).ok()?))
+        {
+            Some(d) => d,
+            None => return Ok(None),
+        };
+
+        let mut data = &data[8..];
+        let value = self
+            .decode_def(&def.ty, &mut data, 0)
+            .with_context(|| format!("Failed to decode {} account", def.name))?;
+
+        // Anchor accounts are often padded for future fields, so trailing
+        // bytes are ignored
+        Ok(Some((&def.name, value)))
+    }
+
+    fn decode_def(&self, ty: &IdlTypeDefTy, data: &mut &[u8], depth: usize) -> Result<Value> {
+        match ty {
+            IdlTypeDefTy::Struct { fields } => self.decode_fields(fields, data, depth),
+            IdlTypeDefTy::Enum { variants } => {
+                let tag = take(data, 1)?[0];
+                let variant = variants
+                    .get(usize::from(tag))
+                    .ok_or_else(|| anyhow!("Invalid enum variant {}", tag))?;
+
+                let fields = match variant.fields {
+                    None => return Ok(Value::String(variant.name.clone())),
+                    Some(IdlEnumFields::Named(ref f)) => self.decode_fields(f, data, depth)?,
+                    Some(IdlEnumFields::Tuple(ref t)) => Value::Array(
+                        t.iter()
+                            .map(|t| self.decode(t, data, depth))
+                            .collect::<Result<_>>()?,
+                    ),
+                };
+
+                let mut map = Map::new();
+                map.insert(variant.name.clone(), fields);
+                Ok(Value::Object(map))
+            },
+        }
+    }
+
+    fn decode_fields(&self, fields: &[IdlField], data: &mut &[u8], depth: usize) -> Result<Value> {
+        fields
+            .iter()
+            .map(|f| {
+                self.decode(&f.ty, data, depth)
+                    .map(|v| (f.name.clone(), v))
+                    .with_context(|| format!("Failed to decode field {:?}", f.name))
+            })
+            .collect::<Result<Map<_, _>>>()
+            .map(Value::Object)
+    }
+
+    fn decode(&self, ty: &IdlType, data: &mut &[u8], depth: usize) -> Result<Value> {
+        /// Guard against self-referential types in malformed IDLs
+        const MAX_DEPTH: usize = 32;
+
+        if depth > MAX_DEPTH {
+            bail!("IDL types are nested too deeply");
+        }
+
+        let depth = depth + 1;
+
+        Ok(match ty {
+            IdlType::Primitive(p) => decode_primitive(*p, data)?,
+            IdlType::Vec { vec } => {
+                let len = u32::from_le_bytes(take_array(data)?).try_into()?;
+
+                // Every element takes at least one byte, so a longer vector
+                // can only come from corrupt data
+                if len > data.len() {
+                    bail!("Vector length {} exceeds account size", len);
+                }
+
+                Value::Array(
+                    (0..len)
+                        .map(|_| self.decode(vec, data, depth))
+                        .collect::<Result<_>>()?,
+                )
+            },
+            IdlType::Option { option } => match take(data, 1)?[0] {
+                0 => Value::Null,
+                1 => self.decode(option, data, depth)?,
+                t => bail!("Invalid option tag {}", t),
+            },
+            IdlType::COption { coption } => match u32::from_le_bytes(take_array(data)?) {
+                0 => {
+                    // COptions are fixed-size, so the empty value is still
+                    // present in the data
+                    self.decode(coption, data, depth)?;
+                    Value::Null
+                },
+                1 => self.decode(coption, data, depth)?,
+                t => bail!("Invalid COption tag {}", t),
+            },
+            IdlType::Array { array: (ty, len) } => Value::Array(
+                (0..*len)
+                    .map(|_| self.decode(ty, data, depth))
+                    .collect::<Result<_>>()?,
+            ),
+            IdlType::Defined { defined } => {
+                let def = self
+                    .types
+                    .get(defined)
+                    .ok_or_else(|| anyhow!("Unknown IDL type {:?}", defined))?;
+
+                self.decode_def(def, data, depth)?
+            },
+        })
+    }
+}
+
+fn discriminator(name: &str) -> [u8; 8] {
+    let mut disc = [0; 8];
+    disc.copy_from_slice(&hashv(&[b"account:", name.as_bytes()]).to_bytes()[..8]);
+    disc
+}
+
+fn take<'a>(data: &mut &'a [u8], len: usize) -> Result<&'a [u8]> {
+    if data.len() < len {
+        bail!("Unexpected end of account data");
+    }
+
+    let (head, tail) = data.split_at(len);
+    *data = tail;
+    Ok(head)
+}
+
+fn take_array<const N: usize>(data: &mut &[u8]) -> Result<[u8; N]> {
+    Ok(take(data, N)?.try_into()?)
+}
+
+fn decode_primitive(ty: IdlPrimitive, data: &mut &[u8]) -> Result<Value> {
+    use IdlPrimitive as P;
+
+    // 128-bit integers don't fit in a JSON number, so they are stored as
+    // strings
+    Ok(match ty {
+        P::Bool => Value::Bool(take(data, 1)?[0] != 0),
+        P::U8 => take(data, 1)?[0].into(),
+        P::I8 => i8::from_le_bytes(take_array(data)?).into(),
+        P::U16 => u16::from_le_bytes(take_array(data)?).into(),
+        P::I16 => i16::from_le_bytes(take_array(data)?).into(),
+        P::U32 => u32::from_le_bytes(take_array(data)?).into(),
+        P::I32 => i32::from_le_bytes(take_array(data)?).into(),
+        P::U64 => u64::from_le_bytes(take_array(data)?).into(),
+        P::I64 => i64::from_le_bytes(take_array(data)?).into(),
+        P::U128 => u128::from_le_bytes(take_array(data)?).to_string().into(),
+        P::I128 => i128::from_le_bytes(take_array(data)?).to_string().into(),
+        P::F32 => Number::from_f64(f32::from_le_bytes(take_array(data)?).into())
+            .map_or(Value::Null, Value::Number),
+        P::F64 => Number::from_f64(f64::from_le_bytes(take_array(data)?))
+            .map_or(Value::Null, Value::Number),
+        P::Bytes => {
+            let len = u32::from_le_bytes(take_array(data)?).try_into()?;
+            take(data, len)?.into()
+        },
+        P::String => {
+            let len = u32::from_le_bytes(take_array(data)?).try_into()?;
+            std::str::from_utf8(take(data, len)?)
+                .context("String was not valid UTF-8")?
+                .into()
+        },
+        P::PublicKey => Pubkey::new_from_array(take_array(data)?).to_string().into(),
+    })
+}
+
+pub(crate) async fn process(client: &Client, idl: &Idl, update: AccountUpdate) -> Result<()> {
+    let (account_type, data) = match idl.decode_account(&update.data)? {
+        Some(d) => d,
+        None => {
+            debug!(
+                "Unknown account discriminator for {} owned by {}",
+                update.key,
+                idl.program()
+            );
+            return Ok(());
+        },
+    };
+
+    anchor::process(
+        client,
+        update.key,
+        idl.program(),
+        account_type.to_owned(),
+        data,
+        update.slot,
+        update.write_version,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn idl() -> Idl {
+        let idl = json!({
+            "version": "0.1.0",
+            "name": "test",
+            "instructions": [],
+            "accounts": [{
+                "name": "Pool",
+                "type": {
+                    "kind": "struct",
+                    "fields": [
+                        { "name": "authority", "type": "publicKey" },
+                        { "name": "amount", "type": "u64" },
+                        { "name": "name", "type": "string" },
+                        { "name": "fee", "type": { "option": "u16" } },
+                        { "name": "state", "type": { "defined": "State" } },
+                        { "name": "weights", "type": { "vec": "i8" } },
+                        { "name": "total", "type": "u128" },
+                    ],
+                },
+            }],
+            "types": [{
+                "name": "State",
+                "type": {
+                    "kind": "enum",
+                    "variants": [
+                        { "name": "Open" },
+                        { "name": "Closed", "fields": [{ "name": "at", "type": "i64" }] },
+                    ],
+                },
+            }],
+        });
+
+        Idl::new(Pubkey::new_unique(), serde_json::from_value(idl).unwrap())
+    }
+
+    #[test]
+    fn test_decode_account() {
+        let authority = Pubkey::new_unique();
+
+        let mut data = discriminator("Pool").to_vec();
+        data.extend(authority.to_bytes());
+        data.extend(5_u64.to_le_bytes());
+        data.extend(4_u32.to_le_bytes());
+        data.extend(b"pool");
+        data.extend([1, 250, 0]);
+        data.extend([1]);
+        data.extend((-3_i64).to_le_bytes());
+        data.extend(2_u32.to_le_bytes());
+        data.extend([255, 1]);
+        data.extend(u128::MAX.to_le_bytes());
+        data.extend([0; 16]);
+
+        let (ty, value) = idl().decode_account(&data).unwrap().unwrap();
+
+        assert_eq!(ty, "Pool");
+        assert_eq!(
+            value,
+            json!({
+                "authority": authority.to_string(),
+                "amount": 5,
+                "name": "pool",
+                "fee": 250,
+                "state": { "Closed": { "at": -3 } },
+                "weights": [-1, 1],
+                "total": u128::MAX.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_skip_unknown_accounts() {
+        let idl = idl();
+
+        assert!(idl.decode_account(&[0; 4]).unwrap().is_none());
+        assert!(
+            idl.decode_account(&discriminator("State"))
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_reject_truncated_accounts() {
+        let mut data = discriminator("Pool").to_vec();
+        data.extend(Pubkey::new_unique().to_bytes());
+
+        assert!(idl().decode_account(&data).is_err());
+    }
+}
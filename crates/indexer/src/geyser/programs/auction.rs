@@ -31,7 +31,7 @@ pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()
     .await?;
 
     match accounts {
-        (Ok(a), Err(_), Err(_)) => auction_data::process(client, update.key, a).await,
+        (Ok(a), Err(_), Err(_)) => auction_data::process(client, update.key, a, update.slot).await,
         (Err(_), Ok(e), Err(_)) => auction_data::process_extended(client, update.key, e).await,
         (Err(_), Err(_), Ok(b)) => bidder_metadata::process(client, update.key, b).await,
         (Err(_), Err(_), Err(_)) => {
@@ -1,11 +1,13 @@
 use anchor_lang_v0_24::AccountDeserialize;
+use borsh::BorshDeserialize;
+use indexer_core::pubkeys;
 use mpl_auction_house::{
     constants::AUCTION_HOUSE_SIZE,
+    instruction::{Buy, ExecuteSale, PublicBuy, Sell},
     receipt::{
         BidReceipt, ListingReceipt, PurchaseReceipt, BID_RECEIPT_SIZE, LISTING_RECEIPT_SIZE,
         PURCHASE_RECEIPT_SIZE,
     },
-    AuctionHouse,
 };
 
 use super::{
@@ -14,7 +16,8 @@ use super::{
         buy, cancel, deposit, execute_sale, public_buy, sell, withdraw, withdraw_from_fee,
         withdraw_from_treasury,
     },
-    AccountUpdate, Client,
+    marketplace::{self, Event, Marketplace, NewListing, NewOffer, Sale},
+    AccountFilter, AccountType, AccountUpdate, Client,
 };
 use crate::prelude::*;
 
@@ -29,8 +32,175 @@ const WITHDRAW: [u8; 8] = [183, 18, 70, 156, 148, 109, 161, 34];
 const WITHDRAW_FROM_FEE: [u8; 8] = [179, 208, 190, 154, 32, 179, 19, 59];
 const WITHDRAW_FROM_TREASURY: [u8; 8] = [0, 164, 86, 76, 56, 72, 12, 170];
 
+/// Account types fetched when backfilling, selected by their size
+pub(crate) const ACCOUNT_TYPES: &[AccountType] = &[
+    AccountType {
+        name: "auction-house",
+        filters: &[AccountFilter::DataSize(AUCTION_HOUSE_SIZE)],
+    },
+    AccountType {
+        name: "listing-receipt",
+        filters: &[AccountFilter::DataSize(LISTING_RECEIPT_SIZE)],
+    },
+    AccountType {
+        name: "bid-receipt",
+        filters: &[AccountFilter::DataSize(BID_RECEIPT_SIZE)],
+    },
+    AccountType {
+        name: "purchase-receipt",
+        filters: &[AccountFilter::DataSize(PURCHASE_RECEIPT_SIZE)],
+    },
+];
+
+/// Get the trade state a listing or bid receipt was printed for
+pub(crate) fn receipt_trade_state(update: &AccountUpdate) -> Option<Pubkey> {
+    match update.data.len() {
+        // The trade state immediately follows the Anchor discriminator
+        LISTING_RECEIPT_SIZE | BID_RECEIPT_SIZE => update
+            .data
+            .get(8..40)
+            .and_then(|b| b.try_into().ok())
+            .map(Pubkey::new_from_array),
+        _ => None,
+    }
+}
+
+/// Get the trade state an instruction creates, cancels or fills
+pub(crate) fn instruction_trade_state(data: &[u8], accounts: &[Pubkey]) -> Option<Pubkey> {
+    let index = match data.get(..8)?.try_into().ok()? {
+        SELL | CANCEL => 6,
+        BUY | PUBLIC_BUY => 10,
+        // Sales are ordered with the listing they fill
+        EXECUTE_SALE => 14,
+        _ => return None,
+    };
+
+    accounts.get(index).copied()
+}
+
+/// The Metaplex Auction House program
+pub(crate) struct AuctionHouse;
+
+impl AuctionHouse {
+    fn decode_buy(mut data: &[u8], accounts: &[Pubkey]) -> Result<Option<Event>> {
+        let params = Buy::deserialize(&mut data)
+            .context("failed to deserialize auction house Buy instruction")?;
+
+        Ok(Self::offer(
+            accounts,
+            params.trade_state_bump,
+            params.buyer_price,
+            params.token_size,
+        ))
+    }
+
+    fn decode_public_buy(mut data: &[u8], accounts: &[Pubkey]) -> Result<Option<Event>> {
+        let params = PublicBuy::deserialize(&mut data)
+            .context("failed to deserialize auction house PublicBuy instruction")?;
+
+        Ok(Self::offer(
+            accounts,
+            params.trade_state_bump,
+            params.buyer_price,
+            params.token_size,
+        ))
+    }
+
+    // Private and public bids share the same account layout
+    fn offer(
+        accounts: &[Pubkey],
+        trade_state_bump: u8,
+        price: u64,
+        token_size: u64,
+    ) -> Option<Event> {
+        if accounts.len() != 14 {
+            debug!("invalid accounts for auction house BuyInstruction");
+            return None;
+        }
+
+        Some(Event::Offered(NewOffer {
+            trade_state: accounts[10],
+            auction_house: accounts[8],
+            buyer: accounts[0],
+            metadata: accounts[5],
+            token_account: Some(accounts[4]),
+            price,
+            token_size,
+            trade_state_bump,
+            expiry: None,
+        }))
+    }
+
+    fn decode_sell(mut data: &[u8], accounts: &[Pubkey]) -> Result<Option<Event>> {
+        let params = Sell::deserialize(&mut data)
+            .context("failed to deserialize auction house Sell instruction")?;
+
+        if accounts.len() != 12 {
+            debug!("invalid accounts for auction house SellInstruction");
+            return Ok(None);
+        }
+
+        Ok(Some(Event::Listed(NewListing {
+            trade_state: accounts[6],
+            auction_house: accounts[4],
+            seller: accounts[0],
+            metadata: accounts[2],
+            price: params.buyer_price,
+            token_size: params.token_size,
+            trade_state_bump: params.trade_state_bump,
+            expiry: None,
+        })))
+    }
+
+    fn decode_execute_sale(mut data: &[u8], accounts: &[Pubkey]) -> Result<Option<Event>> {
+        let params = ExecuteSale::deserialize(&mut data)
+            .context("failed to deserialize auction house ExecuteSale instruction")?;
+
+        if accounts.len() < 21 {
+            debug!("invalid accounts for auction house ExecuteSaleInstruction");
+            return Ok(None);
+        }
+
+        Ok(Some(Event::Sold(Sale {
+            buyer: accounts[0],
+            seller: accounts[1],
+            auction_house: accounts[10],
+            metadata: accounts[4],
+            buyer_trade_state: accounts[13],
+            seller_trade_state: accounts[14],
+            price: params.buyer_price,
+            token_size: params.token_size,
+        })))
+    }
+}
+
+impl Marketplace for AuctionHouse {
+    fn program() -> Pubkey {
+        pubkeys::AUCTION_HOUSE
+    }
+
+    fn decode_instruction(data: &[u8], accounts: &[Pubkey]) -> Result<Vec<Event>> {
+        let (discriminator, params) = match data.get(..8) {
+            Some(d) => (<[u8; 8]>::try_from(d)?, &data[8..]),
+            None => return Ok(vec![]),
+        };
+
+        let event = match discriminator {
+            BUY => Self::decode_buy(params, accounts)?,
+            PUBLIC_BUY => Self::decode_public_buy(params, accounts)?,
+            SELL => Self::decode_sell(params, accounts)?,
+            EXECUTE_SALE => Self::decode_execute_sale(params, accounts)?,
+            // Both sellers and bidders cancel through the same instruction
+            CANCEL if accounts.len() == 8 => Some(Event::Canceled(accounts[6])),
+            _ => None,
+        };
+
+        Ok(event.into_iter().collect())
+    }
+}
+
 async fn process_auction_house(client: &Client, update: AccountUpdate) -> Result<()> {
-    let house: AuctionHouse = AuctionHouse::try_deserialize(&mut update.data.as_slice())
+    let house = mpl_auction_house::AuctionHouse::try_deserialize(&mut update.data.as_slice())
         .context("Failed to deserialize auction house data")?;
 
     auction_house::process(client, update.key, house).await
@@ -96,6 +266,8 @@ pub(crate) async fn process_instruction(
     accounts: &[Pubkey],
     slot: u64,
 ) -> Result<()> {
+    marketplace::process_instruction::<AuctionHouse>(client, data, accounts, slot).await?;
+
     let discriminator: [u8; 8] = data[..8].try_into()?;
     let params = data[8..].to_vec();
 
@@ -114,3 +286,89 @@ pub(crate) async fn process_instruction(
         _ => Ok(()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accounts(n: usize) -> Vec<Pubkey> {
+        (0..n).map(|_| Pubkey::new_unique()).collect()
+    }
+
+    fn instruction(discriminator: [u8; 8], bumps: usize, price: u64) -> Vec<u8> {
+        let mut data = discriminator.to_vec();
+        data.extend((0..bumps).map(|i| 250 + u8::try_from(i).unwrap()));
+        data.extend(price.to_le_bytes());
+        data.extend(1_u64.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn test_decode_buy() {
+        let accts = accounts(14);
+        let events = AuctionHouse::decode_instruction(&instruction(BUY, 2, 1_000), &accts).unwrap();
+
+        assert_eq!(events, vec![Event::Offered(NewOffer {
+            trade_state: accts[10],
+            auction_house: accts[8],
+            buyer: accts[0],
+            metadata: accts[5],
+            token_account: Some(accts[4]),
+            price: 1_000,
+            token_size: 1,
+            trade_state_bump: 250,
+            expiry: None,
+        })]);
+    }
+
+    #[test]
+    fn test_decode_sell() {
+        let accts = accounts(12);
+        let events =
+            AuctionHouse::decode_instruction(&instruction(SELL, 3, 2_000), &accts).unwrap();
+
+        assert_eq!(events, vec![Event::Listed(NewListing {
+            trade_state: accts[6],
+            auction_house: accts[4],
+            seller: accts[0],
+            metadata: accts[2],
+            price: 2_000,
+            token_size: 1,
+            trade_state_bump: 250,
+            expiry: None,
+        })]);
+    }
+
+    #[test]
+    fn test_decode_execute_sale() {
+        let accts = accounts(21);
+        let events =
+            AuctionHouse::decode_instruction(&instruction(EXECUTE_SALE, 3, 3_000), &accts).unwrap();
+
+        assert_eq!(events, vec![Event::Sold(Sale {
+            buyer: accts[0],
+            seller: accts[1],
+            auction_house: accts[10],
+            metadata: accts[4],
+            buyer_trade_state: accts[13],
+            seller_trade_state: accts[14],
+            price: 3_000,
+            token_size: 1,
+        })]);
+    }
+
+    #[test]
+    fn test_decode_cancel() {
+        let accts = accounts(8);
+
+        assert_eq!(
+            AuctionHouse::decode_instruction(&instruction(CANCEL, 0, 0), &accts).unwrap(),
+            vec![Event::Canceled(accts[6])]
+        );
+        assert!(
+            AuctionHouse::decode_instruction(&instruction(CANCEL, 0, 0), &accts[..7])
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
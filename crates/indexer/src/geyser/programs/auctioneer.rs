@@ -0,0 +1,245 @@
+use borsh::BorshDeserialize;
+use indexer_core::{db::models::AuctioneerListingConfig, pubkeys, util};
+
+use super::{
+    accounts::auctioneer,
+    marketplace::{self, Event, Marketplace, NewListing, NewOffer, Sale},
+    AccountUpdate, Client,
+};
+use crate::prelude::*;
+
+// Anchor Discriminators
+const BUY: [u8; 8] = [102, 6, 61, 18, 1, 218, 235, 234];
+const SELL: [u8; 8] = [51, 230, 133, 164, 1, 127, 131, 173];
+const EXECUTE_SALE: [u8; 8] = [37, 74, 217, 157, 79, 49, 35, 6];
+const CANCEL: [u8; 8] = [232, 219, 223, 41, 219, 236, 220, 190];
+const LISTING_CONFIG: [u8; 8] = [183, 196, 26, 41, 131, 46, 184, 115];
+
+#[derive(BorshDeserialize, Debug, Clone, Copy)]
+pub(crate) struct Bid {
+    _version: u8,
+    pub amount: u64,
+    pub buyer_trade_state: Pubkey,
+}
+
+#[derive(BorshDeserialize, Debug, Clone, Copy)]
+pub(crate) struct ListingConfig {
+    _version: u8,
+    pub highest_bid: Bid,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub reserve_price: u64,
+    pub min_bid_increment: u64,
+    pub time_ext_period: u32,
+    pub time_ext_delta: u32,
+    pub allow_high_bid_cancel: bool,
+    _bump: u8,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+struct SellArgs {
+    trade_state_bump: u8,
+    _free_trade_state_bump: u8,
+    _program_as_signer_bump: u8,
+    _auctioneer_authority_bump: u8,
+    token_size: u64,
+    start_time: i64,
+    end_time: i64,
+    reserve_price: Option<u64>,
+    min_bid_increment: Option<u64>,
+    time_ext_period: Option<u32>,
+    time_ext_delta: Option<u32>,
+    allow_high_bid_cancel: Option<bool>,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+struct BuyArgs {
+    trade_state_bump: u8,
+    _escrow_payment_bump: u8,
+    _auctioneer_authority_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+struct ExecuteSaleArgs {
+    _escrow_payment_bump: u8,
+    _free_trade_state_bump: u8,
+    _program_as_signer_bump: u8,
+    _auctioneer_authority_bump: u8,
+    buyer_price: u64,
+    token_size: u64,
+}
+
+/// The Metaplex Auctioneer program, which runs timed auctions on top of an
+/// auction house
+pub(crate) struct Auctioneer;
+
+impl Auctioneer {
+    fn decode_sell(mut data: &[u8], accounts: &[Pubkey]) -> Result<Option<Event>> {
+        let params = SellArgs::deserialize(&mut data)
+            .context("failed to deserialize auctioneer Sell instruction")?;
+
+        if accounts.len() < 13 {
+            debug!("invalid accounts for auctioneer SellInstruction");
+            return Ok(None);
+        }
+
+        // The auction house only sees the placeholder price of an auctioneer
+        // listing, so list at the reserve price until a sale settles it
+        Ok(Some(Event::Listed(NewListing {
+            trade_state: accounts[8],
+            auction_house: accounts[6],
+            seller: accounts[2],
+            metadata: accounts[4],
+            price: params.reserve_price.unwrap_or_default(),
+            token_size: params.token_size,
+            trade_state_bump: params.trade_state_bump,
+            expiry: Some(params.end_time),
+        })))
+    }
+
+    fn decode_buy(mut data: &[u8], accounts: &[Pubkey]) -> Result<Option<Event>> {
+        let params = BuyArgs::deserialize(&mut data)
+            .context("failed to deserialize auctioneer Buy instruction")?;
+
+        if accounts.len() < 16 {
+            debug!("invalid accounts for auctioneer BuyInstruction");
+            return Ok(None);
+        }
+
+        Ok(Some(Event::Offered(NewOffer {
+            trade_state: accounts[14],
+            auction_house: accounts[12],
+            buyer: accounts[3],
+            metadata: accounts[8],
+            token_account: Some(accounts[7]),
+            price: params.buyer_price,
+            token_size: params.token_size,
+            trade_state_bump: params.trade_state_bump,
+            expiry: None,
+        })))
+    }
+
+    fn decode_execute_sale(mut data: &[u8], accounts: &[Pubkey]) -> Result<Option<Event>> {
+        let params = ExecuteSaleArgs::deserialize(&mut data)
+            .context("failed to deserialize auctioneer ExecuteSale instruction")?;
+
+        if accounts.len() < 20 {
+            debug!("invalid accounts for auctioneer ExecuteSaleInstruction");
+            return Ok(None);
+        }
+
+        Ok(Some(Event::Sold(Sale {
+            buyer: accounts[2],
+            seller: accounts[3],
+            auction_house: accounts[13],
+            metadata: accounts[6],
+            buyer_trade_state: accounts[16],
+            seller_trade_state: accounts[17],
+            price: params.buyer_price,
+            token_size: params.token_size,
+        })))
+    }
+}
+
+impl Marketplace for Auctioneer {
+    fn program() -> Pubkey {
+        pubkeys::AUCTIONEER
+    }
+
+    fn decode_instruction(data: &[u8], accounts: &[Pubkey]) -> Result<Vec<Event>> {
+        let (discriminator, params) = match data.get(..8) {
+            Some(d) => (<[u8; 8]>::try_from(d)?, &data[8..]),
+            None => return Ok(vec![]),
+        };
+
+        let event = match discriminator {
+            SELL => Self::decode_sell(params, accounts)?,
+            BUY => Self::decode_buy(params, accounts)?,
+            EXECUTE_SALE => Self::decode_execute_sale(params, accounts)?,
+            // Both sellers and bidders cancel through the same instruction
+            CANCEL => accounts.get(9).copied().map(Event::Canceled),
+            _ => None,
+        };
+
+        Ok(event.into_iter().collect())
+    }
+}
+
+async fn link_listing_config(
+    client: &Client,
+    mut data: &[u8],
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    let params = SellArgs::deserialize(&mut data)
+        .context("failed to deserialize auctioneer Sell instruction")?;
+
+    if accounts.len() < 13 {
+        return Ok(());
+    }
+
+    let accts: Vec<_> = accounts.iter().map(ToString::to_string).collect();
+
+    auctioneer::process_sell(client, AuctioneerListingConfig {
+        address: Owned(accts[1].clone()),
+        auction_house: Some(Owned(accts[6].clone())),
+        seller: Some(Owned(accts[2].clone())),
+        metadata: Some(Owned(accts[4].clone())),
+        trade_state: Some(Owned(accts[8].clone())),
+        start_time: util::unix_timestamp(params.start_time)?,
+        end_time: util::unix_timestamp(params.end_time)?,
+        reserve_price: params
+            .reserve_price
+            .unwrap_or_default()
+            .try_into()
+            .context("Reserve price was too big to store")?,
+        min_bid_increment: params
+            .min_bid_increment
+            .unwrap_or_default()
+            .try_into()
+            .context("Minimum bid increment was too big to store")?,
+        time_ext_period: params.time_ext_period.unwrap_or_default().into(),
+        time_ext_delta: params.time_ext_delta.unwrap_or_default().into(),
+        allow_high_bid_cancel: params.allow_high_bid_cancel.unwrap_or_default(),
+        highest_bid: None,
+        highest_bid_trade_state: None,
+        slot: slot.try_into()?,
+        write_version: None,
+    })
+    .await
+}
+
+pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
+    if update.data.get(..8) != Some(&LISTING_CONFIG[..]) {
+        // Closed accounts have no data left to index
+        return Ok(());
+    }
+
+    let mut data = &update.data[8..];
+    let config = ListingConfig::deserialize(&mut data)
+        .context("Failed to deserialize auctioneer listing config")?;
+
+    auctioneer::process_listing_config(
+        client,
+        update.key,
+        config,
+        update.slot,
+        update.write_version,
+    )
+    .await
+}
+
+pub(crate) async fn process_instruction(
+    client: &Client,
+    data: &[u8],
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    if data.get(..8) == Some(&SELL[..]) {
+        link_listing_config(client, &data[8..], accounts, slot).await?;
+    }
+
+    marketplace::process_instruction::<Auctioneer>(client, data, accounts, slot).await
+}
@@ -0,0 +1,442 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use indexer_core::{db::custom_types::TokenStandardEnum, pubkeys::find_compressed_asset_id};
+use solana_program::keccak;
+
+use super::{
+    instructions::bubblegum::{self, CompressedMetadata, Leaf},
+    Client,
+};
+use crate::prelude::*;
+
+const MINT_V1: [u8; 8] = [145, 98, 192, 118, 184, 147, 118, 104];
+const TRANSFER: [u8; 8] = [163, 52, 200, 231, 140, 3, 69, 186];
+const BURN: [u8; 8] = [116, 110, 29, 56, 107, 219, 42, 93];
+const DELEGATE: [u8; 8] = [90, 147, 75, 178, 85, 88, 4, 137];
+const REDEEM: [u8; 8] = [184, 12, 86, 149, 70, 196, 97, 225];
+const VERIFY_COLLECTION: [u8; 8] = [56, 113, 101, 253, 79, 55, 122, 169];
+
+/// Discriminant of `AccountCompressionEvent::ApplicationData`, wrapping data
+/// logged through account compression
+const APPLICATION_DATA_EVENT: u8 = 1;
+/// Discriminant of `ApplicationDataEvent::V1` wrapping an event payload
+const APPLICATION_DATA_V1: u8 = 0;
+/// Discriminant of `BubblegumEventType::LeafSchemaEvent`
+const LEAF_SCHEMA_EVENT: u8 = 1;
+/// Discriminant of the Bubblegum event `Version::V1`
+const BUBBLEGUM_EVENT_V1: u8 = 0;
+/// Discriminant of `LeafSchema::V1`
+const LEAF_SCHEMA_V1: u8 = 0;
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy)]
+enum TokenStandard {
+    NonFungible,
+    FungibleAsset,
+    Fungible,
+    NonFungibleEdition,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+struct Collection {
+    verified: bool,
+    key: Pubkey,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy)]
+enum UseMethod {
+    Burn,
+    Multiple,
+    Single,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+struct Uses {
+    use_method: UseMethod,
+    remaining: u64,
+    total: u64,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone, Copy)]
+enum TokenProgramVersion {
+    Original,
+    Token2022,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+struct Creator {
+    address: Pubkey,
+    verified: bool,
+    share: u8,
+}
+
+/// Metadata stored in a compressed NFT, as passed to `mint_v1`
+#[derive(BorshDeserialize, BorshSerialize, Debug, Clone)]
+struct MetadataArgs {
+    name: String,
+    symbol: String,
+    uri: String,
+    seller_fee_basis_points: u16,
+    primary_sale_happened: bool,
+    is_mutable: bool,
+    edition_nonce: Option<u8>,
+    token_standard: Option<TokenStandard>,
+    collection: Option<Collection>,
+    uses: Option<Uses>,
+    token_program_version: TokenProgramVersion,
+    creators: Vec<Creator>,
+}
+
+/// Leaf arguments shared by instructions modifying an existing leaf
+#[derive(BorshDeserialize, Debug, Clone)]
+struct LeafArgs {
+    _root: [u8; 32],
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    nonce: u64,
+    index: u32,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+struct VerifyCollectionArgs {
+    leaf: LeafArgs,
+    message: MetadataArgs,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+struct LeafSchemaEvent {
+    id: Pubkey,
+    owner: Pubkey,
+    delegate: Pubkey,
+    nonce: u64,
+    data_hash: [u8; 32],
+    creator_hash: [u8; 32],
+    _leaf_hash: [u8; 32],
+}
+
+fn hash_metadata(args: &MetadataArgs) -> Result<[u8; 32]> {
+    let metadata = args
+        .try_to_vec()
+        .context("Failed to serialize compressed metadata")?;
+    let metadata_hash = keccak::hash(&metadata);
+
+    Ok(keccak::hashv(&[
+        metadata_hash.as_ref(),
+        &args.seller_fee_basis_points.to_le_bytes(),
+    ])
+    .to_bytes())
+}
+
+fn hash_creators(creators: &[Creator]) -> [u8; 32] {
+    let data: Vec<u8> = creators
+        .iter()
+        .flat_map(|c| {
+            c.address
+                .to_bytes()
+                .into_iter()
+                .chain([u8::from(c.verified), c.share])
+        })
+        .collect();
+
+    keccak::hash(&data).to_bytes()
+}
+
+fn convert_metadata(
+    args: &MetadataArgs,
+    merkle_tree: Pubkey,
+    tree_delegate: Pubkey,
+) -> Result<CompressedMetadata> {
+    Ok(CompressedMetadata {
+        data_hash: hash_metadata(args)?,
+        creator_hash: hash_creators(&args.creators),
+        merkle_tree,
+        tree_delegate,
+        name: args.name.trim_end_matches('\0').to_owned(),
+        symbol: args.symbol.trim_end_matches('\0').to_owned(),
+        uri: args.uri.trim_end_matches('\0').to_owned(),
+        seller_fee_basis_points: args.seller_fee_basis_points,
+        primary_sale_happened: args.primary_sale_happened,
+        is_mutable: args.is_mutable,
+        edition_nonce: args.edition_nonce,
+        token_standard: args.token_standard.map(|t| match t {
+            TokenStandard::NonFungible => TokenStandardEnum::NonFungible,
+            TokenStandard::FungibleAsset => TokenStandardEnum::FungibleAsset,
+            TokenStandard::Fungible => TokenStandardEnum::Fungible,
+            TokenStandard::NonFungibleEdition => TokenStandardEnum::NonFungibleEdition,
+        }),
+        collection: args.collection.as_ref().map(|c| (c.key, c.verified)),
+        creators: args
+            .creators
+            .iter()
+            .map(|c| (c.address, c.verified, c.share))
+            .collect(),
+    })
+}
+
+fn leaf_from_args(merkle_tree: Pubkey, owner: Pubkey, delegate: Pubkey, args: &LeafArgs) -> Leaf {
+    let (asset_id, _bump) = find_compressed_asset_id(merkle_tree, args.nonce);
+
+    Leaf {
+        merkle_tree,
+        leaf_index: args.index.into(),
+        asset_id,
+        owner,
+        delegate,
+        nonce: args.nonce,
+        data_hash: args.data_hash,
+        creator_hash: args.creator_hash,
+    }
+}
+
+async fn process_mint_v1(
+    client: &Client,
+    mut data: &[u8],
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    let args = MetadataArgs::deserialize(&mut data)
+        .context("Failed to deserialize Bubblegum mint_v1 args")?;
+
+    if accounts.len() < 6 {
+        bail!("Invalid Bubblegum mint_v1 accounts");
+    }
+
+    // The nonce of the new leaf is only known once the leaf schema event is
+    // logged, so only the metadata is indexed here
+    let metadata = convert_metadata(&args, accounts[3], accounts[5])?;
+
+    bubblegum::upsert_metadata(client, metadata, slot).await
+}
+
+async fn process_transfer(
+    client: &Client,
+    mut data: &[u8],
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    let args = LeafArgs::deserialize(&mut data)
+        .context("Failed to deserialize Bubblegum transfer args")?;
+
+    if accounts.len() < 5 {
+        bail!("Invalid Bubblegum transfer accounts");
+    }
+
+    // Transferring a leaf resets its delegate to the new owner
+    let leaf = leaf_from_args(accounts[4], accounts[3], accounts[3], &args);
+
+    bubblegum::upsert_leaf(client, leaf, slot).await
+}
+
+async fn process_delegate(
+    client: &Client,
+    mut data: &[u8],
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    let args = LeafArgs::deserialize(&mut data)
+        .context("Failed to deserialize Bubblegum delegate args")?;
+
+    if accounts.len() < 5 {
+        bail!("Invalid Bubblegum delegate accounts");
+    }
+
+    let leaf = leaf_from_args(accounts[4], accounts[1], accounts[3], &args);
+
+    bubblegum::upsert_leaf(client, leaf, slot).await
+}
+
+async fn process_burn(
+    client: &Client,
+    mut data: &[u8],
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    let args =
+        LeafArgs::deserialize(&mut data).context("Failed to deserialize Bubblegum burn args")?;
+
+    if accounts.len() < 4 {
+        bail!("Invalid Bubblegum burn accounts");
+    }
+
+    let leaf = leaf_from_args(accounts[3], accounts[1], accounts[2], &args);
+
+    bubblegum::burn_leaf(client, leaf, false, slot).await
+}
+
+async fn process_redeem(
+    client: &Client,
+    mut data: &[u8],
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    let args =
+        LeafArgs::deserialize(&mut data).context("Failed to deserialize Bubblegum redeem args")?;
+
+    if accounts.len() < 4 {
+        bail!("Invalid Bubblegum redeem accounts");
+    }
+
+    let leaf = leaf_from_args(accounts[3], accounts[1], accounts[2], &args);
+
+    bubblegum::burn_leaf(client, leaf, true, slot).await
+}
+
+async fn process_verify_collection(
+    client: &Client,
+    mut data: &[u8],
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    let VerifyCollectionArgs {
+        leaf: args,
+        mut message,
+    } = VerifyCollectionArgs::deserialize(&mut data)
+        .context("Failed to deserialize Bubblegum verify_collection args")?;
+
+    if accounts.len() < 9 {
+        bail!("Invalid Bubblegum verify_collection accounts");
+    }
+
+    // The message holds the metadata before verification, so the new leaf data
+    // hash must be computed from the verified collection
+    let collection = message
+        .collection
+        .as_mut()
+        .context("Bubblegum verify_collection message had no collection")?;
+    collection.verified = true;
+
+    let metadata = convert_metadata(&message, accounts[3], accounts[5])?;
+    let mut leaf = leaf_from_args(accounts[3], accounts[1], accounts[2], &args);
+    leaf.data_hash = metadata.data_hash;
+
+    bubblegum::upsert_metadata(client, metadata, slot).await?;
+    bubblegum::upsert_leaf(client, leaf, slot).await
+}
+
+/// Decode a leaf schema event, either bare or wrapped in an account
+/// compression `ApplicationDataEvent`, returning `None` for other data
+fn parse_leaf_schema_event(data: &[u8]) -> Result<Option<LeafSchemaEvent>> {
+    // Wrapped events are prefixed with the event and version tags and a u32
+    // length.  The tags are the same bytes a bare leaf schema event starts
+    // with, so only treat the data as wrapped if the length matches.
+    let raw = match data {
+        [
+            APPLICATION_DATA_EVENT,
+            APPLICATION_DATA_V1,
+            a,
+            b,
+            c,
+            d,
+            rest @ ..,
+        ] if usize::try_from(u32::from_le_bytes([*a, *b, *c, *d])).ok() == Some(rest.len()) => rest,
+        d => d,
+    };
+
+    let mut body = match raw {
+        [
+            LEAF_SCHEMA_EVENT,
+            BUBBLEGUM_EVENT_V1,
+            LEAF_SCHEMA_V1,
+            rest @ ..,
+        ] => rest,
+        _ => return Ok(None),
+    };
+
+    LeafSchemaEvent::deserialize(&mut body)
+        .context("Failed to deserialize Bubblegum leaf schema event")
+        .map(Some)
+}
+
+/// Process a Bubblegum event logged through the SPL no-op program
+///
+/// Only leaf schema events are handled, as they are the only way to learn the
+/// nonce of a newly-minted leaf.  All other no-op data is ignored.
+pub(crate) async fn process_noop(client: &Client, data: &[u8], slot: u64) -> Result<()> {
+    let event = match parse_leaf_schema_event(data)? {
+        Some(e) => e,
+        None => return Ok(()),
+    };
+
+    let merkle_tree = bubblegum::find_merkle_tree(client, event.id, event.nonce, event.data_hash)
+        .await?
+        .ok_or_else(|| anyhow!("No merkle tree found for compressed asset {}", event.id))?;
+
+    let leaf = Leaf {
+        merkle_tree,
+        // Bubblegum only appends to trees, so leaf indices match their nonce
+        leaf_index: event.nonce,
+        asset_id: event.id,
+        owner: event.owner,
+        delegate: event.delegate,
+        nonce: event.nonce,
+        data_hash: event.data_hash,
+        creator_hash: event.creator_hash,
+    };
+
+    bubblegum::upsert_leaf(client, leaf, slot).await
+}
+
+pub(crate) async fn process_instruction(
+    client: &Client,
+    data: &[u8],
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    if data.len() < 8 {
+        bail!("invalid Bubblegum instruction");
+    }
+
+    let (discriminator, params) = data.split_at(8);
+    let discriminator = <[u8; 8]>::try_from(discriminator)?;
+
+    match discriminator {
+        MINT_V1 => process_mint_v1(client, params, accounts, slot).await,
+        TRANSFER => process_transfer(client, params, accounts, slot).await,
+        BURN => process_burn(client, params, accounts, slot).await,
+        DELEGATE => process_delegate(client, params, accounts, slot).await,
+        REDEEM => process_redeem(client, params, accounts, slot).await,
+        VERIFY_COLLECTION => process_verify_collection(client, params, accounts, slot).await,
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_leaf_schema_event() {
+        let id = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+
+        let mut event = vec![LEAF_SCHEMA_EVENT, BUBBLEGUM_EVENT_V1, LEAF_SCHEMA_V1];
+        event.extend_from_slice(id.as_ref());
+        event.extend_from_slice(owner.as_ref());
+        event.extend_from_slice(owner.as_ref());
+        event.extend_from_slice(&7_u64.to_le_bytes());
+        event.extend_from_slice(&[1; 32]);
+        event.extend_from_slice(&[2; 32]);
+        event.extend_from_slice(&[3; 32]);
+
+        let mut wrapped = vec![APPLICATION_DATA_EVENT, APPLICATION_DATA_V1];
+        wrapped.extend_from_slice(&u32::try_from(event.len()).unwrap().to_le_bytes());
+        wrapped.extend_from_slice(&event);
+
+        for data in [&event, &wrapped] {
+            let parsed = parse_leaf_schema_event(data).unwrap().unwrap();
+            assert_eq!(parsed.id, id);
+            assert_eq!(parsed.owner, owner);
+            assert_eq!(parsed.delegate, owner);
+            assert_eq!(parsed.nonce, 7);
+            assert_eq!(parsed.data_hash, [1; 32]);
+            assert_eq!(parsed.creator_hash, [2; 32]);
+        }
+
+        // A wrapped event whose length doesn't match is not unwrapped
+        let mut bad_len = wrapped.clone();
+        bad_len[2] = bad_len[2].wrapping_add(1);
+        assert!(parse_leaf_schema_event(&bad_len).unwrap().is_none());
+
+        // Change log events and uninitialized data are skipped
+        assert!(parse_leaf_schema_event(&[0, 0, 0]).unwrap().is_none());
+        assert!(parse_leaf_schema_event(&[]).unwrap().is_none());
+    }
+}
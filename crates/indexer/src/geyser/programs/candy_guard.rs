@@ -0,0 +1,255 @@
+use borsh::BorshDeserialize;
+
+use super::{accounts::candy_guard, AccountUpdate, Client};
+use crate::prelude::*;
+
+const CANDY_GUARD: [u8; 8] = [44, 207, 199, 184, 112, 103, 34, 181];
+
+/// Offset of the guard data, after the discriminator, base, bump and authority
+const DATA_OFFSET: usize = 8 + 32 + 1 + 32;
+/// Group labels are stored as fixed-length, zero-padded strings
+const MAX_LABEL_SIZE: usize = 6;
+/// Maximum number of additional programs allowed by the program gate guard
+const MAX_PROGRAM_GATE_SIZE: usize = 5;
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub(crate) struct BotTax {
+    pub lamports: u64,
+    pub last_instruction: bool,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub(crate) struct SolPayment {
+    pub lamports: u64,
+    pub destination: Pubkey,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub(crate) struct TokenPayment {
+    pub amount: u64,
+    pub mint: Pubkey,
+    pub destination_ata: Pubkey,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub(crate) struct StartDate {
+    pub date: i64,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub(crate) struct ThirdPartySigner {
+    pub signer_key: Pubkey,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub(crate) struct TokenGate {
+    pub amount: u64,
+    pub mint: Pubkey,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub(crate) struct Gatekeeper {
+    pub gatekeeper_network: Pubkey,
+    pub expire_on_use: bool,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub(crate) struct EndDate {
+    pub date: i64,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub(crate) struct AllowList {
+    pub merkle_root: [u8; 32],
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub(crate) struct MintLimit {
+    pub id: u8,
+    pub limit: u16,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub(crate) struct NftPayment {
+    pub required_collection: Pubkey,
+    pub destination: Pubkey,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub(crate) struct RedeemedAmount {
+    pub maximum: u64,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub(crate) struct AddressGate {
+    pub address: Pubkey,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub(crate) struct NftGate {
+    pub required_collection: Pubkey,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub(crate) struct NftBurn {
+    pub required_collection: Pubkey,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub(crate) struct TokenBurn {
+    pub amount: u64,
+    pub mint: Pubkey,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub(crate) struct FreezeSolPayment {
+    pub lamports: u64,
+    pub destination: Pubkey,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub(crate) struct FreezeTokenPayment {
+    pub amount: u64,
+    pub mint: Pubkey,
+    pub destination_ata: Pubkey,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub(crate) struct ProgramGate {
+    pub additional: Vec<Pubkey>,
+}
+
+/// A set of guards, each of which is only present if enabled
+#[derive(Debug, Default, Clone)]
+pub(crate) struct GuardSet {
+    pub bot_tax: Option<BotTax>,
+    pub sol_payment: Option<SolPayment>,
+    pub token_payment: Option<TokenPayment>,
+    pub start_date: Option<StartDate>,
+    pub third_party_signer: Option<ThirdPartySigner>,
+    pub token_gate: Option<TokenGate>,
+    pub gatekeeper: Option<Gatekeeper>,
+    pub end_date: Option<EndDate>,
+    pub allow_list: Option<AllowList>,
+    pub mint_limit: Option<MintLimit>,
+    pub nft_payment: Option<NftPayment>,
+    pub redeemed_amount: Option<RedeemedAmount>,
+    pub address_gate: Option<AddressGate>,
+    pub nft_gate: Option<NftGate>,
+    pub nft_burn: Option<NftBurn>,
+    pub token_burn: Option<TokenBurn>,
+    pub freeze_sol_payment: Option<FreezeSolPayment>,
+    pub freeze_token_payment: Option<FreezeTokenPayment>,
+    pub program_gate: Option<ProgramGate>,
+}
+
+/// A candy guard account with its default guard set and groups
+#[derive(Debug, Clone)]
+pub(crate) struct CandyGuard {
+    pub base: Pubkey,
+    pub bump: u8,
+    pub authority: Pubkey,
+    pub default: GuardSet,
+    /// Guard groups with their labels
+    pub groups: Vec<(String, GuardSet)>,
+}
+
+/// Read the guard with the given feature flag, if it is enabled
+///
+/// Guards are serialized in feature flag order, each taking up a fixed amount
+/// of space regardless of its contents.
+fn read_guard<T: BorshDeserialize>(
+    data: &mut &[u8],
+    features: u64,
+    flag: u32,
+    size: usize,
+) -> Result<Option<T>> {
+    if features & (1 << flag) == 0 {
+        return Ok(None);
+    }
+
+    if data.len() < size {
+        bail!("Guard {} overflows account data", flag);
+    }
+
+    let (mut guard, rest) = data.split_at(size);
+    *data = rest;
+
+    T::deserialize(&mut guard)
+        .map(Some)
+        .with_context(|| format!("Failed to deserialize guard {flag}"))
+}
+
+fn parse_guard_set(data: &mut &[u8]) -> Result<GuardSet> {
+    let features = u64::deserialize(data).context("Failed to read guard set features")?;
+
+    Ok(GuardSet {
+        bot_tax: read_guard(data, features, 0, 8 + 1)?,
+        sol_payment: read_guard(data, features, 1, 8 + 32)?,
+        token_payment: read_guard(data, features, 2, 8 + 32 + 32)?,
+        start_date: read_guard(data, features, 3, 8)?,
+        third_party_signer: read_guard(data, features, 4, 32)?,
+        token_gate: read_guard(data, features, 5, 8 + 32)?,
+        gatekeeper: read_guard(data, features, 6, 32 + 1)?,
+        end_date: read_guard(data, features, 7, 8)?,
+        allow_list: read_guard(data, features, 8, 32)?,
+        mint_limit: read_guard(data, features, 9, 1 + 2)?,
+        nft_payment: read_guard(data, features, 10, 32 + 32)?,
+        redeemed_amount: read_guard(data, features, 11, 8)?,
+        address_gate: read_guard(data, features, 12, 32)?,
+        nft_gate: read_guard(data, features, 13, 32)?,
+        nft_burn: read_guard(data, features, 14, 32)?,
+        token_burn: read_guard(data, features, 15, 8 + 32)?,
+        freeze_sol_payment: read_guard(data, features, 16, 8 + 32)?,
+        freeze_token_payment: read_guard(data, features, 17, 8 + 32 + 32)?,
+        program_gate: read_guard(data, features, 18, 4 + MAX_PROGRAM_GATE_SIZE * 32)?,
+    })
+}
+
+fn parse_candy_guard(data: &[u8]) -> Result<CandyGuard> {
+    let mut header = &data[8..DATA_OFFSET];
+    let base = Pubkey::deserialize(&mut header)?;
+    let bump = u8::deserialize(&mut header)?;
+    let authority = Pubkey::deserialize(&mut header)?;
+
+    let mut data = &data[DATA_OFFSET..];
+    let default = parse_guard_set(&mut data).context("Failed to parse default guard set")?;
+    let group_count = u32::deserialize(&mut data).context("Failed to read guard group count")?;
+
+    let mut groups = Vec::new();
+    for i in 0..group_count {
+        if data.len() < MAX_LABEL_SIZE {
+            bail!("Label of guard group {} overflows account data", i);
+        }
+
+        let (label, rest) = data.split_at(MAX_LABEL_SIZE);
+        let label = String::from_utf8_lossy(label)
+            .trim_end_matches('\0')
+            .to_owned();
+        data = rest;
+
+        let guards = parse_guard_set(&mut data)
+            .with_context(|| format!("Failed to parse guard group {label:?}"))?;
+
+        groups.push((label, guards));
+    }
+
+    Ok(CandyGuard {
+        base,
+        bump,
+        authority,
+        default,
+        groups,
+    })
+}
+
+pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
+    if update.data.len() < DATA_OFFSET || update.data[..8] != CANDY_GUARD {
+        // Mint counters and other guard accounts are not indexed
+        return Ok(());
+    }
+
+    let guard = parse_candy_guard(&update.data).context("Failed to parse candy guard")?;
+
+    candy_guard::process(client, update.key, guard, update.slot, update.write_version).await
+}
@@ -114,7 +114,7 @@ mod tests {
     use mpl_candy_machine::CandyMachine;
 
     use super::parse_cm_config_lines;
-    use crate::prelude::*;
+    use crate::{geyser::testing, prelude::*};
 
     fn load_account_dump(filename: impl AsRef<Path>) -> Result<Vec<u8>> {
         let mut path = env::current_dir().context("Failed to get working dir")?;
@@ -170,4 +170,9 @@ mod tests {
             }
         }
     }
+
+    #[tokio::test]
+    async fn test_index_candy_machines() {
+        testing::run_fixture("candy_machine").await;
+    }
 }
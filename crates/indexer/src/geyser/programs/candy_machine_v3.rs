@@ -0,0 +1,218 @@
+use std::collections::HashSet;
+
+use borsh::BorshDeserialize;
+
+use super::{accounts::candy_machine_v3, AccountUpdate, Client};
+use crate::prelude::*;
+
+const CANDY_MACHINE: [u8; 8] = [51, 173, 177, 113, 25, 241, 109, 189];
+
+const MAX_NAME_LENGTH: usize = 32;
+const MAX_SYMBOL_LENGTH: usize = 10;
+const MAX_URI_LENGTH: usize = 200;
+const MAX_CREATOR_LIMIT: usize = 5;
+const MAX_CREATOR_LEN: usize = 32 + 1 + 1;
+
+/// Space reserved for the config line settings, including its option tag
+const CONFIG_LINE_SETTINGS_SIZE: usize = 1 + 4 + MAX_NAME_LENGTH + 4 + 4 + MAX_URI_LENGTH + 4 + 1;
+/// Space reserved for the hidden settings, including its option tag
+const HIDDEN_SETTINGS_SIZE: usize = 1 + 4 + MAX_NAME_LENGTH + 4 + MAX_URI_LENGTH + 32;
+
+/// Offset of the config line data, immediately after the space reserved for
+/// a candy machine with the largest possible settings
+///
+/// This covers the discriminator, features, authority, mint authority,
+/// collection mint, items redeemed, items available, symbol, seller fee basis
+/// points, max supply, is mutable and creators fields, followed by both
+/// settings.
+const HIDDEN_SECTION: usize = 8
+    + 8
+    + 32 * 3
+    + 8
+    + 8
+    + 4
+    + MAX_SYMBOL_LENGTH
+    + 2
+    + 8
+    + 1
+    + 4
+    + MAX_CREATOR_LIMIT * MAX_CREATOR_LEN
+    + CONFIG_LINE_SETTINGS_SIZE
+    + HIDDEN_SETTINGS_SIZE;
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub(crate) struct Creator {
+    pub address: Pubkey,
+    pub verified: bool,
+    pub percentage_share: u8,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub(crate) struct ConfigLineSettings {
+    pub prefix_name: String,
+    pub name_length: u32,
+    pub prefix_uri: String,
+    pub uri_length: u32,
+    pub is_sequential: bool,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub(crate) struct HiddenSettings {
+    pub name: String,
+    pub uri: String,
+    pub hash: [u8; 32],
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+pub(crate) struct CandyMachineData {
+    pub items_available: u64,
+    pub symbol: String,
+    pub seller_fee_basis_points: u16,
+    pub max_supply: u64,
+    pub is_mutable: bool,
+    pub creators: Vec<Creator>,
+    pub config_line_settings: Option<ConfigLineSettings>,
+    pub hidden_settings: Option<HiddenSettings>,
+}
+
+/// A Core Candy Machine (v3) account
+#[derive(BorshDeserialize, Debug, Clone)]
+pub(crate) struct CandyMachine {
+    pub features: u64,
+    pub authority: Pubkey,
+    pub mint_authority: Pubkey,
+    pub collection_mint: Pubkey,
+    pub items_redeemed: u64,
+    pub data: CandyMachineData,
+}
+
+/// A config line with its name and URI prefixes applied
+#[derive(Debug, Clone)]
+pub(crate) struct ConfigLine {
+    pub name: String,
+    pub uri: String,
+    pub idx: usize,
+    pub taken: bool,
+}
+
+fn read_line_field(data: &[u8], prefix: &str, idx: usize) -> String {
+    let value = String::from_utf8_lossy(data);
+
+    // Prefixes may contain the item's index as a placeholder
+    let prefix = prefix
+        .replace("$ID+1$", &(idx + 1).to_string())
+        .replace("$ID$", &idx.to_string());
+
+    format!("{}{}", prefix, value.trim_end_matches('\0'))
+}
+
+/// Parse the loaded config lines of a v3 candy machine
+///
+/// Unlike v2, names and URIs are stored without their common prefixes and
+/// without a length prefix, padded to the lengths given by the config line
+/// settings.  Lines that have not been loaded yet are ignored.
+pub(crate) fn parse_config_lines(
+    data: &[u8],
+    candy_machine: &CandyMachine,
+    settings: &ConfigLineSettings,
+) -> Result<Vec<ConfigLine>> {
+    let items_available = usize::try_from(candy_machine.data.items_available)
+        .context("Failed to convert available item count")?;
+    let items_redeemed = usize::try_from(candy_machine.items_redeemed)
+        .context("Failed to convert redeemed item count")?;
+    let name_length = usize::try_from(settings.name_length)?;
+    let uri_length = usize::try_from(settings.uri_length)?;
+    let line_size = name_length + uri_length;
+
+    let lines_start = HIDDEN_SECTION + 4;
+    let loaded_bitmask_start = lines_start + items_available * line_size;
+    let indices_start = loaded_bitmask_start + items_available / 8 + 1;
+    let indices_end = indices_start + items_available * 4;
+
+    if indices_end > data.len() {
+        bail!(
+            "Config line bytes would overflow available data ({} vs {})",
+            indices_end,
+            data.len()
+        );
+    }
+
+    // Random candy machines move the index of each minted item to the end of
+    // the remaining indices, so the last items_redeemed indices are taken
+    let taken: HashSet<usize> = if settings.is_sequential {
+        (0..items_redeemed).collect()
+    } else {
+        data[indices_start..indices_end]
+            .chunks_exact(4)
+            .skip(items_available.saturating_sub(items_redeemed))
+            .map(|b| usize::try_from(u32::from_le_bytes([b[0], b[1], b[2], b[3]])))
+            .collect::<Result<_, _>>()?
+    };
+
+    let mut lines = Vec::new();
+    for idx in 0..items_available {
+        let loaded = data[loaded_bitmask_start + idx / 8] & (1 << (7 - (idx % 8))) != 0;
+
+        if !loaded {
+            continue;
+        }
+
+        let start = lines_start + idx * line_size;
+        let name = read_line_field(
+            &data[start..start + name_length],
+            &settings.prefix_name,
+            idx,
+        );
+        let uri = read_line_field(
+            &data[start + name_length..start + line_size],
+            &settings.prefix_uri,
+            idx,
+        );
+
+        lines.push(ConfigLine {
+            name,
+            uri,
+            idx,
+            taken: taken.contains(&idx),
+        });
+    }
+
+    Ok(lines)
+}
+
+pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
+    if update.data.len() < 8 {
+        bail!("Candy machine v3 account data was too short");
+    }
+
+    let (discriminator, mut data) = update.data.split_at(8);
+
+    if discriminator != CANDY_MACHINE {
+        return Ok(());
+    }
+
+    let candy_machine =
+        CandyMachine::deserialize(&mut data).context("Failed to deserialize candy machine v3")?;
+
+    let lines = match candy_machine.data {
+        CandyMachineData {
+            config_line_settings: Some(ref settings),
+            hidden_settings: None,
+            ..
+        } => Some(
+            parse_config_lines(&update.data, &candy_machine, settings)
+                .context("Failed to parse candy machine v3 config lines")?,
+        ),
+        _ => None,
+    };
+
+    candy_machine_v3::process(
+        client,
+        update.key,
+        candy_machine,
+        lines,
+        update.slot,
+        update.write_version,
+    )
+    .await
+}
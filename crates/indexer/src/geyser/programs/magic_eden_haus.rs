@@ -1,20 +1,10 @@
 use borsh::BorshDeserialize;
-use indexer_core::{
-    db::{
-        models::{Listing, Offer, Purchase},
-        tables::{listings, offers, purchases},
-        update,
-    },
-    pubkeys, util,
-    uuid::Uuid,
-};
+use indexer_core::pubkeys;
 
 use super::{
-    instructions::{
-        buy::upsert_into_offers_table, execute_sale::upsert_into_purchases_table,
-        sell::upsert_into_listings_table,
-    },
-    Client,
+    accounts::magic_eden,
+    marketplace::{self, Event, Marketplace, NewListing, NewOffer, Sale},
+    AccountFilter, AccountType, AccountUpdate, Client,
 };
 use crate::prelude::*;
 
@@ -24,6 +14,87 @@ const EXECUTE_SALE: [u8; 8] = [37, 74, 217, 157, 79, 49, 35, 6];
 const CANCEL_SELL: [u8; 8] = [198, 198, 130, 203, 163, 95, 175, 75];
 const CANCEL_BUY: [u8; 8] = [238, 76, 36, 218, 132, 177, 224, 233];
 
+// Anchor account discriminators
+const SELLER_TRADE_STATE: [u8; 8] = [1, 238, 72, 137, 138, 21, 254, 249];
+const SELLER_TRADE_STATE_V2: [u8; 8] = [164, 14, 92, 100, 123, 57, 234, 204];
+const BUYER_TRADE_STATE: [u8; 8] = [200, 164, 153, 187, 118, 60, 200, 51];
+const BUYER_TRADE_STATE_V2: [u8; 8] = [195, 55, 46, 41, 54, 7, 225, 155];
+
+/// Account types fetched when backfilling, selected by their discriminator
+pub(crate) const ACCOUNT_TYPES: &[AccountType] = &[
+    AccountType {
+        name: "seller-trade-state",
+        filters: &[AccountFilter::Memcmp(0, &SELLER_TRADE_STATE)],
+    },
+    AccountType {
+        name: "seller-trade-state-v2",
+        filters: &[AccountFilter::Memcmp(0, &SELLER_TRADE_STATE_V2)],
+    },
+    AccountType {
+        name: "buyer-trade-state",
+        filters: &[AccountFilter::Memcmp(0, &BUYER_TRADE_STATE)],
+    },
+    AccountType {
+        name: "buyer-trade-state-v2",
+        filters: &[AccountFilter::Memcmp(0, &BUYER_TRADE_STATE_V2)],
+    },
+];
+
+/// Seller trade state, shared by both account versions since V2 only appends
+/// a payment mint
+#[derive(BorshDeserialize, Debug, Clone, Copy)]
+pub(crate) struct SellerTradeState {
+    pub auction_house: Pubkey,
+    pub seller: Pubkey,
+    _seller_referral: Pubkey,
+    pub buyer_price: u64,
+    pub token_mint: Pubkey,
+    _token_account: Pubkey,
+    pub token_size: u64,
+    pub bump: u8,
+    pub expiry: i64,
+}
+
+#[derive(BorshDeserialize, Debug, Clone, Copy)]
+pub(crate) struct BuyerTradeState {
+    pub auction_house: Pubkey,
+    pub buyer: Pubkey,
+    _buyer_referral: Pubkey,
+    pub buyer_price: u64,
+    pub token_mint: Pubkey,
+    pub token_size: u64,
+    pub bump: u8,
+    pub expiry: i64,
+}
+
+#[derive(BorshDeserialize, Debug, Clone, Copy)]
+struct BuyerTradeStateV2 {
+    auction_house: Pubkey,
+    buyer: Pubkey,
+    buyer_referral: Pubkey,
+    buyer_price: u64,
+    token_mint: Pubkey,
+    token_size: u64,
+    bump: u8,
+    _buyer_creator_royalty_bp: u16,
+    expiry: i64,
+}
+
+impl From<BuyerTradeStateV2> for BuyerTradeState {
+    fn from(v2: BuyerTradeStateV2) -> Self {
+        Self {
+            auction_house: v2.auction_house,
+            buyer: v2.buyer,
+            _buyer_referral: v2.buyer_referral,
+            buyer_price: v2.buyer_price,
+            token_mint: v2.token_mint,
+            token_size: v2.token_size,
+            bump: v2.bump,
+            expiry: v2.expiry,
+        }
+    }
+}
+
 #[derive(BorshDeserialize, Debug, Clone)]
 struct MEInstructionData {
     trade_state_bump: u8,
@@ -33,242 +104,166 @@ struct MEInstructionData {
     expiry: i64,
 }
 
-async fn process_execute_sale(
-    client: &Client,
-    mut data: &[u8],
-    accounts: &[Pubkey],
-    slot: u64,
-) -> Result<()> {
-    let params = MEInstructionData::deserialize(&mut data)
-        .context("failed to deserialize ME ExecuteSale instruction")?;
+/// The Magic Eden V2 marketplace program
+pub(crate) struct MagicEden;
 
-    let accts: Vec<_> = accounts.iter().map(ToString::to_string).collect();
+impl MagicEden {
+    fn decode_sell(mut data: &[u8], accounts: &[Pubkey]) -> Result<Option<Event>> {
+        let params = MEInstructionData::deserialize(&mut data)
+            .context("failed to deserialize ME Sell instruction")?;
 
-    upsert_into_purchases_table(
-        client,
-        Purchase {
-            id: None,
-            buyer: Owned(accts[0].clone()),
-            seller: Owned(accts[1].clone()),
-            auction_house: Owned(accts[9].clone()),
-            marketplace_program: Owned(pubkeys::ME_HAUS.to_string()),
-            metadata: Owned(accts[5].clone()),
-            token_size: params.token_size.try_into()?,
-            price: params.buyer_price.try_into()?,
-            created_at: Utc::now().naive_utc(),
-            slot: slot.try_into()?,
-            write_version: None,
-        },
-        accts[11].clone(),
-        accts[13].clone(),
-    )
-    .await
-    .context("failed to insert listing!")?;
+        if accounts.len() < 9 {
+            debug!("invalid accounts for ME SellInstruction");
+            return Ok(None);
+        }
 
-    Ok(())
-}
+        Ok(Some(Event::Listed(NewListing {
+            trade_state: accounts[8],
+            auction_house: accounts[7],
+            seller: accounts[0],
+            metadata: accounts[5],
+            price: params.buyer_price,
+            token_size: params.token_size,
+            trade_state_bump: params.trade_state_bump,
+            expiry: Some(params.expiry),
+        })))
+    }
 
-async fn process_sale(
-    client: &Client,
-    mut data: &[u8],
-    accounts: &[Pubkey],
-    slot: u64,
-) -> Result<()> {
-    let params = MEInstructionData::deserialize(&mut data)
-        .context("failed to deserialize ME Sell instruction")?;
-
-    let accts: Vec<_> = accounts.iter().map(ToString::to_string).collect();
-
-    let seller = accts[0].clone();
-    let auction_house = accts[7].clone();
-    let metadata = accts[5].clone();
-    let price = i64::try_from(params.buyer_price)?;
-    let token_size = i64::try_from(params.token_size)?;
-    let slot = i64::try_from(slot)?;
-
-    let purchase_id = client
-        .db()
-        .run({
-            move |db| {
-                purchases::table
-                    .filter(
-                        purchases::seller
-                            .eq(seller)
-                            .and(purchases::auction_house.eq(auction_house))
-                            .and(purchases::metadata.eq(metadata))
-                            .and(purchases::price.eq(price))
-                            .and(
-                                purchases::token_size
-                                    .eq(token_size)
-                                    .and(purchases::slot.eq(slot)),
-                            ),
-                    )
-                    .select(purchases::id)
-                    .first::<Uuid>(db)
-                    .optional()
-                    .context("failed to get purchase ids")
-            }
-        })
-        .await?;
-
-    upsert_into_listings_table(client, Listing {
-        id: None,
-        trade_state: Owned(accts[8].clone()),
-        auction_house: Owned(accts[7].clone()),
-        marketplace_program: Owned(pubkeys::ME_HAUS.to_string()),
-        seller: Owned(accts[0].clone()),
-        metadata: Owned(accts[5].clone()),
-        purchase_id,
-        price: params.buyer_price.try_into()?,
-        token_size: params.token_size.try_into()?,
-        trade_state_bump: params.trade_state_bump.try_into()?,
-        created_at: Utc::now().naive_utc(),
-        canceled_at: None,
-        slot,
-        write_version: None,
-        expiry: match params.expiry {
-            e if e <= 0 => None,
-            _ => Some(util::unix_timestamp(params.expiry)?),
-        },
-    })
-    .await
-    .context("failed to insert listing!")?;
+    fn decode_buy(mut data: &[u8], accounts: &[Pubkey]) -> Result<Option<Event>> {
+        let params = MEInstructionData::deserialize(&mut data)
+            .context("failed to deserialize ME Buy instruction")?;
+
+        if accounts.len() != 12 {
+            debug!("invalid accounts for ME BuyInstruction");
+            return Ok(None);
+        }
 
-    Ok(())
+        Ok(Some(Event::Offered(NewOffer {
+            trade_state: accounts[7],
+            auction_house: accounts[6],
+            buyer: accounts[0],
+            metadata: accounts[3],
+            token_account: None,
+            price: params.buyer_price,
+            token_size: params.token_size,
+            trade_state_bump: params.trade_state_bump,
+            expiry: Some(params.expiry),
+        })))
+    }
+
+    fn decode_execute_sale(mut data: &[u8], accounts: &[Pubkey]) -> Result<Option<Event>> {
+        let params = MEInstructionData::deserialize(&mut data)
+            .context("failed to deserialize ME ExecuteSale instruction")?;
+
+        if accounts.len() < 14 {
+            debug!("invalid accounts for ME ExecuteSaleInstruction");
+            return Ok(None);
+        }
+
+        Ok(Some(Event::Sold(Sale {
+            buyer: accounts[0],
+            seller: accounts[1],
+            auction_house: accounts[9],
+            metadata: accounts[5],
+            buyer_trade_state: accounts[11],
+            seller_trade_state: accounts[13],
+            price: params.buyer_price,
+            token_size: params.token_size,
+        })))
+    }
 }
 
-async fn process_buy(
-    client: &Client,
-    mut data: &[u8],
-    accounts: &[Pubkey],
-    slot: u64,
-) -> Result<()> {
-    let params = MEInstructionData::deserialize(&mut data)
-        .context("failed to deserialize ME Buy instruction")?;
+impl Marketplace for MagicEden {
+    fn program() -> Pubkey {
+        pubkeys::ME_HAUS
+    }
+
+    fn decode_instruction(data: &[u8], accounts: &[Pubkey]) -> Result<Vec<Event>> {
+        let (discriminator, params) = match data.get(..8) {
+            Some(d) => (<[u8; 8]>::try_from(d)?, &data[8..]),
+            None => return Ok(vec![]),
+        };
+
+        let event = match discriminator {
+            BUY => Self::decode_buy(params, accounts)?,
+            SELL => Self::decode_sell(params, accounts)?,
+            EXECUTE_SALE => Self::decode_execute_sale(params, accounts)?,
+            CANCEL_SELL => accounts.get(6).copied().map(Event::Canceled),
+            CANCEL_BUY => accounts.get(5).copied().map(Event::Canceled),
+            _ => None,
+        };
 
-    if accounts.len() != 12 {
-        debug!("invalid accounts for BuyInstruction");
-        return Ok(());
+        Ok(event.into_iter().collect())
     }
+}
 
-    let accts: Vec<_> = accounts.iter().map(ToString::to_string).collect();
-    let buyer = accts[0].clone();
-    let auction_house = accts[6].clone();
-    let metadata = accts[3].clone();
-    let price = i64::try_from(params.buyer_price)?;
-    let token_size = i64::try_from(params.token_size)?;
-    let slot = i64::try_from(slot)?;
-
-    let purchase_id: Option<Uuid> = client
-        .db()
-        .run({
-            move |db| {
-                purchases::table
-                    .filter(
-                        purchases::buyer
-                            .eq(buyer)
-                            .and(purchases::auction_house.eq(auction_house))
-                            .and(purchases::metadata.eq(metadata))
-                            .and(purchases::price.eq(price))
-                            .and(
-                                purchases::token_size
-                                    .eq(token_size)
-                                    .and(purchases::slot.eq(slot)),
-                            ),
-                    )
-                    .select(purchases::id)
-                    .first::<Uuid>(db)
-                    .optional()
-                    .context("failed to get purchase ids")
-            }
-        })
-        .await?;
-
-    let offer = Offer {
-        id: None,
-        trade_state: Owned(accts[7].clone()),
-        auction_house: Owned(accts[6].clone()),
-        marketplace_program: Owned(pubkeys::ME_HAUS.to_string()),
-        buyer: Owned(accts[0].clone()),
-        metadata: Owned(accts[3].clone()),
-        token_account: None,
-        purchase_id,
-        price,
-        token_size: params.token_size.try_into()?,
-        trade_state_bump: params.trade_state_bump.try_into()?,
-        created_at: Utc::now().naive_utc(),
-        canceled_at: None,
-        slot,
-        write_version: None,
-        expiry: match params.expiry {
-            e if e <= 0 => None,
-            _ => Some(util::unix_timestamp(params.expiry)?),
-        },
-    };
+async fn process_seller_trade_state(client: &Client, update: AccountUpdate) -> Result<()> {
+    let state = SellerTradeState::deserialize(&mut &update.data[8..])
+        .context("Failed to deserialize ME seller trade state")?;
 
-    upsert_into_offers_table(client, offer)
-        .await
-        .context("failed to insert offer")?;
+    magic_eden::process_seller_trade_state(
+        client,
+        update.key,
+        state,
+        update.slot,
+        update.write_version,
+    )
+    .await
+}
 
-    Ok(())
+async fn process_buyer_trade_state(client: &Client, update: AccountUpdate) -> Result<()> {
+    let state = BuyerTradeState::deserialize(&mut &update.data[8..])
+        .context("Failed to deserialize ME buyer trade state")?;
+
+    magic_eden::process_buyer_trade_state(
+        client,
+        update.key,
+        state,
+        update.slot,
+        update.write_version,
+    )
+    .await
 }
 
-async fn process_cancel_sale(client: &Client, accounts: &[Pubkey], slot: u64) -> Result<()> {
-    let accts: Vec<_> = accounts.iter().map(ToString::to_string).collect();
-    let canceled_at = Utc::now().naive_utc();
-    let trade_state = accts[6].clone();
-    let slot = i64::try_from(slot)?;
-
-    client
-        .db()
-        .run(move |db| {
-            update(
-                listings::table.filter(
-                    listings::trade_state
-                        .eq(trade_state)
-                        .and(listings::purchase_id.is_null())
-                        .and(listings::canceled_at.is_null()),
-                ),
-            )
-            .set((
-                listings::canceled_at.eq(Some(canceled_at)),
-                listings::slot.eq(slot),
-            ))
-            .execute(db)
-        })
-        .await
-        .context("failed to cancel ME listing ")?;
-
-    Ok(())
+async fn process_buyer_trade_state_v2(client: &Client, update: AccountUpdate) -> Result<()> {
+    let state = BuyerTradeStateV2::deserialize(&mut &update.data[8..])
+        .context("Failed to deserialize ME buyer trade state V2")?;
+
+    magic_eden::process_buyer_trade_state(
+        client,
+        update.key,
+        state.into(),
+        update.slot,
+        update.write_version,
+    )
+    .await
 }
 
-async fn process_cancel_buy(client: &Client, accounts: &[Pubkey], slot: u64) -> Result<()> {
-    let accts: Vec<_> = accounts.iter().map(ToString::to_string).collect();
-    let canceled_at = Utc::now().naive_utc();
-    let trade_state = accts[5].clone();
-    let slot = i64::try_from(slot)?;
-
-    client
-        .db()
-        .run(move |db| {
-            update(
-                offers::table.filter(
-                    offers::trade_state
-                        .eq(trade_state)
-                        .and(offers::purchase_id.is_null())
-                        .and(offers::canceled_at.is_null()),
-                ),
-            )
-            .set((
-                offers::canceled_at.eq(Some(canceled_at)),
-                offers::slot.eq(slot),
-            ))
-            .execute(db)
-        })
-        .await
-        .context("failed to cancel ME bid ")?;
-
-    Ok(())
+pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
+    // A drained trade state means the listing or bid backed by it is gone
+    if update.lamports == 0 || update.data.is_empty() {
+        return marketplace::close_trade_state(
+            client,
+            MagicEden::program(),
+            update.key,
+            update.slot,
+        )
+        .await;
+    }
+
+    let discrim: [u8; 8] = match update.data.get(..8) {
+        Some(d) => d.try_into()?,
+        None => return Ok(()),
+    };
+
+    match discrim {
+        SELLER_TRADE_STATE | SELLER_TRADE_STATE_V2 => {
+            process_seller_trade_state(client, update).await
+        },
+        BUYER_TRADE_STATE => process_buyer_trade_state(client, update).await,
+        BUYER_TRADE_STATE_V2 => process_buyer_trade_state_v2(client, update).await,
+        _ => Ok(()),
+    }
 }
 
 pub(crate) async fn process_instruction(
@@ -277,15 +272,157 @@ pub(crate) async fn process_instruction(
     accounts: &[Pubkey],
     slot: u64,
 ) -> Result<()> {
-    let (discriminator, params) = data.split_at(8);
-    let discriminator = <[u8; 8]>::try_from(discriminator)?;
-
-    match discriminator {
-        BUY => process_buy(client, params, accounts, slot).await,
-        SELL => process_sale(client, params, accounts, slot).await,
-        EXECUTE_SALE => process_execute_sale(client, params, accounts, slot).await,
-        CANCEL_SELL => process_cancel_sale(client, accounts, slot).await,
-        CANCEL_BUY => process_cancel_buy(client, accounts, slot).await,
-        _ => Ok(()),
+    marketplace::process_instruction::<MagicEden>(client, data, accounts, slot).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instruction(discriminator: [u8; 8], price: u64, expiry: i64) -> Vec<u8> {
+        let mut data = discriminator.to_vec();
+        data.extend([254, 253]);
+        data.extend(price.to_le_bytes());
+        data.extend(1_u64.to_le_bytes());
+        data.extend(expiry.to_le_bytes());
+        data
+    }
+
+    fn accounts(n: usize) -> Vec<Pubkey> {
+        (0..n).map(|_| Pubkey::new_unique()).collect()
+    }
+
+    #[test]
+    fn test_decode_sell() {
+        let accts = accounts(14);
+        let events = MagicEden::decode_instruction(&instruction(SELL, 1_000, 0), &accts).unwrap();
+
+        assert_eq!(events, vec![Event::Listed(NewListing {
+            trade_state: accts[8],
+            auction_house: accts[7],
+            seller: accts[0],
+            metadata: accts[5],
+            price: 1_000,
+            token_size: 1,
+            trade_state_bump: 254,
+            expiry: Some(0),
+        })]);
+    }
+
+    #[test]
+    fn test_decode_execute_sale() {
+        let accts = accounts(20);
+        let events =
+            MagicEden::decode_instruction(&instruction(EXECUTE_SALE, 5_000, -1), &accts).unwrap();
+
+        assert_eq!(events, vec![Event::Sold(Sale {
+            buyer: accts[0],
+            seller: accts[1],
+            auction_house: accts[9],
+            metadata: accts[5],
+            buyer_trade_state: accts[11],
+            seller_trade_state: accts[13],
+            price: 5_000,
+            token_size: 1,
+        })]);
+    }
+
+    #[test]
+    fn test_decode_cancel() {
+        let accts = accounts(12);
+
+        assert_eq!(
+            MagicEden::decode_instruction(&CANCEL_SELL, &accts).unwrap(),
+            vec![Event::Canceled(accts[6])]
+        );
+        assert_eq!(
+            MagicEden::decode_instruction(&CANCEL_BUY, &accts).unwrap(),
+            vec![Event::Canceled(accts[5])]
+        );
+    }
+
+    #[test]
+    fn test_decode_seller_trade_state() {
+        let (house, seller, mint) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+
+        let mut data = SELLER_TRADE_STATE_V2.to_vec();
+        data.extend(house.as_ref());
+        data.extend(seller.as_ref());
+        data.extend(Pubkey::new_unique().as_ref());
+        data.extend(2_000_u64.to_le_bytes());
+        data.extend(mint.as_ref());
+        data.extend(Pubkey::new_unique().as_ref());
+        data.extend(1_u64.to_le_bytes());
+        data.push(253);
+        data.extend(42_i64.to_le_bytes());
+        // V2 appends the payment mint
+        data.extend(Pubkey::new_unique().as_ref());
+
+        let state = SellerTradeState::deserialize(&mut &data[8..]).unwrap();
+
+        assert_eq!(state.auction_house, house);
+        assert_eq!(state.seller, seller);
+        assert_eq!(state.buyer_price, 2_000);
+        assert_eq!(state.token_mint, mint);
+        assert_eq!(state.token_size, 1);
+        assert_eq!(state.bump, 253);
+        assert_eq!(state.expiry, 42);
+    }
+
+    #[test]
+    fn test_decode_buyer_trade_state_v2() {
+        let (house, buyer, mint) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+
+        let mut data = BUYER_TRADE_STATE_V2.to_vec();
+        data.extend(house.as_ref());
+        data.extend(buyer.as_ref());
+        data.extend(Pubkey::new_unique().as_ref());
+        data.extend(3_000_u64.to_le_bytes());
+        data.extend(mint.as_ref());
+        data.extend(1_u64.to_le_bytes());
+        data.push(252);
+        // Royalty basis points precede the expiry in V2
+        data.extend(500_u16.to_le_bytes());
+        data.extend((-1_i64).to_le_bytes());
+
+        let state: BuyerTradeState = BuyerTradeStateV2::deserialize(&mut &data[8..])
+            .unwrap()
+            .into();
+
+        assert_eq!(state.auction_house, house);
+        assert_eq!(state.buyer, buyer);
+        assert_eq!(state.buyer_price, 3_000);
+        assert_eq!(state.token_mint, mint);
+        assert_eq!(state.token_size, 1);
+        assert_eq!(state.bump, 252);
+        assert_eq!(state.expiry, -1);
+    }
+
+    #[test]
+    fn test_skip_malformed_instructions() {
+        // Buys must have exactly 12 accounts
+        let events =
+            MagicEden::decode_instruction(&instruction(BUY, 1_000, 0), &accounts(11)).unwrap();
+        assert!(events.is_empty());
+
+        assert!(
+            MagicEden::decode_instruction(&[0; 4], &accounts(12))
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            MagicEden::decode_instruction(&[0; 8], &accounts(12))
+                .unwrap()
+                .is_empty()
+        );
+        assert!(MagicEden::decode_instruction(&SELL, &accounts(12)).is_err());
     }
 }
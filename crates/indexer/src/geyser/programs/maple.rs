@@ -1,91 +1,224 @@
-use anchor_lang_v0_24::AccountDeserialize;
-// use solana_program::program_pack::Pack;
+use anchor_lang_v0_24::{AccountDeserialize, Discriminator};
 use syrup_cpi::{Globals, Lender, Loan, OpenTermLoan, Pool, WithdrawalRequest};
 
+use super::{accounts::maple, AccountUpdate, Client};
 use crate::prelude::*;
 
-const GLOBALS_SIZE: usize = 1226;
-const LENDER_SIZE: usize = 240;
-const LOAN_SIZE: usize = 376;
-const OPEN_TERM_LOAN_SIZE: usize = 432;
-const POOL_SIZE: usize = 397;
-const WITHDRAWAL_REQUEST_SIZE: usize = 216;
-
-// instruction ids
-const WITHDRAWAL_REQUEST_INITIALIZE: u8 = 21; // or not?
-
-use super::{accounts::maple, instructions::maple as maple_instruction, AccountUpdate, Client};
-// use crate::prelude::*;
-
-async fn process_globals(client: &Client, update: AccountUpdate) -> Result<()> {
-    let globals = Globals::try_deserialize_unchecked(&mut update.data.as_slice())
-        .context("failed to deserialize globals account!")?;
-
-    maple::process_globals(client, update.key, globals, update.slot).await
+#[allow(clippy::large_enum_variant)]
+enum MapleAccount {
+    Globals(Globals),
+    Lender(Lender),
+    Loan(Loan),
+    OpenTermLoan(OpenTermLoan),
+    Pool(Pool),
+    WithdrawalRequest(WithdrawalRequest),
 }
 
-async fn process_lender(client: &Client, update: AccountUpdate) -> Result<()> {
-    let lender = Lender::try_deserialize_unchecked(&mut update.data.as_slice())
-        .context("failed to deserialize lender account!")?;
-
-    maple::process_lender(client, update.key, lender, update.slot).await
+fn deserialize<T: AccountDeserialize>(mut data: &[u8], name: &str) -> Result<T> {
+    T::try_deserialize(&mut data).with_context(|| format!("Failed to deserialize Maple {name}"))
 }
 
-async fn process_loan(client: &Client, update: AccountUpdate) -> Result<()> {
-    let loan = Loan::try_deserialize_unchecked(&mut update.data.as_slice())
-        .context("failed to deserialize loan account!")?;
-
-    maple::process_loan(client, update.key, loan, update.slot).await
+/// Decode a Maple account by its discriminator, or return `None` for closed
+/// accounts and account types which are not indexed
+fn decode(data: &[u8]) -> Result<Option<MapleAccount>> {
+    let discrim: [u8; 8] = match data.get(..8) {
+        Some(d) => d.try_into()?,
+        // Closed accounts have no data left to index
+        None => return Ok(None),
+    };
+
+    Ok(Some(match discrim {
+        d if d == Globals::discriminator() => MapleAccount::Globals(deserialize(data, "globals")?),
+        d if d == Lender::discriminator() => MapleAccount::Lender(deserialize(data, "lender")?),
+        d if d == Loan::discriminator() => MapleAccount::Loan(deserialize(data, "loan")?),
+        d if d == OpenTermLoan::discriminator() => {
+            MapleAccount::OpenTermLoan(deserialize(data, "open-term loan")?)
+        },
+        d if d == Pool::discriminator() => MapleAccount::Pool(deserialize(data, "pool")?),
+        d if d == WithdrawalRequest::discriminator() => {
+            MapleAccount::WithdrawalRequest(deserialize(data, "withdrawal request")?)
+        },
+        _ => return Ok(None),
+    }))
 }
 
-async fn process_open_term_loan(client: &Client, update: AccountUpdate) -> Result<()> {
-    let loan = OpenTermLoan::try_deserialize_unchecked(&mut update.data.as_slice())
-        .context("failed to deserialize loan account!")?;
+pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
+    let (key, slot, write_version) = (update.key, update.slot, update.write_version);
 
-    maple::process_open_term_loan(client, update.key, loan, update.slot).await
+    match decode(&update.data)? {
+        Some(MapleAccount::Globals(g)) => {
+            maple::process_globals(client, key, &g, slot, write_version).await
+        },
+        Some(MapleAccount::Lender(l)) => {
+            maple::process_lender(client, key, &l, slot, write_version).await
+        },
+        Some(MapleAccount::Loan(l)) => {
+            maple::process_loan(client, key, &l, slot, write_version).await
+        },
+        Some(MapleAccount::OpenTermLoan(l)) => {
+            maple::process_open_term_loan(client, key, &l, slot, write_version).await
+        },
+        Some(MapleAccount::Pool(p)) => {
+            maple::process_pool(client, key, &p, slot, write_version).await
+        },
+        Some(MapleAccount::WithdrawalRequest(r)) => {
+            maple::process_withdrawal_request(client, key, &r, slot, write_version).await
+        },
+        None => Ok(()),
+    }
 }
 
-async fn process_pool(client: &Client, update: AccountUpdate) -> Result<()> {
-    let pool = Pool::try_deserialize_unchecked(&mut update.data.as_slice())
-        .context("failed to deserialize pool account!")?;
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    maple::process_pool(client, update.key, pool, update.slot).await
-}
+    /// Build the data of an account of the given type with every field
+    /// zeroed, for tests to fill in the fields they check
+    fn account<T: Discriminator>() -> Vec<u8> {
+        let mut data = T::discriminator().to_vec();
+        data.resize(8 + 4096, 0);
+        data
+    }
 
-async fn process_withdrawal_request(client: &Client, update: AccountUpdate) -> Result<()> {
-    let request = WithdrawalRequest::try_deserialize_unchecked(&mut update.data.as_slice())
-        .context("failed to deserialize withdrawal request account!")?;
+    #[test]
+    fn test_decode_discriminators() {
+        assert!(matches!(
+            decode(&account::<Globals>()).unwrap(),
+            Some(MapleAccount::Globals(_))
+        ));
+        assert!(matches!(
+            decode(&account::<Lender>()).unwrap(),
+            Some(MapleAccount::Lender(_))
+        ));
+        assert!(matches!(
+            decode(&account::<Loan>()).unwrap(),
+            Some(MapleAccount::Loan(_))
+        ));
+        assert!(matches!(
+            decode(&account::<OpenTermLoan>()).unwrap(),
+            Some(MapleAccount::OpenTermLoan(_))
+        ));
+        assert!(matches!(
+            decode(&account::<Pool>()).unwrap(),
+            Some(MapleAccount::Pool(_))
+        ));
+        assert!(matches!(
+            decode(&account::<WithdrawalRequest>()).unwrap(),
+            Some(MapleAccount::WithdrawalRequest(_))
+        ));
+    }
 
-    maple::process_withdrawal_request(client, update.key, request, update.slot).await
-}
+    #[test]
+    fn test_skip_unindexed_accounts() {
+        // Closed accounts
+        assert!(decode(&[]).unwrap().is_none());
+        assert!(decode(&[0; 4]).unwrap().is_none());
+        // Unknown account types
+        assert!(decode(&[0; 64]).unwrap().is_none());
+
+        // Truncated accounts of a known type
+        let err = decode(&Pool::discriminator()).err().unwrap();
+        assert!(err.to_string().contains("Maple pool"), "{err:?}");
+    }
 
-// TODO use anchor discriminator instead of relying on account length
-pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
-    match update.data.len() {
-        GLOBALS_SIZE => process_globals(client, update).await,
-        LENDER_SIZE => process_lender(client, update).await,
-        LOAN_SIZE => process_loan(client, update).await,
-        OPEN_TERM_LOAN_SIZE => process_open_term_loan(client, update).await,
-        POOL_SIZE => process_pool(client, update).await,
-        WITHDRAWAL_REQUEST_SIZE => process_withdrawal_request(client, update).await,
-        _ => Ok(()),
+    #[test]
+    fn test_pool_row() {
+        let mut pool = match decode(&account::<Pool>()).unwrap() {
+            Some(MapleAccount::Pool(p)) => p,
+            _ => unreachable!(),
+        };
+        let key = Pubkey::new_unique();
+        pool.globals = Pubkey::new_unique();
+        pool.pool_delegate = Pubkey::new_unique();
+        pool.base_mint = Pubkey::new_unique();
+        pool.shares_mint = Pubkey::new_unique();
+        pool.locker = Pubkey::new_unique();
+        pool.state.total_value = 5_000;
+
+        let row = maple::pool_row(key, &pool, 10, 2).unwrap();
+
+        assert_eq!(row.address, key.to_string());
+        assert_eq!(row.globals, pool.globals.to_string());
+        assert_eq!(row.pool_delegate, pool.pool_delegate.to_string());
+        assert_eq!(row.base_mint, pool.base_mint.to_string());
+        assert_eq!(row.shares_mint, pool.shares_mint.to_string());
+        assert_eq!(row.locker, pool.locker.to_string());
+        assert_eq!(row.total_value, 5_000);
+        assert_eq!((row.slot, row.write_version), (10, 2));
+
+        // Values Postgres can't store are rejected rather than wrapped
+        pool.state.total_value = u64::MAX.into();
+        assert!(maple::pool_row(key, &pool, 10, 2).is_err());
     }
-}
 
-pub(crate) async fn process_instruction(
-    client: &Client,
-    data: &[u8],
-    accounts: &[Pubkey],
-    slot: u64,
-) -> Result<()> {
-    let (&discriminator, _) = data
-        .split_first()
-        .context("invalid spl token instruction")?;
-    debug!("Maple ix discriminator: {}", discriminator);
-    match discriminator {
-        WITHDRAWAL_REQUEST_INITIALIZE => {
-            maple_instruction::process_withdrawal_instruction(client, accounts, slot).await
-        },
-        _ => Ok(()),
+    #[test]
+    fn test_loan_rows() {
+        let mut loan = match decode(&account::<Loan>()).unwrap() {
+            Some(MapleAccount::Loan(l)) => l,
+            _ => unreachable!(),
+        };
+        let key = Pubkey::new_unique();
+        loan.pool = Pubkey::new_unique();
+        loan.borrower = Pubkey::new_unique();
+        loan.principal = 1_000;
+
+        let row = maple::loan_row(key, &loan, 10, 2).unwrap();
+
+        assert_eq!(row.address, key.to_string());
+        assert_eq!(row.pool, loan.pool.to_string());
+        assert_eq!(row.borrower, loan.borrower.to_string());
+        assert_eq!(row.principal, 1_000);
+        assert_eq!(row.state, format!("{:?}", loan.state));
+
+        let mut loan = match decode(&account::<OpenTermLoan>()).unwrap() {
+            Some(MapleAccount::OpenTermLoan(l)) => l,
+            _ => unreachable!(),
+        };
+        loan.pool = Pubkey::new_unique();
+        loan.borrower = Pubkey::new_unique();
+        loan.principal = 2_000;
+
+        let row = maple::open_term_loan_row(key, &loan, 10, 2).unwrap();
+
+        assert_eq!(row.pool, loan.pool.to_string());
+        assert_eq!(row.borrower, loan.borrower.to_string());
+        assert_eq!(row.principal, 2_000);
+        assert_eq!(row.state, format!("{:?}", loan.state));
+    }
+
+    #[test]
+    fn test_lender_rows() {
+        let mut globals = match decode(&account::<Globals>()).unwrap() {
+            Some(MapleAccount::Globals(g)) => g,
+            _ => unreachable!(),
+        };
+        globals.admin = Pubkey::new_unique();
+
+        let row = maple::globals_row(Pubkey::new_unique(), &globals, 10, 2).unwrap();
+        assert_eq!(row.admin, globals.admin.to_string());
+
+        let mut lender = match decode(&account::<Lender>()).unwrap() {
+            Some(MapleAccount::Lender(l)) => l,
+            _ => unreachable!(),
+        };
+        lender.owner = Pubkey::new_unique();
+        lender.pool = Pubkey::new_unique();
+
+        let row = maple::lender_row(Pubkey::new_unique(), &lender, 10, 2).unwrap();
+        assert_eq!(row.owner, lender.owner.to_string());
+        assert_eq!(row.pool, lender.pool.to_string());
+
+        let mut request = match decode(&account::<WithdrawalRequest>()).unwrap() {
+            Some(MapleAccount::WithdrawalRequest(r)) => r,
+            _ => unreachable!(),
+        };
+        request.pool = lender.pool;
+        request.lender = Pubkey::new_unique();
+        request.shares = 300;
+
+        let row = maple::withdrawal_request_row(Pubkey::new_unique(), &request, 10, 2).unwrap();
+        assert_eq!(row.pool, lender.pool.to_string());
+        assert_eq!(row.lender, request.lender.to_string());
+        assert_eq!(row.shares, 300);
     }
 }
@@ -1,18 +1,22 @@
+use borsh::BorshDeserialize;
 use metaplex_token_metadata::{
     state::{Key as MetaplexKey, Metadata as MetaplexMetadata},
     utils::try_from_slice_checked as metaplex_try_from_slice_checked,
 };
 use mpl_token_metadata::{
+    instruction::UpdateMetadataAccountArgsV2,
     state::{
-        Creator, Data, Edition, Key, MasterEditionV1, MasterEditionV2, Metadata, MAX_EDITION_LEN,
-        MAX_MASTER_EDITION_LEN, MAX_METADATA_LEN,
+        Collection, CollectionAuthorityRecord, Creator, Data, Edition, EditionMarker, Key,
+        MasterEditionV1, MasterEditionV2, Metadata, TokenStandard, UseAuthorityRecord, Uses,
+        MAX_EDITION_LEN, MAX_MASTER_EDITION_LEN, MAX_METADATA_LEN,
     },
     utils::try_from_slice_checked,
 };
 
 use super::{
-    accounts::{edition, metadata},
-    AccountUpdate, Client,
+    accounts::{authority_record, edition, metadata, token_record},
+    instructions::metadata as metadata_instruction,
+    AccountFilter, AccountType, AccountUpdate, Client,
 };
 use crate::prelude::*;
 
@@ -20,6 +24,162 @@ const METADATA: u8 = Key::MetadataV1 as u8;
 const EDITION_V1: u8 = Key::EditionV1 as u8;
 const MASTER_EDITION_V1: u8 = Key::MasterEditionV1 as u8;
 const MASTER_EDITION_V2: u8 = Key::MasterEditionV2 as u8;
+const EDITION_MARKER: u8 = Key::EditionMarker as u8;
+const USE_AUTHORITY_RECORD: u8 = Key::UseAuthorityRecord as u8;
+const COLLECTION_AUTHORITY_RECORD: u8 = Key::CollectionAuthorityRecord as u8;
+// Added to the metadata program after the version of mpl_token_metadata we
+// depend on, see mpl_token_metadata::state::Key
+const TOKEN_RECORD: u8 = 11;
+
+macro_rules! key_type {
+    ($name:literal, $key:expr) => {
+        AccountType {
+            name: $name,
+            filters: &[AccountFilter::Memcmp(0, &[$key])],
+        }
+    };
+}
+
+/// Account types fetched when backfilling, selected by their key byte
+pub(crate) const ACCOUNT_TYPES: &[AccountType] = &[
+    key_type!("metadata", METADATA),
+    key_type!("edition-v1", EDITION_V1),
+    key_type!("master-edition-v1", MASTER_EDITION_V1),
+    key_type!("master-edition-v2", MASTER_EDITION_V2),
+    key_type!("edition-marker", EDITION_MARKER),
+    key_type!("use-authority-record", USE_AUTHORITY_RECORD),
+    key_type!("collection-authority-record", COLLECTION_AUTHORITY_RECORD),
+    key_type!("token-record", TOKEN_RECORD),
+];
+
+// Instruction discriminators, see mpl_token_metadata::instruction::MetadataInstruction
+const SIGN_METADATA: u8 = 7;
+const MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN: u8 = 11;
+const MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY: u8 = 13;
+const UPDATE_METADATA_ACCOUNT_V2: u8 = 15;
+const VERIFY_COLLECTION: u8 = 18;
+const APPROVE_USE_AUTHORITY: u8 = 20;
+const REVOKE_USE_AUTHORITY: u8 = 21;
+const UNVERIFY_COLLECTION: u8 = 22;
+const APPROVE_COLLECTION_AUTHORITY: u8 = 23;
+const REVOKE_COLLECTION_AUTHORITY: u8 = 24;
+const BURN_NFT: u8 = 29;
+const SET_AND_VERIFY_SIZED_COLLECTION_ITEM: u8 = 32;
+const BURN: u8 = 41;
+
+/// Token standards as of programmable NFTs
+#[derive(BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+enum ProgrammableTokenStandard {
+    NonFungible,
+    FungibleAsset,
+    Fungible,
+    NonFungibleEdition,
+    ProgrammableNonFungible,
+}
+
+#[derive(BorshDeserialize, Debug, Clone, Copy)]
+enum CollectionDetails {
+    V1 { _size: u64 },
+}
+
+#[derive(BorshDeserialize, Debug, Clone, Copy)]
+enum ProgrammableConfig {
+    V1 { rule_set: Option<Pubkey> },
+}
+
+/// A metadata account as of programmable NFTs, which `mpl_token_metadata`
+/// fails to deserialize because of the new token standard
+#[derive(BorshDeserialize, Debug, Clone)]
+struct ProgrammableMetadata {
+    _key: Key,
+    update_authority: Pubkey,
+    mint: Pubkey,
+    data: Data,
+    primary_sale_happened: bool,
+    is_mutable: bool,
+    edition_nonce: Option<u8>,
+    token_standard: Option<ProgrammableTokenStandard>,
+    collection: Option<Collection>,
+    uses: Option<Uses>,
+    _collection_details: Option<CollectionDetails>,
+    programmable_config: Option<ProgrammableConfig>,
+}
+
+/// Arguments of the `Burn` instruction added alongside programmable NFTs
+#[derive(BorshDeserialize, Debug, Clone, Copy)]
+enum BurnArgs {
+    V1 { amount: u64 },
+}
+
+/// The programmable configuration of a pNFT's metadata
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Programmable {
+    /// The rule set governing transfers, if any
+    pub rule_set: Option<Pubkey>,
+}
+
+#[derive(BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TokenState {
+    Unlocked,
+    Locked,
+    Listed,
+}
+
+#[derive(BorshDeserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TokenDelegateRole {
+    Sale,
+    Transfer,
+    Utility,
+    Staking,
+    Standard,
+    LockedTransfer,
+    Migration,
+}
+
+/// The lock and delegate state of a pNFT's token account
+#[derive(BorshDeserialize, Debug, Clone, Copy)]
+pub(crate) struct TokenRecord {
+    _key: u8,
+    pub bump: u8,
+    pub state: TokenState,
+    pub rule_set_revision: Option<u64>,
+    pub delegate: Option<Pubkey>,
+    pub delegate_role: Option<TokenDelegateRole>,
+    pub locked_transfer: Option<Pubkey>,
+}
+
+fn convert_programmable(m: ProgrammableMetadata) -> (Metadata, Option<Programmable>) {
+    let token_standard = m.token_standard.and_then(|t| match t {
+        ProgrammableTokenStandard::NonFungible => Some(TokenStandard::NonFungible),
+        ProgrammableTokenStandard::FungibleAsset => Some(TokenStandard::FungibleAsset),
+        ProgrammableTokenStandard::Fungible => Some(TokenStandard::Fungible),
+        ProgrammableTokenStandard::NonFungibleEdition => Some(TokenStandard::NonFungibleEdition),
+        ProgrammableTokenStandard::ProgrammableNonFungible => None,
+    });
+
+    let programmable = (m.token_standard
+        == Some(ProgrammableTokenStandard::ProgrammableNonFungible))
+    .then(|| Programmable {
+        rule_set: m
+            .programmable_config
+            .and_then(|ProgrammableConfig::V1 { rule_set }| rule_set),
+    });
+
+    let metadata = Metadata {
+        key: Key::MetadataV1,
+        update_authority: m.update_authority,
+        mint: m.mint,
+        data: m.data,
+        primary_sale_happened: m.primary_sale_happened,
+        is_mutable: m.is_mutable,
+        edition_nonce: m.edition_nonce,
+        token_standard,
+        collection: m.collection,
+        uses: m.uses,
+    };
+
+    (metadata, programmable)
+}
 
 async fn process_metadata(client: &Client, update: AccountUpdate) -> Result<()> {
     // Deserializing using mpl_token_metadata crate
@@ -28,6 +188,23 @@ async fn process_metadata(client: &Client, update: AccountUpdate) -> Result<()>
             client,
             update.key,
             metadata,
+            None,
+            update.slot,
+            update.write_version,
+        )
+        .await;
+    }
+
+    // Deserializing programmable NFTs, whose token standard is unknown to
+    // mpl_token_metadata
+    if let Ok(m) = ProgrammableMetadata::deserialize(&mut update.data.as_slice()) {
+        let (metadata, programmable) = convert_programmable(m);
+
+        return metadata::process(
+            client,
+            update.key,
+            metadata,
+            programmable,
             update.slot,
             update.write_version,
         )
@@ -70,6 +247,7 @@ async fn process_metadata(client: &Client, update: AccountUpdate) -> Result<()>
         client,
         update.key,
         metaplex_metadata,
+        None,
         update.slot,
         update.write_version,
     )
@@ -109,6 +287,62 @@ async fn process_master_edition_v2(client: &Client, update: AccountUpdate) -> Re
     edition::process_master(client, update.key, master_edition, update.slot).await
 }
 
+async fn process_edition_marker(client: &Client, update: AccountUpdate) -> Result<()> {
+    let marker = EditionMarker::deserialize(&mut update.data.as_slice())
+        .context("Failed to parse edition marker data")?;
+
+    edition::process_marker(
+        client,
+        update.key,
+        marker,
+        update.slot,
+        update.write_version,
+    )
+    .await
+}
+
+async fn process_use_authority_record(client: &Client, update: AccountUpdate) -> Result<()> {
+    let record = UseAuthorityRecord::deserialize(&mut update.data.as_slice())
+        .context("Failed to parse use authority record data")?;
+
+    authority_record::process_use_authority(
+        client,
+        update.key,
+        record,
+        update.slot,
+        update.write_version,
+    )
+    .await
+}
+
+async fn process_collection_authority_record(client: &Client, update: AccountUpdate) -> Result<()> {
+    let record = CollectionAuthorityRecord::deserialize(&mut update.data.as_slice())
+        .context("Failed to parse collection authority record data")?;
+
+    authority_record::process_collection_authority(
+        client,
+        update.key,
+        record,
+        update.slot,
+        update.write_version,
+    )
+    .await
+}
+
+async fn process_token_record(client: &Client, update: AccountUpdate) -> Result<()> {
+    let record = TokenRecord::deserialize(&mut update.data.as_slice())
+        .context("Failed to parse token record data")?;
+
+    token_record::process(
+        client,
+        update.key,
+        record,
+        update.slot,
+        update.write_version,
+    )
+    .await
+}
+
 pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
     let first_byte = update.data.first().copied();
 
@@ -118,6 +352,12 @@ pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()
         Some(EDITION_V1) => process_edition(client, update).await,
         Some(MASTER_EDITION_V1) => process_master_edition_v1(client, update).await,
         Some(MASTER_EDITION_V2) => process_master_edition_v2(client, update).await,
+        Some(EDITION_MARKER) => process_edition_marker(client, update).await,
+        Some(USE_AUTHORITY_RECORD) => process_use_authority_record(client, update).await,
+        Some(COLLECTION_AUTHORITY_RECORD) => {
+            process_collection_authority_record(client, update).await
+        },
+        Some(TOKEN_RECORD) => process_token_record(client, update).await,
         Some(b) => {
             trace!("Unhandled metadata key byte {:02x}", b);
 
@@ -125,3 +365,62 @@ pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()
         },
     }
 }
+
+pub(crate) async fn process_instruction(
+    client: &Client,
+    data: &[u8],
+    accounts: &[Pubkey],
+    slot: u64,
+) -> Result<()> {
+    let (&discriminator, params) = data.split_first().context("invalid metadata instruction")?;
+
+    match discriminator {
+        SIGN_METADATA => metadata_instruction::process_sign_metadata(client, accounts, slot).await,
+        UPDATE_METADATA_ACCOUNT_V2 => {
+            let args = UpdateMetadataAccountArgsV2::deserialize(&mut &*params)
+                .context("Failed to deserialize UpdateMetadataAccountV2 args")?;
+
+            metadata_instruction::process_update_metadata_account(client, accounts, args, slot)
+                .await
+        },
+        VERIFY_COLLECTION => {
+            metadata_instruction::process_verify_collection(client, accounts, slot).await
+        },
+        SET_AND_VERIFY_SIZED_COLLECTION_ITEM => {
+            metadata_instruction::process_set_and_verify_sized_collection_item(
+                client, accounts, slot,
+            )
+            .await
+        },
+        UNVERIFY_COLLECTION => {
+            metadata_instruction::process_unverify_collection(client, accounts, slot).await
+        },
+        BURN_NFT => metadata_instruction::process_burn_nft(client, accounts, slot).await,
+        BURN => {
+            let BurnArgs::V1 { amount } =
+                BurnArgs::deserialize(&mut &*params).context("Failed to deserialize Burn args")?;
+
+            metadata_instruction::process_burn(client, accounts, amount, slot).await
+        },
+        MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_TOKEN
+        | MINT_NEW_EDITION_FROM_MASTER_EDITION_VIA_VAULT_PROXY => {
+            let edition =
+                u64::deserialize(&mut &*params).context("Failed to deserialize edition number")?;
+
+            metadata_instruction::process_print_edition(client, accounts, edition).await
+        },
+        APPROVE_USE_AUTHORITY => {
+            metadata_instruction::process_approve_use_authority(client, accounts).await
+        },
+        REVOKE_USE_AUTHORITY => {
+            metadata_instruction::process_revoke_use_authority(client, accounts, slot).await
+        },
+        APPROVE_COLLECTION_AUTHORITY => {
+            metadata_instruction::process_approve_collection_authority(client, accounts).await
+        },
+        REVOKE_COLLECTION_AUTHORITY => {
+            metadata_instruction::process_revoke_collection_authority(client, accounts, slot).await
+        },
+        _ => Ok(()),
+    }
+}
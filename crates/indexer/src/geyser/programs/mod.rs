@@ -1,6 +1,11 @@
+pub mod anchor;
 pub mod auction;
 pub mod auction_house;
+pub mod auctioneer;
+pub mod bubblegum;
+pub mod candy_guard;
 pub mod candy_machine;
+pub mod candy_machine_v3;
 pub mod cardinal_paid_claim_approver;
 pub mod cardinal_time_invalidator;
 pub mod cardinal_token_manager;
@@ -17,8 +22,14 @@ pub mod namespaces;
 pub mod reward_center;
 pub mod spl_governance;
 pub mod token;
+pub mod token_2022;
+pub mod token_auth_rules;
 pub mod token_bonding;
 pub mod tribeca_govern;
 pub mod tribeca_locked_voter;
 
-pub(self) use super::{accounts, instructions, AccountUpdate, Client};
+pub(self) use super::{
+    accounts, instructions, marketplace,
+    registry::{AccountFilter, AccountType},
+    AccountUpdate, Client,
+};
@@ -1,9 +1,11 @@
 use anchor_lang_v0_24::{AccountDeserialize, Discriminator};
-use hpl_reward_center::state::{Listing, Offer, RewardCenter};
+use hpl_reward_center::state::{self, Listing, Offer};
+use indexer_core::pubkeys;
 
 use super::{
     accounts::hpl_reward_center as reward_center,
     instructions::hpl_reward_center::{close_listing, close_offer, execute_sale},
+    marketplace::{self, Event, Marketplace},
     AccountUpdate, Client,
 };
 use crate::prelude::*;
@@ -12,8 +14,35 @@ const EXECUTE_SALE: [u8; 8] = [37, 74, 217, 157, 79, 49, 35, 6];
 const CLOSE_OFFER: [u8; 8] = [191, 72, 67, 35, 239, 209, 97, 132];
 const CLOSE_LISTING: [u8; 8] = [33, 15, 192, 81, 78, 175, 159, 97];
 
+/// The Holaplex reward center program, which wraps an auction house to pay
+/// out rewards on sales
+pub(crate) struct RewardCenter;
+
+impl Marketplace for RewardCenter {
+    fn program() -> Pubkey {
+        pubkeys::REWARD_CENTER
+    }
+
+    fn decode_instruction(data: &[u8], accounts: &[Pubkey]) -> Result<Vec<Event>> {
+        let discriminator: [u8; 8] = match data.get(..8) {
+            Some(d) => d.try_into()?,
+            None => return Ok(vec![]),
+        };
+
+        // Sales only carry the accounts involved, so execute_sale looks up
+        // the listing price and indexes the sale itself
+        let event = match discriminator {
+            CLOSE_LISTING => accounts.get(9).copied().map(Event::Canceled),
+            CLOSE_OFFER => accounts.get(12).copied().map(Event::Canceled),
+            _ => None,
+        };
+
+        Ok(event.into_iter().collect())
+    }
+}
+
 async fn process_reward_center(client: &Client, update: AccountUpdate) -> Result<()> {
-    let reward_center: RewardCenter = RewardCenter::try_deserialize(&mut update.data.as_slice())
+    let reward_center = state::RewardCenter::try_deserialize(&mut update.data.as_slice())
         .context("Failed to deserialize reward center data")?;
 
     reward_center::reward_center::process(
@@ -54,6 +83,8 @@ pub(crate) async fn process_instruction(
     accounts: &[Pubkey],
     slot: u64,
 ) -> Result<()> {
+    marketplace::process_instruction::<RewardCenter>(client, data, accounts, slot).await?;
+
     let discriminator: [u8; 8] = data[..8].try_into()?;
     let params = data[8..].to_vec();
 
@@ -69,7 +100,9 @@ pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()
     let discrim: [u8; 8] = update.data[..8].try_into()?;
 
     match discrim {
-        d if d == RewardCenter::discriminator() => process_reward_center(client, update).await,
+        d if d == state::RewardCenter::discriminator() => {
+            process_reward_center(client, update).await
+        },
         d if d == Listing::discriminator() => process_listing(client, update).await,
         d if d == Offer::discriminator() => process_offer(client, update).await,
         _ => Ok(()),
@@ -1,10 +1,28 @@
 use solana_program::program_pack::Pack;
 use spl_token::state::{Account as TokenAccount, Mint as MintAccount};
 
-use super::{accounts::token, instructions::token as token_instruction, AccountUpdate, Client};
+use super::{
+    accounts::token,
+    instructions::token::{self as token_instruction, Transfer},
+    AccountFilter, AccountType, AccountUpdate, Client,
+};
 use crate::prelude::*;
 
+const TRANSFER: u8 = 3;
 const BURN: u8 = 8;
+const TRANSFER_CHECKED: u8 = 12;
+
+/// Account types fetched when backfilling, selected by their size
+pub(crate) const ACCOUNT_TYPES: &[AccountType] = &[
+    AccountType {
+        name: "token-account",
+        filters: &[AccountFilter::DataSize(TokenAccount::LEN)],
+    },
+    AccountType {
+        name: "mint",
+        filters: &[AccountFilter::DataSize(MintAccount::LEN)],
+    },
+];
 async fn process_token(client: &Client, update: AccountUpdate) -> Result<()> {
     let token_account = TokenAccount::unpack_unchecked(&update.data)
         .context("Failed to deserialize token account data!")?;
@@ -38,12 +56,43 @@ pub(crate) async fn process_instruction(
     accounts: &[Pubkey],
     slot: u64,
 ) -> Result<()> {
-    let (&discriminator, _) = data
+    let (&discriminator, params) = data
         .split_first()
         .context("invalid spl token instruction")?;
 
     match discriminator {
+        TRANSFER => {
+            // Only single-token transfers can move an NFT
+            if params.get(..8) != Some(&1_u64.to_le_bytes()[..]) || accounts.len() < 3 {
+                return Ok(());
+            }
+
+            let transfer = Transfer {
+                source: accounts[0],
+                mint: None,
+                destination: accounts[1],
+                authority: accounts[2],
+            };
+
+            token_instruction::process_transfer_instruction(client, transfer, slot).await
+        },
         BURN => token_instruction::process_burn_instruction(client, accounts, slot).await,
+        TRANSFER_CHECKED => {
+            // Amount followed by the mint decimals, which must be zero for
+            // an NFT
+            if params.get(..9) != Some(&[1, 0, 0, 0, 0, 0, 0, 0, 0][..]) || accounts.len() < 4 {
+                return Ok(());
+            }
+
+            let transfer = Transfer {
+                source: accounts[0],
+                mint: Some(accounts[1]),
+                destination: accounts[2],
+                authority: accounts[3],
+            };
+
+            token_instruction::process_transfer_instruction(client, transfer, slot).await
+        },
         _ => Ok(()),
     }
 }
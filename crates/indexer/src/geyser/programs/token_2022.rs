@@ -0,0 +1,236 @@
+use solana_program::program_pack::Pack;
+use spl_token::state::{Account as TokenAccount, Mint as MintAccount, Multisig};
+
+use super::{
+    accounts::{token, token_2022},
+    AccountUpdate, Client,
+};
+use crate::prelude::*;
+
+/// Extended mints and accounts are padded to the length of a token account
+/// and followed by a one-byte account type, then the extension TLV entries
+const ACCOUNT_TYPE_OFFSET: usize = TokenAccount::LEN;
+const ACCOUNT_TYPE_MINT: u8 = 1;
+const ACCOUNT_TYPE_ACCOUNT: u8 = 2;
+
+const TLV_HEADER_LEN: usize = 4;
+
+// Extension type IDs, see spl_token_2022::extension::ExtensionType
+const UNINITIALIZED: u16 = 0;
+const TRANSFER_FEE_CONFIG: u16 = 1;
+const NON_TRANSFERABLE: u16 = 9;
+const PERMANENT_DELEGATE: u16 = 12;
+const METADATA_POINTER: u16 = 18;
+
+/// A transfer fee schedule in effect from a given epoch
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TransferFee {
+    pub epoch: u64,
+    pub maximum_fee: u64,
+    pub transfer_fee_basis_points: u16,
+}
+
+/// The `TransferFeeConfig` mint extension
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TransferFeeConfig {
+    pub transfer_fee_config_authority: Option<Pubkey>,
+    pub withdraw_withheld_authority: Option<Pubkey>,
+    pub withheld_amount: u64,
+    pub older_transfer_fee: TransferFee,
+    pub newer_transfer_fee: TransferFee,
+}
+
+/// The `MetadataPointer` mint extension
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct MetadataPointer {
+    pub authority: Option<Pubkey>,
+    pub metadata_address: Option<Pubkey>,
+}
+
+/// The subset of Token-2022 mint extensions tracked by the indexer
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct MintExtensions {
+    pub transfer_fee_config: Option<TransferFeeConfig>,
+    pub non_transferable: bool,
+    pub metadata_pointer: Option<MetadataPointer>,
+    pub permanent_delegate: Option<Pubkey>,
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16> {
+    data.get(offset..offset + 2)
+        .and_then(|b| b.try_into().ok())
+        .map(u16::from_le_bytes)
+        .ok_or_else(|| anyhow!("Extension data too short for u16 at offset {}", offset))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Result<u64> {
+    data.get(offset..offset + 8)
+        .and_then(|b| b.try_into().ok())
+        .map(u64::from_le_bytes)
+        .ok_or_else(|| anyhow!("Extension data too short for u64 at offset {}", offset))
+}
+
+/// Read an `OptionalNonZeroPubkey`, which encodes `None` as all zeroes
+fn read_optional_pubkey(data: &[u8], offset: usize) -> Result<Option<Pubkey>> {
+    let bytes: [u8; 32] = data
+        .get(offset..offset + 32)
+        .and_then(|b| b.try_into().ok())
+        .ok_or_else(|| anyhow!("Extension data too short for pubkey at offset {}", offset))?;
+
+    Ok(if bytes == [0; 32] {
+        None
+    } else {
+        Some(Pubkey::new_from_array(bytes))
+    })
+}
+
+fn read_transfer_fee(data: &[u8], offset: usize) -> Result<TransferFee> {
+    Ok(TransferFee {
+        epoch: read_u64(data, offset)?,
+        maximum_fee: read_u64(data, offset + 8)?,
+        transfer_fee_basis_points: read_u16(data, offset + 16)?,
+    })
+}
+
+/// Parse the extension TLV entries of an extended Token-2022 mint
+///
+/// `tlv` should start immediately after the account type byte.  Unknown
+/// extension types are skipped.
+pub(crate) fn parse_mint_extensions(tlv: &[u8]) -> Result<MintExtensions> {
+    let mut exts = MintExtensions::default();
+    let mut rest = tlv;
+
+    while rest.len() >= TLV_HEADER_LEN {
+        let ty = read_u16(rest, 0)?;
+        let len = usize::from(read_u16(rest, 2)?);
+
+        if ty == UNINITIALIZED {
+            break;
+        }
+
+        let value = rest
+            .get(TLV_HEADER_LEN..TLV_HEADER_LEN + len)
+            .with_context(|| format!("Extension {ty} overflows account data"))?;
+
+        match ty {
+            TRANSFER_FEE_CONFIG => {
+                exts.transfer_fee_config = Some(TransferFeeConfig {
+                    transfer_fee_config_authority: read_optional_pubkey(value, 0)?,
+                    withdraw_withheld_authority: read_optional_pubkey(value, 32)?,
+                    withheld_amount: read_u64(value, 64)?,
+                    older_transfer_fee: read_transfer_fee(value, 72)?,
+                    newer_transfer_fee: read_transfer_fee(value, 90)?,
+                });
+            },
+            NON_TRANSFERABLE => exts.non_transferable = true,
+            PERMANENT_DELEGATE => exts.permanent_delegate = read_optional_pubkey(value, 0)?,
+            METADATA_POINTER => {
+                exts.metadata_pointer = Some(MetadataPointer {
+                    authority: read_optional_pubkey(value, 0)?,
+                    metadata_address: read_optional_pubkey(value, 32)?,
+                });
+            },
+            t => trace!("Skipping Token-2022 mint extension type {}", t),
+        }
+
+        rest = &rest[TLV_HEADER_LEN + len..];
+    }
+
+    Ok(exts)
+}
+
+async fn process_token(client: &Client, update: AccountUpdate) -> Result<()> {
+    // The base state of a Token-2022 account shares the SPL token layout
+    let token_account = TokenAccount::unpack_unchecked(&update.data[..TokenAccount::LEN])
+        .context("Failed to deserialize Token-2022 account data!")?;
+
+    token::process(
+        client,
+        update.key,
+        token_account,
+        update.slot,
+        update.write_version,
+    )
+    .await
+}
+
+async fn process_mint(client: &Client, update: AccountUpdate) -> Result<()> {
+    let mint_account = MintAccount::unpack_unchecked(&update.data[..MintAccount::LEN])
+        .context("Failed to deserialize Token-2022 mint data!")?;
+
+    if update.data.len() > ACCOUNT_TYPE_OFFSET + 1 {
+        let exts = parse_mint_extensions(&update.data[ACCOUNT_TYPE_OFFSET + 1..])
+            .context("Failed to parse Token-2022 mint extensions")?;
+
+        token_2022::process_mint_extensions(
+            client,
+            update.key,
+            exts,
+            update.slot,
+            update.write_version,
+        )
+        .await?;
+    }
+
+    token::process_mint(client, update.key, mint_account, update.slot).await
+}
+
+pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
+    match update.data.len() {
+        MintAccount::LEN => process_mint(client, update).await,
+        TokenAccount::LEN => process_token(client, update).await,
+        // Token-2022 pads extended accounts so they are never multisig-sized
+        Multisig::LEN => Ok(()),
+        l if l > ACCOUNT_TYPE_OFFSET => match update.data[ACCOUNT_TYPE_OFFSET] {
+            ACCOUNT_TYPE_MINT => process_mint(client, update).await,
+            ACCOUNT_TYPE_ACCOUNT => process_token(client, update).await,
+            b => {
+                trace!("Unhandled Token-2022 account type {:02x}", b);
+
+                Ok(())
+            },
+        },
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_mint_extensions, Pubkey, METADATA_POINTER, NON_TRANSFERABLE};
+
+    fn tlv(ty: u16, value: &[u8]) -> Vec<u8> {
+        let mut out = ty.to_le_bytes().to_vec();
+        out.extend_from_slice(&u16::try_from(value.len()).unwrap().to_le_bytes());
+        out.extend_from_slice(value);
+        out
+    }
+
+    #[test]
+    fn test_parse_mint_extensions() {
+        let metadata = Pubkey::new_unique();
+        let mut pointer = [0_u8; 64].to_vec();
+        pointer[32..].copy_from_slice(metadata.as_ref());
+
+        let mut data = tlv(NON_TRANSFERABLE, &[]);
+        data.extend(tlv(METADATA_POINTER, &pointer));
+        data.extend([0; 8]);
+
+        let exts = parse_mint_extensions(&data).unwrap();
+
+        assert!(exts.non_transferable);
+        assert!(exts.transfer_fee_config.is_none());
+        assert!(exts.permanent_delegate.is_none());
+
+        let pointer = exts.metadata_pointer.unwrap();
+        assert_eq!(pointer.authority, None);
+        assert_eq!(pointer.metadata_address, Some(metadata));
+    }
+
+    #[test]
+    fn test_parse_mint_extensions_overflow() {
+        let mut data = tlv(METADATA_POINTER, &[0; 64]);
+        data.truncate(40);
+
+        assert!(parse_mint_extensions(&data).is_err());
+    }
+}
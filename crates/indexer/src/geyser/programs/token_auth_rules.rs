@@ -0,0 +1,235 @@
+use std::str::FromStr;
+
+use borsh::BorshDeserialize;
+
+use super::{accounts::token_auth_rules, AccountUpdate, Client};
+use crate::prelude::*;
+
+// Account keys, see mpl_token_auth_rules::state::Key
+const RULE_SET: u8 = 1;
+
+/// Length of the borsh-encoded key and revision map location
+const HEADER_LEN: usize = 1 + 8;
+const RULE_SET_LIB_VERSION: u8 = 1;
+const RULE_SET_REV_MAP_VERSION: u8 = 1;
+
+#[derive(BorshDeserialize, Debug, Clone, Copy)]
+struct RuleSetHeader {
+    _key: u8,
+    rev_map_version_location: u64,
+}
+
+#[derive(BorshDeserialize, Debug, Clone)]
+struct RuleSetRevisionMap {
+    rule_set_revisions: Vec<u64>,
+}
+
+/// The latest revision of a rule set
+#[derive(Debug, Clone)]
+pub(crate) struct RuleSet {
+    pub owner: Pubkey,
+    pub name: String,
+    /// Index of the latest revision
+    pub revision: u64,
+}
+
+/// Minimal MessagePack reader for the fields of a rule set revision we index
+struct MsgPack<'a>(&'a [u8]);
+
+impl<'a> MsgPack<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.0.len() < len {
+            bail!("Unexpected end of MessagePack data");
+        }
+
+        let (head, rest) = self.0.split_at(len);
+        self.0 = rest;
+
+        Ok(head)
+    }
+
+    fn byte(&mut self) -> Result<u8> {
+        self.take(1).map(|b| b[0])
+    }
+
+    fn be_uint(&mut self, len: usize) -> Result<u64> {
+        let bytes = self.take(len)?;
+
+        Ok(bytes.iter().fold(0, |n, b| (n << 8) | u64::from(*b)))
+    }
+
+    fn be_len(&mut self, len: usize) -> Result<usize> {
+        usize::try_from(self.be_uint(len)?).context("MessagePack length was too big")
+    }
+
+    fn array_len(&mut self) -> Result<usize> {
+        match self.byte()? {
+            b @ 0x90..=0x9f => Ok(usize::from(b & 0x0f)),
+            0xdc => self.be_len(2),
+            0xdd => self.be_len(4),
+            b => bail!("Expected MessagePack array, got {:#04x}", b),
+        }
+    }
+
+    fn uint(&mut self) -> Result<u64> {
+        match self.byte()? {
+            b @ 0x00..=0x7f => Ok(b.into()),
+            0xcc => self.be_uint(1),
+            0xcd => self.be_uint(2),
+            0xce => self.be_uint(4),
+            0xcf => self.be_uint(8),
+            b => bail!("Expected MessagePack unsigned integer, got {:#04x}", b),
+        }
+    }
+
+    fn str(&mut self) -> Result<&'a str> {
+        let len = match self.byte()? {
+            b @ 0xa0..=0xbf => usize::from(b & 0x1f),
+            0xd9 => self.be_len(1)?,
+            0xda => self.be_len(2)?,
+            0xdb => self.be_len(4)?,
+            b => bail!("Expected MessagePack string, got {:#04x}", b),
+        };
+
+        std::str::from_utf8(self.take(len)?).context("MessagePack string was not valid UTF-8")
+    }
+
+    /// Read a public key serialized either as a base58 string or as an array
+    /// of bytes
+    fn pubkey(&mut self) -> Result<Pubkey> {
+        match self.0.first().copied() {
+            Some(0xa0..=0xbf | 0xd9..=0xdb) => {
+                Pubkey::from_str(self.str()?).context("Failed to parse public key")
+            },
+            _ => {
+                let len = self.array_len()?;
+
+                if len != 32 {
+                    bail!("Public key array had length {}", len);
+                }
+
+                let mut bytes = [0_u8; 32];
+                for b in &mut bytes {
+                    *b = self
+                        .uint()
+                        .and_then(|u| u8::try_from(u).context("Public key byte overflowed"))?;
+                }
+
+                Ok(Pubkey::new_from_array(bytes))
+            },
+        }
+    }
+}
+
+/// Parse the owner and name of a MessagePack-encoded `RuleSetV1`
+fn parse_revision(data: &[u8]) -> Result<(Pubkey, String)> {
+    let mut reader = MsgPack(data);
+
+    // Rule sets are serialized as compact arrays of their fields: library
+    // version, owner, name and operations
+    if reader.array_len()? < 3 {
+        bail!("Rule set revision was missing fields");
+    }
+
+    let _lib_version = reader.uint()?;
+    let owner = reader.pubkey().context("Failed to read rule set owner")?;
+    let name = reader.str().context("Failed to read rule set name")?;
+
+    Ok((owner, name.to_owned()))
+}
+
+fn parse_rule_set(data: &[u8]) -> Result<RuleSet> {
+    let header = RuleSetHeader::deserialize(&mut &data[..HEADER_LEN])
+        .context("Failed to deserialize rule set header")?;
+
+    let map_start = usize::try_from(header.rev_map_version_location)?;
+    let (&map_version, mut map) = data
+        .get(map_start..)
+        .and_then(<[u8]>::split_first)
+        .context("Rule set revision map location was out of bounds")?;
+
+    if map_version != RULE_SET_REV_MAP_VERSION {
+        bail!("Unsupported rule set revision map version {}", map_version);
+    }
+
+    let revisions = RuleSetRevisionMap::deserialize(&mut map)
+        .context("Failed to deserialize rule set revision map")?
+        .rule_set_revisions;
+
+    let (revision, &start) = revisions
+        .iter()
+        .enumerate()
+        .last()
+        .context("Rule set had no revisions")?;
+    let start = usize::try_from(start)?;
+
+    let (&lib_version, revision_data) = data
+        .get(start..map_start)
+        .and_then(<[u8]>::split_first)
+        .context("Rule set revision location was out of bounds")?;
+
+    if lib_version != RULE_SET_LIB_VERSION {
+        bail!("Unsupported rule set library version {}", lib_version);
+    }
+
+    let (owner, name) =
+        parse_revision(revision_data).context("Failed to parse latest rule set revision")?;
+
+    Ok(RuleSet {
+        owner,
+        name,
+        revision: revision.try_into()?,
+    })
+}
+
+pub(crate) async fn process(client: &Client, update: AccountUpdate) -> Result<()> {
+    if update.data.len() < HEADER_LEN || update.data[0] != RULE_SET {
+        // Frequency accounts and uninitialized buffers are not indexed
+        return Ok(());
+    }
+
+    let rule_set = parse_rule_set(&update.data).context("Failed to parse rule set")?;
+
+    token_auth_rules::process(
+        client,
+        update.key,
+        rule_set,
+        update.slot,
+        update.write_version,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_rule_set, Pubkey, RULE_SET, RULE_SET_LIB_VERSION, RULE_SET_REV_MAP_VERSION};
+
+    #[test]
+    fn test_parse_rule_set() {
+        let owner = Pubkey::new_unique();
+        let owner_str = owner.to_string();
+        let name = "royalties";
+
+        let mut revision = vec![RULE_SET_LIB_VERSION, 0x94, 0x01];
+        revision.push(0xd9);
+        revision.push(u8::try_from(owner_str.len()).unwrap());
+        revision.extend(owner_str.as_bytes());
+        revision.push(0xa0 | u8::try_from(name.len()).unwrap());
+        revision.extend(name.as_bytes());
+        revision.push(0x80);
+
+        let map_start = 9 + revision.len();
+        let mut data = vec![RULE_SET];
+        data.extend(u64::try_from(map_start).unwrap().to_le_bytes());
+        data.extend(&revision);
+        data.push(RULE_SET_REV_MAP_VERSION);
+        data.extend(1_u32.to_le_bytes());
+        data.extend(9_u64.to_le_bytes());
+
+        let rule_set = parse_rule_set(&data).unwrap();
+
+        assert_eq!(rule_set.owner, owner);
+        assert_eq!(rule_set.name, name);
+        assert_eq!(rule_set.revision, 0);
+    }
+}
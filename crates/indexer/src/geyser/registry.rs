@@ -0,0 +1,821 @@
+//! Table of the programs the Geyser indexer knows how to process, and which
+//! of them are enabled for this worker
+
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    path::PathBuf,
+    str::FromStr,
+};
+
+use futures_util::future::{BoxFuture, FutureExt};
+use indexer_core::{clap, pubkeys};
+use indexer_rabbitmq::geyser::Message;
+
+use super::{
+    programs::{self, anchor::Idl},
+    AccountUpdate, Client, IgnoreType,
+};
+use crate::{metrics, prelude::*};
+
+/// A program indexed by the Geyser indexer
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, strum::EnumString, strum::Display, strum::IntoStaticStr,
+)]
+#[strum(serialize_all = "kebab-case")]
+pub enum ProgramName {
+    /// The Metaplex token metadata program
+    Metadata,
+    /// The Metaplex token-auth-rules program
+    TokenAuthRules,
+    /// The Holaplex reward center program
+    RewardCenter,
+    /// The Maple Finance program
+    Maple,
+    /// The Metaplex auction program
+    Auction,
+    /// The Metaplex program
+    Metaplex,
+    /// The Metaplex auction house program
+    AuctionHouse,
+    /// The Metaplex auctioneer program
+    Auctioneer,
+    /// The Magic Eden V2 marketplace program
+    MagicEden,
+    /// The SPL token program
+    Token,
+    /// The Token-2022 program
+    Token2022,
+    /// The Graph program
+    Graph,
+    /// The Metaplex candy machine V2 program
+    CandyMachine,
+    /// The Metaplex candy machine V3 program
+    CandyMachineV3,
+    /// The Metaplex candy guard program
+    CandyGuard,
+    /// The SPL name service program
+    NameService,
+    /// The Cardinal token manager program
+    CardinalTokenManager,
+    /// The Cardinal time invalidator program
+    CardinalTimeInvalidator,
+    /// The Cardinal use invalidator program
+    CardinalUseInvalidator,
+    /// The Cardinal paid claim approver program
+    CardinalPaidClaimApprover,
+    /// The Goki smart wallet program
+    GokiSmartWallet,
+    /// The Tribeca locked voter program
+    TribecaLockedVoter,
+    /// The Tribeca govern program
+    TribecaGovern,
+    /// The Cardinal namespaces program
+    Namespaces,
+    /// The Strata token bonding program
+    TokenBonding,
+    /// All known deployments of the SPL governance program
+    SplGovernance,
+    /// The Genopets habitat program
+    Genopets,
+    /// The Metaplex Bubblegum program
+    Bubblegum,
+    /// The SPL no-op program, which carries Bubblegum change logs
+    SplNoop,
+}
+
+impl ProgramName {
+    /// Returns true if the program's processors timestamp the events they
+    /// index with the block time of their slot
+    #[must_use]
+    pub fn timestamps_events(self) -> bool {
+        matches!(
+            self,
+            Self::Metadata
+                | Self::RewardCenter
+                | Self::Auction
+                | Self::AuctionHouse
+                | Self::Auctioneer
+                | Self::MagicEden
+                | Self::Token
+                | Self::Token2022
+                | Self::TokenBonding
+                | Self::Bubblegum
+        )
+    }
+}
+
+impl IgnoreType {
+    fn programs(self) -> &'static [ProgramName] {
+        match self {
+            Self::Metadata => &[ProgramName::Metadata, ProgramName::TokenAuthRules],
+            Self::CandyMachine => &[
+                ProgramName::CandyMachine,
+                ProgramName::CandyMachineV3,
+                ProgramName::CandyGuard,
+            ],
+            Self::Tokens => &[ProgramName::Token, ProgramName::Token2022],
+        }
+    }
+}
+
+/// Arguments for selecting which programs a Geyser worker indexes
+#[derive(Debug, clap::Args)]
+#[group(skip)]
+pub struct Args {
+    /// List of programs to index, ignoring all others
+    ///
+    /// For example, `spl-governance,tribeca-govern` will run a worker which
+    /// only indexes governance programs.
+    #[arg(
+        long,
+        env,
+        use_value_delimiter(true),
+        conflicts_with("disable_programs")
+    )]
+    enable_programs: Option<Vec<ProgramName>>,
+
+    /// List of programs to ignore
+    #[arg(long, env, use_value_delimiter(true))]
+    disable_programs: Option<Vec<ProgramName>>,
+
+    /// List of topics or programs to ignore on startup
+    ///
+    /// For example, `metadata,candy-machine` will ignore the Metaplex metadata
+    /// and candy machine programs.
+    #[arg(long, env, use_value_delimiter(true))]
+    ignore_on_startup: Option<Vec<IgnoreType>>,
+
+    /// List of programs to ignore on startup, in addition to any given by
+    /// `--ignore-on-startup`
+    #[arg(long, env, use_value_delimiter(true))]
+    disable_programs_on_startup: Option<Vec<ProgramName>>,
+
+    /// List of additional programs to index from their Anchor IDLs
+    ///
+    /// Each entry is a program ID and the path to its IDL JSON, separated by
+    /// `=`.  Decoded accounts are stored in the `anchor_accounts` table.
+    #[arg(long = "anchor-idl", env = "ANCHOR_IDLS", use_value_delimiter(true))]
+    anchor_idls: Vec<AnchorIdl>,
+}
+
+/// A program ID and the path to its Anchor IDL
+#[derive(Debug, Clone)]
+pub struct AnchorIdl {
+    program: Pubkey,
+    path: PathBuf,
+}
+
+impl FromStr for AnchorIdl {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (program, path) = s
+            .split_once('=')
+            .ok_or_else(|| anyhow!("Expected PROGRAM_ID=PATH, got {:?}", s))?;
+
+        Ok(Self {
+            program: program.parse().context("Invalid program ID")?,
+            path: path.into(),
+        })
+    }
+}
+
+/// A condition on the data of accounts fetched from an RPC node
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountFilter {
+    /// Match accounts with exactly this many bytes of data
+    DataSize(usize),
+    /// Match accounts whose data contains the given bytes at the given offset
+    Memcmp(usize, &'static [u8]),
+}
+
+/// A type of account owned by a program, and the filters selecting it
+#[derive(Debug, Clone, Copy)]
+pub struct AccountType {
+    /// Name of the account type, for logging
+    pub name: &'static str,
+    /// Filters matching only accounts of this type
+    pub filters: &'static [AccountFilter],
+}
+
+/// A query for the accounts of an enabled program, optionally restricted to a
+/// single account type
+#[derive(Debug, Clone, Copy)]
+pub struct AccountQuery {
+    /// The name of the program, or `None` if it is indexed from an IDL
+    pub program: Option<ProgramName>,
+    /// The ID of the program deployment owning the accounts
+    pub id: Pubkey,
+    /// The type of account to fetch, or `None` to fetch every account
+    pub account_type: Option<AccountType>,
+}
+
+type AccountHandler = for<'a> fn(&'a Client, AccountUpdate) -> BoxFuture<'a, Result<()>>;
+type InstructionHandler =
+    for<'a> fn(&'a Client, &'a [u8], &'a [Pubkey], u64) -> BoxFuture<'a, Result<()>>;
+
+macro_rules! accounts {
+    ($module:ident) => {{
+        fn handler(client: &Client, update: AccountUpdate) -> BoxFuture<Result<()>> {
+            programs::$module::process(client, update).boxed()
+        }
+
+        Some(handler as AccountHandler)
+    }};
+}
+
+macro_rules! instructions {
+    ($module:ident) => {{
+        fn handler<'a>(
+            client: &'a Client,
+            data: &'a [u8],
+            accounts: &'a [Pubkey],
+            slot: u64,
+        ) -> BoxFuture<'a, Result<()>> {
+            programs::$module::process_instruction(client, data, accounts, slot).boxed()
+        }
+
+        Some(handler as InstructionHandler)
+    }};
+}
+
+fn process_noop<'a>(
+    client: &'a Client,
+    data: &'a [u8],
+    _accounts: &'a [Pubkey],
+    slot: u64,
+) -> BoxFuture<'a, Result<()>> {
+    programs::bubblegum::process_noop(client, data, slot).boxed()
+}
+
+struct Program {
+    name: ProgramName,
+    ids: Vec<Pubkey>,
+    process_account: Option<AccountHandler>,
+    process_instruction: Option<InstructionHandler>,
+    account_types: &'static [AccountType],
+}
+
+impl Program {
+    fn new(
+        name: ProgramName,
+        ids: impl IntoIterator<Item = Pubkey>,
+        process_account: Option<AccountHandler>,
+        process_instruction: Option<InstructionHandler>,
+    ) -> Self {
+        Self {
+            name,
+            ids: ids.into_iter().collect(),
+            process_account,
+            process_instruction,
+            account_types: &[],
+        }
+    }
+
+    fn account_types(self, account_types: &'static [AccountType]) -> Self {
+        Self {
+            account_types,
+            ..self
+        }
+    }
+}
+
+// Higher-ranked function pointers don't implement Debug
+impl fmt::Debug for Program {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Program")
+            .field("name", &self.name)
+            .field("ids", &self.ids)
+            .finish_non_exhaustive()
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+fn programs() -> Vec<Program> {
+    use ProgramName as N;
+
+    vec![
+        Program::new(
+            N::Metadata,
+            [pubkeys::METADATA],
+            accounts!(metadata),
+            instructions!(metadata),
+        )
+        .account_types(programs::metadata::ACCOUNT_TYPES),
+        Program::new(
+            N::TokenAuthRules,
+            [pubkeys::TOKEN_AUTH_RULES],
+            accounts!(token_auth_rules),
+            None,
+        ),
+        Program::new(
+            N::RewardCenter,
+            [pubkeys::REWARD_CENTER],
+            accounts!(reward_center),
+            instructions!(reward_center),
+        ),
+        Program::new(N::Maple, [pubkeys::MAPLE], accounts!(maple), None),
+        Program::new(N::Auction, [pubkeys::AUCTION], accounts!(auction), None),
+        Program::new(N::Metaplex, [pubkeys::METAPLEX], accounts!(metaplex), None),
+        Program::new(
+            N::AuctionHouse,
+            [pubkeys::AUCTION_HOUSE],
+            accounts!(auction_house),
+            instructions!(auction_house),
+        )
+        .account_types(programs::auction_house::ACCOUNT_TYPES),
+        Program::new(
+            N::Auctioneer,
+            [pubkeys::AUCTIONEER],
+            accounts!(auctioneer),
+            instructions!(auctioneer),
+        ),
+        Program::new(
+            N::MagicEden,
+            [pubkeys::ME_HAUS],
+            accounts!(magic_eden_haus),
+            instructions!(magic_eden_haus),
+        )
+        .account_types(programs::magic_eden_haus::ACCOUNT_TYPES),
+        Program::new(
+            N::Token,
+            [pubkeys::TOKEN],
+            accounts!(token),
+            instructions!(token),
+        )
+        .account_types(programs::token::ACCOUNT_TYPES),
+        // Token-2022 instructions are backwards-compatible with SPL token
+        Program::new(
+            N::Token2022,
+            [pubkeys::TOKEN_2022],
+            accounts!(token_2022),
+            instructions!(token),
+        ),
+        Program::new(N::Graph, [pubkeys::GRAPH_PROGRAM], accounts!(graph), None),
+        Program::new(
+            N::CandyMachine,
+            [pubkeys::CANDY_MACHINE],
+            accounts!(candy_machine),
+            None,
+        ),
+        Program::new(
+            N::CandyMachineV3,
+            [pubkeys::CANDY_MACHINE_V3],
+            accounts!(candy_machine_v3),
+            None,
+        ),
+        Program::new(
+            N::CandyGuard,
+            [pubkeys::CANDY_GUARD],
+            accounts!(candy_guard),
+            None,
+        ),
+        Program::new(
+            N::NameService,
+            [pubkeys::NAME_SERVICE],
+            accounts!(name_service),
+            None,
+        ),
+        Program::new(
+            N::CardinalTokenManager,
+            [pubkeys::CARDINAL_TOKEN_MANAGER],
+            accounts!(cardinal_token_manager),
+            None,
+        ),
+        Program::new(
+            N::CardinalTimeInvalidator,
+            [pubkeys::CARDINAL_TIME_INVALIDATOR],
+            accounts!(cardinal_time_invalidator),
+            None,
+        ),
+        Program::new(
+            N::CardinalUseInvalidator,
+            [pubkeys::CARDINAL_USE_INVALIDATOR],
+            accounts!(cardinal_use_invalidator),
+            None,
+        ),
+        Program::new(
+            N::CardinalPaidClaimApprover,
+            [pubkeys::CARDINAL_PAID_CLAIM_APPROVER],
+            accounts!(cardinal_paid_claim_approver),
+            None,
+        ),
+        Program::new(
+            N::GokiSmartWallet,
+            [pubkeys::GOKI_SMART_WALLET],
+            accounts!(goki_smart_wallet),
+            None,
+        ),
+        Program::new(
+            N::TribecaLockedVoter,
+            [pubkeys::TRIBECA_LOCKED_VOTER],
+            accounts!(tribeca_locked_voter),
+            None,
+        ),
+        Program::new(
+            N::TribecaGovern,
+            [pubkeys::TRIBECA_GOVERN],
+            accounts!(tribeca_govern),
+            None,
+        ),
+        Program::new(
+            N::Namespaces,
+            [pubkeys::NAMESPACES],
+            accounts!(namespaces),
+            None,
+        ),
+        Program::new(
+            N::TokenBonding,
+            [pubkeys::TOKEN_BONDING],
+            accounts!(token_bonding),
+            None,
+        ),
+        Program::new(
+            N::SplGovernance,
+            pubkeys::SPL_GOVERNANCE,
+            accounts!(spl_governance),
+            None,
+        ),
+        Program::new(N::Genopets, [genostub::ID], accounts!(genopets), None),
+        Program::new(
+            N::Bubblegum,
+            [pubkeys::BUBBLEGUM],
+            None,
+            instructions!(bubblegum),
+        ),
+        Program::new(
+            N::SplNoop,
+            [pubkeys::SPL_NOOP],
+            None,
+            Some(process_noop as InstructionHandler),
+        ),
+    ]
+}
+
+/// Registry of the programs enabled for a Geyser worker, indexed by program
+/// ID
+#[derive(Debug)]
+pub struct Registry {
+    programs: Vec<Program>,
+    ids: HashMap<Pubkey, usize>,
+    ignore_on_startup: HashSet<ProgramName>,
+    idls: HashMap<Pubkey, Idl>,
+}
+
+impl Registry {
+    /// Construct a new registry from the given command-line arguments
+    ///
+    /// # Errors
+    /// This function fails if an IDL cannot be loaded or belongs to a program
+    /// which is already indexed.
+    pub fn new(args: Args) -> Result<Self> {
+        let Args {
+            enable_programs,
+            disable_programs,
+            ignore_on_startup,
+            disable_programs_on_startup,
+            anchor_idls,
+        } = args;
+
+        let enabled = enable_programs.map(|p| p.into_iter().collect::<HashSet<_>>());
+        let disabled: HashSet<_> = disable_programs.into_iter().flatten().collect();
+
+        let programs: Vec<_> = programs()
+            .into_iter()
+            .filter(|p| {
+                enabled.as_ref().map_or(true, |e| e.contains(&p.name))
+                    && !disabled.contains(&p.name)
+            })
+            .collect();
+
+        let ids: HashMap<_, _> = programs
+            .iter()
+            .enumerate()
+            .flat_map(|(i, p)| p.ids.iter().map(move |id| (*id, i)))
+            .collect();
+
+        let idls = anchor_idls
+            .into_iter()
+            .map(|AnchorIdl { program, path }| {
+                if let Some(i) = ids.get(&program) {
+                    bail!(
+                        "Program {} is already indexed as {}, disable it to index it from an IDL",
+                        program,
+                        programs[*i].name,
+                    );
+                }
+
+                info!("Indexing program {} from IDL {:?}", program, path);
+                Ok((program, Idl::load(program, path)?))
+            })
+            .collect::<Result<_>>()?;
+
+        let ignore_on_startup = ignore_on_startup
+            .into_iter()
+            .flatten()
+            .flat_map(|t| t.programs().iter().copied())
+            .chain(disable_programs_on_startup.into_iter().flatten())
+            .collect();
+
+        info!(
+            "Indexing programs: {}",
+            programs
+                .iter()
+                .map(|p| p.name.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+
+        Ok(Self {
+            programs,
+            ids,
+            ignore_on_startup,
+            idls,
+        })
+    }
+
+    fn get(&self, id: &Pubkey) -> Option<&Program> {
+        self.ids.get(id).map(|i| &self.programs[*i])
+    }
+
+    /// Count a message handled by the given program
+    fn record(&self, program: &Pubkey, message: &'static str) {
+        if let Some(p) = self.get(program) {
+            metrics::program_message(p.name.into(), message);
+        }
+    }
+
+    fn account_handler(&self, owner: &Pubkey, is_startup: bool) -> Option<AccountHandler> {
+        self.get(owner)
+            .filter(|p| !(is_startup && self.ignore_on_startup.contains(&p.name)))
+            .and_then(|p| p.process_account)
+    }
+
+    fn instruction_handler(&self, program: &Pubkey) -> Option<InstructionHandler> {
+        self.get(program).and_then(|p| p.process_instruction)
+    }
+
+    /// Returns true if an enabled program handles updates of accounts with
+    /// the given owner
+    #[must_use]
+    pub fn handles_account(&self, owner: &Pubkey, is_startup: bool) -> bool {
+        self.account_handler(owner, is_startup).is_some() || self.idls.contains_key(owner)
+    }
+
+    /// Returns true if an enabled program handles the given message
+    #[must_use]
+    pub fn handles(&self, msg: &Message) -> bool {
+        match msg {
+            Message::AccountUpdate(u) => self.handles_account(&u.owner, u.is_startup),
+            Message::InstructionNotify(i) => self.instruction_handler(&i.program).is_some(),
+            Message::SlotStatusUpdate(_) => true,
+        }
+    }
+
+    /// List the queries needed to fetch every account indexed by an enabled
+    /// program
+    ///
+    /// Programs with known account types are queried once per type, others
+    /// are queried for all of their accounts.
+    #[must_use]
+    pub fn account_queries(&self) -> Vec<AccountQuery> {
+        let builtin = self
+            .programs
+            .iter()
+            .filter(|p| p.process_account.is_some())
+            .flat_map(|p| {
+                p.ids.iter().flat_map(move |&id| {
+                    let types: Vec<_> = if p.account_types.is_empty() {
+                        vec![None]
+                    } else {
+                        p.account_types.iter().copied().map(Some).collect()
+                    };
+
+                    types.into_iter().map(move |account_type| AccountQuery {
+                        program: Some(p.name),
+                        id,
+                        account_type,
+                    })
+                })
+            });
+
+        let idls = self.idls.keys().map(|&id| AccountQuery {
+            program: None,
+            id,
+            account_type: None,
+        });
+
+        builtin.chain(idls).collect()
+    }
+
+    /// List the enabled programs which timestamp the events they index
+    #[must_use]
+    pub fn timestamped_programs(&self) -> Vec<ProgramName> {
+        self.programs
+            .iter()
+            .map(|p| p.name)
+            .filter(|n| n.timestamps_events())
+            .collect()
+    }
+
+    /// Returns true if the given program is enabled
+    #[must_use]
+    pub fn is_enabled(&self, name: ProgramName) -> bool {
+        self.programs.iter().any(|p| p.name == name)
+    }
+
+    /// List the programs whose account updates are indexed by an enabled
+    /// program or IDL
+    #[must_use]
+    pub fn account_owners(&self) -> Vec<Pubkey> {
+        self.programs
+            .iter()
+            .filter(|p| p.process_account.is_some())
+            .flat_map(|p| p.ids.iter().copied())
+            .chain(self.idls.keys().copied())
+            .collect()
+    }
+
+    /// List the programs whose instructions are indexed by an enabled
+    /// program
+    #[must_use]
+    pub fn instruction_programs(&self) -> Vec<Pubkey> {
+        self.programs
+            .iter()
+            .filter(|p| p.process_instruction.is_some())
+            .flat_map(|p| p.ids.iter().copied())
+            .collect()
+    }
+
+    /// Index an account update or instruction with the handler of the
+    /// program it belongs to
+    ///
+    /// # Errors
+    /// This function fails if the program's handler fails.
+    pub async fn process(&self, client: &Client, msg: Message) -> Result<()> {
+        match msg {
+            Message::AccountUpdate(update) => {
+                match (
+                    self.account_handler(&update.owner, update.is_startup),
+                    self.idls.get(&update.owner),
+                ) {
+                    (Some(process), _) => {
+                        self.record(&update.owner, "account_update");
+                        process(client, update).await
+                    },
+                    (None, Some(idl)) => {
+                        metrics::program_message("anchor-idl", "account_update");
+                        programs::anchor::process(client, idl, update).await
+                    },
+                    (None, None) => {
+                        debug!(
+                            "Unhandled account update for program {}",
+                            bs58::encode(update.owner).into_string()
+                        );
+                        Ok(())
+                    },
+                }
+            },
+            Message::InstructionNotify(ins) => match self.instruction_handler(&ins.program) {
+                Some(process) => {
+                    self.record(&ins.program, "instruction");
+                    process(client, &ins.data, &ins.accounts, ins.slot).await
+                },
+                None => Ok(()),
+            },
+            Message::SlotStatusUpdate(_) => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args() -> Args {
+        Args {
+            enable_programs: None,
+            disable_programs: None,
+            ignore_on_startup: None,
+            disable_programs_on_startup: None,
+            anchor_idls: vec![],
+        }
+    }
+
+    #[test]
+    fn test_program_ids_are_unique() {
+        let mut seen = HashSet::new();
+
+        for program in programs() {
+            for id in program.ids {
+                assert!(seen.insert(id), "{} is registered twice", id);
+            }
+        }
+    }
+
+    #[test]
+    fn test_allowlist() {
+        let registry = Registry::new(Args {
+            enable_programs: Some(vec![ProgramName::SplGovernance, ProgramName::TribecaGovern]),
+            ..args()
+        })
+        .unwrap();
+
+        assert!(registry.get(&pubkeys::SPL_GOVERNANCE[0]).is_some());
+        assert!(registry.get(&pubkeys::TRIBECA_GOVERN).is_some());
+        assert!(registry.get(&pubkeys::METADATA).is_none());
+        assert!(
+            registry
+                .instruction_handler(&pubkeys::AUCTION_HOUSE)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_denylist() {
+        let registry = Registry::new(Args {
+            disable_programs: Some(vec![ProgramName::Token, ProgramName::Token2022]),
+            ..args()
+        })
+        .unwrap();
+
+        assert!(registry.get(&pubkeys::TOKEN).is_none());
+        assert!(registry.get(&pubkeys::TOKEN_2022).is_none());
+        assert!(!registry.is_enabled(ProgramName::Token));
+        assert!(registry.is_enabled(ProgramName::AuctionHouse));
+        assert!(
+            registry
+                .instruction_handler(&pubkeys::AUCTION_HOUSE)
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_account_queries() {
+        let registry = Registry::new(Args {
+            enable_programs: Some(vec![ProgramName::Metadata, ProgramName::Maple]),
+            ..args()
+        })
+        .unwrap();
+
+        let queries = registry.account_queries();
+        let metadata: Vec<_> = queries
+            .iter()
+            .filter(|q| q.id == pubkeys::METADATA)
+            .collect();
+
+        assert_eq!(metadata.len(), programs::metadata::ACCOUNT_TYPES.len());
+        assert!(metadata.iter().all(|q| q.account_type.is_some()));
+        assert!(
+            queries
+                .iter()
+                .any(|q| q.id == pubkeys::MAPLE && q.account_type.is_none())
+        );
+    }
+
+    #[test]
+    fn test_subscribed_programs() {
+        let registry = Registry::new(Args {
+            enable_programs: Some(vec![ProgramName::Maple, ProgramName::AuctionHouse]),
+            ..args()
+        })
+        .unwrap();
+
+        let owners = registry.account_owners();
+        assert!(owners.contains(&pubkeys::MAPLE));
+        assert!(owners.contains(&pubkeys::AUCTION_HOUSE));
+        assert_eq!(registry.instruction_programs(), [pubkeys::AUCTION_HOUSE]);
+    }
+
+    #[test]
+    fn test_timestamped_programs() {
+        let registry = Registry::new(Args {
+            enable_programs: Some(vec![ProgramName::Maple, ProgramName::AuctionHouse]),
+            ..args()
+        })
+        .unwrap();
+
+        assert_eq!(registry.timestamped_programs(), [ProgramName::AuctionHouse]);
+    }
+
+    #[test]
+    fn test_ignore_on_startup() {
+        let registry = Registry::new(Args {
+            ignore_on_startup: Some(vec![IgnoreType::CandyMachine]),
+            disable_programs_on_startup: Some(vec![ProgramName::Maple]),
+            ..args()
+        })
+        .unwrap();
+
+        for program in [
+            ProgramName::CandyMachine,
+            ProgramName::CandyMachineV3,
+            ProgramName::CandyGuard,
+            ProgramName::Maple,
+        ] {
+            assert!(registry.ignore_on_startup.contains(&program));
+        }
+        assert!(!registry.ignore_on_startup.contains(&ProgramName::Metadata));
+    }
+}
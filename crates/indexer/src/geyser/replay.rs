@@ -0,0 +1,327 @@
+//! Recording and replaying of Geyser messages as JSON lines, for reproducing
+//! bugs and re-indexing slot ranges without a live AMQP queue
+
+use std::{
+    fmt::Display,
+    fs::{File, OpenOptions},
+    future::Future,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use indexer_rabbitmq::geyser::Message;
+use tokio::{sync::mpsc, task::JoinHandle};
+
+use super::{process_message, Client, Registry};
+use crate::prelude::*;
+
+/// The number of serialized messages which can be waiting to be written to a
+/// recording before recording waits for the writer
+const RECORD_BUFFER: usize = 1024;
+
+/// Get the slot a message was produced in
+#[must_use]
+pub fn message_slot(msg: &Message) -> u64 {
+    match msg {
+        Message::AccountUpdate(u) => u.slot,
+        Message::InstructionNotify(i) => i.slot,
+        Message::SlotStatusUpdate(s) => s.slot,
+    }
+}
+
+/// Appends every message it is given to a JSON lines file
+///
+/// Messages are written by a blocking task in the background, so recording
+/// never blocks the async consumer on file I/O.
+#[derive(Debug)]
+pub struct Recorder {
+    tx: mpsc::Sender<Vec<u8>>,
+    writer: JoinHandle<()>,
+}
+
+impl Recorder {
+    /// Open the given file for recording, appending to it if it exists, and
+    /// start the task writing to it
+    ///
+    /// # Errors
+    /// This function fails if the file cannot be opened.
+    pub fn new(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .with_context(|| format!("Failed to open recording {path:?}"))?;
+
+        info!("Recording messages to {:?}", path);
+
+        let (tx, rx) = mpsc::channel(RECORD_BUFFER);
+
+        let writer = tokio::task::spawn_blocking(move || {
+            if let Err(e) = write_lines(&path, BufWriter::new(file), rx) {
+                error!("Recording stopped: {:?}", e);
+            }
+        });
+
+        Ok(Self { tx, writer })
+    }
+
+    /// Queue a message to be written to the recording
+    ///
+    /// # Errors
+    /// This function fails if the message cannot be serialized or the
+    /// recording has stopped.
+    pub async fn record(&self, msg: &Message) -> Result<()> {
+        let mut line = serde_json::to_vec(msg).context("Failed to serialize message")?;
+        line.push(b'\n');
+
+        self.tx
+            .send(line)
+            .await
+            .map_err(|_| anyhow!("Recording has stopped"))
+    }
+
+    /// Stop recording, waiting until every queued message has been written
+    ///
+    /// # Errors
+    /// This function fails if the recording task panicked.
+    pub async fn finish(self) -> Result<()> {
+        let Self { tx, writer } = self;
+        drop(tx);
+
+        writer.await.context("Recording task panicked")
+    }
+}
+
+fn write_lines(
+    path: &Path,
+    mut file: BufWriter<File>,
+    mut rx: mpsc::Receiver<Vec<u8>>,
+) -> Result<()> {
+    while let Some(mut line) = rx.blocking_recv() {
+        loop {
+            file.write_all(&line)
+                .with_context(|| format!("Failed to write to recording {path:?}"))?;
+
+            match rx.try_recv() {
+                Ok(l) => line = l,
+                Err(_) => break,
+            }
+        }
+
+        // Flush whenever the writer catches up so a crash leaves a readable
+        // recording behind
+        file.flush()
+            .with_context(|| format!("Failed to flush recording {path:?}"))?;
+    }
+
+    Ok(())
+}
+
+/// Inclusive range of slots to replay
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SlotRange {
+    /// The first slot to replay, or `None` to start from the beginning
+    pub start: Option<u64>,
+    /// The last slot to replay, or `None` to replay to the end
+    pub end: Option<u64>,
+}
+
+impl SlotRange {
+    fn contains(self, slot: u64) -> bool {
+        self.start.map_or(true, |s| slot >= s) && self.end.map_or(true, |e| slot <= e)
+    }
+}
+
+fn read_lines(path: &Path, tx: &mpsc::Sender<Result<(usize, String)>>) -> Result<()> {
+    let reader: Box<dyn BufRead> = if path == Path::new("-") {
+        Box::new(BufReader::new(io::stdin()))
+    } else {
+        Box::new(BufReader::new(
+            File::open(path).with_context(|| format!("Failed to open replay {path:?}"))?,
+        ))
+    };
+
+    for (i, line) in reader.lines().enumerate() {
+        let line = line.with_context(|| format!("Failed to read line {} of replay", i + 1))?;
+
+        if tx.blocking_send(Ok((i + 1, line))).is_err() {
+            // The replay was stopped early
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Counts of the outcomes of a replay
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct ReplayStats {
+    processed: u64,
+    skipped: u64,
+    failed: u64,
+}
+
+/// Index messages read from a JSON lines file, or standard input if the path
+/// is `-`, in the order they were recorded
+///
+/// Messages which fail to parse or process are logged and skipped.
+///
+/// # Errors
+/// This function fails if the file cannot be read.
+pub async fn replay(
+    client: &Client,
+    registry: &Registry,
+    path: PathBuf,
+    range: SlotRange,
+) -> Result<()> {
+    let ReplayStats {
+        processed,
+        skipped,
+        failed,
+    } = replay_messages(path, range, |m| process_message(m, client, registry)).await?;
+
+    client.flush_writes().await?;
+
+    info!(
+        "Replay finished: {} processed, {} outside slot range, {} failed",
+        processed, skipped, failed
+    );
+
+    Ok(())
+}
+
+/// Read the messages of a recording within the given slot range and hand
+/// them to `process` one at a time
+async fn replay_messages<F: Future<Output = MessageResult<D>>, D: Display>(
+    path: PathBuf,
+    range: SlotRange,
+    mut process: impl FnMut(Message) -> F,
+) -> Result<ReplayStats> {
+    let (tx, mut rx) = mpsc::channel(1024);
+
+    // Reading is blocking, so lines are handed over from a separate thread
+    let reader = tokio::task::spawn_blocking(move || {
+        if let Err(e) = read_lines(&path, &tx) {
+            tx.blocking_send(Err(e)).ok();
+        }
+    });
+
+    let mut stats = ReplayStats::default();
+
+    while let Some(line) = rx.recv().await {
+        let (line_no, line) = line?;
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let msg: Message = match serde_json::from_str(&line) {
+            Ok(m) => m,
+            Err(e) => {
+                warn!("Skipping malformed message on line {}: {}", line_no, e);
+                stats.failed += 1;
+                continue;
+            },
+        };
+
+        if !range.contains(message_slot(&msg)) {
+            stats.skipped += 1;
+            continue;
+        }
+
+        match process(msg).await {
+            Ok(()) => stats.processed += 1,
+            Err(e) => {
+                warn!("Line {}: {}", line_no, e);
+                stats.failed += 1;
+            },
+        }
+    }
+
+    reader.await.context("Replay reader panicked")?;
+
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use indexer_rabbitmq::geyser::{
+        AccountUpdate, InstructionNotify, SlotStatus, SlotStatusUpdate,
+    };
+
+    use super::*;
+
+    fn messages() -> Vec<Message> {
+        vec![
+            Message::AccountUpdate(AccountUpdate {
+                key: Pubkey::new_unique(),
+                lamports: 1,
+                owner: Pubkey::new_unique(),
+                executable: false,
+                rent_epoch: 0,
+                data: vec![1, 2, 3],
+                write_version: 7,
+                slot: 1,
+                is_startup: false,
+            }),
+            Message::InstructionNotify(InstructionNotify {
+                program: Pubkey::new_unique(),
+                data: vec![4, 5],
+                accounts: vec![Pubkey::new_unique()],
+                slot: 2,
+            }),
+            Message::SlotStatusUpdate(SlotStatusUpdate {
+                slot: 3,
+                parent: Some(2),
+                status: SlotStatus::Confirmed,
+            }),
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_record_replay() {
+        let path = std::env::temp_dir().join(format!(
+            "holaplex-indexer-recording-{}.jsonl",
+            std::process::id()
+        ));
+        std::fs::remove_file(&path).ok();
+
+        let msgs = messages();
+        let recorder = Recorder::new(path.clone()).unwrap();
+
+        for msg in &msgs {
+            recorder.record(msg).await.unwrap();
+        }
+
+        recorder.finish().await.unwrap();
+
+        let mut replayed = vec![];
+        let stats = replay_messages(
+            path.clone(),
+            SlotRange {
+                start: Some(2),
+                end: None,
+            },
+            |m| {
+                replayed.push(serde_json::to_value(m).unwrap());
+                async { MessageResult::<u64>::Ok(()) }
+            },
+        )
+        .await;
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(stats.unwrap(), ReplayStats {
+            processed: 2,
+            skipped: 1,
+            failed: 0,
+        });
+
+        // Only messages from the requested slots are replayed, unchanged
+        let expected: Vec<_> = msgs[1..]
+            .iter()
+            .map(|m| serde_json::to_value(m).unwrap())
+            .collect();
+        assert_eq!(replayed, expected);
+    }
+}
@@ -0,0 +1,78 @@
+use indexer_core::db::{custom_types::SlotStatusEnum, models::Slot, mutations};
+use indexer_rabbitmq::geyser::{SlotStatus, SlotStatusUpdate};
+
+use super::Client;
+use crate::prelude::*;
+
+pub(crate) async fn process(client: &Client, update: SlotStatusUpdate) -> Result<SlotStatusEnum> {
+    let SlotStatusUpdate {
+        slot,
+        parent,
+        status,
+    } = update;
+
+    let status = match status {
+        SlotStatus::Processed => SlotStatusEnum::Processed,
+        SlotStatus::Confirmed => SlotStatusEnum::Confirmed,
+        SlotStatus::Rooted => SlotStatusEnum::Rooted,
+    };
+
+    // Blocks only get a block time once confirmed.  Without an RPC node or a
+    // block time reported by the gRPC stream the block time is left unknown
+    // rather than guessed from the current time.
+    let block_time = match status {
+        SlotStatusEnum::Processed => None,
+        SlotStatusEnum::Confirmed | SlotStatusEnum::Rooted => {
+            match client.cached_block_time(slot)? {
+                Some(t) => Some(t),
+                None => client.fetch_block_time(slot).await?,
+            }
+        },
+    };
+
+    let row = Slot {
+        slot: slot.try_into()?,
+        parent_slot: parent.map(TryInto::try_into).transpose()?,
+        status,
+        block_time,
+        updated_at: Utc::now().naive_utc(),
+    };
+
+    client
+        .db()
+        .run(move |db| mutations::slot::upsert(db, &row))
+        .await
+        .context("Failed to store slot status")?;
+
+    Ok(status)
+}
+
+/// Record the production time of a block, as reported by block metadata
+/// from the gRPC stream
+///
+/// # Errors
+/// This function fails if the slot row cannot be written.
+pub(crate) async fn process_block_time(
+    client: &Client,
+    slot: u64,
+    parent: u64,
+    block_time: NaiveDateTime,
+) -> Result<()> {
+    client.cache_block_time(slot, block_time)?;
+
+    // The slot status is only ever raised by the upsert, so this can't undo
+    // a status update received first
+    let row = Slot {
+        slot: slot.try_into()?,
+        parent_slot: Some(parent.try_into()?),
+        status: SlotStatusEnum::Processed,
+        block_time: Some(block_time),
+        updated_at: Utc::now().naive_utc(),
+    };
+
+    client
+        .db()
+        .run(move |db| mutations::slot::upsert(db, &row))
+        .await
+        .context("Failed to store slot block time")
+}
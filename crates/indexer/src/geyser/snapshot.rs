@@ -0,0 +1,359 @@
+//! Offline loading of accounts from a Solana snapshot, for bootstrapping a
+//! database without a validator
+
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    io::Read,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use futures_util::{stream::FuturesUnordered, StreamExt};
+use indexer_rabbitmq::geyser::Message;
+use tokio::sync::mpsc;
+
+use super::{process_message, AccountUpdate, Client, Registry};
+use crate::prelude::*;
+
+/// Size of the metadata stored before each account in an `AppendVec`
+const HEADER_LEN: usize = 136;
+/// Maximum size of an account's data, as enforced by the runtime
+const MAX_DATA_LEN: usize = 10 * 1024 * 1024;
+
+/// An account stored in an `AppendVec`, borrowing its data from the file
+#[derive(Debug, Clone, Copy)]
+struct StoredAccount<'a> {
+    key: Pubkey,
+    lamports: u64,
+    owner: Pubkey,
+    executable: bool,
+    rent_epoch: u64,
+    write_version: u64,
+    data: &'a [u8],
+}
+
+impl<'a> StoredAccount<'a> {
+    fn into_update(self, slot: u64) -> AccountUpdate {
+        AccountUpdate {
+            key: self.key,
+            lamports: self.lamports,
+            owner: self.owner,
+            executable: self.executable,
+            rent_epoch: self.rent_epoch,
+            data: self.data.to_vec(),
+            write_version: self.write_version,
+            slot,
+            is_startup: true,
+        }
+    }
+}
+
+fn u64_at(buf: &[u8], offset: usize) -> u64 {
+    let mut bytes = [0; 8];
+    bytes.copy_from_slice(&buf[offset..offset + 8]);
+    u64::from_le_bytes(bytes)
+}
+
+fn pubkey_at(buf: &[u8], offset: usize) -> Pubkey {
+    let mut bytes = [0; 32];
+    bytes.copy_from_slice(&buf[offset..offset + 32]);
+    Pubkey::new_from_array(bytes)
+}
+
+/// Iterator over the accounts stored in an `AppendVec` file
+///
+/// Each account is stored as its `StoredMeta` (write version, data length and
+/// pubkey), `AccountMeta` (lamports, rent epoch, owner and executable flag),
+/// hash and data, padded to 8-byte alignment.  Files are preallocated, so
+/// iteration stops at the first entry which is zeroed or does not fit.
+struct AppendVec<'a> {
+    buf: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> AppendVec<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Self { buf, offset: 0 }
+    }
+}
+
+impl<'a> Iterator for AppendVec<'a> {
+    type Item = StoredAccount<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let header = self
+            .buf
+            .get(self.offset..self.offset.checked_add(HEADER_LEN)?)?;
+
+        let write_version = u64_at(header, 0);
+        let data_len = usize::try_from(u64_at(header, 8)).ok()?;
+        let key = pubkey_at(header, 16);
+
+        if (write_version == 0 && key == Pubkey::default()) || data_len > MAX_DATA_LEN {
+            return None;
+        }
+
+        let start = self.offset + HEADER_LEN;
+        let data = self.buf.get(start..start + data_len)?;
+
+        // Entries are aligned to 8 bytes
+        self.offset = (start + data_len + 7) & !7;
+
+        Some(StoredAccount {
+            key,
+            lamports: u64_at(header, 48),
+            rent_epoch: u64_at(header, 56),
+            owner: pubkey_at(header, 64),
+            executable: header[96] != 0,
+            write_version,
+            data,
+        })
+    }
+}
+
+/// Parse the slot from an `AppendVec` file name, which has the form
+/// `<slot>.<id>`
+fn append_vec_slot(path: &Path) -> Option<u64> {
+    let (slot, id) = path.file_name()?.to_str()?.split_once('.')?;
+    id.parse::<u64>().ok()?;
+
+    slot.parse().ok()
+}
+
+/// Call a function with the slot and contents of every `AppendVec` in a
+/// snapshot archive or directory
+fn for_each_append_vec(path: &Path, mut f: impl FnMut(u64, &[u8]) -> Result<()>) -> Result<()> {
+    if path.is_dir() {
+        // Accept either an unpacked snapshot or its accounts directory
+        let accounts = path.join("accounts");
+        let dir = if accounts.is_dir() { &accounts } else { path };
+
+        for entry in fs::read_dir(dir).with_context(|| format!("Failed to list {dir:?}"))? {
+            let path = entry.context("Failed to read directory entry")?.path();
+
+            if let Some(slot) = append_vec_slot(&path) {
+                let buf = fs::read(&path).with_context(|| format!("Failed to read {path:?}"))?;
+                f(slot, &buf)?;
+            }
+        }
+
+        return Ok(());
+    }
+
+    let file = File::open(path).with_context(|| format!("Failed to open {path:?}"))?;
+    let reader: Box<dyn Read> = if path.extension().map_or(false, |e| e == "zst") {
+        Box::new(zstd::Decoder::new(file).context("Failed to start zstd decoder")?)
+    } else {
+        Box::new(file)
+    };
+
+    let mut archive = tar::Archive::new(reader);
+
+    for entry in archive
+        .entries()
+        .context("Failed to read snapshot archive")?
+    {
+        let mut entry = entry.context("Failed to read archive entry")?;
+        let path = entry.path().context("Invalid archive entry path")?;
+
+        let slot = match append_vec_slot(&path) {
+            Some(s) if path.starts_with("accounts") => s,
+            _ => continue,
+        };
+
+        let mut buf = Vec::with_capacity(entry.size().try_into()?);
+        entry
+            .read_to_end(&mut buf)
+            .context("Failed to read AppendVec from archive")?;
+
+        f(slot, &buf)?;
+    }
+
+    Ok(())
+}
+
+/// Find the latest version of every account owned by an indexed program
+///
+/// Accounts may be stored in several `AppendVec`s, and closed accounts are
+/// left behind as zero-lamport entries, so only versions newer than any other
+/// version of the same account are loaded.
+fn scan(path: &Path, registry: &Registry) -> Result<HashMap<Pubkey, (u64, u64)>> {
+    let mut latest = HashMap::new();
+    let mut closed = HashMap::new();
+
+    for_each_append_vec(path, |slot, buf| {
+        for account in AppendVec::new(buf) {
+            let version = (slot, account.write_version);
+
+            let map = if account.lamports == 0 {
+                &mut closed
+            } else if registry.handles_account(&account.owner, true) {
+                &mut latest
+            } else {
+                continue;
+            };
+
+            map.entry(account.key)
+                .and_modify(|v: &mut (u64, u64)| *v = (*v).max(version))
+                .or_insert(version);
+        }
+
+        Ok(())
+    })?;
+
+    latest.retain(|k, v| closed.get(k).map_or(true, |c| c < v));
+
+    Ok(latest)
+}
+
+/// Index every account in a Solana snapshot archive or directory of
+/// `AppendVec` files which is owned by a program enabled in the registry.
+/// Accounts are processed as startup updates.
+///
+/// # Errors
+/// This function fails if the snapshot cannot be read.
+pub async fn load(
+    client: &Client,
+    registry: Arc<Registry>,
+    path: PathBuf,
+    concurrency: usize,
+) -> Result<()> {
+    let path = Arc::new(path);
+
+    info!("Scanning snapshot {:?} for indexed accounts...", path);
+
+    let latest = tokio::task::spawn_blocking({
+        let path = Arc::clone(&path);
+        let registry = Arc::clone(&registry);
+        move || scan(&path, &registry)
+    })
+    .await
+    .context("Snapshot scan panicked")??;
+
+    let total = latest.len();
+    info!("Loading {} accounts", total);
+
+    let (tx, mut rx) = mpsc::channel(1024);
+
+    // Reading is blocking, so accounts are handed over from a separate thread
+    let reader = tokio::task::spawn_blocking(move || {
+        for_each_append_vec(&path, |slot, buf| {
+            for account in AppendVec::new(buf) {
+                if latest.get(&account.key) != Some(&(slot, account.write_version)) {
+                    continue;
+                }
+
+                if tx.blocking_send(account.into_update(slot)).is_err() {
+                    bail!("Snapshot loading was stopped");
+                }
+            }
+
+            Ok(())
+        })
+    });
+
+    let mut tasks = FuturesUnordered::new();
+    let mut reading = true;
+    let (mut processed, mut failed) = (0_usize, 0_usize);
+
+    loop {
+        // Keep at most `concurrency` accounts in flight
+        while reading && tasks.len() < concurrency.max(1) {
+            match rx.recv().await {
+                Some(u) => tasks.push(process_message(
+                    Message::AccountUpdate(u),
+                    client,
+                    &registry,
+                )),
+                None => reading = false,
+            }
+        }
+
+        let res = match tasks.next().await {
+            Some(r) => r,
+            None => break,
+        };
+
+        processed += 1;
+
+        if let Err(e) = res {
+            warn!("{}", e);
+            failed += 1;
+        }
+
+        if processed % 100_000 == 0 {
+            info!("Loaded {}/{} accounts", processed, total);
+        }
+    }
+
+    reader.await.context("Snapshot reader panicked")??;
+    client.flush_writes().await?;
+
+    info!(
+        "Snapshot loaded: {} accounts processed, {} failed",
+        processed, failed
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stored(key: Pubkey, owner: Pubkey, write_version: u64, data: &[u8]) -> Vec<u8> {
+        let mut buf = vec![];
+        buf.extend(write_version.to_le_bytes());
+        buf.extend(u64::try_from(data.len()).unwrap().to_le_bytes());
+        buf.extend(key.to_bytes());
+        buf.extend(1_000_u64.to_le_bytes());
+        buf.extend(0_u64.to_le_bytes());
+        buf.extend(owner.to_bytes());
+        buf.extend([0; 8]);
+        buf.extend([0; 32]);
+        buf.extend(data);
+        buf.resize((buf.len() + 7) & !7, 0);
+        buf
+    }
+
+    #[test]
+    fn test_parse_append_vec() {
+        let (a, b, owner) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+
+        let mut buf = stored(a, owner, 5, &[1, 2, 3]);
+        buf.extend(stored(b, owner, 6, &[]));
+        // Preallocated space at the end of the file
+        buf.extend([0; 4096]);
+
+        let accounts: Vec<_> = AppendVec::new(&buf).collect();
+
+        assert_eq!(accounts.len(), 2);
+        assert_eq!(accounts[0].key, a);
+        assert_eq!(accounts[0].owner, owner);
+        assert_eq!(accounts[0].write_version, 5);
+        assert_eq!(accounts[0].lamports, 1_000);
+        assert_eq!(accounts[0].data, &[1, 2, 3]);
+        assert_eq!(accounts[1].key, b);
+        assert!(accounts[1].data.is_empty());
+    }
+
+    #[test]
+    fn test_stop_at_truncated_account() {
+        let mut buf = stored(Pubkey::new_unique(), Pubkey::new_unique(), 1, &[7; 64]);
+        buf.truncate(HEADER_LEN + 10);
+
+        assert_eq!(AppendVec::new(&buf).count(), 0);
+    }
+
+    #[test]
+    fn test_append_vec_slot() {
+        assert_eq!(append_vec_slot(Path::new("accounts/1234.56")), Some(1234));
+        assert_eq!(append_vec_slot(Path::new("snapshots/1234/1234")), None);
+        assert_eq!(append_vec_slot(Path::new("version")), None);
+    }
+}
@@ -0,0 +1,319 @@
+//! Harness for end-to-end tests of the Geyser processors
+//!
+//! A fixture is a directory under `tests/data/fixtures` containing:
+//!  - `accounts/<owner>/<key>.dmp`: raw account data, indexed as updates of
+//!    the account `<key>` owned by the program `<owner>`
+//!  - `messages.jsonl`: Geyser messages as written by `--record`, indexed in
+//!    order after the accounts
+//!  - `snapshots/<table>.json`: the rows each table should contain afterwards
+//!
+//! Each fixture runs against a new database created on the Postgres server at
+//! `TEST_DATABASE_URL` (which must be Postgres 13 or newer).  Fixtures are
+//! skipped if the variable is not set, except in CI (where `CI` is set), in
+//! which case they fail.  Run with `UPDATE_SNAPSHOTS=1` to overwrite the
+//! snapshots with the rows actually written.
+
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use indexer_core::{
+    clap::{self, Parser},
+    db::{self, queries::table_dump, sql_query},
+};
+use indexer_rabbitmq::geyser::Message;
+use serde_json::Value;
+
+use super::{process_message, AccountUpdate, Client, ClientArgs, Registry, RegistryArgs};
+use crate::{db::Pool, prelude::*};
+
+/// Columns generated from the clock or at random, which are left out of
+/// snapshots
+const VOLATILE_COLUMNS: &[&str] = &["id", "created_at", "updated_at", "indexed_at"];
+
+static DB_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+#[derive(Debug, Parser)]
+struct Opts {
+    #[command(flatten)]
+    db: db::ConnectArgs,
+
+    #[command(flatten)]
+    client: ClientArgs,
+
+    #[command(flatten)]
+    programs: RegistryArgs,
+}
+
+/// A database which is dropped along with this handle
+struct TestDb {
+    server: db::Pool,
+    name: String,
+}
+
+impl TestDb {
+    fn create(server_url: &str) -> Result<(Self, String)> {
+        let (url, params) = server_url.split_once('?').unwrap_or((server_url, ""));
+        let (base, _) = url
+            .rsplit_once('/')
+            .ok_or_else(|| anyhow!("Invalid TEST_DATABASE_URL"))?;
+
+        let name = format!(
+            "indexer_test_{}_{}",
+            std::process::id(),
+            DB_COUNTER.fetch_add(1, Ordering::Relaxed)
+        );
+
+        let server = db::Pool::builder()
+            .max_size(1)
+            .build(db::ConnectionManager::new(server_url))
+            .context("Failed to connect to the test database server")?;
+
+        sql_query(format!("create database {name}"))
+            .execute(&server.get()?)
+            .context("Failed to create test database")?;
+
+        let url = if params.is_empty() {
+            format!("{base}/{name}")
+        } else {
+            format!("{base}/{name}?{params}")
+        };
+
+        Ok((Self { server, name }, url))
+    }
+}
+
+impl Drop for TestDb {
+    fn drop(&mut self) {
+        let res = self.server.get().map_err(Error::from).and_then(|c| {
+            sql_query(format!(
+                "drop database if exists {} with (force)",
+                self.name
+            ))
+            .execute(&c)
+            .map_err(Into::into)
+        });
+
+        if let Err(e) = res {
+            warn!("Failed to drop test database {}: {:?}", self.name, e);
+        }
+    }
+}
+
+/// A processor test environment with its own database and a client which
+/// does not dispatch to AMQP, Dialect or an RPC node
+pub(crate) struct Harness {
+    client: Arc<Client>,
+    registry: Registry,
+    _db: TestDb,
+}
+
+impl Harness {
+    /// Create a new database and client, or return `None` if
+    /// `TEST_DATABASE_URL` is not set
+    pub fn new() -> Result<Option<Self>> {
+        let server_url = match env::var("TEST_DATABASE_URL") {
+            Ok(u) => u,
+            Err(_) => return Ok(None),
+        };
+
+        let (test_db, url) = TestDb::create(&server_url)?;
+
+        let Opts {
+            db,
+            client,
+            programs,
+        } = Opts::try_parse_from([
+            "test",
+            "--database-write-url",
+            &url,
+            "--meili-url",
+            "http://127.0.0.1:7700",
+            "--meili-key",
+            "test",
+        ])?;
+
+        let pool = Pool::new(
+            db::connect(db, db::ConnectMode::Write { migrate: true })
+                .context("Failed to migrate test database")?,
+        );
+
+        Ok(Some(Self {
+            client: Client::new_offline(pool, client)?,
+            registry: Registry::new(programs)?,
+            _db: test_db,
+        }))
+    }
+
+    /// Index a single Geyser message
+    pub async fn process(&self, msg: Message) -> Result<()> {
+        process_message(msg, &self.client, &self.registry).await
+    }
+
+    /// Index the accounts and messages of a fixture
+    pub async fn run_fixture(&self, dir: &Path) -> Result<()> {
+        for (i, update) in load_accounts(&dir.join("accounts"))?
+            .into_iter()
+            .enumerate()
+        {
+            self.process(Message::AccountUpdate(AccountUpdate {
+                write_version: i.try_into()?,
+                ..update
+            }))
+            .await?;
+        }
+
+        let messages = dir.join("messages.jsonl");
+
+        if messages.exists() {
+            let lines = fs::read_to_string(&messages)
+                .with_context(|| format!("Failed to read {messages:?}"))?;
+
+            for (i, line) in lines.lines().enumerate() {
+                if line.trim().is_empty() {
+                    continue;
+                }
+
+                let msg = serde_json::from_str(line)
+                    .with_context(|| format!("Malformed message on line {}", i + 1))?;
+                self.process(msg)
+                    .await
+                    .with_context(|| format!("Failed to process message on line {}", i + 1))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Load the rows of a table, without any volatile columns
+    pub async fn rows(&self, table: impl Into<String>) -> Result<Vec<Value>> {
+        let table = table.into();
+        let mut rows = self
+            .client
+            .db()
+            .run(move |db| table_dump::load(db, &table))
+            .await?;
+
+        for row in &mut rows {
+            if let Value::Object(map) = row {
+                for col in VOLATILE_COLUMNS {
+                    map.remove(*col);
+                }
+            }
+        }
+
+        rows.sort_by_cached_key(ToString::to_string);
+
+        Ok(rows)
+    }
+
+    /// Compare every table with a snapshot in the fixture against its
+    /// snapshot, or overwrite the snapshots if `UPDATE_SNAPSHOTS` is set
+    pub async fn check_snapshots(&self, dir: &Path) -> Result<()> {
+        let update = env::var_os("UPDATE_SNAPSHOTS").is_some();
+
+        for entry in fs::read_dir(dir.join("snapshots")).context("Fixture has no snapshots")? {
+            let path = entry?.path();
+            let table = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .ok_or_else(|| anyhow!("Invalid snapshot name {path:?}"))?
+                .to_owned();
+
+            let rows = self.rows(table).await?;
+
+            if update {
+                let mut json = serde_json::to_string_pretty(&rows)?;
+                json.push('\n');
+                fs::write(&path, json).with_context(|| format!("Failed to write {path:?}"))?;
+                continue;
+            }
+
+            let expected: Vec<Value> = serde_json::from_slice(&fs::read(&path)?)
+                .with_context(|| format!("Malformed snapshot {path:?}"))?;
+
+            ensure!(
+                rows == expected,
+                "Rows do not match snapshot {:?}:\n{}",
+                path,
+                serde_json::to_string_pretty(&rows)?
+            );
+        }
+
+        Ok(())
+    }
+}
+
+fn load_accounts(dir: &Path) -> Result<Vec<AccountUpdate>> {
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut paths: Vec<PathBuf> = vec![];
+
+    for owner in fs::read_dir(dir)? {
+        for account in fs::read_dir(owner?.path())? {
+            paths.push(account?.path());
+        }
+    }
+
+    // Keep the processing order stable between runs
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let parse = |p: Option<&Path>| -> Result<Pubkey> {
+                p.and_then(Path::file_stem)
+                    .and_then(|s| s.to_str())
+                    .ok_or_else(|| anyhow!("Invalid account dump path {path:?}"))?
+                    .parse()
+                    .with_context(|| format!("Invalid pubkey in {path:?}"))
+            };
+
+            Ok(AccountUpdate {
+                key: parse(Some(&path))?,
+                lamports: 1,
+                owner: parse(path.parent())?,
+                executable: false,
+                rent_epoch: 0,
+                data: fs::read(&path).with_context(|| format!("Failed to read {path:?}"))?,
+                write_version: 0,
+                slot: 1,
+                is_startup: false,
+            })
+        })
+        .collect()
+}
+
+/// Run the fixture with the given name through the processors and check its
+/// snapshots, skipping it if no test database is configured outside of CI
+///
+/// # Panics
+/// This function panics if the fixture fails to process, its snapshots do not
+/// match, or no test database is configured in CI.
+pub(crate) async fn run_fixture(name: &str) {
+    let harness = match Harness::new().unwrap() {
+        Some(h) => h,
+        None => {
+            assert!(
+                env::var_os("CI").is_none(),
+                "TEST_DATABASE_URL must be set to run fixture {name} in CI"
+            );
+
+            eprintln!("TEST_DATABASE_URL is not set, skipping fixture {name}");
+            return;
+        },
+    };
+
+    let mut dir = env::current_dir().unwrap();
+    dir.extend(["tests", "data", "fixtures", name]);
+
+    harness.run_fixture(&dir).await.unwrap();
+    harness.check_snapshots(&dir).await.unwrap();
+}
@@ -146,7 +146,7 @@ async fn fetch_json(
         .http()
         .run(|h| {
             let url = url.clone();
-            async move { h.get(url).send().await?.bytes().await }
+            async move { h.get(url).send().await?.error_for_status()?.bytes().await }
         })
         .await
         .context("Failed to download metadata JSON")?;
@@ -232,7 +232,7 @@ async fn try_locate_json(
                     url_str, meta_key, e
                 );
 
-                resp = Err(());
+                resp = Err(e);
             },
         }
     }
@@ -248,7 +248,7 @@ async fn try_locate_json(
 
             None
         },
-        Err(()) if TRY_LAST_RESORT => {
+        Err(_) if TRY_LAST_RESORT => {
             let (json, extra) = fetch_json(client, meta_key, Ok(id.url.clone()))
                 .await
                 .with_context(|| {
@@ -261,12 +261,13 @@ async fn try_locate_json(
 
             Some((json, vec![], extra))
         },
-        Err(()) => {
-            bail!(
+        // Keep the cause, so rate limits and timeouts are retried
+        Err(e) => {
+            return Err(e.context(format!(
                 "Cached metadata fetch {:?} for {} failed (not trying last-resort)",
                 id.url.as_str(),
                 meta_key
-            )
+            )));
         },
     })
 }
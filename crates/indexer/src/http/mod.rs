@@ -31,11 +31,23 @@ impl fmt::Display for MessageId {
 
 /// Define processing logic for an incoming entity type
 #[async_trait::async_trait]
-pub trait Process: Entity {
+pub trait Process: Entity + MessageLabel {
     /// Process and consume an incoming entity
     async fn process(self, client: &Client) -> MessageResult<MessageId>;
 }
 
+impl MessageLabel for MetadataJson {
+    fn label(&self) -> &'static str {
+        "metadata_json"
+    }
+}
+
+impl MessageLabel for StoreConfig {
+    fn label(&self) -> &'static str {
+        "store_config"
+    }
+}
+
 #[async_trait::async_trait]
 impl Process for MetadataJson {
     async fn process(self, client: &Client) -> MessageResult<MessageId> {
@@ -21,6 +21,14 @@ impl fmt::Display for MessageId {
     }
 }
 
+impl MessageLabel for Message {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::RefreshTable(_) => "refresh_table",
+        }
+    }
+}
+
 /// Process a message from the background job RabbitMQ queue
 ///
 /// # Errors
@@ -16,6 +16,7 @@ pub mod geyser;
 pub mod http;
 #[cfg(feature = "job-runner")]
 pub mod jobs;
+pub(crate) mod metrics;
 #[cfg(feature = "reqwest")]
 pub(crate) mod reqwest;
 #[cfg(feature = "search")]
@@ -33,20 +34,29 @@ pub mod prelude {
     pub use indexer_core::prelude::*;
     pub use solana_program::pubkey::Pubkey;
 
-    pub use crate::{MessageError, MessageResult};
+    pub use crate::{MessageError, MessageLabel, MessageResult};
 }
 
 mod runtime {
     use std::{
+        cell::RefCell,
+        collections::hash_map::DefaultHasher,
         fmt::{Debug, Display},
         future::Future,
+        hash::{Hash, Hasher},
+        time::Instant,
     };
 
-    use futures_util::{stream::FuturesUnordered, FutureExt, StreamExt};
+    use futures_util::{
+        future::{self, BoxFuture},
+        stream::FuturesUnordered,
+        FutureExt, StreamExt,
+    };
     use indexer_core::{
         clap,
         clap::{Args, Parser},
         db,
+        rand::{self, Rng},
     };
     use indexer_rabbitmq::{
         consumer::Consumer,
@@ -54,9 +64,12 @@ mod runtime {
         lapin::options::{BasicAckOptions, BasicRejectOptions},
         QueueType,
     };
-    use tokio::sync::{broadcast, broadcast::error::RecvError};
+    use tokio::{
+        sync::{broadcast, broadcast::error::RecvError, mpsc},
+        task::JoinHandle,
+    };
 
-    use super::{db::Pool, prelude::*};
+    use super::{db::Pool, metrics, prelude::*};
 
     #[derive(Debug, Parser)]
     struct Opts<T: Debug + Args> {
@@ -72,15 +85,70 @@ mod runtime {
         #[command(flatten)]
         db: db::ConnectArgs,
 
+        #[command(flatten)]
+        retry: RetryArgs,
+
+        #[command(flatten)]
+        metrics: metrics::Args,
+
         #[command(flatten)]
         extra: T,
     }
 
+    /// Settings for retrying messages which failed with a retryable error
+    #[derive(Debug, Clone, Copy, clap::Args)]
+    struct RetryArgs {
+        /// The number of times to retry a message which failed with a
+        /// retryable error before dead-lettering it
+        #[arg(long, env, default_value_t = 3)]
+        max_retries: u32,
+
+        /// The delay before the first retry of a failed message, in
+        /// milliseconds, doubled after each attempt
+        #[arg(long, env, default_value_t = 250)]
+        retry_delay_ms: u64,
+
+        /// The longest delay between two retries of a message, in
+        /// milliseconds
+        #[arg(long, env, default_value_t = 10_000)]
+        max_retry_delay_ms: u64,
+    }
+
+    impl RetryArgs {
+        /// Compute the delay before the given retry, with up to half of it
+        /// randomized so failed messages don't all retry at once
+        fn delay(self, retry: u32) -> StdDuration {
+            let max = self
+                .retry_delay_ms
+                .saturating_mul(2_u64.saturating_pow(retry.saturating_sub(1)))
+                .min(self.max_retry_delay_ms);
+
+            StdDuration::from_millis(rand::thread_rng().gen_range(max / 2..=max))
+        }
+    }
+
     /// Common parameters for all indexers
     #[allow(missing_copy_implementations)]
     #[derive(Debug)]
     pub struct Params {
         concurrency: usize,
+        retry: RetryArgs,
+    }
+
+    impl Params {
+        /// The number of messages to process concurrently
+        #[must_use]
+        pub fn concurrency(&self) -> usize {
+            self.concurrency
+        }
+
+        /// Get the delay before retrying a message which failed with a
+        /// retryable error after the given number of attempts, or `None` if
+        /// it has already been retried as many times as configured
+        #[must_use]
+        pub fn retry_delay(&self, attempts: u32) -> Option<StdDuration> {
+            (attempts <= self.retry.max_retries).then(|| self.retry.delay(attempts))
+        }
     }
 
     /// Entrypoint for `holaplex-indexer` binaries
@@ -96,6 +164,8 @@ mod runtime {
                 thread_count,
                 db,
                 migrate_db: migrate,
+                retry,
+                metrics,
                 extra,
             } = opts;
 
@@ -118,7 +188,13 @@ mod runtime {
 
             let concurrency = thread_count.unwrap_or_else(indexer_core::num_cpus::get);
 
-            rt.block_on(f(extra, Params { concurrency }, db))
+            rt.spawn(async move {
+                if let Err(e) = metrics::serve(metrics).await {
+                    error!("{:?}", e);
+                }
+            });
+
+            rt.block_on(f(extra, Params { concurrency, retry }, db))
         })
     }
 
@@ -156,83 +232,383 @@ mod runtime {
         Stopped,
     }
 
+    /// Whether a message which failed to process may succeed if it is
+    /// processed again
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ErrorKind {
+        /// The failure may be transient, e.g. a dropped connection, a rate
+        /// limit, or a row another message has yet to insert
+        Retryable,
+        /// Processing the message again would fail the same way
+        Permanent,
+    }
+
+    impl ErrorKind {
+        /// Classify an error by the errors in its chain of sources
+        #[must_use]
+        pub fn of(err: &Error) -> Self {
+            let retryable = err.chain().any(|e| {
+                if let Some(e) = e.downcast_ref::<db::Error>() {
+                    // A missing row may be inserted by a message which has
+                    // not been processed yet
+                    return matches!(
+                        e,
+                        db::Error::NotFound
+                            | db::Error::DatabaseError(
+                                db::DatabaseErrorKind::ForeignKeyViolation
+                                    | db::DatabaseErrorKind::SerializationFailure
+                                    | db::DatabaseErrorKind::UnableToSendCommand,
+                                _,
+                            )
+                    );
+                }
+
+                #[cfg(feature = "reqwest")]
+                {
+                    use crate::reqwest::StatusCode;
+
+                    if let Some(e) = e.downcast_ref::<crate::reqwest::Error>() {
+                        return e.is_timeout()
+                            || e.is_connect()
+                            || e.status().map_or(false, |s| {
+                                s == StatusCode::TOO_MANY_REQUESTS || s.is_server_error()
+                            });
+                    }
+                }
+
+                e.is::<NotReady>() || e.is::<db::PoolError>() || e.is::<std::io::Error>()
+            });
+
+            if retryable {
+                Self::Retryable
+            } else {
+                Self::Permanent
+            }
+        }
+    }
+
+    /// An error for a message which depends on data that has not arrived yet,
+    /// and may succeed if it is processed again later
+    #[derive(Debug, thiserror::Error)]
+    #[error("{0}")]
+    pub struct NotReady(pub String);
+
     /// An error from a message processor, including a message identifier
     #[derive(Debug, thiserror::Error)]
     #[error("Failed to process {1}: {0:?}")]
-    pub struct MessageError<D>(#[source] Error, D);
+    pub struct MessageError<D>(#[source] Error, D, ErrorKind);
 
     impl<D: Display> MessageError<D> {
-        /// Construct a new message error
+        /// Construct a new message error, classifying it with
+        /// [`ErrorKind::of`]
         #[inline]
         #[must_use]
         pub fn new(err: Error, id: D) -> Self {
-            Self(err, id)
+            let kind = ErrorKind::of(&err);
+            Self(err, id, kind)
+        }
+
+        /// Construct a new message error of the given kind
+        #[inline]
+        #[must_use]
+        pub fn with_kind(err: Error, id: D, kind: ErrorKind) -> Self {
+            Self(err, id, kind)
+        }
+
+        /// Get whether the message may succeed if it is processed again
+        #[inline]
+        #[must_use]
+        pub fn kind(&self) -> ErrorKind {
+            self.2
         }
     }
 
     /// Convenience alias for the result of a message processor function
     pub type MessageResult<D> = Result<(), MessageError<D>>;
 
+    /// A message type whose variants are counted separately in metrics
+    pub trait MessageLabel {
+        /// Get a short, fixed name for the kind of this message
+        fn label(&self) -> &'static str;
+    }
+
+    tokio::task_local! {
+        /// Writes which must complete before the message being processed is
+        /// acknowledged
+        static DEFERRED_ACKS: RefCell<Vec<BoxFuture<'static, Result<()>>>>;
+
+        /// The number of the current attempt at processing a message
+        static ATTEMPT: u32;
+    }
+
+    /// Get the number of the current attempt at processing the AMQP message
+    /// being processed, starting from 1, or `None` outside of an AMQP consumer
+    #[must_use]
+    pub fn attempt() -> Option<u32> {
+        ATTEMPT.try_with(|a| *a).ok()
+    }
+
+    /// Hold the acknowledgement of the AMQP message currently being processed
+    /// until the given future completes.  If the future fails the message is
+    /// requeued.
+    ///
+    /// Outside of an AMQP consumer the future is dropped, so callers should
+    /// make sure their writes complete some other way.
+    pub(crate) fn defer_ack(f: impl Future<Output = Result<()>> + Send + 'static) {
+        DEFERRED_ACKS
+            .try_with(|d| d.borrow_mut().push(f.boxed()))
+            .ok();
+    }
+
+    /// Wait for the deferred writes of a processed message, then acknowledge
+    /// it, or requeue it if a write failed
+    async fn ack_deferred(
+        deferred: Vec<BoxFuture<'static, Result<()>>>,
+        acker: lapin::acker::Acker,
+    ) -> Result<()> {
+        match future::try_join_all(deferred).await {
+            Ok(_) => acker
+                .ack(BasicAckOptions::default())
+                .await
+                .context("Failed to send ACK for delivery"),
+            Err(e) => {
+                warn!("Requeueing message after deferred write failed: {:?}", e);
+
+                acker
+                    .reject(BasicRejectOptions { requeue: true })
+                    .await
+                    .context("Failed to send NAK for delivery")
+            },
+        }
+    }
+
+    enum Delivery<T> {
+        Message(T, lapin::acker::Acker),
+        Hangup,
+        Stop,
+    }
+
+    async fn next_delivery<Q: QueueType>(
+        consumer: &mut Consumer<Q>,
+        stop_rx: &mut broadcast::Receiver<()>,
+    ) -> Result<Delivery<Q::Message>>
+    where
+        Q::Message: for<'de> serde::Deserialize<'de>,
+    {
+        tokio::select! {
+            r = consumer.read() => Ok(match r.context("Failed to read AMQP message")? {
+                Some((msg, acker)) => Delivery::Message(msg, acker),
+                None => Delivery::Hangup,
+            }),
+            r = stop_rx.recv() => match r {
+                Ok(()) | Err(RecvError::Closed) => Ok(Delivery::Stop),
+                Err(e) => Err(e).context("Error receiving stop signal"),
+            },
+        }
+    }
+
+    /// Process a message, retrying it if it fails with a retryable error, and
+    /// acknowledge or dead-letter it
+    ///
+    /// Retries happen in-process, so the attempt count is not written to the
+    /// message's headers: rejecting a delivery cannot modify it, and the
+    /// dead-letter exchange and its redelivery are owned by
+    /// `indexer_rabbitmq`.  Attempt counts are reported by
+    /// [`attempt`], the `indexer_message_attempts` metric and the
+    /// dead-letter log instead.
+    async fn handle_one<
+        M: Clone + MessageLabel,
+        F: Future<Output = MessageResult<D>>,
+        D: Display,
+    >(
+        msg: M,
+        acker: lapin::acker::Acker,
+        process: &impl Fn(M) -> F,
+        retry: RetryArgs,
+    ) -> Result<()> {
+        let label = msg.label();
+        let start = Instant::now();
+        let mut attempts = 1;
+
+        metrics::message_started();
+
+        let (res, deferred) = loop {
+            let (res, deferred) = ATTEMPT
+                .scope(
+                    attempts,
+                    DEFERRED_ACKS.scope(RefCell::default(), async {
+                        let res = process(msg.clone()).await;
+
+                        (res, DEFERRED_ACKS.with(RefCell::take))
+                    }),
+                )
+                .await;
+
+            match res {
+                Err(e) if e.2 == ErrorKind::Retryable && attempts <= retry.max_retries => {
+                    metrics::attempt_failed(label, e.2);
+
+                    let delay = retry.delay(attempts);
+
+                    debug!(
+                        "Retrying {} in {:?} after {} attempt(s): {:?}",
+                        e.1, delay, attempts, e.0
+                    );
+
+                    tokio::time::sleep(delay).await;
+                    attempts += 1;
+                },
+                r => break (r, deferred),
+            }
+        };
+
+        if let Err(ref e) = res {
+            metrics::attempt_failed(label, e.2);
+        }
+
+        metrics::message_finished(
+            label,
+            res.as_ref().err().map(|e| e.2),
+            attempts,
+            start.elapsed(),
+        );
+
+        match res {
+            Ok(()) if deferred.is_empty() => acker
+                .ack(BasicAckOptions::default())
+                .await
+                .context("Failed to send ACK for delivery"),
+            Ok(()) => {
+                // Let the worker move on while the writes are pending
+                tokio::spawn(async move {
+                    if let Err(e) = ack_deferred(deferred, acker).await {
+                        error!("{:?}", e);
+                    }
+                });
+
+                Ok(())
+            },
+            Err(e) => {
+                warn!(
+                    "Failed to process {} after {} attempt(s): {:?}",
+                    e.1, attempts, e.0
+                );
+
+                acker
+                    .reject(BasicRejectOptions { requeue: false })
+                    .await
+                    .context("Failed to send NAK for delivery")
+            },
+        }
+    }
+
     async fn consume_one<Q: QueueType, F: Future<Output = MessageResult<D>>, D: Display>(
         worker_id: usize,
         mut consumer: Consumer<Q>,
         process: impl Fn(Q::Message) -> F,
+        retry: RetryArgs,
         mut stop_rx: broadcast::Receiver<()>,
     ) -> Result<StopType>
     where
-        Q::Message: Debug + for<'de> serde::Deserialize<'de>,
+        Q::Message: Clone + Debug + MessageLabel + for<'de> serde::Deserialize<'de>,
     {
-        enum Delivery<T> {
-            Message(Option<(T, lapin::acker::Acker)>),
-            Stop,
-        }
+        loop {
+            let (msg, acker) = match next_delivery(&mut consumer, &mut stop_rx).await? {
+                Delivery::Message(m, a) => (m, a),
+                Delivery::Hangup => break Ok(StopType::Hangup),
+                Delivery::Stop => break Ok(StopType::Stopped),
+            };
 
-        // Ideally T would be ! but ! is unstable.
-        fn handle_stop<T>(r: Result<(), RecvError>) -> Result<Delivery<T>> {
-            match r {
-                Ok(()) | Err(RecvError::Closed) => Ok(Delivery::Stop),
-                Err(e) => Err(e).context("Error receiving stop signal"),
-            }
+            trace!("Worker {}: {:?}", worker_id, msg);
+
+            handle_one(msg, acker, &process, retry).await?;
         }
+    }
 
-        loop {
-            let del = tokio::select! {
-                r = consumer.read() => {
-                    Delivery::Message(r.context("Failed to read AMQP message")?)
-                },
-                r = stop_rx.recv() => handle_stop(r)?,
-            };
+    /// Read messages from a consumer and hand each one to the worker lane
+    /// assigned to its partition key, so messages with the same key are
+    /// processed in the order they were received
+    async fn consume_partitioned<
+        Q: QueueType,
+        F: Send + Future<Output = MessageResult<D>> + 'static,
+        D: Display + Send + 'static,
+        K: Hash,
+    >(
+        mut consumer: Consumer<Q>,
+        process: impl Fn(Q::Message) -> F + Send + Sync + Clone + 'static,
+        partition: impl Fn(&Q::Message) -> Option<K>,
+        retry: RetryArgs,
+        lanes: usize,
+        mut stop_rx: broadcast::Receiver<()>,
+    ) -> Result<StopType>
+    where
+        Q::Message:
+            Clone + Debug + MessageLabel + Send + 'static + for<'de> serde::Deserialize<'de>,
+    {
+        let (senders, tasks): (Vec<_>, Vec<_>) = (0..lanes.max(1))
+            .map(|i| {
+                // Lanes are unbounded so a lane backing off a failed message
+                // never stalls delivery to the others
+                let (tx, mut rx) = mpsc::unbounded_channel::<(Q::Message, lapin::acker::Acker)>();
+                let process = process.clone();
 
-            let (msg, acker) = match del {
-                Delivery::Message(Some(d)) => d,
-                Delivery::Message(None) => break Ok(StopType::Hangup),
-                Delivery::Stop => break Ok(StopType::Stopped),
+                let task = tokio::spawn(async move {
+                    while let Some((msg, acker)) = rx.recv().await {
+                        trace!("Lane {}: {:?}", i, msg);
+
+                        handle_one(msg, acker, &process, retry).await?;
+                    }
+
+                    Result::<_>::Ok(())
+                });
+
+                (tx, task)
+            })
+            .unzip();
+
+        let mut next_lane = 0;
+
+        let stop = loop {
+            let (msg, acker) = match next_delivery(&mut consumer, &mut stop_rx).await? {
+                Delivery::Message(m, a) => (m, a),
+                Delivery::Hangup => break StopType::Hangup,
+                Delivery::Stop => break StopType::Stopped,
             };
 
-            trace!("Worker {}: {:?}", worker_id, msg);
+            let lane = if let Some(key) = partition(&msg) {
+                let mut hasher = DefaultHasher::new();
+                key.hash(&mut hasher);
 
-            match process(msg).await {
-                Ok(()) => acker
-                    .ack(BasicAckOptions::default())
-                    .await
-                    .context("Failed to send ACK for delivery")?,
-                Err(e) => {
-                    warn!("Failed to process {}: {:?}", e.1, e.0);
-
-                    acker
-                        .reject(BasicRejectOptions { requeue: false })
-                        .await
-                        .context("Failed to send NAK for delivery")?;
-                },
+                usize::try_from(hasher.finish() % u64::try_from(senders.len())?)?
+            } else {
+                // Unordered messages are spread evenly across the lanes
+                next_lane = (next_lane + 1) % senders.len();
+                next_lane
+            };
+
+            if senders[lane].send((msg, acker)).is_err() {
+                break StopType::Stopped;
             }
+        };
+
+        // Let the lanes finish the messages already handed to them
+        std::mem::drop(senders);
+
+        for task in tasks {
+            task.await
+                .context("Worker lane terminated unexpectedly")??;
         }
+
+        Ok(stop)
     }
 
     /// Consume messages from an AMQP consumer until the connection closes
     ///
     /// # Errors
     /// This function fails if a message cannot be received, but _does not_ fail
-    /// if a received message fails to process.
+    /// if a received message fails to process.  Messages which fail with a
+    /// retryable error are retried with exponential backoff before being
+    /// dead-lettered.
     ///
     /// # Panics
     /// This function will panic if the internal scheduler enters a deadlock
@@ -250,10 +626,83 @@ mod runtime {
         process: impl Fn(Q::Message) -> F + Send + Sync + Clone + 'static,
     ) -> Result<()>
     where
-        Q::Message: Debug + Send + for<'a> serde::Deserialize<'a>,
+        Q::Message: Clone + Debug + MessageLabel + Send + for<'a> serde::Deserialize<'a>,
     {
-        let Params { concurrency } = *params;
+        let Params { concurrency, retry } = *params;
+
+        supervise(conn, queue_type, grace_period, |stop_tx| {
+            (0..concurrency)
+                .map(|i| {
+                    tokio::spawn(consume_one(
+                        i,
+                        consumer.clone(),
+                        process.clone(),
+                        retry,
+                        stop_tx.subscribe(),
+                    ))
+                })
+                .collect()
+        })
+        .await
+    }
 
+    /// Consume messages from an AMQP consumer until the connection closes,
+    /// processing messages with the same partition key one at a time in the
+    /// order they were received
+    ///
+    /// Each partition key is assigned to one of `concurrency` worker lanes.
+    /// Messages without a partition key are not ordered and are spread across
+    /// all lanes.
+    ///
+    /// # Errors
+    /// This function fails if a message cannot be received, but _does not_ fail
+    /// if a received message fails to process.  Messages which fail with a
+    /// retryable error are retried with exponential backoff before being
+    /// dead-lettered.
+    ///
+    /// # Panics
+    /// This function will panic if the internal scheduler enters a deadlock
+    /// state.
+    pub async fn amqp_consume_partitioned<
+        Q: QueueType + Send + Sync + 'static,
+        F: Send + Future<Output = MessageResult<D>> + 'static,
+        D: Display + Send + 'static,
+        K: Hash,
+    >(
+        params: &Params,
+        conn: indexer_rabbitmq::lapin::Connection,
+        consumer: Consumer<Q>,
+        queue_type: Q,
+        grace_period: StdDuration,
+        partition: impl Fn(&Q::Message) -> Option<K> + Send + 'static,
+        process: impl Fn(Q::Message) -> F + Send + Sync + Clone + 'static,
+    ) -> Result<()>
+    where
+        Q::Message: Clone + Debug + MessageLabel + Send + 'static + for<'a> serde::Deserialize<'a>,
+    {
+        let Params { concurrency, retry } = *params;
+
+        supervise(conn, queue_type, grace_period, |stop_tx| {
+            vec![tokio::spawn(consume_partitioned(
+                consumer,
+                process,
+                partition,
+                retry,
+                concurrency,
+                stop_tx.subscribe(),
+            ))]
+        })
+        .await
+    }
+
+    /// Run the dead-letter consumer and the given consumer tasks until a stop
+    /// signal is received or a task stops, then shut them down
+    async fn supervise<Q: QueueType + Send + Sync + 'static>(
+        conn: indexer_rabbitmq::lapin::Connection,
+        queue_type: Q,
+        grace_period: StdDuration,
+        spawn: impl FnOnce(&broadcast::Sender<()>) -> Vec<JoinHandle<Result<StopType>>>,
+    ) -> Result<()> {
         let dl_task = tokio::spawn(indexer_rabbitmq::dl_consumer::run(
             conn,
             queue_type,
@@ -262,15 +711,10 @@ mod runtime {
 
         let (stop_tx, _stop_rx) = broadcast::channel(1);
 
-        let mut q_tasks = (0..concurrency)
-            .map(|i| {
-                tokio::spawn(consume_one(
-                    i,
-                    consumer.clone(),
-                    process.clone(),
-                    stop_tx.subscribe(),
-                ))
-                .map(|r| match r {
+        let mut q_tasks = spawn(&stop_tx)
+            .into_iter()
+            .map(|t| {
+                t.map(|r| match r {
                     Ok(Ok(StopType::Hangup)) => warn!("AMQP server hung up!"),
                     Ok(Ok(StopType::Stopped)) => (),
                     Ok(Err(e)) => error!("Fatal error in worker: {:?}", e),
@@ -362,4 +806,65 @@ mod runtime {
 
         Ok(())
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_error_kind() {
+            let fk = Error::from(db::Error::DatabaseError(
+                db::DatabaseErrorKind::ForeignKeyViolation,
+                Box::new(String::from("missing row")),
+            ));
+            assert_eq!(
+                ErrorKind::of(&fk.context("Failed to insert")),
+                ErrorKind::Retryable
+            );
+
+            let io = Error::from(std::io::Error::from(std::io::ErrorKind::ConnectionReset));
+            assert_eq!(ErrorKind::of(&io), ErrorKind::Retryable);
+
+            let not_ready = Error::from(NotReady("Block time unknown".into()));
+            assert_eq!(
+                ErrorKind::of(&not_ready.context("Failed to insert")),
+                ErrorKind::Retryable
+            );
+
+            let not_found = Error::from(db::Error::NotFound);
+            assert_eq!(
+                ErrorKind::of(&not_found.context("Failed to load metadata")),
+                ErrorKind::Retryable
+            );
+
+            assert_eq!(ErrorKind::of(&anyhow!("Bad data")), ErrorKind::Permanent);
+            assert_eq!(
+                ErrorKind::of(&Error::from(db::Error::QueryBuilderError(
+                    "bad query".into()
+                ))),
+                ErrorKind::Permanent
+            );
+        }
+
+        #[test]
+        fn test_retry_delay() {
+            let retry = RetryArgs {
+                max_retries: 5,
+                retry_delay_ms: 100,
+                max_retry_delay_ms: 1_000,
+            };
+
+            for (attempt, max) in [(1, 100), (2, 200), (3, 400), (5, 1_000), (40, 1_000)] {
+                let delay = retry.delay(attempt);
+                assert!(
+                    delay >= StdDuration::from_millis(max / 2),
+                    "{attempt}: {delay:?}"
+                );
+                assert!(
+                    delay <= StdDuration::from_millis(max),
+                    "{attempt}: {delay:?}"
+                );
+            }
+        }
+    }
 }
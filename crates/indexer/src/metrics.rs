@@ -0,0 +1,256 @@
+//! Prometheus metrics for indexer workers, served over HTTP if a metrics
+//! address is given
+
+use std::{convert::Infallible, net::SocketAddr};
+
+use hyper::{
+    header::CONTENT_TYPE,
+    service::{make_service_fn, service_fn},
+    Body, Method, Request, Response, Server, StatusCode,
+};
+use indexer_core::clap;
+use once_cell::sync::Lazy;
+use prometheus::{
+    register_histogram, register_histogram_vec, register_int_counter_vec, register_int_gauge,
+    Encoder, Histogram, HistogramVec, IntCounterVec, IntGauge, TextEncoder,
+};
+
+use crate::{prelude::*, ErrorKind};
+
+/// Arguments for the metrics listener
+#[derive(Debug, Clone, Copy, clap::Args)]
+pub(crate) struct Args {
+    /// Serve Prometheus metrics at `/metrics` on this address
+    #[arg(long, env)]
+    metrics_addr: Option<SocketAddr>,
+}
+
+static MESSAGES: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "indexer_messages_total",
+        "Messages processed, by message type and outcome",
+        &["message", "result"]
+    )
+    .unwrap()
+});
+
+static FAILED_ATTEMPTS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "indexer_message_failures_total",
+        "Failed attempts to process a message, by message type and error kind",
+        &["message", "kind"]
+    )
+    .unwrap()
+});
+
+static DEAD_LETTERED: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "indexer_messages_dead_lettered_total",
+        "Messages rejected to the dead-letter exchange, by message type and error kind",
+        &["message", "kind"]
+    )
+    .unwrap()
+});
+
+static DURATION: Lazy<HistogramVec> = Lazy::new(|| {
+    register_histogram_vec!(
+        "indexer_message_duration_seconds",
+        "Time taken to process a message, including retries, by message type",
+        &["message"]
+    )
+    .unwrap()
+});
+
+static ATTEMPTS: Lazy<HistogramVec> = Lazy::new(|| {
+    register_histogram_vec!(
+        "indexer_message_attempts",
+        "Attempts taken to process or dead-letter a message, by message type",
+        &["message"],
+        vec![1.0, 2.0, 3.0, 5.0, 8.0, 13.0]
+    )
+    .unwrap()
+});
+
+static IN_FLIGHT: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "indexer_messages_in_flight",
+        "Messages currently being processed"
+    )
+    .unwrap()
+});
+
+static POOL_WAIT: Lazy<Histogram> = Lazy::new(|| {
+    register_histogram!(
+        "indexer_db_pool_wait_seconds",
+        "Time spent waiting for a database connection from the pool"
+    )
+    .unwrap()
+});
+
+#[cfg(feature = "geyser")]
+static PROGRAM_MESSAGES: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "indexer_geyser_program_messages_total",
+        "Geyser account updates and instructions, by program and message type",
+        &["program", "message"]
+    )
+    .unwrap()
+});
+
+fn kind_label(kind: ErrorKind) -> &'static str {
+    match kind {
+        ErrorKind::Retryable => "retryable",
+        ErrorKind::Permanent => "permanent",
+    }
+}
+
+/// Record the start of processing a message
+pub(crate) fn message_started() {
+    IN_FLIGHT.inc();
+}
+
+/// Record a failed attempt to process a message
+pub(crate) fn attempt_failed(message: &'static str, kind: ErrorKind) {
+    FAILED_ATTEMPTS
+        .with_label_values(&[message, kind_label(kind)])
+        .inc();
+}
+
+/// Record the outcome of processing a message, after any retries
+pub(crate) fn message_finished(
+    message: &'static str,
+    dead_letter: Option<ErrorKind>,
+    attempts: u32,
+    elapsed: StdDuration,
+) {
+    IN_FLIGHT.dec();
+
+    let result = match dead_letter {
+        None => "ok",
+        Some(kind) => {
+            DEAD_LETTERED
+                .with_label_values(&[message, kind_label(kind)])
+                .inc();
+            "failed"
+        },
+    };
+
+    MESSAGES.with_label_values(&[message, result]).inc();
+    ATTEMPTS
+        .with_label_values(&[message])
+        .observe(attempts.into());
+    DURATION
+        .with_label_values(&[message])
+        .observe(elapsed.as_secs_f64());
+}
+
+/// Record the time taken to acquire a database connection
+pub(crate) fn pool_waited(elapsed: StdDuration) {
+    POOL_WAIT.observe(elapsed.as_secs_f64());
+}
+
+/// Record a Geyser message handled by the given program
+#[cfg(feature = "geyser")]
+pub(crate) fn program_message(program: &'static str, message: &'static str) {
+    PROGRAM_MESSAGES
+        .with_label_values(&[program, message])
+        .inc();
+}
+
+fn render() -> Result<(Vec<u8>, String)> {
+    let encoder = TextEncoder::new();
+    let mut buf = vec![];
+
+    encoder
+        .encode(&prometheus::gather(), &mut buf)
+        .context("Failed to encode metrics")?;
+
+    Ok((buf, encoder.format_type().to_owned()))
+}
+
+async fn handle(req: Request<Body>) -> Result<Response<Body>, Infallible> {
+    let res = if req.method() != Method::GET || req.uri().path() != "/metrics" {
+        Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .body(Body::empty())
+    } else {
+        match render() {
+            Ok((buf, ty)) => Response::builder()
+                .header(CONTENT_TYPE, ty)
+                .body(buf.into()),
+            Err(e) => {
+                error!("{:?}", e);
+
+                Response::builder()
+                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                    .body(Body::empty())
+            },
+        }
+    };
+
+    // Responses built from static parts can't fail
+    Ok(res.unwrap_or_else(|_| Response::new(Body::empty())))
+}
+
+/// Serve metrics at the configured address, if any, until the server fails
+///
+/// # Errors
+/// This function fails if the address cannot be bound or the server fails.
+pub(crate) async fn serve(args: Args) -> Result<()> {
+    let Args { metrics_addr } = args;
+
+    let addr = match metrics_addr {
+        Some(a) => a,
+        None => return Ok(()),
+    };
+
+    let server = Server::try_bind(&addr)
+        .with_context(|| format!("Failed to bind metrics listener to {addr}"))?
+        .serve(make_service_fn(|_| async {
+            Ok::<_, Infallible>(service_fn(handle))
+        }));
+
+    info!("Serving metrics on http://{}/metrics", addr);
+
+    server.await.context("Metrics server failed")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_metrics_endpoint() {
+        message_started();
+        attempt_failed("test_message", ErrorKind::Retryable);
+        message_finished(
+            "test_message",
+            Some(ErrorKind::Permanent),
+            2,
+            StdDuration::from_millis(5),
+        );
+
+        let res = handle(Request::get("/metrics").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::OK);
+
+        let body = hyper::body::to_bytes(res.into_body()).await.unwrap();
+        let body = std::str::from_utf8(&body).unwrap();
+
+        for line in [
+            r#"indexer_messages_total{message="test_message",result="failed"} 1"#,
+            r#"indexer_message_failures_total{kind="retryable",message="test_message"} 1"#,
+            r#"indexer_messages_dead_lettered_total{kind="permanent",message="test_message"} 1"#,
+            r#"indexer_message_attempts_sum{message="test_message"} 2"#,
+            r#"indexer_message_duration_seconds_count{message="test_message"} 1"#,
+        ] {
+            assert!(body.contains(line), "{line} missing from:\n{body}");
+        }
+
+        let res = handle(Request::get("/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -37,6 +37,15 @@ impl fmt::Display for MessageId {
     }
 }
 
+impl MessageLabel for Message {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Upsert { .. } => "upsert",
+            Self::IndirectMetadata { .. } => "indirect_metadata",
+        }
+    }
+}
+
 /// A schemaless Meilisearch document
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub struct Document {
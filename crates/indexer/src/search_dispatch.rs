@@ -52,7 +52,7 @@ pub struct Args {
 #[allow(missing_docs)]
 #[derive(Debug)]
 pub struct Client {
-    producer: Producer,
+    producer: Option<Producer>,
     backfill: bool,
     meili_client: meilisearch::client::Client,
 }
@@ -72,14 +72,32 @@ impl Client {
         }: Args,
     ) -> Result<Self> {
         Ok(Self {
-            producer: Producer::new(conn, queue)
-                .await
-                .context("Couldn't create AMQP search producer")?,
+            producer: Some(
+                Producer::new(conn, queue)
+                    .await
+                    .context("Couldn't create AMQP search producer")?,
+            ),
             backfill: backfill_search,
             meili_client: search.into_client(),
         })
     }
 
+    /// Creates a ``search_dispatch`` client which discards all upserts, for
+    /// running without an AMQP server
+    #[must_use]
+    pub fn offline(
+        Args {
+            backfill_search,
+            search,
+        }: Args,
+    ) -> Self {
+        Self {
+            producer: None,
+            backfill: backfill_search,
+            meili_client: search.into_client(),
+        }
+    }
+
     /// Gets a document using the id
     ///
     /// # Errors
@@ -102,11 +120,12 @@ impl Client {
         id: impl std::fmt::Display,
         body: impl Serialize,
     ) -> Result<()> {
-        if is_for_backfill && !self.backfill {
-            return Ok(());
-        }
+        let producer = match self.producer {
+            Some(ref p) if !is_for_backfill || self.backfill => p,
+            _ => return Ok(()),
+        };
 
-        self.producer
+        producer
             .write(Message::Upsert {
                 index: index.to_owned(),
                 document: Document {
@@ -125,11 +144,12 @@ impl Client {
         index: &'static str,
         mint: Pubkey,
     ) -> Result<()> {
-        if is_for_backfill && !self.backfill {
-            return Ok(());
-        }
+        let producer = match self.producer {
+            Some(ref p) if !is_for_backfill || self.backfill => p,
+            _ => return Ok(()),
+        };
 
-        self.producer
+        producer
             .write(Message::IndirectMetadata {
                 index: index.to_owned(),
                 mint,